@@ -2,13 +2,14 @@ from __future__ import annotations
 
 import importlib
 import logging
+import os
 import typing as t
 import sys
 
 from enum import Enum, auto
 from functools import reduce
 
-from sqlglot import exp
+from sqlglot import exp, serde
 from sqlglot.dialects import DIALECT_MODULE_NAMES
 from sqlglot.errors import ParseError
 from sqlglot.generator import Generator, unsupported_args
@@ -27,6 +28,14 @@ from sqlglot.time import TIMEZONES, format_time, subsecond_precision
 from sqlglot.tokens import Token, Tokenizer, TokenType
 from sqlglot.trie import new_trie
 
+try:
+    from sqlglotrs import parse_select as rs_parse_select  # type: ignore
+
+    # The Rust parser only handles simple SELECTs and is opt-in while it matures
+    USE_RS_PARSER = os.environ.get("SQLGLOTRS_PARSER", "0") == "1"
+except ImportError:
+    USE_RS_PARSER = False
+
 DATE_ADD_OR_DIFF = t.Union[
     exp.DateAdd,
     exp.DateDiff,
@@ -306,6 +315,12 @@ class Dialect(metaclass=_Dialect):
     IDENTIFIERS_CAN_START_WITH_DIGIT = False
     """Whether an unquoted identifier can start with a digit."""
 
+    UNICODE_IDENTIFIERS = False
+    """
+    Whether unquoted identifiers follow Unicode's XID rules past ASCII, so that a non-ASCII char
+    which can't continue an identifier, e.g. an emoji or full-width punctuation, isn't part of one.
+    """
+
     DPIPE_IS_STRING_CONCAT = True
     """Whether the DPIPE token (`||`) is a string concatenation operator."""
 
@@ -362,6 +377,12 @@ class Dialect(metaclass=_Dialect):
     SAFE_DIVISION = False
     """Whether division by zero throws an error (`False`) or returns NULL (`True`)."""
 
+    SUPPORTS_RS_PARSER = True
+    """
+    Whether the queries sqlglotrs' parser supports parse the same in this dialect, so that it can
+    be used when the `SQLGLOTRS_PARSER` environment variable is set to 1.
+    """
+
     CONCAT_COALESCE = False
     """A `NULL` arg in `CONCAT` yields `NULL` by default, but in some dialects it yields an empty string."""
 
@@ -847,9 +868,16 @@ class Dialect(metaclass=_Dialect):
     # Specifies what types a given type can be coerced into
     COERCES_TO: t.Dict[exp.DataType.Type, t.Set[exp.DataType.Type]] = {}
 
-    # Determines the supported Dialect instance settings
+    # Determines the supported Dialect instance settings. The tokenizer keeps control chars and
+    # zero-width ones as part of the tokens around them, unless `control_characters` is set to
+    # "skip" them as white space or to "error" on them, e.g. `dialect="duckdb, control_characters
+    # = skip"`. Those in strings, quoted identifiers and comments are always kept. NUL chars are
+    # scanned as any other char, unless `nul_bytes` is set to "strip" them everywhere or to "error"
+    # on the query.
     SUPPORTED_SETTINGS = {
+        "control_characters",
         "normalization_strategy",
+        "nul_bytes",
         "version",
     }
 
@@ -1055,8 +1083,28 @@ class Dialect(metaclass=_Dialect):
         return path
 
     def parse(self, sql: str, **opts) -> t.List[t.Optional[exp.Expression]]:
+        if USE_RS_PARSER and self.SUPPORTS_RS_PARSER and not opts:
+            expression = self._parse_rs(sql)
+            if expression:
+                return [expression]
         return self.parser(**opts).parse(self.tokenize(sql), sql)
 
+    def _parse_rs(self, sql: str) -> t.Optional[exp.Expression]:
+        """Parses `sql` with the Rust parser, returning None if it isn't a query it supports."""
+        tokenizer = self.tokenizer()
+        if not tokenizer.use_rs_tokenizer or not tokenizer._RS_TOKENIZER:
+            return None
+
+        tree = rs_parse_select(
+            tokenizer._RS_TOKENIZER,
+            sql,
+            tokenizer._rs_dialect_settings,
+            self.NULL_ORDERING,
+            self.TYPED_DIVISION,
+            self.SAFE_DIVISION,
+        )
+        return t.cast(exp.Expression, serde.load(tree)) if tree else None
+
     def parse_into(
         self, expression_type: exp.IntoType, sql: str, **opts
     ) -> t.List[t.Optional[exp.Expression]]:
@@ -164,6 +164,17 @@ class MySQL(Dialect):
     SUPPORTS_SEMI_ANTI_JOIN = False
     SAFE_DIVISION = True
 
+    # Versioned comments, e.g. `/*!40101 SET NAMES utf8 */`, are kept as comments unless the
+    # `execute_versioned_comments` setting is given, in which case the contents of those that the
+    # dialect's version would execute are tokenized as SQL, e.g. with
+    # `dialect="mysql, execute_versioned_comments, version = 5.7"`.
+    #
+    # See also https://dev.mysql.com/doc/refman/8.4/en/comments.html
+    SUPPORTED_SETTINGS = {
+        *Dialect.SUPPORTED_SETTINGS,
+        "execute_versioned_comments",
+    }
+
     # https://prestodb.io/docs/current/functions/datetime.html#mysql-date-functions
     TIME_MAPPING = {
         "%M": "%B",
@@ -188,6 +199,7 @@ class MySQL(Dialect):
         HEX_STRINGS = [("x'", "'"), ("X'", "'"), ("0x", "")]
 
         NESTED_COMMENTS = False
+        VERSIONED_COMMENTS = True
 
         KEYWORDS = {
             **tokens.Tokenizer.KEYWORDS,
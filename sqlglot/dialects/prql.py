@@ -14,6 +14,8 @@ def _select_all(table: exp.Expression) -> t.Optional[exp.Select]:
 
 class PRQL(Dialect):
     DPIPE_IS_STRING_CONCAT = False
+    # PRQL queries aren't SQL
+    SUPPORTS_RS_PARSER = False
 
     class Tokenizer(tokens.Tokenizer):
         IDENTIFIERS = ["`"]
@@ -409,6 +409,8 @@ class TSQL(Dialect):
     CONCAT_COALESCE = True
     NORMALIZATION_STRATEGY = NormalizationStrategy.CASE_INSENSITIVE
     ALTER_TABLE_ADD_REQUIRED_FOR_EACH_COLUMN = False
+    # Projections can be written as alias = expression
+    SUPPORTS_RS_PARSER = False
 
     TIME_FORMAT = "'yyyy-mm-dd hh:mm:ss'"
 
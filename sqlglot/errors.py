@@ -69,6 +69,42 @@ class TokenError(SqlglotError):
     pass
 
 
+class TokenLimitError(TokenError):
+    """
+    Raised when a query goes past one of a tokenizer's limits.
+
+    Args:
+        message: the error message.
+        limit: the name of the limit, i.e. "max_input_bytes" or "max_tokens".
+        value: the limit's value.
+        line: the line the tokenizer reached, which is 1 if the query was rejected up front.
+        col: the column the tokenizer reached, which is 0 if the query was rejected up front.
+        start: the offset of the token the tokenizer reached.
+    """
+
+    def __init__(self, message: str, limit: str, value: int, line: int, col: int, start: int):
+        super().__init__(message)
+        self.limit = limit
+        self.value = value
+        self.line = line
+        self.col = col
+        self.start = start
+
+
+class TokenizerConformanceError(TokenError):
+    """
+    Raised in the Rust tokenizer's conformance mode when it disagrees with the Python tokenizer.
+
+    Args:
+        message: the error message.
+        sql: a snippet of the query, shrunk as far as the tokenizers still disagree on it.
+    """
+
+    def __init__(self, message: str, sql: str):
+        super().__init__(message)
+        self.sql = sql
+
+
 class OptimizeError(SqlglotError):
     pass
 
@@ -3552,10 +3552,23 @@ class Parser(metaclass=_Parser):
         return self.expression(exp.Hint, expressions=hints)
 
     def _parse_hint(self) -> t.Optional[exp.Hint]:
-        if self._match(TokenType.HINT) and self._prev_comments:
+        if not self._match(TokenType.HINT):
+            return None
+
+        if self._prev_comments:
             return exp.maybe_parse(self._prev_comments[0], into=exp.Hint, dialect=self.dialect)
 
-        return None
+        # Tokenizers created with `hint_tokens=True` scan the hint's body up to a HINT_END token
+        start = self._index
+        while self._curr and self._curr.token_type != TokenType.HINT_END:
+            self._advance()
+
+        tokens = self._tokens[start : self._index]
+        self._match(TokenType.HINT_END)
+        if not tokens:
+            return None
+
+        return self.dialect.parser().parse_into(exp.Hint, tokens, self.sql)[0]
 
     def _parse_into(self) -> t.Optional[exp.Into]:
         if not self._match(TokenType.INTO):
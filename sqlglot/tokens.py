@@ -1,15 +1,29 @@
 from __future__ import annotations
 
+import functools
+import gzip
+import heapq
+import json
+import logging
 import os
+import time
 import typing as t
 from enum import auto
-
-from sqlglot.errors import SqlglotError, TokenError
+from types import MappingProxyType
+
+from sqlglot.errors import (
+    SqlglotError,
+    TokenError,
+    TokenizerConformanceError,
+    TokenLimitError,
+)
 from sqlglot.helper import AutoName
-from sqlglot.trie import TrieResult, in_trie, new_trie
+from sqlglot.trie import TrieResult, closest_in_trie, in_trie, new_trie
 
 if t.TYPE_CHECKING:
-    from sqlglot.dialects.dialect import DialectType
+    import pyarrow
+
+    from sqlglot.dialects.dialect import Dialect, DialectType
 
 
 try:
@@ -24,6 +38,14 @@ try:
 except ImportError:
     USE_RS_TOKENIZER = False
 
+# Set to "raise" or "log" to check the Rust tokenizer against the Python one, see `Tokenizer`
+RS_CONFORMANCE = os.environ.get("SQLGLOTRS_CONFORMANCE") or None
+
+logger = logging.getLogger("sqlglot")
+
+# The most tokenizations spent shrinking a query the tokenizers disagree on
+_CONFORMANCE_MINIMIZE_ATTEMPTS = 256
+
 
 class TokenType(AutoName):
     L_PAREN = auto()
@@ -427,16 +449,64 @@ class TokenType(AutoName):
     NAMESPACE = auto()
     EXPORT = auto()
 
+    # emitted by sqlglotrs' lenient tokenizer for input it can't scan
+    ERROR = auto()
+
+    # emitted for comments by tokenizers created with `comment_tokens=True`
+    STANDALONE_COMMENT = auto()
+
+    # closes the hints whose bodies are scanned as tokens by tokenizers created with
+    # `hint_tokens=True`
+    HINT_END = auto()
+
     # sentinel
     HIVE_TOKEN_STREAM = auto()
 
+    @classmethod
+    def from_int(cls, token_type_id: int) -> TokenType:
+        """Returns the builtin token type identified by `token_type_id` (see `to_int`)."""
+        if 0 <= token_type_id < len(_ALL_TOKEN_TYPES):
+            return _ALL_TOKEN_TYPES[token_type_id]
+        if CUSTOM_TOKEN_TYPE_START <= token_type_id <= MAX_TOKEN_TYPE_ID:
+            raise ValueError(f"Token type id {token_type_id} is reserved for custom token types")
+        raise ValueError(f"Unknown token type id {token_type_id}")
+
+    def to_int(self) -> int:
+        """
+        Returns the integer id used for this token type by sqlglotrs and in serialized token
+        streams. Builtin token types are numbered by declaration order, so ids are only stable
+        within a given sqlglot release.
+        """
+        return _TOKEN_TYPE_TO_INDEX[self]
+
+
+# Token type ids are 16-bit integers: ids below CUSTOM_TOKEN_TYPE_START are reserved for the
+# builtin members of TokenType, while the remaining ids are never assigned to a builtin member
+# and can be used for custom token types, e.g. by FFI consumers that build their own settings.
+CUSTOM_TOKEN_TYPE_START = 1 << 15
+MAX_TOKEN_TYPE_ID = (1 << 16) - 1
 
 _ALL_TOKEN_TYPES = list(TokenType)
 _TOKEN_TYPE_TO_INDEX = {token_type: i for i, token_type in enumerate(_ALL_TOKEN_TYPES)}
+_TOKEN_TYPE_NAMES = [token_type.name for token_type in _ALL_TOKEN_TYPES]
 
 
 class Token:
-    __slots__ = ("token_type", "text", "line", "col", "start", "end", "comments")
+    __slots__ = (
+        "token_type",
+        "text",
+        "line",
+        "col",
+        "start",
+        "end",
+        "comments",
+        "prefix",
+        "start_line",
+        "start_column",
+        "start_byte",
+        "end_byte",
+        "_extras",
+    )
 
     @classmethod
     def number(cls, number: int) -> Token:
@@ -467,17 +537,29 @@ class Token:
         start: int = 0,
         end: int = 0,
         comments: t.Optional[t.List[str]] = None,
+        prefix: str = "",
+        start_line: int = 1,
+        start_column: int = 1,
+        start_byte: int = 0,
+        end_byte: int = 0,
     ) -> None:
         """Token initializer.
 
         Args:
             token_type: The TokenType Enum.
             text: The text of the token.
-            line: The line that the token ends on.
-            col: The column that the token ends on.
+            line: The line that the token ends on, also available as `end_line`.
+            col: The column that the token ends on, also available as `end_column`.
             start: The start index of the token.
             end: The ending index of the token.
             comments: The comments to attach to the token.
+            prefix: The chars written before a string's opening quote, e.g. `N` for `N'abc'`, as
+                they were cased, so that generators can write the string as it was.
+            start_line: The line that the token starts on.
+            start_column: The column that the token starts on.
+            start_byte: The offset of the token in the query's UTF-8.
+            end_byte: The offset right after the token in the query's UTF-8, which unlike `end`
+                is exclusive.
         """
         self.token_type = token_type
         self.text = text
@@ -486,12 +568,353 @@ class Token:
         self.start = start
         self.end = end
         self.comments = [] if comments is None else comments
+        self.prefix = prefix
+        self.start_line = start_line
+        self.start_column = start_column
+        self.start_byte = start_byte
+        self.end_byte = end_byte
+        self._extras: t.Optional[t.Dict[str, t.Any]] = None
+
+    @property
+    def end_line(self) -> int:
+        """The line of the token's last char, i.e. `line`, which pairs with `start_line`."""
+        return self.line
+
+    @property
+    def end_column(self) -> int:
+        """The column of the token's last char, i.e. `col`."""
+        return self.col
+
+    @property
+    def extras(self) -> t.Dict[str, t.Any]:
+        """Arbitrary annotations attached by downstream passes, created on first access."""
+        if self._extras is None:
+            self._extras = {}
+        return self._extras
+
+    @extras.setter
+    def extras(self, extras: t.Optional[t.Dict[str, t.Any]]) -> None:
+        self._extras = extras
+
+    def freeze(self) -> FrozenToken:
+        """
+        Returns an immutable, hashable copy of this token, with a read-only copy of its `extras`,
+        which isn't hashed or compared.
+        """
+        return FrozenToken(self)
 
     def __repr__(self) -> str:
-        attributes = ", ".join(f"{k}: {getattr(self, k)}" for k in self.__slots__)
+        # The prefix is left out, as only strings have one, and so are the extras
+        attributes = ", ".join(f"{k}: {getattr(self, k)}" for k in _TOKEN_REPR_ATTRIBUTES)
         return f"<Token {attributes}>"
 
 
+_TOKEN_REPR_ATTRIBUTES = ("token_type", "text", "line", "col", "start", "end", "comments")
+
+# The positions frozen tokens carry besides those above, which only depend on them and the query
+_TOKEN_POSITION_ATTRIBUTES = ("start_line", "start_column", "start_byte", "end_byte")
+
+
+class FrozenToken:
+    """
+    A read-only counterpart of `Token` whose hash is computed once at construction, so frozen
+    tokens can be shared and used as cache keys without defensive copies. See `Token.freeze`.
+    """
+
+    __slots__ = (*_TOKEN_REPR_ATTRIBUTES, *_TOKEN_POSITION_ATTRIBUTES, "extras", "_hash")
+
+    token_type: TokenType
+    text: str
+    line: int
+    col: int
+    start: int
+    end: int
+    comments: t.Tuple[str, ...]
+    start_line: int
+    start_column: int
+    start_byte: int
+    end_byte: int
+    extras: t.Mapping[str, t.Any]
+
+    def __init__(self, token: Token) -> None:
+        for name in (*_TOKEN_REPR_ATTRIBUTES, *_TOKEN_POSITION_ATTRIBUTES):
+            object.__setattr__(self, name, getattr(token, name))
+        object.__setattr__(self, "comments", tuple(token.comments))
+        object.__setattr__(self, "extras", MappingProxyType(dict(token._extras or {})))
+        object.__setattr__(self, "_hash", hash(self._key()))
+
+    def _key(self) -> t.Tuple[t.Any, ...]:
+        return tuple(getattr(self, name) for name in _TOKEN_REPR_ATTRIBUTES)
+
+    def __setattr__(self, name: str, value: t.Any) -> None:
+        raise AttributeError(f"FrozenToken attribute '{name}' is read-only")
+
+    def __delattr__(self, name: str) -> None:
+        raise AttributeError(f"FrozenToken attribute '{name}' is read-only")
+
+    @property
+    def end_line(self) -> int:
+        return self.line
+
+    @property
+    def end_column(self) -> int:
+        return self.col
+
+    def __hash__(self) -> int:
+        return self._hash
+
+    def __eq__(self, other: object) -> bool:
+        return (
+            isinstance(other, FrozenToken)
+            and self._hash == other._hash
+            and self._key() == other._key()
+        )
+
+    def __repr__(self) -> str:
+        attributes = ", ".join(f"{k}: {getattr(self, k)}" for k in _TOKEN_REPR_ATTRIBUTES)
+        return f"<FrozenToken {attributes}>"
+
+
+def _conformance_key(tokens: t.List[Token], error: t.Optional[TokenError]) -> t.Tuple[t.Any, ...]:
+    """
+    What the tokenizers have to agree on: the tokens if the query could be tokenized, and the kind
+    of error otherwise, since the error messages and the tokens before an error aren't the same.
+    """
+    if error is not None:
+        return (type(error),)
+    return tuple(
+        (
+            token.token_type,
+            token.text,
+            token.line,
+            token.col,
+            token.start_line,
+            token.start_column,
+            token.start,
+            token.end,
+            token.start_byte,
+            token.end_byte,
+            token.comments,
+            token.prefix,
+        )
+        for token in tokens
+    )
+
+
+def _conformance_describe(tokens: t.List[Token], error: t.Optional[TokenError]) -> str:
+    if error is not None:
+        return f"{type(error).__name__}({str(error)!r})"
+    return repr([(token.token_type.name, token.text, token.start, token.end) for token in tokens])
+
+
+def _builtin_dialect_name(module: str, name: str) -> t.Optional[str]:
+    """
+    Returns the name of the builtin dialect that a class was defined for, given its module and the
+    name of the dialect class, e.g. "snowflake" for `Snowflake` or `Snowflake.Tokenizer`, which is
+    what the presets embedded in sqlglotrs are named after. The default dialect's name is "".
+    """
+    package, _, dialect = module.rpartition(".")
+    if package != "sqlglot.dialects" or name.lower() != dialect:
+        return None
+    return "" if dialect == "dialect" else dialect
+
+
+@functools.lru_cache(maxsize=None)
+def _keyword_dictionary(klass: t.Type[Tokenizer]) -> t.Dict:
+    """The trie of a tokenizer class' keywords that start like a word, see `suggest_keyword`."""
+    return new_trie(key for key in klass.KEYWORDS if key[0].isalpha() or key[0] == "_")
+
+
+def _rs_tokenizer_settings(klass: t.Type[Tokenizer]) -> t.Dict[str, t.Any]:
+    """The arguments of sqlglotrs' `TokenizerSettings` for a tokenizer class."""
+    return dict(
+        white_space={k: _TOKEN_TYPE_TO_INDEX[v] for k, v in klass.WHITE_SPACE.items()},
+        single_tokens={k: _TOKEN_TYPE_TO_INDEX[v] for k, v in klass.SINGLE_TOKENS.items()},
+        keywords={k: _TOKEN_TYPE_TO_INDEX[v] for k, v in klass.KEYWORDS.items()},
+        numeric_literals=klass.NUMERIC_LITERALS,
+        identifiers=klass._IDENTIFIERS,
+        identifier_escapes=klass._IDENTIFIER_ESCAPES,
+        string_escapes=klass._STRING_ESCAPES,
+        quotes=klass._QUOTES,
+        format_strings={
+            k: (v1, _TOKEN_TYPE_TO_INDEX[v2]) for k, (v1, v2) in klass._FORMAT_STRINGS.items()
+        },
+        verbatim_strings=klass.VERBATIM_STRINGS,
+        has_bit_strings=bool(klass.BIT_STRINGS),
+        has_hex_strings=bool(klass.HEX_STRINGS),
+        comments=klass._COMMENTS,
+        var_single_tokens=klass.VAR_SINGLE_TOKENS,
+        placeholder_styles=klass.PLACEHOLDER_STYLES,
+        commands={_TOKEN_TYPE_TO_INDEX[v] for v in klass.COMMANDS},
+        command_prefix_tokens={_TOKEN_TYPE_TO_INDEX[v] for v in klass.COMMAND_PREFIX_TOKENS},
+        heredoc_tag_is_identifier=klass.HEREDOC_TAG_IS_IDENTIFIER,
+        string_escapes_allowed_in_raw_strings=klass.STRING_ESCAPES_ALLOWED_IN_RAW_STRINGS,
+        nested_comments=klass.NESTED_COMMENTS,
+        hint_start=klass.HINT_START,
+        tokens_preceding_hint={_TOKEN_TYPE_TO_INDEX[v] for v in klass.TOKENS_PRECEDING_HINT},
+    )
+
+
+def _rs_token_type_settings(klass: t.Type[Tokenizer]) -> t.Dict[str, t.Any]:
+    """The arguments of sqlglotrs' `TokenTypeSettings` for a tokenizer class."""
+    return dict(
+        bit_string=_TOKEN_TYPE_TO_INDEX[TokenType.BIT_STRING],
+        break_=_TOKEN_TYPE_TO_INDEX[TokenType.BREAK],
+        dcolon=_TOKEN_TYPE_TO_INDEX[TokenType.DCOLON],
+        heredoc_string=_TOKEN_TYPE_TO_INDEX[TokenType.HEREDOC_STRING],
+        raw_string=_TOKEN_TYPE_TO_INDEX[TokenType.RAW_STRING],
+        hex_string=_TOKEN_TYPE_TO_INDEX[TokenType.HEX_STRING],
+        identifier=_TOKEN_TYPE_TO_INDEX[TokenType.IDENTIFIER],
+        number=_TOKEN_TYPE_TO_INDEX[TokenType.NUMBER],
+        parameter=_TOKEN_TYPE_TO_INDEX[TokenType.PARAMETER],
+        semicolon=_TOKEN_TYPE_TO_INDEX[TokenType.SEMICOLON],
+        string=_TOKEN_TYPE_TO_INDEX[TokenType.STRING],
+        var=_TOKEN_TYPE_TO_INDEX[TokenType.VAR],
+        heredoc_string_alternative=_TOKEN_TYPE_TO_INDEX[klass.HEREDOC_STRING_ALTERNATIVE],
+        hint=_TOKEN_TYPE_TO_INDEX[TokenType.HINT],
+        error=_TOKEN_TYPE_TO_INDEX[TokenType.ERROR],
+        comment=_TOKEN_TYPE_TO_INDEX[TokenType.STANDALONE_COMMENT],
+        hint_end=_TOKEN_TYPE_TO_INDEX[TokenType.HINT_END],
+        placeholder=_TOKEN_TYPE_TO_INDEX[TokenType.PLACEHOLDER],
+    )
+
+
+# The limits of the sqlglotrs errors that are raised as `TokenLimitError`s, by their kind
+_RS_LIMIT_ERRORS = {"input_too_large": "max_input_bytes", "too_many_tokens": "max_tokens"}
+
+
+def _versioned_comments_version(dialect: Dialect) -> t.Optional[int]:
+    """The version whose versioned comments are tokenized as SQL, or None to keep them all."""
+    if dialect.tokenizer_class.VERSIONED_COMMENTS and dialect.settings.get(
+        "execute_versioned_comments"
+    ):
+        return dialect.version
+    return None
+
+
+# The invisible formatting chars that the `control_characters` setting applies to, on top of the
+# control chars, i.e. soft hyphens, zero-width chars, bidirectional marks and byte order marks
+_FORMAT_CHARS = frozenset(
+    chr(code)
+    for start, end in (
+        (0xAD, 0xAD),
+        (0x61C, 0x61C),
+        (0x180E, 0x180E),
+        (0x200B, 0x200F),
+        (0x202A, 0x202E),
+        (0x2060, 0x2064),
+        (0x2066, 0x206F),
+        (0xFEFF, 0xFEFF),
+    )
+    for code in range(start, end + 1)
+)
+
+_CONTROL_CHARACTERS_POLICIES = ("keep", "skip", "error")
+
+
+def _control_characters(dialect: Dialect) -> str:
+    """
+    What to do with the control and zero-width chars where a token could start, as set by the
+    dialect's `control_characters` setting: "keep" them, "skip" them or "error" on them.
+    """
+    policy = str(dialect.settings.get("control_characters", "keep")).lower()
+    if policy not in _CONTROL_CHARACTERS_POLICIES:
+        raise ValueError(
+            f"Unknown control_characters policy '{policy}', expected one of "
+            + ", ".join(_CONTROL_CHARACTERS_POLICIES)
+        )
+    return policy
+
+
+def _is_control_char(char: str) -> bool:
+    return char < " " or "\x7f" <= char <= "\x9f" or char in _FORMAT_CHARS
+
+
+_NUL_BYTES_POLICIES = ("text", "strip", "error")
+
+# The codecs whose code units columns are counted in, and their size in bytes, for each position
+# encoding but "utf32", whose columns are counted in chars
+_POSITION_ENCODING_CODECS = {"utf8": ("utf-8", 1), "utf16": ("utf-16-le", 2)}
+
+
+def _nul_bytes(dialect: Dialect) -> str:
+    """
+    What to do with the NUL chars of a query, as set by the dialect's `nul_bytes` setting: scan
+    them as any other char ("text"), "strip" them or "error" on the query.
+    """
+    policy = str(dialect.settings.get("nul_bytes", "text")).lower()
+    if policy not in _NUL_BYTES_POLICIES:
+        raise ValueError(
+            f"Unknown nul_bytes policy '{policy}', expected one of "
+            + ", ".join(_NUL_BYTES_POLICIES)
+        )
+    return policy
+
+
+def _is_identifier_char(char: str) -> bool:
+    """Whether a char can be part of an unquoted identifier with `UNICODE_IDENTIFIERS`."""
+    return char.isascii() or f"_{char}".isidentifier()
+
+
+def _word_end(sql: str, i: int, digits: bool = False) -> int:
+    """The end of the run of ASCII word chars, or of ASCII digits, that starts at index i of sql."""
+    while i < len(sql) and sql[i].isascii() and (
+        sql[i].isdigit() if digits else sql[i].isalnum() or sql[i] == "_"
+    ):
+        i += 1
+    return i
+
+
+def _placeholder_end(sql: str, start: int, styles: t.Collection[str]) -> int:
+    """The end of the placeholder of one of the styles that starts at index start of sql, or 0.
+
+    A placeholder can't follow its own sigil, e.g. the second `:` of `::name`, nor run into a word.
+    """
+    sigil = sql[start]
+    if start and sql[start - 1] == sigil:
+        return 0
+
+    i = start + 1
+    char = sql[i : i + 1]
+    is_name_start = char.isascii() and (char.isalpha() or char == "_")
+    style, end = None, 0
+
+    if sigil in ":$":
+        style = "numeric" if sigil == ":" else "dollar"
+        end = _word_end(sql, i, digits=True)
+        if end == i and sigil == ":" and is_name_start:
+            style, end = "named", _word_end(sql, i)
+    elif sigil == "@" and is_name_start:
+        style, end = "at", _word_end(sql, i)
+    elif sigil == "{" and is_name_start:
+        name_end = _word_end(sql, i)
+        if sql.startswith("}", name_end):
+            style, end = "brace", name_end + 1
+    elif sigil == "%" and char == "s":
+        style, end = "format", i + 1
+    elif sigil == "%" and char == "(":
+        name_end = _word_end(sql, i + 1)
+        if name_end > i + 1 and sql.startswith(")s", name_end):
+            style, end = "pyformat", name_end + 2
+
+    if end <= i or style not in styles or _word_end(sql, end) > end:
+        return 0
+    return end
+
+
+def _rs_dialect_settings(dialect: Dialect) -> t.Dict[str, t.Any]:
+    """The arguments of sqlglotrs' `TokenizerDialectSettings` for a dialect."""
+    return dict(
+        unescaped_sequences=dialect.UNESCAPED_SEQUENCES,
+        identifiers_can_start_with_digit=dialect.IDENTIFIERS_CAN_START_WITH_DIGIT,
+        numbers_can_be_underscore_separated=dialect.NUMBERS_CAN_BE_UNDERSCORE_SEPARATED,
+        unicode_identifiers=dialect.UNICODE_IDENTIFIERS,
+        versioned_comments_version=_versioned_comments_version(dialect),
+        control_characters=_control_characters(dialect),
+        nul_bytes=_nul_bytes(dialect),
+    )
+
+
 class _Tokenizer(type):
     def __new__(cls, clsname, bases, attrs):
         klass = super().__new__(cls, clsname, bases, attrs)
@@ -546,53 +969,35 @@ class _Tokenizer(type):
             if " " in key or any(single in key for single in klass.SINGLE_TOKENS)
         )
 
+        qualname = attrs.get("__qualname__")
+        if qualname is None:
+            # Dialects that don't define a tokenizer get an empty subclass of their base's one, see
+            # `_Dialect`, which has the same settings and can therefore share its preset
+            klass._RS_PRESET = bases[0]._RS_PRESET if len(bases) == 1 else None
+        elif not bases:
+            klass._RS_PRESET = ""
+        elif qualname.endswith(".Tokenizer"):
+            dialect = qualname[: -len(".Tokenizer")]
+            klass._RS_PRESET = _builtin_dialect_name(attrs["__module__"], dialect)
+        else:
+            klass._RS_PRESET = None
+
         if USE_RS_TOKENIZER:
-            settings = RsTokenizerSettings(
-                white_space={k: _TOKEN_TYPE_TO_INDEX[v] for k, v in klass.WHITE_SPACE.items()},
-                single_tokens={k: _TOKEN_TYPE_TO_INDEX[v] for k, v in klass.SINGLE_TOKENS.items()},
-                keywords={k: _TOKEN_TYPE_TO_INDEX[v] for k, v in klass.KEYWORDS.items()},
-                numeric_literals=klass.NUMERIC_LITERALS,
-                identifiers=klass._IDENTIFIERS,
-                identifier_escapes=klass._IDENTIFIER_ESCAPES,
-                string_escapes=klass._STRING_ESCAPES,
-                quotes=klass._QUOTES,
-                format_strings={
-                    k: (v1, _TOKEN_TYPE_TO_INDEX[v2])
-                    for k, (v1, v2) in klass._FORMAT_STRINGS.items()
-                },
-                has_bit_strings=bool(klass.BIT_STRINGS),
-                has_hex_strings=bool(klass.HEX_STRINGS),
-                comments=klass._COMMENTS,
-                var_single_tokens=klass.VAR_SINGLE_TOKENS,
-                commands={_TOKEN_TYPE_TO_INDEX[v] for v in klass.COMMANDS},
-                command_prefix_tokens={
-                    _TOKEN_TYPE_TO_INDEX[v] for v in klass.COMMAND_PREFIX_TOKENS
-                },
-                heredoc_tag_is_identifier=klass.HEREDOC_TAG_IS_IDENTIFIER,
-                string_escapes_allowed_in_raw_strings=klass.STRING_ESCAPES_ALLOWED_IN_RAW_STRINGS,
-                nested_comments=klass.NESTED_COMMENTS,
-                hint_start=klass.HINT_START,
-                tokens_preceding_hint={
-                    _TOKEN_TYPE_TO_INDEX[v] for v in klass.TOKENS_PRECEDING_HINT
-                },
-            )
-            token_types = RsTokenTypeSettings(
-                bit_string=_TOKEN_TYPE_TO_INDEX[TokenType.BIT_STRING],
-                break_=_TOKEN_TYPE_TO_INDEX[TokenType.BREAK],
-                dcolon=_TOKEN_TYPE_TO_INDEX[TokenType.DCOLON],
-                heredoc_string=_TOKEN_TYPE_TO_INDEX[TokenType.HEREDOC_STRING],
-                raw_string=_TOKEN_TYPE_TO_INDEX[TokenType.RAW_STRING],
-                hex_string=_TOKEN_TYPE_TO_INDEX[TokenType.HEX_STRING],
-                identifier=_TOKEN_TYPE_TO_INDEX[TokenType.IDENTIFIER],
-                number=_TOKEN_TYPE_TO_INDEX[TokenType.NUMBER],
-                parameter=_TOKEN_TYPE_TO_INDEX[TokenType.PARAMETER],
-                semicolon=_TOKEN_TYPE_TO_INDEX[TokenType.SEMICOLON],
-                string=_TOKEN_TYPE_TO_INDEX[TokenType.STRING],
-                var=_TOKEN_TYPE_TO_INDEX[TokenType.VAR],
-                heredoc_string_alternative=_TOKEN_TYPE_TO_INDEX[klass.HEREDOC_STRING_ALTERNATIVE],
-                hint=_TOKEN_TYPE_TO_INDEX[TokenType.HINT],
-            )
-            klass._RS_TOKENIZER = RsTokenizer(settings, token_types)
+            klass._RS_TOKENIZER = None
+            if klass._RS_PRESET is not None:
+                # The settings of the builtin dialects are embedded in sqlglotrs, which saves
+                # converting them, unless it was built without them or the dialect has none
+                try:
+                    klass._RS_TOKENIZER = RsTokenizer.for_dialect(klass._RS_PRESET)
+                except ValueError:
+                    pass
+            if klass._RS_TOKENIZER is None:
+                # Tokenizers that end up with the same settings share their keyword automaton,
+                # e.g. those that only override methods of the Python tokenizer
+                klass._RS_TOKENIZER = RsTokenizer.cached(
+                    RsTokenizerSettings(**_rs_tokenizer_settings(klass)),
+                    RsTokenTypeSettings(**_rs_token_type_settings(klass)),
+                )
         else:
             klass._RS_TOKENIZER = None
 
@@ -650,7 +1055,8 @@ class Tokenizer(metaclass=_Tokenizer):
     # identifier escape, e.g. if we use double-quotes, then they also act as escapes: "x"""
     IDENTIFIER_ESCAPES: t.List[str] = []
 
-    # Whether the heredoc tags follow the same lexical rules as unquoted identifiers
+    # Whether the heredoc tags follow the same lexical rules as unquoted identifiers, in which case
+    # a heredoc prefix without such a tag, e.g. Postgres' `$1`, is a HEREDOC_STRING_ALTERNATIVE
     HEREDOC_TAG_IS_IDENTIFIER = False
 
     # Token that we'll generate as a fallback if the heredoc prefix doesn't correspond to a heredoc
@@ -659,8 +1065,21 @@ class Tokenizer(metaclass=_Tokenizer):
     # Whether string escape characters function as such when placed within raw strings
     STRING_ESCAPES_ALLOWED_IN_RAW_STRINGS = True
 
+    # The opening delimiters of the format strings whose contents are taken as written, with no
+    # escapes or unescaped sequences, so that they end at the first closing delimiter, e.g. `r'`
+    VERBATIM_STRINGS: t.Set[str] = set()
+
+    # The placeholder styles whose placeholders are scanned as single PLACEHOLDER tokens, out of
+    # `numeric` (`:1`), `named` (`:name`), `format` (`%s`), `pyformat` (`%(name)s`),
+    # `dollar` (`$1`), `at` (`@name`) and `brace` (`{name}`)
+    PLACEHOLDER_STYLES: t.Set[str] = set()
+
     NESTED_COMMENTS = True
 
+    # Whether `/*!` comments are versioned, i.e. their contents are executed by servers whose
+    # version is at least the one they may start with, e.g. `/*!40101 ... */` from 4.1.1 on
+    VERSIONED_COMMENTS = False
+
     HINT_START = "/*+"
 
     TOKENS_PRECEDING_HINT = {TokenType.SELECT, TokenType.INSERT, TokenType.UPDATE, TokenType.DELETE}
@@ -674,6 +1093,7 @@ class Tokenizer(metaclass=_Tokenizer):
     _STRING_ESCAPES: t.Set[str] = set()
     _KEYWORD_TRIE: t.Dict = {}
     _RS_TOKENIZER: t.Optional[t.Any] = None
+    _RS_PRESET: t.Optional[str] = None
 
     KEYWORDS: t.Dict[str, TokenType] = {
         **{f"{{%{postfix}": TokenType.BLOCK_START for postfix in ("", "+", "-")},
@@ -1005,6 +1425,9 @@ class Tokenizer(metaclass=_Tokenizer):
         "dialect",
         "use_rs_tokenizer",
         "_start",
+        "_start_line",
+        "_start_col",
+        "_byte_cursor",
         "_current",
         "_line",
         "_col",
@@ -1014,17 +1437,80 @@ class Tokenizer(metaclass=_Tokenizer):
         "_peek",
         "_prev_token_line",
         "_rs_dialect_settings",
+        "_versioned_comments_version",
+        "_in_versioned_comment",
+        "_in_hint",
+        "_control_characters",
+        "_nul_bytes",
+        "_unescaped_sequences",
+        "_unescaped_sequence_size",
+        "max_input_bytes",
+        "max_tokens",
+        "comment_tokens",
+        "_comment_tokens",
+        "hint_tokens",
+        "position_encoding",
+        "conformance",
     )
 
     def __init__(
         self,
         dialect: DialectType = None,
         use_rs_tokenizer: t.Optional[bool] = None,
+        max_input_bytes: t.Optional[int] = None,
+        max_tokens: t.Optional[int] = None,
+        comment_tokens: bool = False,
+        hint_tokens: bool = False,
+        conformance: t.Optional[str] = None,
+        position_encoding: str = "utf32",
         **opts: t.Any,
     ) -> None:
+        """
+        Args:
+            dialect: the dialect whose SQL is tokenized.
+            use_rs_tokenizer: whether to tokenize with sqlglotrs, which defaults to whether it's
+                installed.
+            max_input_bytes: the most bytes of UTF-8 a query can have, past which tokenizing it
+                raises a `TokenLimitError` before anything is scanned, e.g. for untrusted input.
+            max_tokens: the most tokens a query can have, past which tokenizing it raises a
+                `TokenLimitError` at the first token too many.
+            comment_tokens: whether comments are emitted as `STANDALONE_COMMENT` tokens where they
+                were written, e.g. for linters, rather than being attached to the tokens around
+                them. Hints are still `HINT` tokens and the other tokens are the same either way.
+            hint_tokens: whether the bodies of hints are scanned as tokens between their `HINT`
+                token, whose text is the hint's start, e.g. `/*+`, and a `HINT_END` one, rather
+                than being kept as the `HINT` token's comment, so that they needn't be tokenized
+                again to be parsed.
+            conformance: "raise" or "log" to also tokenize with the Python tokenizer whenever the
+                Rust one tokenizes, e.g. as a safety net when rolling it out. If they disagree, a
+                `TokenizerConformanceError` with a minimized snippet of the query is raised, or
+                logged as a warning and the Python tokenizer's result is used. Defaults to the
+                `SQLGLOTRS_CONFORMANCE` environment variable, and only applies to `tokenize`.
+            position_encoding: the units that the columns of tokens are counted in: "utf32" for
+                chars, "utf16" for UTF-16 code units, as LSP and Monaco count them, or "utf8" for
+                bytes. Lines and offsets are counted in lines and chars either way.
+        """
         from sqlglot.dialects import Dialect
 
         self.dialect = Dialect.get_or_raise(dialect)
+        self.max_input_bytes = max_input_bytes
+        self.max_tokens = max_tokens
+        self.comment_tokens = comment_tokens
+        self.hint_tokens = hint_tokens
+        self.conformance = conformance if conformance is not None else RS_CONFORMANCE
+        if self.conformance not in (None, "raise", "log"):
+            raise ValueError(f"conformance must be 'raise' or 'log', not {self.conformance!r}")
+        if position_encoding != "utf32" and position_encoding not in _POSITION_ENCODING_CODECS:
+            raise ValueError(
+                f"Unknown position_encoding '{position_encoding}', expected 'utf8', 'utf16' or "
+                "'utf32'"
+            )
+        self.position_encoding = position_encoding
+        self._versioned_comments_version = _versioned_comments_version(self.dialect)
+        self._control_characters = _control_characters(self.dialect)
+        self._nul_bytes = _nul_bytes(self.dialect)
+        self._unescaped_sequences = self.dialect.UNESCAPED_SEQUENCES
+        self._unescaped_sequence_size = max(map(len, self._unescaped_sequences), default=0)
 
         # initialize `use_rs_tokenizer`, and allow it to be overwritten per Tokenizer instance
         self.use_rs_tokenizer = (
@@ -1032,11 +1518,34 @@ class Tokenizer(metaclass=_Tokenizer):
         )
 
         if self.use_rs_tokenizer:
-            self._rs_dialect_settings = RsTokenizerDialectSettings(
-                unescaped_sequences=self.dialect.UNESCAPED_SEQUENCES,
-                identifiers_can_start_with_digit=self.dialect.IDENTIFIERS_CAN_START_WITH_DIGIT,
-                numbers_can_be_underscore_separated=self.dialect.NUMBERS_CAN_BE_UNDERSCORE_SEPARATED,
-            )
+            dialect_class = type(self.dialect)
+            preset = _builtin_dialect_name(dialect_class.__module__, dialect_class.__qualname__)
+            self._rs_dialect_settings = None
+            # The presets are the settings of dialects as they are by default
+            if (
+                preset is not None
+                and self._versioned_comments_version is None
+                and self._control_characters == "keep"
+                and self._nul_bytes == "text"
+                and max_input_bytes is None
+                and max_tokens is None
+                and not comment_tokens
+                and not hint_tokens
+                and position_encoding == "utf32"
+            ):
+                try:
+                    self._rs_dialect_settings = RsTokenizerDialectSettings.for_dialect(preset)
+                except ValueError:
+                    pass
+            if self._rs_dialect_settings is None:
+                self._rs_dialect_settings = RsTokenizerDialectSettings(
+                    **_rs_dialect_settings(self.dialect),
+                    max_input_bytes=max_input_bytes,
+                    max_tokens=max_tokens,
+                    comment_tokens=comment_tokens,
+                    hint_tokens=hint_tokens,
+                    position_encoding=position_encoding,
+                )
 
         self.reset()
 
@@ -1045,35 +1554,502 @@ class Tokenizer(metaclass=_Tokenizer):
         self.size = 0
         self.tokens: t.List[Token] = []
         self._start = 0
+        self._start_line = 1
+        self._start_col = 0
+        self._byte_cursor: t.Optional[t.Tuple[int, int]] = None
         self._current = 0
         self._line = 1
         self._col = 0
         self._comments: t.List[str] = []
+        self._comment_tokens: t.List[Token] = []
 
         self._char = ""
         self._end = False
         self._peek = ""
         self._prev_token_line = -1
+        self._in_versioned_comment = False
+        self._in_hint = False
 
     def tokenize(self, sql: str) -> t.List[Token]:
         """Returns a list of tokens corresponding to the SQL string `sql`."""
         if self.use_rs_tokenizer:
+            if self.conformance:
+                return self._tokenize_conformance(sql)
             return self.tokenize_rs(sql)
+        return self._tokenize_py(sql)
 
+    def _tokenize_py(self, sql: str) -> t.List[Token]:
         self.reset()
         self.sql = sql
         self.size = len(sql)
+        # The byte offsets of ASCII queries are their char offsets, see `_byte_offset`
+        self._byte_cursor = None if sql.isascii() else (0, 0)
+
+        if self.max_input_bytes is not None:
+            size = len(sql.encode())
+            if size > self.max_input_bytes:
+                raise TokenLimitError(
+                    f"Error tokenizing '{sql[:50]}': The query has {size} bytes, more than "
+                    f"max_input_bytes of {self.max_input_bytes}",
+                    limit="max_input_bytes",
+                    value=self.max_input_bytes,
+                    line=1,
+                    col=0,
+                    start=0,
+                )
+
+        if self._nul_bytes == "error" and "\0" in sql:
+            nul = sql.index("\0")
+            context = sql[max(nul - 50, 0) : nul + 50]
+            raise TokenError(f"Error tokenizing '{context}'") from TokenError(
+                "Unexpected character U+0000"
+            )
+
+        # A byte order mark isn't part of the query, which starts right after it
+        if sql.startswith("\ufeff"):
+            self._current = 1
+            self._end = self.size == 1
 
         try:
             self._scan()
+        except TokenLimitError:
+            raise
         except Exception as e:
             start = max(self._current - 50, 0)
             end = min(self._current + 50, self.size - 1)
             context = self.sql[start:end]
             raise TokenError(f"Error tokenizing '{context}'") from e
 
+        if self._comment_tokens:
+            # Both lists are in the order their tokens were written
+            self.tokens = list(
+                heapq.merge(self.tokens, self._comment_tokens, key=lambda token: token.start)
+            )
         return self.tokens
 
+    def stream(self, sql: str) -> t.Iterator[Token]:
+        """
+        Yields the tokens of `sql` as they're scanned. With the Rust tokenizer, tokens are scanned a
+        batch at a time rather than all being materialized at once.
+        """
+        if not self.use_rs_tokenizer:
+            yield from self.tokenize(sql)
+            return
+
+        if not self._RS_TOKENIZER:
+            raise SqlglotError("Rust tokenizer is not available")
+
+        try:
+            for token in self._RS_TOKENIZER.stream(sql, self._rs_dialect_settings):
+                token.token_type = _ALL_TOKEN_TYPES[token.token_type_index]
+                yield token
+        except ValueError as e:
+            raise self._rs_token_error(getattr(e, "error", None), str(e)) from e
+
+    def tokenize_file(self, path: t.Union[str, os.PathLike]) -> t.Iterator[Token]:
+        """
+        Yields the tokens of the SQL file at `path` like `stream`. With the Rust tokenizer, the
+        file is memory-mapped and decoded and scanned a chunk at a time on the Rust side, so that
+        multi-GB dumps are never read into a str, or into memory at all, first, and `.gz` and
+        `.zst` files are decompressed as they're scanned if sqlglotrs was built with its `gzip`
+        and `zstd` features. The Python tokenizer reads the file as UTF-8, decompressing `.gz`
+        files first.
+        """
+        if not self.use_rs_tokenizer:
+            name = str(path).lower()
+            if name.endswith(".zst"):
+                raise SqlglotError("Reading zstd compressed input requires the Rust tokenizer")
+            with (gzip.open if name.endswith(".gz") else open)(
+                path, "rt", encoding="utf-8"
+            ) as file:
+                sql = file.read()
+            yield from self.tokenize(sql)
+            return
+
+        if not self._RS_TOKENIZER:
+            raise SqlglotError("Rust tokenizer is not available")
+
+        try:
+            for token in self._RS_TOKENIZER.stream_file(path, self._rs_dialect_settings):
+                token.token_type = _ALL_TOKEN_TYPES[token.token_type_index]
+                yield token
+        except ValueError as e:
+            raise self._rs_token_error(getattr(e, "error", None), str(e)) from e
+
+    def tokenize_reader(
+        self, reader: t.Union[t.BinaryIO, t.TextIO], chunk_size: int = 65536
+    ) -> t.Iterator[Token]:
+        """
+        Yields the tokens of the SQL read from `reader`, a file-like object opened in binary or
+        text mode, e.g. a pipe or a socket's file. With the Rust tokenizer, it's read `chunk_size`
+        bytes at a time and only the text since the last complete statement is kept in memory, so
+        that streams of any length can be tokenized. The Python tokenizer reads it all at once.
+        """
+        if chunk_size < 1:
+            raise ValueError("chunk_size must be positive")
+
+        if not self.use_rs_tokenizer:
+            data = reader.read()
+            yield from self.tokenize(data.decode("utf-8") if isinstance(data, bytes) else data)
+            return
+
+        if not self._RS_TOKENIZER:
+            raise SqlglotError("Rust tokenizer is not available")
+
+        try:
+            for token in self._RS_TOKENIZER.stream_reader(
+                reader, self._rs_dialect_settings, chunk_size
+            ):
+                token.token_type = _ALL_TOKEN_TYPES[token.token_type_index]
+                yield token
+        except ValueError as e:
+            # The reader's own errors, e.g. reading a closed file, are raised as they are
+            error = getattr(e, "error", None)
+            if error is None:
+                raise
+            raise self._rs_token_error(error, str(e)) from e
+
+    def tokenize_bytes(
+        self, data: bytes, encoding: str = "utf-8", errors: str = "replace"
+    ) -> t.List[Token]:
+        """
+        Returns the tokens of `data`, decoded like `data.decode(encoding, errors)`. With the Rust
+        tokenizer, UTF-8, Latin-1 and UTF-16 are decoded on the Rust side, so that huge dumps in
+        any of them don't have to be held both as bytes and as a str. Token positions are those
+        of the decoded text.
+        """
+        if not self.use_rs_tokenizer or self.conformance:
+            return self.tokenize(data.decode(encoding, errors))
+
+        if not self._RS_TOKENIZER:
+            raise SqlglotError("Rust tokenizer is not available")
+
+        tokens, error = self._RS_TOKENIZER.tokenize_bytes(
+            data, self._rs_dialect_settings, encoding, errors
+        )
+        for token in tokens:
+            token.token_type = _ALL_TOKEN_TYPES[token.token_type_index]
+
+        self.tokens = tokens
+        if error is not None:
+            raise self._rs_token_error(error)
+
+        return tokens
+
+    def iter_tokens(self, sql: str, chunk_size: int = 1024) -> t.Iterator[t.List[Token]]:
+        """
+        Yields the tokens of `sql` in lists of `chunk_size`, the last of which may be shorter. With
+        the Rust tokenizer, each chunk is scanned as it's requested without holding the GIL, so
+        that consumers can start before the whole script is scanned.
+        """
+        if chunk_size < 1:
+            raise ValueError("chunk_size must be positive")
+
+        if not self.use_rs_tokenizer:
+            tokens = self.tokenize(sql)
+            for i in range(0, len(tokens), chunk_size):
+                yield tokens[i : i + chunk_size]
+            return
+
+        if not self._RS_TOKENIZER:
+            raise SqlglotError("Rust tokenizer is not available")
+
+        try:
+            for chunk in self._RS_TOKENIZER.iter_tokens(sql, self._rs_dialect_settings, chunk_size):
+                for token in chunk:
+                    token.token_type = _ALL_TOKEN_TYPES[token.token_type_index]
+                yield chunk
+        except ValueError as e:
+            raise self._rs_token_error(getattr(e, "error", None), str(e)) from e
+
+    def tokenize_with_stats(self, sql: str) -> t.Tuple[t.List[Token], t.Dict[str, t.Any]]:
+        """
+        Returns the tokens of `sql` along with counts of the scan, e.g. to compare how dialect
+        settings fare on the same queries: its `elapsed_ns`, the `bytes` scanned and the `tokens`
+        produced. The Rust tokenizer also counts the `keyword_hits` and `keyword_misses` of its
+        keyword automaton and the `allocations` of token texts, which are None otherwise.
+        """
+        if not self.use_rs_tokenizer:
+            started = time.perf_counter_ns()
+            tokens = self.tokenize(sql)
+            return tokens, {
+                "elapsed_ns": time.perf_counter_ns() - started,
+                "bytes": len(sql.encode()),
+                "tokens": len(tokens),
+                "keyword_hits": None,
+                "keyword_misses": None,
+                "allocations": None,
+            }
+
+        if not self._RS_TOKENIZER:
+            raise SqlglotError("Rust tokenizer is not available")
+
+        tokens, error, stats = self._RS_TOKENIZER.tokenize_with_stats(
+            sql, self._rs_dialect_settings
+        )
+        for token in tokens:
+            token.token_type = _ALL_TOKEN_TYPES[token.token_type_index]
+
+        self.tokens = tokens
+        if error is not None:
+            raise self._rs_token_error(error)
+
+        return tokens, stats
+
+    def tokenize_with_trace(self, sql: str) -> t.Tuple[t.List[Token], t.List[t.Dict[str, t.Any]]]:
+        """
+        Returns the tokens of `sql` along with the decisions the Rust tokenizer made scanning it,
+        e.g. to find out why a dialect's settings mis-tokenize a query. Each step is a dict with
+        its `kind`, e.g. "scan_number" or "keyword_matched", the `position` of the token being
+        scanned and a `detail` such as the matched keyword. If the query can't be tokenized, the
+        trace up to the error is the raised `TokenError`'s `trace` attribute.
+        """
+        if not self.use_rs_tokenizer or not self._RS_TOKENIZER:
+            raise SqlglotError("Tracing requires the Rust tokenizer")
+
+        tokens, error, steps = self._RS_TOKENIZER.tokenize_with_trace(
+            sql, self._rs_dialect_settings
+        )
+        for token in tokens:
+            token.token_type = _ALL_TOKEN_TYPES[token.token_type_index]
+        trace = [
+            {"kind": kind, "position": position, "detail": detail}
+            for kind, position, detail in steps
+        ]
+
+        self.tokens = tokens
+        if error is not None:
+            token_error = self._rs_token_error(error)
+            token_error.trace = trace  # type: ignore
+            raise token_error
+
+        return tokens, trace
+
+    def tokenize_to_json(self, sql: str) -> str:
+        """
+        Returns the tokens of `sql` as a JSON array of objects with their type name, text, line,
+        col, start, end and comments. With the Rust tokenizer, the JSON is written without
+        creating any Python tokens.
+        """
+        if not self.use_rs_tokenizer:
+            return json.dumps(
+                [
+                    {
+                        "type": token.token_type.name,
+                        "text": token.text,
+                        "line": token.line,
+                        "col": token.col,
+                        "start": token.start,
+                        "end": token.end,
+                        "comments": token.comments,
+                    }
+                    for token in self.tokenize(sql)
+                ],
+                ensure_ascii=False,
+                separators=(",", ":"),
+            )
+
+        if not self._RS_TOKENIZER:
+            raise SqlglotError("Rust tokenizer is not available")
+
+        try:
+            return self._RS_TOKENIZER.tokenize_to_json(
+                sql, self._rs_dialect_settings, _TOKEN_TYPE_NAMES
+            )
+        except ValueError as e:
+            raise self._rs_token_error(getattr(e, "error", None), str(e)) from e
+
+    def tokenize_to_arrow(self, sql: str) -> pyarrow.RecordBatch:
+        """
+        Returns the tokens of `sql` as a pyarrow RecordBatch with the columns token_type, i.e. the
+        type's name, text, line, col, start and end, which requires pyarrow. With the Rust
+        tokenizer, the columns are built without creating any Python tokens and imported by
+        pyarrow without copying.
+        """
+        import pyarrow as pa
+
+        if not self.use_rs_tokenizer:
+            tokens = self.tokenize(sql)
+            return pa.RecordBatch.from_arrays(
+                [
+                    [token.token_type.name for token in tokens],
+                    [token.text for token in tokens],
+                    [token.line for token in tokens],
+                    [token.col for token in tokens],
+                    [token.start for token in tokens],
+                    [token.end for token in tokens],
+                ],
+                schema=pa.schema(
+                    [
+                        pa.field("token_type", pa.string(), nullable=False),
+                        pa.field("text", pa.string(), nullable=False),
+                        *(
+                            pa.field(name, pa.int64(), nullable=False)
+                            for name in ("line", "col", "start", "end")
+                        ),
+                    ]
+                ),
+            )
+
+        if not self._RS_TOKENIZER:
+            raise SqlglotError("Rust tokenizer is not available")
+
+        try:
+            columns = self._RS_TOKENIZER.tokenize_to_arrow(
+                sql, self._rs_dialect_settings, _TOKEN_TYPE_NAMES
+            )
+        except ValueError as e:
+            raise self._rs_token_error(getattr(e, "error", None), str(e)) from e
+
+        return pa.record_batch(columns)
+
+    def register_escape_sequences(self, sequences: t.Dict[str, str]) -> None:
+        """
+        Adds escape sequences that strings are unescaped with to those of the dialect, e.g.
+        `{"\\u00e9": "é"}`, replacing those with the same sequence, for this tokenizer only. Each
+        sequence starts with one of the dialect's `STRING_ESCAPES` and has at least one more char,
+        and where several match, the longest wins.
+        """
+        for sequence in sequences:
+            if len(sequence) < 2 or sequence[0] not in self._STRING_ESCAPES:
+                raise ValueError(
+                    f"Escape sequence {sequence!r} doesn't start with one of the string escapes "
+                    f"{sorted(self._STRING_ESCAPES)} followed by at least one char"
+                )
+
+        self._unescaped_sequences = {**self._unescaped_sequences, **sequences}
+        self._unescaped_sequence_size = max(map(len, self._unescaped_sequences))
+        if self.use_rs_tokenizer:
+            self._rs_dialect_settings.register_unescaped_sequences(sequences)
+
+    def verify_roundtrip(
+        self, sql: str, tokens: t.Sequence[Token]
+    ) -> t.Optional[t.Dict[str, t.Any]]:
+        """
+        Checks that `tokens`, e.g. as returned by `tokenize(sql)`, account for all of `sql`: their
+        spans are in order without overlapping, and all that's between them is trivia, i.e. white
+        space and comments. Returns None if they do, or else the first violation as a dict with
+        its `kind`, one of "out_of_bounds", "out_of_order", "overlap" or "untokenized", the
+        `index` of the token it's at, which is `len(tokens)` past the last one, the char offsets
+        of its `start` and exclusive `end`, and a `message`.
+        """
+
+        def violation(kind: str, index: int, start: int, end: int) -> t.Dict[str, t.Any]:
+            messages = {
+                "out_of_bounds": "isn't within the query",
+                "out_of_order": "starts before the token preceding it",
+                "overlap": "overlaps the token preceding it",
+                "untokenized": "is neither trivia nor part of a token",
+            }
+            return {
+                "kind": kind,
+                "index": index,
+                "start": start,
+                "end": end,
+                "message": f"{sql[start:end]!r} at {start}-{end} {messages[kind]}",
+            }
+
+        covered = 0
+        previous: t.Optional[Token] = None
+        for index, token in enumerate(tokens):
+            start, end = token.start, token.end + 1
+            if start < 0 or end < start or end > len(sql):
+                return violation("out_of_bounds", index, start, end)
+
+            if previous is not None:
+                # The tokens of a number with a type suffix, e.g. hive's 1L, share its span
+                if (start, end) == (previous.start, previous.end + 1):
+                    continue
+                if start < previous.start:
+                    return violation("out_of_order", index, start, end)
+            if start < covered:
+                return violation("overlap", index, start, end)
+
+            trivia_end = self._skip_trivia(sql, covered, start)
+            if trivia_end < start:
+                return violation("untokenized", index, trivia_end, start)
+            covered = end
+            previous = token
+
+        trivia_end = self._skip_trivia(sql, covered, len(sql))
+        if trivia_end < len(sql):
+            return violation("untokenized", len(tokens), trivia_end, len(sql))
+        return None
+
+    def suggest_keyword(self, text: str, max_distance: int = 2) -> t.List[str]:
+        """
+        Returns the keywords within `max_distance` edits of `text`, i.e. insertions, deletions or
+        substitutions of a char, closest first, e.g. to point out that `GROUP BT` was meant to be
+        `GROUP BY`. Case is ignored and only keywords that start like a word are suggested.
+        """
+        if self.use_rs_tokenizer:
+            if not self._RS_TOKENIZER:
+                raise SqlglotError("Rust tokenizer is not available")
+            return self._RS_TOKENIZER.suggest_keyword(text, max_distance)
+
+        closest = closest_in_trie(_keyword_dictionary(type(self)), text.upper(), max_distance)
+        return [keyword for keyword, _ in closest]
+
+    def _skip_trivia(self, sql: str, start: int, end: int) -> int:
+        """Returns the offset of the first char of `sql[start:end]` that isn't trivia, or `end`."""
+        versioned = self._versioned_comments_version is not None
+        i = start
+        while i < end:
+            char = sql[i]
+            if (
+                char.isspace()
+                or (i == 0 and char == "\ufeff")
+                or (self._control_characters == "skip" and _is_control_char(char))
+                or (self._nul_bytes == "strip" and char == "\0")
+            ):
+                i += 1
+                continue
+
+            comment_end = self._skip_comment(sql, i, end)
+            if comment_end is not None:
+                i = comment_end
+            elif versioned and sql.startswith("/*!", i, end):
+                # The delimiters of versioned comments whose contents were tokenized
+                i += 3
+                while i < end and sql[i].isdigit():
+                    i += 1
+            elif versioned and sql.startswith("*/", i, end):
+                i += 2
+            else:
+                break
+        return i
+
+    def _skip_comment(self, sql: str, start: int, end: int) -> t.Optional[int]:
+        """Returns the offset past the comment at `start` if it ends by `end`, else None."""
+        comment_start = max(
+            (delimiter for delimiter in self._COMMENTS if sql.startswith(delimiter, start, end)),
+            key=len,
+            default=None,
+        )
+        if comment_start is None:
+            return None
+
+        comment_end = self._COMMENTS[comment_start]
+        i = start + len(comment_start)
+        if comment_end is None:
+            while i < end and self.WHITE_SPACE.get(sql[i]) is not TokenType.BREAK:
+                i += 1
+            return i
+
+        depth = 1
+        while i < end:
+            if sql.startswith(comment_end, i, end):
+                i += len(comment_end)
+                depth -= 1
+                if not depth:
+                    return i
+            elif self.NESTED_COMMENTS and sql.startswith(comment_start, i, end):
+                i += len(comment_start)
+                depth += 1
+            else:
+                i += 1
+        return None
+
     def _scan(self, until: t.Optional[t.Callable] = None) -> None:
         while self.size and not self._end:
             current = self._current
@@ -1091,9 +2067,26 @@ class Tokenizer(metaclass=_Tokenizer):
 
             self._start = current
             self._advance(offset)
-
-            if not self._char.isspace():
-                if self._char.isdigit():
+            # A token starts at the first unit of its first char, which is wider than one unit in
+            # some encodings
+            self._start_line = self._line
+            self._start_col = self._col
+            if self.position_encoding != "utf32" and not self._char.isascii():
+                self._start_col += 1 - self._width(self._char)
+
+            if self._in_hint and self._char == "*" and self._peek == "/":
+                self._advance()
+                self._in_hint = False
+                self._add(TokenType.HINT_END)
+            elif self._in_versioned_comment and self._char == "*" and self._peek == "/":
+                self._advance()
+                self._in_versioned_comment = False
+            elif not self._char.isspace():
+                if self._is_handled_control_char(self._char):
+                    self._scan_control_char()
+                elif self._char.isdigit() and (
+                    self._char.isascii() or not self.dialect.UNICODE_IDENTIFIERS
+                ):
                     self._scan_number()
                 elif self._char in self._IDENTIFIERS:
                     self._scan_identifier(self._IDENTIFIERS[self._char])
@@ -1116,13 +2109,22 @@ class Tokenizer(metaclass=_Tokenizer):
         return self.sql[start:end] if end <= self.size else ""
 
     def _advance(self, i: int = 1, alnum: bool = False) -> None:
+        # The columns the chars moved over count for, which is their number unless columns are
+        # counted in code units
+        columns = i
+        if self.position_encoding != "utf32":
+            if i >= 0:
+                columns = self._width(self.sql[self._current : self._current + i])
+            else:
+                columns = -self._width(self.sql[max(self._current + i, 0) : self._current])
+
         if self.WHITE_SPACE.get(self._char) is TokenType.BREAK:
             # Ensures we don't count an extra line if we get a \r\n line break sequence
             if not (self._char == "\r" and self._peek == "\n"):
-                self._col = i
+                self._col = columns
                 self._line += 1
         else:
-            self._col += i
+            self._col += columns
 
         self._current += i
         self._end = self._current >= self.size
@@ -1142,19 +2144,65 @@ class Tokenizer(metaclass=_Tokenizer):
                 _end = _current >= self.size
                 _peek = "" if _end else self.sql[_current]
 
+            if self.position_encoding != "utf32":
+                _col = self._col + self._width(self.sql[self._current : _current])
+
             self._col = _col
             self._current = _current
             self._end = _end
             self._peek = _peek
             self._char = self.sql[_current - 1]
 
+    def _byte_offset(self, char: int) -> int:
+        """
+        The offset in the query's UTF-8 at which the char at `char` starts, counted from the last
+        offset asked for, as tokens are added in order.
+        """
+        if self._byte_cursor is None:
+            return char
+
+        at, byte = self._byte_cursor
+        if at < char:
+            byte += len(self.sql[at:char].encode("utf-8", "surrogatepass"))
+        elif at > char:
+            byte -= len(self.sql[char:at].encode("utf-8", "surrogatepass"))
+        self._byte_cursor = (char, byte)
+        return byte
+
+    def _width(self, text: str) -> int:
+        """The number of code units of `position_encoding` that text counts for."""
+        codec, size = _POSITION_ENCODING_CODECS[self.position_encoding]
+        return len(text.encode(codec, "surrogatepass")) // size
+
     @property
     def _text(self) -> str:
         return self.sql[self._start : self._current]
 
+    def _check_max_tokens(self) -> None:
+        if (
+            self.max_tokens is not None
+            and len(self.tokens) + len(self._comment_tokens) >= self.max_tokens
+        ):
+            context = self.sql[max(self._current - 50, 0) : min(self._current + 50, self.size - 1)]
+            raise TokenLimitError(
+                f"Error tokenizing '{context}': The query has more tokens than max_tokens of "
+                f"{self.max_tokens}",
+                limit="max_tokens",
+                value=self.max_tokens,
+                line=self._line,
+                col=self._col,
+                start=self._start,
+            )
+
     def _add(self, token_type: TokenType, text: t.Optional[str] = None) -> None:
+        self._check_max_tokens()
         self._prev_token_line = self._line
 
+        # Only strings, quoted identifiers and commands can have a NUL char in their text, as it
+        # ends every other token when it's stripped
+        if self._nul_bytes == "strip":
+            text = (self._text if text is None else text).replace("\0", "")
+
         if self._comments and token_type == TokenType.SEMICOLON and self.tokens:
             self.tokens[-1].comments.extend(self._comments)
             self._comments = []
@@ -1168,6 +2216,10 @@ class Tokenizer(metaclass=_Tokenizer):
                 start=self._start,
                 end=self._current - 1,
                 comments=self._comments,
+                start_line=self._start_line,
+                start_column=self._start_col,
+                start_byte=self._byte_offset(self._start),
+                end_byte=self._byte_offset(self._current),
             )
         )
         self._comments = []
@@ -1181,13 +2233,18 @@ class Tokenizer(metaclass=_Tokenizer):
         ):
             start = self._current
             tokens = len(self.tokens)
+            comment_tokens = len(self._comment_tokens)
             self._scan(lambda: self._peek == ";")
             self.tokens = self.tokens[:tokens]
+            self._comment_tokens = self._comment_tokens[:comment_tokens]
             text = self.sql[start : self._current].strip()
             if text:
                 self._add(TokenType.STRING, text)
 
     def _scan_keywords(self) -> None:
+        if self.PLACEHOLDER_STYLES and self._scan_placeholder():
+            return
+
         size = 0
         word = None
         chars = self._text
@@ -1245,10 +2302,58 @@ class Tokenizer(metaclass=_Tokenizer):
 
         self._scan_var()
 
+    def _scan_versioned_comment(self, comment_start: str) -> bool:
+        """
+        Starts tokenizing the contents of a versioned comment as SQL if the dialect's version is
+        at least the comment's, as MySQL executes `/*!` comments only on servers that are.
+        """
+        if (
+            self._versioned_comments_version is None
+            or comment_start != "/*"
+            or self._in_versioned_comment
+            or self.sql[self._current + 1 : self._current + 2] != "!"
+        ):
+            return False
+
+        # Versions are five digits, e.g. 40101 for 4.1.1, or six from 10.0 on
+        digits_start = self._current + 2
+        digits = 0
+        for char in self.sql[digits_start : digits_start + 6]:
+            if not "0" <= char <= "9":
+                break
+            digits += 1
+
+        if digits < 5:
+            digits = 0
+        else:
+            version = int(self.sql[digits_start : digits_start + digits])
+            version = version // 10000 * 1_000_000 + version // 100 % 100 * 1000 + version % 100
+            if version > self._versioned_comments_version:
+                return False
+
+        self._advance(2 + digits)
+        self._in_versioned_comment = True
+        return True
+
     def _scan_comment(self, comment_start: str) -> bool:
         if comment_start not in self._COMMENTS:
             return False
 
+        if self._scan_versioned_comment(comment_start):
+            return True
+
+        is_hint = (
+            comment_start == self.HINT_START
+            and self.tokens
+            and self.tokens[-1].token_type in self.TOKENS_PRECEDING_HINT
+        )
+        if is_hint and self.hint_tokens:
+            # The hint's body is tokenized as SQL, up to the HINT_END token that closes it
+            self._advance(len(comment_start) - 1)
+            self._add(TokenType.HINT)
+            self._in_hint = True
+            return True
+
         comment_start_line = self._line
         comment_start_size = len(comment_start)
         comment_end = self._COMMENTS[comment_start]
@@ -1277,18 +2382,36 @@ class Tokenizer(metaclass=_Tokenizer):
                     self._advance(comment_start_size)
                     comment_count += 1
 
-            self._comments.append(self._text[comment_start_size : -comment_end_size + 1])
+            comment = self._text[comment_start_size : -comment_end_size + 1]
             self._advance(comment_end_size - 1)
         else:
             while not self._end and self.WHITE_SPACE.get(self._peek) is not TokenType.BREAK:
                 self._advance(alnum=True)
-            self._comments.append(self._text[comment_start_size:])
+            comment = self._text[comment_start_size:]
+
+        if self._nul_bytes == "strip":
+            comment = comment.replace("\0", "")
+
+        if self.comment_tokens and not is_hint:
+            self._check_max_tokens()
+            self._comment_tokens.append(
+                Token(
+                    TokenType.STANDALONE_COMMENT,
+                    text=comment,
+                    line=self._line,
+                    col=self._col,
+                    start=self._start,
+                    end=self._current - 1,
+                    start_line=self._start_line,
+                    start_column=self._start_col,
+                    start_byte=self._byte_offset(self._start),
+                    end_byte=self._byte_offset(self._current),
+                )
+            )
+            return True
 
-        if (
-            comment_start == self.HINT_START
-            and self.tokens
-            and self.tokens[-1].token_type in self.TOKENS_PRECEDING_HINT
-        ):
+        self._comments.append(comment)
+        if is_hint:
             self._add(TokenType.HINT)
 
         # Leading comment is attached to the succeeding token, whilst trailing comment to the preceding.
@@ -1312,7 +2435,9 @@ class Tokenizer(metaclass=_Tokenizer):
         scientific = 0
 
         while True:
-            if self._peek.isdigit():
+            if self._peek.isdigit() and (
+                self._peek.isascii() or not self.dialect.UNICODE_IDENTIFIERS
+            ):
                 self._advance()
             elif self._peek == "." and not decimal:
                 if self.tokens and self.tokens[-1].token_type == TokenType.PARAMETER:
@@ -1329,7 +2454,12 @@ class Tokenizer(metaclass=_Tokenizer):
                 number_text = self._text
                 literal = ""
 
-                while self._peek.strip() and self._peek not in self.SINGLE_TOKENS:
+                while (
+                    self._peek.strip()
+                    and self._peek not in self.SINGLE_TOKENS
+                    and (not self.dialect.UNICODE_IDENTIFIERS or _is_identifier_char(self._peek))
+                    and not self._is_handled_control_char(self._peek)
+                ):
                     literal += self._peek
                     self._advance()
 
@@ -1395,31 +2525,26 @@ class Tokenizer(metaclass=_Tokenizer):
             elif token_type == TokenType.BIT_STRING:
                 base = 2
             elif token_type == TokenType.HEREDOC_STRING:
-                self._advance()
-
-                if self._char == end:
-                    tag = ""
+                if self.HEREDOC_TAG_IS_IDENTIFIER:
+                    tag = self._heredoc_tag(start, end)
+                    if tag is None:
+                        self._add(self.HEREDOC_STRING_ALTERNATIVE)
+                        return True
+                    self._advance(len(tag) + 1)
                 else:
-                    tag = self._extract_string(
-                        end,
-                        raw_string=True,
-                        raise_unmatched=not self.HEREDOC_TAG_IS_IDENTIFIER,
-                    )
-
-                if tag and self.HEREDOC_TAG_IS_IDENTIFIER and (self._end or not tag.isidentifier()):
-                    if not self._end:
-                        self._advance(-1)
-
-                    self._advance(-len(tag))
-                    self._add(self.HEREDOC_STRING_ALTERNATIVE)
-                    return True
+                    self._advance()
+                    tag = "" if self._char == end else self._extract_string(end, raw_string=True)
 
                 end = f"{start}{tag}{end}"
         else:
             return False
 
         self._advance(len(start))
-        text = self._extract_string(end, raw_string=token_type == TokenType.RAW_STRING)
+        text = self._extract_string(
+            end,
+            raw_string=token_type == TokenType.RAW_STRING,
+            verbatim=start in self.VERBATIM_STRINGS,
+        )
 
         if base:
             try:
@@ -1430,8 +2555,23 @@ class Tokenizer(metaclass=_Tokenizer):
                 )
 
         self._add(token_type, text)
+        prefix_size = next((i for i, char in enumerate(start) if char == end[0]), len(start))
+        if prefix_size:
+            self.tokens[-1].prefix = self.sql[self._start : self._start + prefix_size]
         return True
 
+    def _heredoc_tag(self, start: str, end: str) -> t.Optional[str]:
+        """
+        Returns the tag of the heredoc string at the current char, which is looked ahead without
+        advancing, or None if it isn't an identifier followed by `end`, e.g. in Postgres' `$1`.
+        """
+        sql = self.sql
+        tag_start = self._current + len(start) - 1
+        i = tag_start
+        while i < self.size and (sql[i] if i == tag_start else f"_{sql[i]}").isidentifier():
+            i += 1
+        return sql[tag_start:i] if sql.startswith(end, i) else None
+
     def _scan_identifier(self, identifier_end: str) -> None:
         self._advance()
         text = self._extract_string(
@@ -1439,13 +2579,48 @@ class Tokenizer(metaclass=_Tokenizer):
         )
         self._add(TokenType.IDENTIFIER, text)
 
+    def _scan_placeholder(self) -> bool:
+        start = self._current - 1
+        end = _placeholder_end(self.sql, start, self.PLACEHOLDER_STYLES)
+        if not end:
+            return False
+
+        self._advance(end - start - 1)
+        self._add(TokenType.PLACEHOLDER)
+        return True
+
+    def _is_handled_control_char(self, char: str) -> bool:
+        """
+        Whether char is a control or invisible formatting char that the `control_characters`
+        setting applies to, or a NUL char that the `nul_bytes` one strips.
+        """
+        return (self._nul_bytes == "strip" and char == "\0") or (
+            self._control_characters != "keep" and _is_control_char(char)
+        )
+
+    def _scan_control_char(self) -> None:
+        if self._nul_bytes == "strip" and self._char == "\0":
+            return
+        if self._control_characters == "error":
+            raise TokenError(f"Unexpected character U+{ord(self._char):04X}")
+
     def _scan_var(self) -> None:
-        while True:
-            char = self._peek.strip()
-            if char and (char in self.VAR_SINGLE_TOKENS or char not in self.SINGLE_TOKENS):
-                self._advance(alnum=True)
-            else:
-                break
+        unicode_identifiers = self.dialect.UNICODE_IDENTIFIERS
+
+        # A char that can't be part of an identifier is a var of its own
+        if not unicode_identifiers or _is_identifier_char(self._char):
+            while True:
+                char = self._peek.strip()
+                if (
+                    char
+                    and (char in self.VAR_SINGLE_TOKENS or char not in self.SINGLE_TOKENS)
+                    and (not unicode_identifiers or _is_identifier_char(char))
+                    and not self._is_handled_control_char(char)
+                ):
+                    # Non-ASCII alphanumerics can't be skipped over, as not all of them are XID
+                    self._advance(alnum=not unicode_identifiers)
+                else:
+                    break
 
         self._add(
             TokenType.VAR
@@ -1453,31 +2628,44 @@ class Tokenizer(metaclass=_Tokenizer):
             else self.KEYWORDS.get(self._text.upper(), TokenType.VAR)
         )
 
+    def _unescaped_sequence(self) -> t.Optional[str]:
+        """The longest of the unescaped sequences that starts at the current char, if any."""
+        for size in range(self._unescaped_sequence_size, 1, -1):
+            sequence = self._chars(size)
+            if sequence in self._unescaped_sequences:
+                return sequence
+        return None
+
     def _extract_string(
         self,
         delimiter: str,
         escapes: t.Optional[t.Set[str]] = None,
         raw_string: bool = False,
+        verbatim: bool = False,
         raise_unmatched: bool = True,
     ) -> str:
         text = ""
         delim_size = len(delimiter)
         escapes = self._STRING_ESCAPES if escapes is None else escapes
+        escapes_allowed = not verbatim and (
+            self.STRING_ESCAPES_ALLOWED_IN_RAW_STRINGS or not raw_string
+        )
 
         while True:
             if (
                 not raw_string
-                and self.dialect.UNESCAPED_SEQUENCES
+                and not verbatim
+                and self._unescaped_sequences
                 and self._peek
                 and self._char in self.STRING_ESCAPES
             ):
-                unescaped_sequence = self.dialect.UNESCAPED_SEQUENCES.get(self._char + self._peek)
-                if unescaped_sequence:
-                    self._advance(2)
-                    text += unescaped_sequence
+                sequence = self._unescaped_sequence()
+                if sequence:
+                    self._advance(len(sequence))
+                    text += self._unescaped_sequences[sequence]
                     continue
             if (
-                (self.STRING_ESCAPES_ALLOWED_IN_RAW_STRINGS or not raw_string)
+                escapes_allowed
                 and self._char in escapes
                 and (self._peek == delimiter or self._peek in escapes)
                 and (self._char not in self._QUOTES or self._char == self._peek)
@@ -1513,14 +2701,88 @@ class Tokenizer(metaclass=_Tokenizer):
         if not self._RS_TOKENIZER:
             raise SqlglotError("Rust tokenizer is not available")
 
-        tokens, error_msg = self._RS_TOKENIZER.tokenize(sql, self._rs_dialect_settings)
+        tokens, error = self._RS_TOKENIZER.tokenize(sql, self._rs_dialect_settings)
         for token in tokens:
             token.token_type = _ALL_TOKEN_TYPES[token.token_type_index]
 
         # Setting this here so partial token lists can be inspected even if there is a failure
         self.tokens = tokens
 
-        if error_msg is not None:
-            raise TokenError(error_msg)
+        if error is not None:
+            raise self._rs_token_error(error)
 
         return tokens
+
+    def _tokenize_conformance(self, sql: str) -> t.List[Token]:
+        """Tokenizes `sql` with both tokenizers, see the `conformance` argument."""
+        rs_tokens, rs_error = self._conformance_run(sql, rs=True)
+        py_tokens, py_error = self._conformance_run(sql, rs=False)
+
+        if _conformance_key(rs_tokens, rs_error) != _conformance_key(py_tokens, py_error):
+            snippet = self._minimize_divergence(sql)
+            rs_repr, py_repr = (
+                _conformance_describe(*self._conformance_run(snippet, rs=rs))
+                for rs in (True, False)
+            )
+            message = (
+                f"The Rust and Python tokenizers disagree on {snippet!r}: "
+                f"Rust gives {rs_repr}, Python gives {py_repr}"
+            )
+            if self.conformance == "raise":
+                raise TokenizerConformanceError(message, sql=snippet)
+            logger.warning(message)
+            rs_tokens, rs_error = py_tokens, py_error
+
+        self.tokens = rs_tokens
+        if rs_error is not None:
+            raise rs_error
+        return rs_tokens
+
+    def _conformance_run(
+        self, sql: str, rs: bool
+    ) -> t.Tuple[t.List[Token], t.Optional[TokenError]]:
+        try:
+            tokens = self.tokenize_rs(sql) if rs else self._tokenize_py(sql)
+        except TokenError as e:
+            return self.tokens, e
+        return tokens, None
+
+    def _minimize_divergence(self, sql: str) -> str:
+        """
+        Shrinks a query the tokenizers disagree on by dropping ever smaller chunks of it for as long
+        as they still disagree, within `_CONFORMANCE_MINIMIZE_ATTEMPTS` tokenizations.
+        """
+
+        def diverges(candidate: str) -> bool:
+            return _conformance_key(*self._conformance_run(candidate, rs=True)) != (
+                _conformance_key(*self._conformance_run(candidate, rs=False))
+            )
+
+        attempts = 0
+        chunk = len(sql) // 2
+        while chunk and attempts < _CONFORMANCE_MINIMIZE_ATTEMPTS:
+            i = 0
+            while i < len(sql) and attempts < _CONFORMANCE_MINIMIZE_ATTEMPTS:
+                candidate = sql[:i] + sql[i + chunk :]
+                attempts += 1
+                if candidate and diverges(candidate):
+                    sql = candidate
+                else:
+                    i += chunk
+            chunk //= 2
+        return sql
+
+    def _rs_token_error(self, error: t.Any, message: t.Optional[str] = None) -> TokenError:
+        """The `TokenError` that a sqlglotrs `TokenizeError` is raised as."""
+        message = str(error) if message is None else message
+        limit = error and _RS_LIMIT_ERRORS.get(error.kind)
+        if limit:
+            return TokenLimitError(
+                message,
+                limit=limit,
+                value=getattr(self, limit),
+                line=error.line,
+                col=error.column,
+                start=error.start,
+            )
+        return TokenError(message)
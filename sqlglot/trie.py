@@ -79,3 +79,44 @@ def in_trie(trie: t.Dict, key: key) -> t.Tuple[TrieResult, t.Dict]:
         return (TrieResult.EXISTS, current)
 
     return (TrieResult.PREFIX, current)
+
+
+def closest_in_trie(trie: t.Dict, key: str, max_distance: int) -> t.List[t.Tuple[str, int]]:
+    """
+    Finds the keys of a trie of strings that are within `max_distance` edits of a key, an edit
+    being the insertion, deletion or substitution of a char.
+
+    Examples:
+        >>> closest_in_trie(new_trie(["cart", "cat", "dog"]), "cat", 1)
+        [('cat', 0), ('cart', 1)]
+
+    Args:
+        trie: The trie to be searched.
+        key: The key to find keys close to.
+        max_distance: The number of edits past which keys aren't considered close.
+
+    Returns:
+        The close keys along with their distance to `key`, closest first and alphabetically
+        among keys that are equally close.
+    """
+    closest: t.List[t.Tuple[str, int]] = []
+
+    # `row` holds the distances between `prefix` and each prefix of `key`. A branch is given up on
+    # once they all exceed `max_distance`, since following more chars never brings them back down.
+    def walk(node: t.Dict, prefix: str, row: t.List[int]) -> None:
+        if 0 in node and row[-1] <= max_distance:
+            closest.append((prefix, row[-1]))
+
+        for char, child in node.items():
+            if char == 0:
+                continue
+
+            next_row = [row[0] + 1]
+            for i, key_char in enumerate(key):
+                next_row.append(min(row[i] + (key_char != char), row[i + 1] + 1, next_row[i] + 1))
+
+            if min(next_row) <= max_distance:
+                walk(child, prefix + char, next_row)
+
+    walk(trie, "", list(range(len(key) + 1)))
+    return sorted(closest, key=lambda pair: (pair[1], pair[0]))
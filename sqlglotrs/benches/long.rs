@@ -1,8 +1,10 @@
 use std::path::Path;
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use pyo3::Python;
 use sqlglotrs::settings::{TokenTypeSettings, TokenizerDialectSettings, TokenizerSettings};
 use sqlglotrs::tokenizer::Tokenizer;
+use sqlglotrs_core::settings as core_settings;
 
 pub const LONG: &str = r#"
 SELECT
@@ -48,20 +50,27 @@ fn long(c: &mut Criterion) {
     // Read tokenizer settings
     let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("benches");
     let settings_file = std::fs::read_to_string(path.join("tokenizer_settings.json")).unwrap();
-    let tokenizer_settings = serde_json::from_str::<TokenizerSettings>(&settings_file).unwrap();
+    let tokenizer_settings =
+        serde_json::from_str::<core_settings::TokenizerSettings>(&settings_file).unwrap();
 
     let settings_type_file =
         std::fs::read_to_string(path.join("token_type_settings.json")).unwrap();
     let settings_type_file =
-        serde_json::from_str::<TokenTypeSettings>(&settings_type_file).unwrap();
+        serde_json::from_str::<core_settings::TokenTypeSettings>(&settings_type_file).unwrap();
 
     let dialect_settings = std::fs::read_to_string(path.join("dialect_settings.json")).unwrap();
     let dialect_settings =
-        serde_json::from_str::<TokenizerDialectSettings>(&dialect_settings).unwrap();
-    let tokenizer = Tokenizer::new(tokenizer_settings, settings_type_file);
+        serde_json::from_str::<core_settings::TokenizerDialectSettings>(&dialect_settings).unwrap();
+    let dialect_settings = TokenizerDialectSettings(dialect_settings);
+    let tokenizer = Tokenizer::new(
+        TokenizerSettings(tokenizer_settings),
+        TokenTypeSettings(settings_type_file),
+    );
 
-    c.bench_function("long", |b| {
-        b.iter(|| black_box(tokenizer.tokenize(LONG, &dialect_settings)));
+    Python::with_gil(|py| {
+        c.bench_function("long", |b| {
+            b.iter(|| black_box(tokenizer.tokenize(py, LONG, &dialect_settings)));
+        });
     });
 }
 
@@ -0,0 +1,408 @@
+//! Tokenizes SQL files the way sqlglot does and reports each of their statements as a line of
+//! JSON, e.g. to find the most common query shapes of a repository with thousands of them:
+//!
+//! ```text
+//! sqlglotrs --dialect postgres 'migrations/**/*.sql' queries/ > statements.jsonl
+//! ```
+//!
+//! Paths can be files, directories, which are searched for `.sql` files, or glob patterns.
+//! Files are processed on a thread pool, while the records are written in the order of the paths
+//! given and of the statements in each file. The exit status is 1 if any file couldn't be read or
+//! tokenized.
+
+use rayon::prelude::*;
+use serde::Serialize;
+use sqlglotrs_core::fingerprint::{fingerprint_parts, hash_parts};
+use sqlglotrs_core::input::SqlFile;
+use sqlglotrs_core::presets;
+use sqlglotrs_core::settings::TokenizerDialectSettings;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::statement::statements;
+use sqlglotrs_core::token::RawToken;
+use sqlglotrs_core::tokenizer::{ReaderScan, TokenShift, TokenizerConfig, TokenizerError};
+use sqlglotrs_core::words::Words;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use walkdir::WalkDir;
+
+const USAGE: &str = "\
+Usage: sqlglotrs [OPTIONS] <PATH>...
+
+Tokenizes SQL files and writes a JSON object per statement to stdout, with its file, index,
+char and byte offsets, fingerprint, token count and tokenizer error, if any.
+
+Arguments:
+  <PATH>...              Files, directories searched for .sql files, or patterns such as
+                         'queries/**/*.sql'
+
+Options:
+  -d, --dialect <NAME>   The dialect to tokenize with, named as in sqlglot [default: sqlglot's]
+  -j, --jobs <N>         The number of threads [default: the number of CPUs]
+  -h, --help             Print this help
+";
+
+// How much of a compressed file is read at a time
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A line of output, for a statement or for a file that couldn't be read. Offsets are half-open,
+/// in chars and in bytes of the file's UTF-8.
+#[derive(Serialize)]
+struct Record<'a> {
+    file: &'a str,
+    statement: usize,
+    start: usize,
+    end: usize,
+    start_byte: usize,
+    end_byte: usize,
+    tokens: usize,
+    fingerprint: Option<String>,
+    error: Option<String>,
+}
+
+struct Options {
+    dialect: String,
+    jobs: usize,
+    paths: Vec<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        dialect: String::new(),
+        jobs: 0,
+        paths: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("{} expects a value", name));
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-d" | "--dialect" => options.dialect = value(&arg)?,
+            "-j" | "--jobs" => {
+                let jobs = value(&arg)?;
+                options.jobs = jobs
+                    .parse()
+                    .map_err(|_| format!("invalid number of jobs: {}", jobs))?;
+            }
+            "--" => options.paths.extend(args.by_ref()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option: {}", arg)),
+            _ => options.paths.push(arg),
+        }
+    }
+    if options.paths.is_empty() {
+        return Err("no paths given".to_string());
+    }
+    Ok(Some(options))
+}
+
+/// What statements are scanned with.
+struct Scanner {
+    config: TokenizerConfig,
+    dialect_settings: TokenizerDialectSettings,
+}
+
+impl Scanner {
+    /// A scanner for the preset of a builtin dialect, if there's one by that name.
+    fn new(dialect: &str) -> Option<Scanner> {
+        Some(Scanner {
+            config: TokenizerConfig::new(
+                presets::tokenizer_settings(dialect)?,
+                presets::token_type_settings(dialect)?,
+            ),
+            dialect_settings: presets::dialect_settings(dialect)?,
+        })
+    }
+}
+
+/// The records of a file, which are added a batch of statements at a time when it's streamed.
+/// The SQL of a batch starts `chars` chars and `bytes` bytes into the file.
+struct Records<'a> {
+    file: &'a str,
+    lines: String,
+    statements: usize,
+    chars: usize,
+    bytes: usize,
+}
+
+impl Records<'_> {
+    /// Adds the records of the statements scanned from `sql`. Unless the scan is done, the last
+    /// statement may go on in the next batch, e.g. in a `BEGIN ... END` block whose semicolons
+    /// ended the batch, in which case it's left out and the index of its first token returned.
+    fn add(
+        &mut self,
+        scanner: &Scanner,
+        sql: &str,
+        tokens: &[RawToken],
+        error: Option<TokenizerError>,
+        is_done: bool,
+    ) -> Option<usize> {
+        let shift = TokenShift {
+            chars: -(self.chars as isize),
+            bytes: -(self.bytes as isize),
+            lines: 0,
+            line: 0,
+            columns: 0,
+        };
+        // Statements are found in the batch, so its tokens are moved back to the start of it
+        let batch_tokens: Vec<RawToken> = tokens
+            .iter()
+            .map(|token| {
+                let mut token = token.clone();
+                shift.apply(&mut token);
+                token
+            })
+            .collect();
+        let token_types = &scanner.config.token_types;
+        let source = Source::new(sql);
+        let words = Words::new(&source, &batch_tokens, token_types);
+        let mut statements = statements(&words);
+
+        let open = match statements.last() {
+            Some(last) if !is_done && last.end == tokens.len() => statements.pop(),
+            _ => None,
+        };
+        // The scan stops at an error, so the statement it's in runs to the end of the file. It
+        // starts at the error if the last scanned statement was ended by a semicolon.
+        let end = self.chars + source.char_count();
+        let failed = error.map(|error| {
+            let (start, count) = match statements.last() {
+                Some(last) if last.end == tokens.len() => {
+                    let last = statements.pop().unwrap();
+                    (tokens[last.start].start, last.len())
+                }
+                _ => (error.start.min(end), 0),
+            };
+            (start, count, error.to_string())
+        });
+
+        for statement in &statements {
+            let (first, last) = (&tokens[statement.start], &tokens[statement.end - 1]);
+            let parts = fingerprint_parts(&source, &batch_tokens[statement.clone()], token_types);
+            self.push(&Record {
+                file: self.file,
+                statement: self.statements,
+                start: first.start,
+                end: last.end + 1,
+                start_byte: first.start_byte,
+                end_byte: last.end_byte,
+                tokens: statement.len(),
+                fingerprint: Some(hash_parts(&parts)),
+                error: None,
+            });
+        }
+        if let Some((start, count, message)) = failed {
+            self.push(&Record {
+                file: self.file,
+                statement: self.statements,
+                start,
+                end,
+                start_byte: self.bytes + source.byte_offset(start - self.chars),
+                end_byte: self.bytes + sql.len(),
+                tokens: count,
+                fingerprint: None,
+                error: Some(message),
+            });
+        }
+        open.map(|statement| statement.start)
+    }
+
+    /// Adds the record of a file that couldn't be read, or whose reading failed.
+    fn read_failed(&mut self, error: io::Error) {
+        self.push(&Record {
+            file: self.file,
+            statement: self.statements,
+            start: 0,
+            end: 0,
+            start_byte: 0,
+            end_byte: 0,
+            tokens: 0,
+            fingerprint: None,
+            error: Some(error.to_string()),
+        });
+    }
+
+    fn push(&mut self, record: &Record) {
+        self.lines.push_str(&to_line(record));
+        self.statements += 1;
+    }
+}
+
+/// The files to process, in the order their paths were given. Directories and patterns expand to
+/// the files they contain or match, sorted by name.
+fn collect_files(paths: &[String]) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    for path in paths {
+        if Path::new(path).is_dir() {
+            files.extend(
+                WalkDir::new(path)
+                    .sort_by_file_name()
+                    .into_iter()
+                    .filter_map(Result::ok)
+                    .filter(|entry| entry.file_type().is_file())
+                    .map(|entry| entry.into_path())
+                    .filter(|path| is_sql_file(path)),
+            );
+        } else if glob::Pattern::escape(path) != *path && !Path::new(path).exists() {
+            // Unreadable entries and invalid patterns match nothing, as in a shell
+            files.extend(
+                glob::glob(path)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Result::ok)
+                    .filter(|path| path.is_file()),
+            );
+        } else {
+            files.push(PathBuf::from(path));
+        }
+    }
+    files
+}
+
+/// Whether a file found in a directory is SQL, which may be compressed, e.g. `dump.sql.gz`.
+fn is_sql_file(path: &Path) -> bool {
+    let name = path.to_string_lossy().to_ascii_lowercase();
+    [".sql", ".sql.gz", ".sql.zst"]
+        .iter()
+        .any(|extension| name.ends_with(extension))
+}
+
+/// The records of a file as lines of JSON, along with whether it was read and tokenized.
+fn process_file(scanner: &Scanner, path: &Path) -> (String, bool) {
+    let file = path.to_string_lossy();
+    let mut records = Records {
+        file: &file,
+        lines: String::new(),
+        statements: 0,
+        chars: 0,
+        bytes: 0,
+    };
+    // Uncompressed files are memory-mapped and compressed ones decompressed, and either is
+    // scanned a chunk at a time, so that neither is read into a buffer first
+    let ok = match SqlFile::open(path) {
+        Ok(file) => stream_file(scanner, &mut records, file),
+        Err(error) => {
+            records.read_failed(error);
+            false
+        }
+    };
+    (records.lines, ok)
+}
+
+/// Adds the records of a file read from `reader`, returning whether it was read and tokenized.
+fn stream_file(scanner: &Scanner, records: &mut Records, reader: impl Read) -> bool {
+    let mut scan = ReaderScan::new(reader, READ_CHUNK_SIZE);
+    // The SQL and tokens since the start of the batch, which begins with the statement the last
+    // batch left open, if any
+    let mut sql = String::new();
+    let mut tokens = Vec::new();
+    loop {
+        let (batch, error) = match scan.next_batch(&scanner.config, &scanner.dialect_settings) {
+            Ok(batch) => batch,
+            Err(error) => {
+                records.read_failed(error);
+                return false;
+            }
+        };
+        let is_done = batch.is_empty() || error.is_some();
+        let ok = error.is_none();
+        sql.push_str(scan.scanned_text());
+        tokens.extend(batch);
+
+        let open = records.add(scanner, &sql, &tokens, error, is_done);
+        if is_done {
+            return ok;
+        }
+        let (kept, chars, bytes) = match open {
+            Some(first) => (first, tokens[first].start, tokens[first].start_byte),
+            None => {
+                let last = &tokens[tokens.len() - 1];
+                (tokens.len(), last.end + 1, last.end_byte)
+            }
+        };
+        sql.drain(..bytes - records.bytes);
+        tokens.drain(..kept);
+        records.chars = chars;
+        records.bytes = bytes;
+    }
+}
+
+fn to_line(record: &Record) -> String {
+    let mut line = serde_json::to_string(record).expect("records serialize to JSON");
+    line.push('\n');
+    line
+}
+
+// A closed pipe, e.g. when the output is piped to `head`, just means that no more records are
+// wanted
+fn write_failed(error: io::Error) -> ExitCode {
+    if error.kind() == io::ErrorKind::BrokenPipe {
+        return ExitCode::SUCCESS;
+    }
+    eprintln!("error: {}", error);
+    ExitCode::FAILURE
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            print!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let Some(scanner) = Scanner::new(&options.dialect) else {
+        let dialects: Vec<&str> = presets::dialects()
+            .filter(|name| !name.is_empty())
+            .collect();
+        eprintln!(
+            "error: unknown dialect {:?}, expected one of: {}",
+            options.dialect,
+            dialects.join(", ")
+        );
+        return ExitCode::from(2);
+    };
+    let pool = match rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs)
+        .build()
+    {
+        Ok(pool) => pool,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let files = collect_files(&options.paths);
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    let mut succeeded = true;
+    // Files are processed a batch at a time, so that the output of a huge corpus isn't all held
+    // in memory before it's written
+    for batch in files.chunks(pool.current_num_threads() * 16) {
+        let results: Vec<(String, bool)> = pool.install(|| {
+            batch
+                .par_iter()
+                .map(|path| process_file(&scanner, path))
+                .collect()
+        });
+        for (lines, ok) in results {
+            succeeded &= ok;
+            if let Err(error) = out.write_all(lines.as_bytes()) {
+                return write_failed(error);
+            }
+        }
+    }
+    if let Err(error) = out.flush() {
+        return write_failed(error);
+    }
+
+    if succeeded {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,221 @@
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde_json::Value;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// A directory of SQL files that's removed once the test is done.
+struct Corpus(PathBuf);
+
+impl Corpus {
+    fn new(name: &str, files: &[(&str, &str)]) -> Corpus {
+        let root =
+            std::env::temp_dir().join(format!("sqlglotrs-cli-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for (path, sql) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, sql).unwrap();
+        }
+        Corpus(root)
+    }
+}
+
+impl Drop for Corpus {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn run(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_sqlglotrs"))
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn records(output: &Output) -> Vec<Value> {
+    String::from_utf8(output.stdout.clone())
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+fn files(records: &[Value]) -> Vec<&str> {
+    records
+        .iter()
+        .map(|record| record["file"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_statements() {
+    let corpus = Corpus::new(
+        "statements",
+        &[(
+            "a.sql",
+            "SELECT 1; select  2 ;\nSELECT 'é' FROM t WHERE x = 3",
+        )],
+    );
+    let output = run(&corpus.0, &["a.sql"]);
+    assert!(output.status.success());
+
+    let records = records(&output);
+    assert_eq!(records.len(), 3);
+    assert_eq!(
+        records[2],
+        serde_json::json!({
+            "file": "a.sql",
+            "statement": 2,
+            "start": 22,
+            "end": 51,
+            "start_byte": 22,
+            "end_byte": 52,
+            "tokens": 8,
+            "fingerprint": records[2]["fingerprint"],
+            "error": null,
+        })
+    );
+    // Statements that only differ by their literals, case and spacing share a fingerprint
+    assert_eq!(records[0]["fingerprint"], records[1]["fingerprint"]);
+    assert_ne!(records[0]["fingerprint"], records[2]["fingerprint"]);
+    assert_eq!(records[0]["fingerprint"].as_str().unwrap().len(), 16);
+}
+
+#[test]
+fn test_directories_and_patterns() {
+    let corpus = Corpus::new(
+        "paths",
+        &[
+            ("q/b.sql", "SELECT 1"),
+            ("q/a.SQL", "SELECT 1"),
+            ("q/notes.txt", "SELECT 1"),
+            ("q/sub/c.sql", "SELECT 1"),
+            ("q/sub/deeper/d1.sql", "SELECT 1"),
+            ("q/sub/deeper/d2.sql", "SELECT 1"),
+        ],
+    );
+
+    let output = run(&corpus.0, &["q"]);
+    assert_eq!(
+        files(&records(&output)),
+        [
+            "q/a.SQL",
+            "q/b.sql",
+            "q/sub/c.sql",
+            "q/sub/deeper/d1.sql",
+            "q/sub/deeper/d2.sql"
+        ]
+    );
+
+    let output = run(&corpus.0, &["q/*.sql", "q/**/d[!2].sql", "q/*.txt"]);
+    assert_eq!(
+        files(&records(&output)),
+        ["q/b.sql", "q/sub/deeper/d1.sql", "q/notes.txt"]
+    );
+
+    let output = run(&corpus.0, &["-j", "2", "q/**/?.sql"]);
+    assert_eq!(files(&records(&output)), ["q/b.sql", "q/sub/c.sql"]);
+}
+
+#[test]
+fn test_compressed() {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(b"SELECT 1; SELECT 2").unwrap();
+    let corpus = Corpus::new("compressed", &[]);
+    fs::create_dir_all(corpus.0.join("q")).unwrap();
+    fs::write(corpus.0.join("q/a.SQL.GZ"), encoder.finish().unwrap()).unwrap();
+
+    // Compressed files are found in directories and decompressed as they're read, whatever the
+    // case of their extensions
+    let output = run(&corpus.0, &["q"]);
+    assert!(output.status.success());
+    let records = records(&output);
+    assert_eq!(files(&records), ["q/a.SQL.GZ", "q/a.SQL.GZ"]);
+    assert_eq!(records[1]["start"], 10);
+    assert_eq!(records[1]["end"], 18);
+}
+
+#[test]
+fn test_streamed() {
+    // A block long enough to span several reads, whose semicolons don't end the statement
+    let sql = format!(
+        "SELECT 1;\nBEGIN\n{}END;\nSELECT 'x",
+        "  INSERT INTO t VALUES ('é', 1);\n".repeat(5000)
+    );
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(sql.as_bytes()).unwrap();
+    let corpus = Corpus::new("streamed", &[("a.sql", &sql)]);
+    fs::write(corpus.0.join("a.sql.gz"), encoder.finish().unwrap()).unwrap();
+
+    // Compressed files are scanned as they're decompressed, with the same records as if they
+    // were read at once
+    let mapped = records(&run(&corpus.0, &["a.sql"]));
+    let mut streamed = records(&run(&corpus.0, &["a.sql.gz"]));
+    for record in &mut streamed {
+        record["file"] = "a.sql".into();
+    }
+    assert_eq!(streamed, mapped);
+    assert_eq!(mapped.len(), 3);
+    assert_eq!(mapped[1]["tokens"], 5000 * 10 + 2);
+    assert_eq!(mapped[2]["start_byte"], sql.len() - 9);
+    assert!(mapped[2]["error"].is_string());
+}
+
+#[test]
+fn test_dialect() {
+    let corpus = Corpus::new("dialect", &[("a.sql", "SELECT `a` FROM t # note")]);
+
+    let output = run(&corpus.0, &["a.sql"]);
+    assert_eq!(records(&output)[0]["tokens"], 8);
+
+    // MySQL quotes identifiers with backticks and starts comments with `#`
+    let output = run(&corpus.0, &["--dialect", "mysql", "a.sql"]);
+    assert!(output.status.success());
+    assert_eq!(records(&output)[0]["tokens"], 4);
+    assert_eq!(records(&output)[0]["end"], 17);
+
+    let output = run(&corpus.0, &["--dialect", "nope", "a.sql"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown dialect"));
+}
+
+#[test]
+fn test_errors() {
+    let corpus = Corpus::new(
+        "errors",
+        &[
+            ("a.sql", "SELECT 1;\nSELECT 'x;\nSELECT 2"),
+            ("b.sql", "SELECT 1"),
+        ],
+    );
+    let output = run(&corpus.0, &["a.sql", "missing.sql", "b.sql"]);
+    assert_eq!(output.status.code(), Some(1));
+
+    let records = records(&output);
+    assert_eq!(files(&records), ["a.sql", "a.sql", "missing.sql", "b.sql"]);
+    assert!(records[0]["error"].is_null());
+    // The statement the scan failed in runs to the end of the file
+    assert_eq!(records[1]["statement"], 1);
+    assert_eq!(records[1]["start"], 10);
+    assert_eq!(records[1]["end"], 29);
+    assert_eq!(records[1]["tokens"], 1);
+    assert!(records[1]["fingerprint"].is_null());
+    assert!(records[1]["error"].as_str().unwrap().contains("Missing '"));
+    assert!(records[2]["error"].is_string());
+    assert!(records[3]["error"].is_null());
+}
+
+#[test]
+fn test_usage() {
+    let output = run(Path::new("."), &[]);
+    assert_eq!(output.status.code(), Some(2));
+
+    let output = run(Path::new("."), &["--help"]);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Usage: sqlglotrs"));
+}
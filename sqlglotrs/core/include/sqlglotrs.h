@@ -0,0 +1,61 @@
+/*
+ * The C ABI of sqlglotrs-core, built with its `ffi` feature, see src/ffi.rs.
+ *
+ * Every object returned by a function here is owned by the caller, who frees it with the matching
+ * `_free` function, and everything an object hands out lives as long as that object. Texts are
+ * UTF-8 and, except for error messages and names, not NUL-terminated, so their length in bytes is
+ * written to `len`. Positions are char offsets into the query, while columns are counted in the
+ * tokenizer's position encoding, chars by default.
+ */
+
+#ifndef SQLGLOTRS_H
+#define SQLGLOTRS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct SqlglotTokenizer SqlglotTokenizer;
+typedef struct SqlglotTokens SqlglotTokens;
+
+/* Returns NULL if there's no preset for the dialect, where the default dialect is "". */
+SqlglotTokenizer *sqlglot_tokenizer_for_dialect(const char *dialect);
+/* Returns NULL if the snapshot can't be read or there's no preset for a non-NULL dialect. */
+SqlglotTokenizer *sqlglot_tokenizer_from_snapshot(const uint8_t *snapshot, size_t len,
+                                                  const char *dialect);
+/* 0 means there's no limit. */
+void sqlglot_tokenizer_set_limits(SqlglotTokenizer *tokenizer, size_t max_input_bytes,
+                                  size_t max_tokens);
+void sqlglot_tokenizer_set_comment_tokens(SqlglotTokenizer *tokenizer, bool comment_tokens);
+void sqlglot_tokenizer_set_hint_tokens(SqlglotTokenizer *tokenizer, bool hint_tokens);
+/* "utf8", "utf16" or "utf32". Returns false for any other encoding. */
+bool sqlglot_tokenizer_set_position_encoding(SqlglotTokenizer *tokenizer, const char *encoding);
+void sqlglot_tokenizer_free(SqlglotTokenizer *tokenizer);
+
+/* Never returns NULL. If the scan fails, the result holds the tokens before the error. */
+SqlglotTokens *sqlglot_tokenize(const SqlglotTokenizer *tokenizer, const char *sql, size_t len);
+void sqlglot_tokens_free(SqlglotTokens *tokens);
+/* Both return NULL if the scan succeeded. */
+const char *sqlglot_tokens_error(const SqlglotTokens *tokens);
+const char *sqlglot_tokens_error_kind(const SqlglotTokens *tokens);
+size_t sqlglot_tokens_len(const SqlglotTokens *tokens);
+
+uint16_t sqlglot_token_type(const SqlglotTokens *tokens, size_t i);
+const char *sqlglot_token_text(const SqlglotTokens *tokens, size_t i, size_t *len);
+void sqlglot_token_position(const SqlglotTokens *tokens, size_t i, size_t *line, size_t *col,
+                            size_t *start, size_t *end);
+size_t sqlglot_token_comments_len(const SqlglotTokens *tokens, size_t i);
+const char *sqlglot_token_comment(const SqlglotTokens *tokens, size_t i, size_t j, size_t *len);
+
+/* Returns a static string, or NULL for custom token types. */
+const char *sqlglot_token_type_name(uint16_t token_type);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
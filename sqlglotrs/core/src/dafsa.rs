@@ -0,0 +1,253 @@
+use crate::snapshot::{Reader, SnapshotError, Writer};
+use rustc_hash::FxHashMap as HashMap;
+use std::collections::{BTreeMap, VecDeque};
+
+/// A state of a `Dafsa`, i.e. the prefix of the keys matched so far.
+pub type State = u32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Match {
+    Failed,
+    Prefix,
+    Exists,
+}
+
+/// The multi-char keywords, comment and string delimiters as a deterministic acyclic automaton,
+/// i.e. a trie whose equivalent suffixes are merged, laid out flat so that a lookup neither hashes
+/// nor allocates. Text is uppercased as it's matched, so keys are expected in uppercase, as
+/// sqlglot's keywords are.
+#[derive(Debug)]
+pub struct Dafsa {
+    // The transitions out of state `s` are `edges[offsets[s]..offsets[s + 1]]`, sorted by char
+    offsets: Vec<u32>,
+    edges: Vec<(char, State)>,
+    words: Vec<bool>,
+    // Length in chars of the longest key, past which no lookup can succeed
+    pub max_key_length: usize,
+}
+
+type Signature = (bool, Vec<(char, usize)>);
+
+impl Dafsa {
+    pub const ROOT: State = 0;
+
+    pub fn new<'a, I>(keys: I) -> Dafsa
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut max_key_length = 0;
+        let mut trie: Vec<(bool, BTreeMap<char, usize>)> = vec![(false, BTreeMap::new())];
+        for key in keys {
+            max_key_length = max_key_length.max(key.chars().count());
+            let mut node = 0;
+            for c in key.chars() {
+                node = match trie[node].1.get(&c) {
+                    Some(&child) => child,
+                    None => {
+                        trie.push((false, BTreeMap::new()));
+                        let child = trie.len() - 1;
+                        trie[node].1.insert(c, child);
+                        child
+                    }
+                };
+            }
+            trie[node].0 = true;
+        }
+
+        // Children are always created after their parent, so going backwards every node is
+        // reached after its children have been merged with the equivalent nodes seen so far
+        let mut merged = vec![0; trie.len()];
+        let mut register: HashMap<Signature, usize> = HashMap::default();
+        let mut nodes: Vec<Signature> = Vec::new();
+        for node in (0..trie.len()).rev() {
+            let (is_word, children) = &trie[node];
+            let signature = (
+                *is_word,
+                children
+                    .iter()
+                    .map(|(&c, &child)| (c, merged[child]))
+                    .collect(),
+            );
+            merged[node] = *register.entry(signature).or_insert_with_key(|signature| {
+                nodes.push(signature.clone());
+                nodes.len() - 1
+            });
+        }
+
+        // Number the states breadth first, which puts the root first
+        let mut states = vec![State::MAX; nodes.len()];
+        let mut order = Vec::with_capacity(nodes.len());
+        let mut queue = VecDeque::from([merged[0]]);
+        states[merged[0]] = 0;
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for &(_, child) in &nodes[node].1 {
+                if states[child] == State::MAX {
+                    states[child] = (order.len() + queue.len()) as State;
+                    queue.push_back(child);
+                }
+            }
+        }
+
+        let mut offsets = Vec::with_capacity(order.len() + 1);
+        let mut edges = Vec::new();
+        let mut words = Vec::with_capacity(order.len());
+        for node in order {
+            let (is_word, children) = &nodes[node];
+            offsets.push(edges.len() as u32);
+            edges.extend(children.iter().map(|&(c, child)| (c, states[child])));
+            words.push(*is_word);
+        }
+        offsets.push(edges.len() as u32);
+
+        Dafsa {
+            offsets,
+            edges,
+            words,
+            max_key_length,
+        }
+    }
+
+    /// Follows `c` from `state`, returning the state it leads to and whether that completes a
+    /// key, or `state` itself if no key continues with `c`.
+    pub fn step(&self, state: State, c: char) -> (Match, State) {
+        let next = if c.is_ascii() {
+            self.transition(state, c.to_ascii_uppercase())
+        } else {
+            c.to_uppercase()
+                .try_fold(state, |state, upper| self.transition(state, upper))
+        };
+        match next {
+            Some(next) if self.words[next as usize] => (Match::Exists, next),
+            Some(next) => (Match::Prefix, next),
+            None => (Match::Failed, state),
+        }
+    }
+
+    /// Follows every char of `text` from `state`, see `step`. Empty text never matches.
+    pub fn walk(&self, state: State, text: &str) -> (Match, State) {
+        let mut result = (Match::Failed, state);
+        for c in text.chars() {
+            result = self.step(result.1, c);
+            if result.0 == Match::Failed {
+                break;
+            }
+        }
+        result
+    }
+
+    /// The keys within `max_distance` edits of `text`, an edit being the insertion, deletion or
+    /// substitution of a char, along with their distance, closest first and alphabetically among
+    /// equally close ones. Text is uppercased as it is by `step`.
+    pub fn suggest(&self, text: &str, max_distance: usize) -> Vec<(String, usize)> {
+        let text = text
+            .chars()
+            .flat_map(char::to_uppercase)
+            .collect::<Vec<_>>();
+        let row = (0..=text.len()).collect::<Vec<_>>();
+        let mut suggestions = Vec::new();
+        self.suggest_from(
+            Dafsa::ROOT,
+            &text,
+            &row,
+            max_distance,
+            &mut String::new(),
+            &mut suggestions,
+        );
+        suggestions.sort_by(|(a, a_distance), (b, b_distance)| {
+            a_distance.cmp(b_distance).then_with(|| a.cmp(b))
+        });
+        suggestions
+    }
+
+    // Walks the keys starting with `key`, which leads to `state`, where `row` holds the distances
+    // between `key` and each prefix of `text`. A branch is given up on once every distance in its
+    // row exceeds `max_distance`, since following more chars never brings them back down.
+    fn suggest_from(
+        &self,
+        state: State,
+        text: &[char],
+        row: &[usize],
+        max_distance: usize,
+        key: &mut String,
+        suggestions: &mut Vec<(String, usize)>,
+    ) {
+        if self.words[state as usize] && row[text.len()] <= max_distance {
+            suggestions.push((key.clone(), row[text.len()]));
+        }
+        for &(c, next) in self.edges(state) {
+            let mut next_row = Vec::with_capacity(row.len());
+            next_row.push(row[0] + 1);
+            for (i, &text_char) in text.iter().enumerate() {
+                let substitution = row[i] + usize::from(text_char != c);
+                next_row.push(substitution.min(row[i + 1] + 1).min(next_row[i] + 1));
+            }
+            if next_row.iter().any(|&distance| distance <= max_distance) {
+                key.push(c);
+                self.suggest_from(next, text, &next_row, max_distance, key, suggestions);
+                key.pop();
+            }
+        }
+    }
+
+    pub(crate) fn write(&self, w: &mut Writer) {
+        w.len(self.max_key_length);
+        w.len(self.words.len());
+        for (state, &is_word) in self.words.iter().enumerate() {
+            w.bool(&is_word);
+            let edges = self.edges(state as State);
+            w.len(edges.len());
+            for (c, next) in edges {
+                w.char(c);
+                w.u32(*next);
+            }
+        }
+    }
+
+    /// Reads what `write` wrote, checking that it's an automaton `transition` can follow
+    /// without going out of bounds.
+    pub(crate) fn read(r: &mut Reader) -> Result<Dafsa, SnapshotError> {
+        let max_key_length = r.u32()? as usize;
+        let states = r.len()?;
+        if states == 0 {
+            return Err(SnapshotError::new("its keyword automaton has no root"));
+        }
+        let mut offsets = Vec::with_capacity(states + 1);
+        let mut edges = Vec::new();
+        let mut words = Vec::with_capacity(states);
+        for _ in 0..states {
+            words.push(r.bool()?);
+            offsets.push(edges.len() as u32);
+            let mut previous = None;
+            for _ in 0..r.len()? {
+                let (c, next) = (r.char()?, r.u32()?);
+                if next as usize >= states || previous.is_some_and(|previous| previous >= c) {
+                    return Err(SnapshotError::new("its keyword automaton is malformed"));
+                }
+                previous = Some(c);
+                edges.push((c, next));
+            }
+        }
+        offsets.push(edges.len() as u32);
+
+        Ok(Dafsa {
+            offsets,
+            edges,
+            words,
+            max_key_length,
+        })
+    }
+
+    fn edges(&self, state: State) -> &[(char, State)] {
+        let state = state as usize;
+        &self.edges[self.offsets[state] as usize..self.offsets[state + 1] as usize]
+    }
+
+    fn transition(&self, state: State, c: char) -> Option<State> {
+        let edges = self.edges(state);
+        edges
+            .binary_search_by_key(&c, |&(c, _)| c)
+            .ok()
+            .map(|i| edges[i].1)
+    }
+}
@@ -0,0 +1,367 @@
+//! A C ABI over the scanner, so that hosts other than Python, e.g. Go through cgo or Java through
+//! JNI, can embed it. It's only compiled with the `ffi` feature, and `include/sqlglotrs.h`
+//! declares it for C. The shared library can be built with
+//!
+//! ```text
+//! cargo rustc -p sqlglotrs-core --release --features ffi --crate-type cdylib
+//! ```
+//!
+//! Every object the library returns is owned by the caller, who frees it with the matching
+//! `_free` function, and everything handed out by an object, e.g. the texts of a token list, lives
+//! as long as that object. Texts are UTF-8 and, except for error messages, not NUL-terminated, so
+//! their length is returned along with them. Positions are char offsets into the query, while
+//! columns are counted in the tokenizer's position encoding, chars by default.
+
+use crate::presets;
+use crate::settings::{PositionEncoding, TokenType, TokenizerDialectSettings};
+use crate::token::RawToken;
+use crate::tokenizer::TokenizerConfig;
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+use std::sync::Arc;
+
+/// A tokenizer definition along with the dialect settings it scans with.
+pub struct SqlglotTokenizer {
+    config: Arc<TokenizerConfig>,
+    dialect_settings: TokenizerDialectSettings,
+}
+
+/// The tokens of a scan, along with the error it stopped at, if any.
+pub struct SqlglotTokens {
+    tokens: Vec<RawToken>,
+    // The kind and the message of the error
+    error: Option<(CString, CString)>,
+}
+
+/// Creates a tokenizer from the preset of a builtin dialect, named as in sqlglot, e.g.
+/// `"postgres"`, where the default dialect is `""`. Returns NULL if there's no such preset.
+///
+/// # Safety
+///
+/// `dialect` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokenizer_for_dialect(
+    dialect: *const c_char,
+) -> *mut SqlglotTokenizer {
+    let Ok(dialect) = CStr::from_ptr(dialect).to_str() else {
+        return ptr::null_mut();
+    };
+    let (Some(settings), Some(token_types), Some(dialect_settings)) = (
+        presets::tokenizer_settings(dialect),
+        presets::token_type_settings(dialect),
+        presets::dialect_settings(dialect),
+    ) else {
+        return ptr::null_mut();
+    };
+    Box::into_raw(Box::new(SqlglotTokenizer {
+        config: TokenizerConfig::cached(settings, token_types),
+        dialect_settings,
+    }))
+}
+
+/// Creates a tokenizer from a snapshot of a definition, e.g. of a custom dialect, as written by
+/// `Tokenizer.to_snapshot` in Python. It scans with the default dialect settings, or with those
+/// of the builtin dialect `dialect` if it isn't NULL. Returns NULL if the snapshot can't be read
+/// or there's no such preset.
+///
+/// # Safety
+///
+/// `snapshot` must point to `len` readable bytes and `dialect` must be NULL or a NUL-terminated
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokenizer_from_snapshot(
+    snapshot: *const u8,
+    len: usize,
+    dialect: *const c_char,
+) -> *mut SqlglotTokenizer {
+    let dialect_settings = if dialect.is_null() {
+        TokenizerDialectSettings::default()
+    } else {
+        match CStr::from_ptr(dialect)
+            .to_str()
+            .ok()
+            .and_then(presets::dialect_settings)
+        {
+            Some(dialect_settings) => dialect_settings,
+            None => return ptr::null_mut(),
+        }
+    };
+    match TokenizerConfig::from_snapshot(bytes(snapshot, len)) {
+        Ok(config) => Box::into_raw(Box::new(SqlglotTokenizer {
+            config: Arc::new(config),
+            dialect_settings,
+        })),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Sets the most bytes a query can have and the most tokens a scan can produce, where 0 means
+/// there's no limit, see `TokenizerDialectSettings`.
+///
+/// # Safety
+///
+/// `tokenizer` must have been returned by this library and not be in use on another thread.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokenizer_set_limits(
+    tokenizer: *mut SqlglotTokenizer,
+    max_input_bytes: usize,
+    max_tokens: usize,
+) {
+    let settings = &mut (*tokenizer).dialect_settings;
+    settings.max_input_bytes = (max_input_bytes > 0).then_some(max_input_bytes);
+    settings.max_tokens = (max_tokens > 0).then_some(max_tokens);
+}
+
+/// Sets whether comments are emitted as tokens of their own, see `TokenizerDialectSettings`.
+///
+/// # Safety
+///
+/// `tokenizer` must have been returned by this library and not be in use on another thread.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokenizer_set_comment_tokens(
+    tokenizer: *mut SqlglotTokenizer,
+    comment_tokens: bool,
+) {
+    (*tokenizer).dialect_settings.comment_tokens = comment_tokens;
+}
+
+/// Sets whether the bodies of hints are scanned as tokens, see `TokenizerDialectSettings`.
+///
+/// # Safety
+///
+/// `tokenizer` must have been returned by this library and not be in use on another thread.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokenizer_set_hint_tokens(
+    tokenizer: *mut SqlglotTokenizer,
+    hint_tokens: bool,
+) {
+    (*tokenizer).dialect_settings.hint_tokens = hint_tokens;
+}
+
+/// Sets the units that columns are counted in, i.e. `"utf8"`, `"utf16"` or `"utf32"`, see
+/// `TokenizerDialectSettings`. Returns false, leaving the tokenizer as it was, for any other
+/// name.
+///
+/// # Safety
+///
+/// `tokenizer` must have been returned by this library and not be in use on another thread, and
+/// `encoding` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokenizer_set_position_encoding(
+    tokenizer: *mut SqlglotTokenizer,
+    encoding: *const c_char,
+) -> bool {
+    let encoding = match CStr::from_ptr(encoding).to_bytes() {
+        b"utf8" => PositionEncoding::Utf8,
+        b"utf16" => PositionEncoding::Utf16,
+        b"utf32" => PositionEncoding::Utf32,
+        _ => return false,
+    };
+    (*tokenizer).dialect_settings.position_encoding = encoding;
+    true
+}
+
+/// # Safety
+///
+/// `tokenizer` must be NULL or have been returned by this library, and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokenizer_free(tokenizer: *mut SqlglotTokenizer) {
+    if !tokenizer.is_null() {
+        drop(Box::from_raw(tokenizer));
+    }
+}
+
+/// Scans the `len` bytes of UTF-8 at `sql`. The result is never NULL: if the scan fails, it
+/// holds the tokens before the error, which `sqlglot_tokens_error` describes. A tokenizer can
+/// scan on several threads at once.
+///
+/// # Safety
+///
+/// `tokenizer` must have been returned by this library and `sql` must point to `len` readable
+/// bytes.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokenize(
+    tokenizer: *const SqlglotTokenizer,
+    sql: *const c_char,
+    len: usize,
+) -> *mut SqlglotTokens {
+    let tokenizer = &*tokenizer;
+    let result = match std::str::from_utf8(bytes(sql.cast(), len)) {
+        Ok(sql) => {
+            let (tokens, error) = tokenizer
+                .config
+                .tokenize_raw(sql, &tokenizer.dialect_settings);
+            SqlglotTokens {
+                tokens,
+                error: error
+                    .as_ref()
+                    .map(|error| (c_string(error.kind.as_str()), c_string(&error.to_string()))),
+            }
+        }
+        Err(error) => SqlglotTokens {
+            tokens: Vec::new(),
+            error: Some((
+                c_string("invalid_utf8"),
+                c_string(&format!("The query isn't valid UTF-8: {error}")),
+            )),
+        },
+    };
+    Box::into_raw(Box::new(result))
+}
+
+/// # Safety
+///
+/// `tokens` must be NULL or have been returned by this library, and neither it nor anything it
+/// handed out may be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokens_free(tokens: *mut SqlglotTokens) {
+    if !tokens.is_null() {
+        drop(Box::from_raw(tokens));
+    }
+}
+
+/// The message of the error the scan stopped at, or NULL if it succeeded.
+///
+/// # Safety
+///
+/// `tokens` must have been returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokens_error(tokens: *const SqlglotTokens) -> *const c_char {
+    match &(*tokens).error {
+        Some((_, message)) => message.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// The kind of the error the scan stopped at as a NUL-terminated string, i.e. the name of a
+/// `TokenizerErrorKind` such as `"unexpected_keyword"` or `"invalid_utf8"` if the query wasn't
+/// UTF-8, or NULL if it succeeded.
+///
+/// # Safety
+///
+/// `tokens` must have been returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokens_error_kind(tokens: *const SqlglotTokens) -> *const c_char {
+    match &(*tokens).error {
+        Some((kind, _)) => kind.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// # Safety
+///
+/// `tokens` must have been returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_tokens_len(tokens: *const SqlglotTokens) -> usize {
+    (*tokens).tokens.len()
+}
+
+/// The id of the `TokenType` of the token at `i`, see `sqlglot_token_type_name`.
+///
+/// # Safety
+///
+/// `tokens` must have been returned by this library and `i` must be less than its length.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_token_type(tokens: *const SqlglotTokens, i: usize) -> TokenType {
+    token(tokens, i).token_type
+}
+
+/// The text of the token at `i`, whose length in bytes is written to `len`.
+///
+/// # Safety
+///
+/// `tokens` must have been returned by this library, `i` must be less than its length and `len`
+/// must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_token_text(
+    tokens: *const SqlglotTokens,
+    i: usize,
+    len: *mut usize,
+) -> *const c_char {
+    let text = token(tokens, i).text.as_str();
+    *len = text.len();
+    text.as_ptr().cast()
+}
+
+/// Writes the line and column the token at `i` ends on and its first and last char offsets.
+///
+/// # Safety
+///
+/// `tokens` must have been returned by this library, `i` must be less than its length and the
+/// other pointers must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_token_position(
+    tokens: *const SqlglotTokens,
+    i: usize,
+    line: *mut usize,
+    col: *mut usize,
+    start: *mut usize,
+    end: *mut usize,
+) {
+    let token = token(tokens, i);
+    (*line, *col, *start, *end) = (token.line, token.col, token.start, token.end);
+}
+
+/// The number of comments attached to the token at `i`.
+///
+/// # Safety
+///
+/// `tokens` must have been returned by this library and `i` must be less than its length.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_token_comments_len(
+    tokens: *const SqlglotTokens,
+    i: usize,
+) -> usize {
+    token(tokens, i).comments.len()
+}
+
+/// The comment at `j` of the token at `i`, whose length in bytes is written to `len`.
+///
+/// # Safety
+///
+/// `tokens` must have been returned by this library, `i` and `j` must be less than the number of
+/// tokens and of their comments, and `len` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn sqlglot_token_comment(
+    tokens: *const SqlglotTokens,
+    i: usize,
+    j: usize,
+    len: *mut usize,
+) -> *const c_char {
+    let comment = &token(tokens, i).comments[j];
+    *len = comment.len();
+    comment.as_ptr().cast()
+}
+
+/// The name of the sqlglot `TokenType` with the given id as a static NUL-terminated string, e.g.
+/// `"SELECT"`, or NULL for custom token types.
+#[no_mangle]
+pub extern "C" fn sqlglot_token_type_name(token_type: TokenType) -> *const c_char {
+    static NAMES: std::sync::OnceLock<Vec<CString>> = std::sync::OnceLock::new();
+    let names = NAMES.get_or_init(|| {
+        (0..)
+            .map_while(presets::token_type_name)
+            .map(|name| CString::new(name).unwrap())
+            .collect()
+    });
+    names
+        .get(token_type as usize)
+        .map_or(ptr::null(), |name| name.as_ptr())
+}
+
+// Queries can contain NULs, which would end an error message early
+fn c_string(text: &str) -> CString {
+    CString::new(text.replace('\0', "\\0")).unwrap()
+}
+
+unsafe fn bytes<'a>(data: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data, len)
+    }
+}
+
+unsafe fn token<'a>(tokens: *const SqlglotTokens, i: usize) -> &'a RawToken {
+    &(&(*tokens).tokens)[i]
+}
@@ -0,0 +1,102 @@
+use crate::placeholder::find_all_placeholders;
+use crate::settings::TokenTypeSettings;
+use crate::source::Source;
+use crate::token::RawToken;
+use std::borrow::Cow;
+
+// FNV-1a, which unlike the hashers in std is specified, so fingerprints stay the same across
+// processes, platforms and releases
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Reduces a token stream to the parts its fingerprint is computed from: the query shape, with
+/// bind parameters of any style, such as `:id`, `%s` or `$1`, collapsed into a single `?`, and
+/// trailing semicolons dropped.
+pub fn fingerprint_parts<'a>(
+    source: &Source,
+    tokens: &'a [RawToken],
+    token_types: &TokenTypeSettings,
+) -> Vec<Cow<'a, str>> {
+    let mut end = tokens.len();
+    while end > 0 && tokens[end - 1].token_type == token_types.semicolon {
+        end -= 1;
+    }
+    let tokens = &tokens[..end];
+
+    let placeholders = find_all_placeholders(source, tokens);
+    let mut placeholders = placeholders
+        .iter()
+        .map(|(_, placeholder)| placeholder)
+        .peekable();
+    let mut parts = Vec::with_capacity(tokens.len());
+    for (i, part) in query_shape(source, tokens, token_types)
+        .into_iter()
+        .enumerate()
+    {
+        let token = &tokens[i];
+        while placeholders
+            .next_if(|placeholder| placeholder.end < token.start)
+            .is_some()
+        {}
+        if let Some(placeholder) = placeholders.peek() {
+            if placeholder.start == token.start {
+                parts.push(Cow::Borrowed("?"));
+                continue;
+            }
+            // The rest of a placeholder's tokens, e.g. the name following its sigil
+            if placeholder.start < token.start {
+                continue;
+            }
+        }
+        if i > 0 {
+            let prev = &tokens[i - 1];
+            // The name or number of a parameter directly follows its sigil
+            if prev.token_type == token_types.parameter
+                && token.start == prev.end + 1
+                && (token.token_type == token_types.var || token.token_type == token_types.number)
+            {
+                continue;
+            }
+        }
+        parts.push(part);
+    }
+    parts
+}
+
+/// Hashes fingerprint parts into 16 hex digits.
+pub fn hash_parts<S: AsRef<str>>(parts: &[S]) -> String {
+    let mut hash = FNV_OFFSET_BASIS;
+    for part in parts {
+        // 0xff never occurs in UTF-8, so it can't be confused with the contents of a part
+        for &byte in part.as_ref().as_bytes().iter().chain(&[0xff]) {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+    format!("{:016x}", hash)
+}
+
+/// Reduces a token stream to the shape of the query: literals and parameters become `?`, and
+/// unquoted words are uppercased since their case doesn't matter. Quoted identifiers are kept as
+/// they are.
+pub fn query_shape<'a>(
+    source: &Source,
+    tokens: &'a [RawToken],
+    token_types: &TokenTypeSettings,
+) -> Vec<Cow<'a, str>> {
+    tokens
+        .iter()
+        .map(|token| {
+            if token.token_type == token_types.identifier {
+                Cow::Borrowed(&*token.text)
+            } else if token.token_type == token_types.number
+                || token.token_type == token_types.parameter
+                || !source.is_verbatim_words(token)
+            {
+                Cow::Borrowed("?")
+            } else {
+                Cow::Owned(token.text.to_uppercase())
+            }
+        })
+        .collect()
+}
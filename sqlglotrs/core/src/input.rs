@@ -0,0 +1,168 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+/// The SQL of a file as the scanner reads it, a chunk at a time, e.g. with a `ReaderScan`, which
+/// also decodes it a chunk at a time. Uncompressed files are memory-mapped on unix, so that
+/// multi-GB dumps are paged in by the OS as the scanner reaches them instead of being read into a
+/// buffer, let alone a string, first. Compressed files, and every file where files can't be
+/// mapped, are read as a stream, so that they're decompressed as they're scanned.
+pub enum SqlFile {
+    #[cfg(unix)]
+    Mapped {
+        mapping: Mapping,
+        read: usize,
+    },
+    Stream(Box<dyn Read + Send>),
+}
+
+impl SqlFile {
+    pub fn open(path: &Path) -> io::Result<SqlFile> {
+        #[cfg(unix)]
+        if !is_compressed(path) {
+            let mapping = Mapping::new(&File::open(path)?)?;
+            return Ok(SqlFile::Mapped { mapping, read: 0 });
+        }
+        let file = BufReader::new(File::open(path)?);
+        decoder(path, file).map(SqlFile::Stream)
+    }
+}
+
+impl Read for SqlFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            #[cfg(unix)]
+            SqlFile::Mapped { mapping, read } => {
+                let count = (&mapping.bytes()[*read..]).read(buf)?;
+                *read += count;
+                Ok(count)
+            }
+            SqlFile::Stream(reader) => reader.read(buf),
+        }
+    }
+}
+
+/// A read-only, private mapping of a whole file, which is unmapped on drop.
+#[cfg(unix)]
+pub struct Mapping {
+    ptr: *mut libc::c_void,
+    len: usize,
+}
+
+// SAFETY: the mapping is never written to, so it can be read from any thread
+#[cfg(unix)]
+unsafe impl Send for Mapping {}
+
+#[cfg(unix)]
+impl Mapping {
+    fn new(file: &File) -> io::Result<Mapping> {
+        use std::os::unix::io::AsRawFd;
+
+        let len = usize::try_from(file.metadata()?.len())
+            .map_err(|error| io::Error::new(io::ErrorKind::OutOfMemory, error))?;
+        // Empty mappings are rejected by mmap
+        if len == 0 {
+            return Ok(Mapping {
+                ptr: std::ptr::null_mut(),
+                len,
+            });
+        }
+
+        // SAFETY: the file is mapped read-only and privately, so the mapping never aliases
+        // memory owned by Rust. Truncating the file while it's mapped is the caller's problem,
+        // as with any other mmap of a shared file.
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                len,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: the range is the mapping itself. The advice only tunes read-ahead, so a
+        // failure is harmless.
+        unsafe { libc::madvise(ptr, len, libc::MADV_SEQUENTIAL) };
+        Ok(Mapping { ptr, len })
+    }
+
+    /// The mapped bytes, which are paged in as they're read.
+    pub fn bytes(&self) -> &[u8] {
+        if self.len == 0 {
+            return &[];
+        }
+        // SAFETY: the mapping is `len` readable bytes that live as long as `self`
+        unsafe { std::slice::from_raw_parts(self.ptr as *const u8, self.len) }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Mapping {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            // SAFETY: the mapping was created by `Mapping::new` and isn't borrowed anymore
+            unsafe { libc::munmap(self.ptr, self.len) };
+        }
+    }
+}
+
+#[cfg(unix)]
+fn is_compressed(path: &Path) -> bool {
+    matches!(extension(path).as_deref(), Some("gz" | "zst"))
+}
+
+// Extensions are matched in any case, e.g. in `DUMP.SQL.GZ`
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+}
+
+/// Reads `reader` as the file at `path`, decompressing `.gz` and `.zst` files on the fly so that
+/// compressed exports never have to be expanded to disk first.
+fn decoder<R: BufRead + Send + 'static>(
+    path: &Path,
+    reader: R,
+) -> io::Result<Box<dyn Read + Send>> {
+    match extension(path).as_deref() {
+        Some("gz") => gzip_decoder(reader),
+        Some("zst") => zstd_decoder(reader),
+        _ => Ok(Box::new(reader)),
+    }
+}
+
+#[cfg(feature = "gzip")]
+fn gzip_decoder<R: BufRead + Send + 'static>(reader: R) -> io::Result<Box<dyn Read + Send>> {
+    // Concatenated gzip members are common in chunked warehouse exports
+    Ok(Box::new(flate2::bufread::MultiGzDecoder::new(reader)))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gzip_decoder<R: BufRead + Send + 'static>(_reader: R) -> io::Result<Box<dyn Read + Send>> {
+    Err(unsupported_compression("gzip"))
+}
+
+#[cfg(feature = "zstd")]
+fn zstd_decoder<R: BufRead + Send + 'static>(reader: R) -> io::Result<Box<dyn Read + Send>> {
+    Ok(Box::new(zstd::Decoder::with_buffer(reader)?))
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd_decoder<R: BufRead + Send + 'static>(_reader: R) -> io::Result<Box<dyn Read + Send>> {
+    Err(unsupported_compression("zstd"))
+}
+
+#[cfg(not(all(feature = "gzip", feature = "zstd")))]
+fn unsupported_compression(feature: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!(
+            "Reading {} compressed input requires sqlglotrs-core to be built with the `{}` feature",
+            feature, feature
+        ),
+    )
+}
@@ -0,0 +1,22 @@
+//! The SQL scanner behind sqlglotrs, for Rust programs that want to tokenize queries the way
+//! sqlglot does without embedding Python. The `sqlglotrs` crate wraps these types in its
+//! bindings.
+
+pub mod dafsa;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fingerprint;
+pub mod input;
+pub mod placeholder;
+pub mod presets;
+pub mod settings;
+pub mod snapshot;
+pub mod source;
+pub mod statement;
+pub mod stats;
+pub mod token;
+pub mod tokenizer;
+pub mod trace;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod words;
@@ -0,0 +1,208 @@
+use crate::source::{adjacent, Source};
+use crate::token::RawToken;
+
+/// The placeholder styles understood by `convert_placeholders`, named after DB-API paramstyles
+/// plus `dollar` for Postgres-style `$1` and `at` for T-SQL-style `@name`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaceholderStyle {
+    /// `?`
+    Qmark,
+    /// `:1`
+    Numeric,
+    /// `:name`
+    Named,
+    /// `%s`
+    Format,
+    /// `%(name)s`
+    Pyformat,
+    /// `$1`
+    Dollar,
+    /// `@name`
+    At,
+}
+
+impl PlaceholderStyle {
+    pub const ALL: [PlaceholderStyle; 7] = [
+        PlaceholderStyle::Qmark,
+        PlaceholderStyle::Numeric,
+        PlaceholderStyle::Named,
+        PlaceholderStyle::Format,
+        PlaceholderStyle::Pyformat,
+        PlaceholderStyle::Dollar,
+        PlaceholderStyle::At,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PlaceholderStyle::Qmark => "qmark",
+            PlaceholderStyle::Numeric => "numeric",
+            PlaceholderStyle::Named => "named",
+            PlaceholderStyle::Format => "format",
+            PlaceholderStyle::Pyformat => "pyformat",
+            PlaceholderStyle::Dollar => "dollar",
+            PlaceholderStyle::At => "at",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<PlaceholderStyle> {
+        PlaceholderStyle::ALL
+            .into_iter()
+            .find(|style| style.as_str() == name)
+    }
+
+    pub fn is_named(self) -> bool {
+        matches!(
+            self,
+            PlaceholderStyle::Named | PlaceholderStyle::Pyformat | PlaceholderStyle::At
+        )
+    }
+
+    pub fn is_numbered(self) -> bool {
+        matches!(self, PlaceholderStyle::Numeric | PlaceholderStyle::Dollar)
+    }
+
+    pub fn render(self, key: &str) -> String {
+        match self {
+            PlaceholderStyle::Qmark => "?".to_string(),
+            PlaceholderStyle::Numeric => format!(":{}", key),
+            PlaceholderStyle::Named => format!(":{}", key),
+            PlaceholderStyle::Format => "%s".to_string(),
+            PlaceholderStyle::Pyformat => format!("%({})s", key),
+            PlaceholderStyle::Dollar => format!("${}", key),
+            PlaceholderStyle::At => format!("@{}", key),
+        }
+    }
+}
+
+/// A placeholder found in a token stream, spanning the chars `start..=end` of the query. The key
+/// is the parameter's name for named styles and its 1-based position otherwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Placeholder {
+    pub start: usize,
+    pub end: usize,
+    pub key: String,
+}
+
+/// Finds the placeholders of the given style. Strings, quoted identifiers and comments never
+/// match, since only tokens that appear verbatim in the query are considered.
+pub fn find_placeholders(
+    source: &Source,
+    tokens: &[RawToken],
+    style: PlaceholderStyle,
+) -> Vec<Placeholder> {
+    let text = |i: usize| {
+        tokens
+            .get(i)
+            .filter(|token| source.is_verbatim(token))
+            .map(|token| &*token.text)
+    };
+    // The text of the token `offset` positions after `i`, if nothing separates the two
+    let next = |i: usize, offset: usize| {
+        (i + offset < tokens.len() && adjacent(&tokens[i + offset - 1], &tokens[i + offset]))
+            .then(|| text(i + offset))
+            .flatten()
+    };
+
+    let mut placeholders = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let found = match (style, text(i)) {
+            (PlaceholderStyle::Qmark, Some("?")) => Some((0, (placeholders.len() + 1).to_string())),
+            (PlaceholderStyle::Format, Some("%")) => match next(i, 1) {
+                Some("s") => Some((1, (placeholders.len() + 1).to_string())),
+                _ => None,
+            },
+            // Tokenizers with `placeholder_styles` scan each placeholder as a single token
+            (PlaceholderStyle::Format, Some("%s")) => {
+                Some((0, (placeholders.len() + 1).to_string()))
+            }
+            (PlaceholderStyle::Numeric, Some(":")) => next(i, 1)
+                .filter(|t| is_number(t))
+                .map(|t| (1, t.to_string())),
+            (PlaceholderStyle::Numeric, Some(t)) => t
+                .strip_prefix(':')
+                .filter(|t| is_number(t))
+                .map(|t| (0, t.to_string())),
+            (PlaceholderStyle::Named, Some(":")) => next(i, 1)
+                .filter(|t| is_name(t))
+                .map(|t| (1, t.to_string())),
+            (PlaceholderStyle::Named, Some(t)) => t
+                .strip_prefix(':')
+                .filter(|t| is_name(t))
+                .map(|t| (0, t.to_string())),
+            (PlaceholderStyle::Pyformat, Some("%")) => {
+                match (next(i, 1), next(i, 2), next(i, 3), next(i, 4)) {
+                    (Some("("), Some(name), Some(")"), Some("s")) if is_name(name) => {
+                        Some((4, name.to_string()))
+                    }
+                    _ => None,
+                }
+            }
+            (PlaceholderStyle::Pyformat, Some(t)) => t
+                .strip_prefix("%(")
+                .and_then(|t| t.strip_suffix(")s"))
+                .filter(|t| is_name(t))
+                .map(|t| (0, t.to_string())),
+            (PlaceholderStyle::Dollar, Some("$")) => next(i, 1)
+                .filter(|t| is_number(t))
+                .map(|t| (1, t.to_string())),
+            // Dialects without a `$` single token scan `$1` as a single word
+            (PlaceholderStyle::Dollar, Some(t)) => t
+                .strip_prefix('$')
+                .filter(|t| is_number(t))
+                .map(|t| (0, t.to_string())),
+            // The second `@` of a variable such as MySQL's `@@sql_mode` doesn't start a parameter
+            (PlaceholderStyle::At, Some("@"))
+                if i == 0 || text(i - 1) != Some("@") || !adjacent(&tokens[i - 1], &tokens[i]) =>
+            {
+                next(i, 1)
+                    .filter(|t| is_name(t))
+                    .map(|t| (1, t.to_string()))
+            }
+            // Dialects where `@` can start a word scan `@name` as a single one
+            (PlaceholderStyle::At, Some(t)) => t
+                .strip_prefix('@')
+                .filter(|t| is_name(t))
+                .map(|t| (0, t.to_string())),
+            _ => None,
+        };
+
+        if let Some((length, key)) = found {
+            placeholders.push(Placeholder {
+                start: tokens[i].start,
+                end: tokens[i + length].end,
+                key,
+            });
+            i += length;
+        }
+        i += 1;
+    }
+    placeholders
+}
+
+/// Finds the placeholders of every style, in order of appearance, along with their style.
+pub fn find_all_placeholders(
+    source: &Source,
+    tokens: &[RawToken],
+) -> Vec<(PlaceholderStyle, Placeholder)> {
+    let mut placeholders: Vec<_> = PlaceholderStyle::ALL
+        .into_iter()
+        .flat_map(|style| {
+            find_placeholders(source, tokens, style)
+                .into_iter()
+                .map(move |placeholder| (style, placeholder))
+        })
+        .collect();
+    placeholders.sort_by_key(|(_, placeholder)| placeholder.start);
+    placeholders
+}
+
+fn is_number(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_name(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
@@ -0,0 +1,141 @@
+//! Settings of sqlglot's builtin dialects, embedded so that they don't have to be converted from
+//! Python every time a tokenizer is created. They are generated from the dialects by
+//! `gen_presets.py`, so their token types are the ids of sqlglot's `TokenType` members.
+
+use crate::settings::{
+    ControlCharacters, NulBytes, PositionEncoding, TokenType, TokenTypeSettings,
+    TokenizerDialectSettings, TokenizerSettings,
+};
+use std::sync::Arc;
+
+#[cfg(feature = "presets")]
+#[rustfmt::skip]
+mod data;
+
+#[cfg(feature = "presets")]
+use data::{PRESETS, TOKEN_TYPE_NAMES};
+
+#[cfg(not(feature = "presets"))]
+static PRESETS: &[Preset] = &[];
+#[cfg(not(feature = "presets"))]
+static TOKEN_TYPE_NAMES: &[&str] = &[];
+
+/// The settings of a dialect, named as in sqlglot, where the default dialect is "".
+struct Preset {
+    dialect: &'static str,
+    settings: &'static SettingsPreset,
+    token_types: TokenTypeSettings,
+    unescaped_sequences: &'static [(&'static str, &'static str)],
+    identifiers_can_start_with_digit: bool,
+    numbers_can_be_underscore_separated: bool,
+    unicode_identifiers: bool,
+}
+
+/// `TokenizerSettings` as static tables, which dialects that share a tokenizer point to.
+struct SettingsPreset {
+    white_space: &'static [(char, TokenType)],
+    single_tokens: &'static [(char, TokenType)],
+    keywords: &'static [(&'static str, TokenType)],
+    numeric_literals: &'static [(&'static str, &'static str)],
+    identifiers: &'static [(char, char)],
+    identifier_escapes: &'static [char],
+    string_escapes: &'static [char],
+    quotes: &'static [(&'static str, &'static str)],
+    format_strings: &'static [(&'static str, &'static str, TokenType)],
+    verbatim_strings: &'static [&'static str],
+    has_bit_strings: bool,
+    has_hex_strings: bool,
+    comments: &'static [(&'static str, Option<&'static str>)],
+    var_single_tokens: &'static [char],
+    placeholder_styles: &'static [&'static str],
+    commands: &'static [TokenType],
+    command_prefix_tokens: &'static [TokenType],
+    tokens_preceding_hint: &'static [TokenType],
+    heredoc_tag_is_identifier: bool,
+    string_escapes_allowed_in_raw_strings: bool,
+    nested_comments: bool,
+    hint_start: &'static str,
+}
+
+fn find(dialect: &str) -> Option<&'static Preset> {
+    PRESETS.iter().find(|preset| preset.dialect == dialect)
+}
+
+/// The names of the dialects that have presets, which is empty without the `presets` feature.
+pub fn dialects() -> impl Iterator<Item = &'static str> {
+    PRESETS.iter().map(|preset| preset.dialect)
+}
+
+/// Returns the tokenizer settings of `dialect`, or `None` if it has no preset.
+pub fn tokenizer_settings(dialect: &str) -> Option<TokenizerSettings> {
+    let preset = find(dialect)?.settings;
+    Some(TokenizerSettings {
+        white_space: table(preset.white_space, |&(k, v)| (k, v)),
+        single_tokens: table(preset.single_tokens, |&(k, v)| (k, v)),
+        keywords: table(preset.keywords, |&(k, v)| (k.to_string(), v)),
+        numeric_literals: table(preset.numeric_literals, |&(k, v)| {
+            (k.to_string(), v.to_string())
+        }),
+        identifiers: table(preset.identifiers, |&(k, v)| (k, v)),
+        identifier_escapes: table(preset.identifier_escapes, |&c| c),
+        string_escapes: table(preset.string_escapes, |&c| c),
+        quotes: table(preset.quotes, |&(k, v)| (k.to_string(), v.to_string())),
+        format_strings: table(preset.format_strings, |&(k, end, token_type)| {
+            (k.to_string(), (end.to_string(), token_type))
+        }),
+        verbatim_strings: table(preset.verbatim_strings, |&k| k.to_string()),
+        has_bit_strings: preset.has_bit_strings,
+        has_hex_strings: preset.has_hex_strings,
+        comments: table(preset.comments, |&(k, v)| {
+            (k.to_string(), v.map(str::to_string))
+        }),
+        var_single_tokens: table(preset.var_single_tokens, |&c| c),
+        placeholder_styles: table(preset.placeholder_styles, |&k| k.to_string()),
+        commands: table(preset.commands, |&t| t),
+        command_prefix_tokens: table(preset.command_prefix_tokens, |&t| t),
+        tokens_preceding_hint: table(preset.tokens_preceding_hint, |&t| t),
+        heredoc_tag_is_identifier: preset.heredoc_tag_is_identifier,
+        string_escapes_allowed_in_raw_strings: preset.string_escapes_allowed_in_raw_strings,
+        nested_comments: preset.nested_comments,
+        hint_start: preset.hint_start.to_string(),
+    })
+}
+
+/// Returns the token types `dialect` scans with. Dialects only disagree on their
+/// `heredoc_string_alternative`.
+pub fn token_type_settings(dialect: &str) -> Option<TokenTypeSettings> {
+    Some(find(dialect)?.token_types.clone())
+}
+
+/// Returns the settings the Python side takes from the `Dialect` rather than its tokenizer.
+pub fn dialect_settings(dialect: &str) -> Option<TokenizerDialectSettings> {
+    let preset = find(dialect)?;
+    Some(TokenizerDialectSettings {
+        unescaped_sequences: preset
+            .unescaped_sequences
+            .iter()
+            .map(|&(k, v)| (k.to_string(), v.to_string()))
+            .collect(),
+        identifiers_can_start_with_digit: preset.identifiers_can_start_with_digit,
+        numbers_can_be_underscore_separated: preset.numbers_can_be_underscore_separated,
+        unicode_identifiers: preset.unicode_identifiers,
+        versioned_comments_version: None,
+        control_characters: ControlCharacters::Keep,
+        nul_bytes: NulBytes::Text,
+        max_input_bytes: None,
+        max_tokens: None,
+        comment_tokens: false,
+        hint_tokens: false,
+        position_encoding: PositionEncoding::Utf32,
+    })
+}
+
+/// The name of the sqlglot `TokenType` member that a token type of the presets stands for, e.g.
+/// `"SELECT"`, or `None` if it's a custom one or there are no presets.
+pub fn token_type_name(token_type: TokenType) -> Option<&'static str> {
+    TOKEN_TYPE_NAMES.get(token_type as usize).copied()
+}
+
+fn table<S, E, T: FromIterator<E>>(entries: &[S], entry: impl Fn(&S) -> E) -> Arc<T> {
+    Arc::new(entries.iter().map(entry).collect())
+}
@@ -0,0 +1,2364 @@
+// @generated by gen_presets.py, don't edit this file by hand
+
+use super::{Preset, SettingsPreset};
+use crate::settings::TokenTypeSettings;
+
+pub(super) static PRESETS: &[Preset] = &[
+    Preset {
+        dialect: "",
+        settings: &DEFAULT,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "bigquery",
+        settings: &BIGQUERY,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[
+            ("\\\\", "\\"), ("\\a", "\u{7}"), ("\\b", "\u{8}"), ("\\f", "\u{c}"),
+            ("\\n", "\n"), ("\\r", "\r"), ("\\t", "\t"), ("\\v", "\u{b}"),
+        ],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "clickhouse",
+        settings: &CLICKHOUSE,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[
+            ("\\0", "\u{0}"), ("\\\\", "\\"), ("\\a", "\u{7}"), ("\\b", "\u{8}"),
+            ("\\f", "\u{c}"), ("\\n", "\n"), ("\\r", "\r"), ("\\t", "\t"),
+            ("\\v", "\u{b}"),
+        ],
+        identifiers_can_start_with_digit: true,
+        numbers_can_be_underscore_separated: true,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "databricks",
+        settings: &DATABRICKS,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[
+            ("\\\\", "\\"), ("\\a", "\u{7}"), ("\\b", "\u{8}"), ("\\f", "\u{c}"),
+            ("\\n", "\n"), ("\\r", "\r"), ("\\t", "\t"), ("\\v", "\u{b}"),
+        ],
+        identifiers_can_start_with_digit: true,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "duckdb",
+        settings: &DUCKDB,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 49,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: true,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "hive",
+        settings: &HIVE,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[
+            ("\\\\", "\\"), ("\\a", "\u{7}"), ("\\b", "\u{8}"), ("\\f", "\u{c}"),
+            ("\\n", "\n"), ("\\r", "\r"), ("\\t", "\t"), ("\\v", "\u{b}"),
+        ],
+        identifiers_can_start_with_digit: true,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "mysql",
+        settings: &MYSQL,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[
+            ("\\\\", "\\"), ("\\a", "\u{7}"), ("\\b", "\u{8}"), ("\\f", "\u{c}"),
+            ("\\n", "\n"), ("\\r", "\r"), ("\\t", "\t"), ("\\v", "\u{b}"),
+        ],
+        identifiers_can_start_with_digit: true,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "oracle",
+        settings: &ORACLE,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "postgres",
+        settings: &POSTGRES,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 49,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "presto",
+        settings: &PRESTO,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "redshift",
+        settings: &REDSHIFT,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 49,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[
+            ("\\\\", "\\"), ("\\a", "\u{7}"), ("\\b", "\u{8}"), ("\\f", "\u{c}"),
+            ("\\n", "\n"), ("\\r", "\r"), ("\\t", "\t"), ("\\v", "\u{b}"),
+        ],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "snowflake",
+        settings: &SNOWFLAKE,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[
+            ("\\\\", "\\"), ("\\a", "\u{7}"), ("\\b", "\u{8}"), ("\\f", "\u{c}"),
+            ("\\n", "\n"), ("\\r", "\r"), ("\\t", "\t"), ("\\v", "\u{b}"),
+        ],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "spark",
+        settings: &SPARK,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[
+            ("\\\\", "\\"), ("\\a", "\u{7}"), ("\\b", "\u{8}"), ("\\f", "\u{c}"),
+            ("\\n", "\n"), ("\\r", "\r"), ("\\t", "\t"), ("\\v", "\u{b}"),
+        ],
+        identifiers_can_start_with_digit: true,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "sqlite",
+        settings: &SQLITE,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "trino",
+        settings: &PRESTO,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+    Preset {
+        dialect: "tsql",
+        settings: &TSQL,
+        token_types: TokenTypeSettings {
+            bit_string: 71,
+            break_: 58,
+            dcolon: 12,
+            heredoc_string: 76,
+            raw_string: 75,
+            hex_string: 72,
+            identifier: 61,
+            number: 60,
+            parameter: 49,
+            semicolon: 14,
+            string: 59,
+            var: 70,
+            heredoc_string_alternative: 70,
+            hint: 264,
+            error: 392,
+            comment: 393,
+            placeholder: 322,
+            hint_end: 394,
+        },
+        unescaped_sequences: &[],
+        identifiers_can_start_with_digit: false,
+        numbers_can_be_underscore_separated: false,
+        unicode_identifiers: false,
+    },
+];
+
+pub(super) static TOKEN_TYPE_NAMES: &[&str] = &[
+    "L_PAREN", "R_PAREN", "L_BRACKET", "R_BRACKET", "L_BRACE", "R_BRACE",
+    "COMMA", "DOT", "DASH", "PLUS", "COLON", "DOTCOLON",
+    "DCOLON", "DQMARK", "SEMICOLON", "STAR", "BACKSLASH", "SLASH",
+    "LT", "LTE", "GT", "GTE", "NOT", "EQ",
+    "NEQ", "NULLSAFE_EQ", "COLON_EQ", "AND", "OR", "AMP",
+    "DPIPE", "PIPE_GT", "PIPE", "PIPE_SLASH", "DPIPE_SLASH", "CARET",
+    "CARET_AT", "TILDA", "ARROW", "DARROW", "FARROW", "HASH",
+    "HASH_ARROW", "DHASH_ARROW", "LR_ARROW", "DAT", "LT_AT", "AT_GT",
+    "DOLLAR", "PARAMETER", "SESSION_PARAMETER", "DAMP", "XOR", "DSTAR",
+    "URI_START", "BLOCK_START", "BLOCK_END", "SPACE", "BREAK", "STRING",
+    "NUMBER", "IDENTIFIER", "DATABASE", "COLUMN", "COLUMN_DEF", "SCHEMA",
+    "TABLE", "WAREHOUSE", "STAGE", "STREAMLIT", "VAR", "BIT_STRING",
+    "HEX_STRING", "BYTE_STRING", "NATIONAL_STRING", "RAW_STRING", "HEREDOC_STRING", "UNICODE_STRING",
+    "BIT", "BOOLEAN", "TINYINT", "UTINYINT", "SMALLINT", "USMALLINT",
+    "MEDIUMINT", "UMEDIUMINT", "INT", "UINT", "BIGINT", "UBIGINT",
+    "INT128", "UINT128", "INT256", "UINT256", "FLOAT", "DOUBLE",
+    "UDOUBLE", "DECIMAL", "DECIMAL32", "DECIMAL64", "DECIMAL128", "DECIMAL256",
+    "UDECIMAL", "BIGDECIMAL", "CHAR", "NCHAR", "VARCHAR", "NVARCHAR",
+    "BPCHAR", "TEXT", "MEDIUMTEXT", "LONGTEXT", "BLOB", "MEDIUMBLOB",
+    "LONGBLOB", "TINYBLOB", "TINYTEXT", "NAME", "BINARY", "VARBINARY",
+    "JSON", "JSONB", "TIME", "TIMETZ", "TIMESTAMP", "TIMESTAMPTZ",
+    "TIMESTAMPLTZ", "TIMESTAMPNTZ", "TIMESTAMP_S", "TIMESTAMP_MS", "TIMESTAMP_NS", "DATETIME",
+    "DATETIME2", "DATETIME64", "SMALLDATETIME", "DATE", "DATE32", "INT4RANGE",
+    "INT4MULTIRANGE", "INT8RANGE", "INT8MULTIRANGE", "NUMRANGE", "NUMMULTIRANGE", "TSRANGE",
+    "TSMULTIRANGE", "TSTZRANGE", "TSTZMULTIRANGE", "DATERANGE", "DATEMULTIRANGE", "UUID",
+    "GEOGRAPHY", "NULLABLE", "GEOMETRY", "POINT", "RING", "LINESTRING",
+    "MULTILINESTRING", "POLYGON", "MULTIPOLYGON", "HLLSKETCH", "HSTORE", "SUPER",
+    "SERIAL", "SMALLSERIAL", "BIGSERIAL", "XML", "YEAR", "USERDEFINED",
+    "MONEY", "SMALLMONEY", "ROWVERSION", "IMAGE", "VARIANT", "OBJECT",
+    "INET", "IPADDRESS", "IPPREFIX", "IPV4", "IPV6", "ENUM",
+    "ENUM8", "ENUM16", "FIXEDSTRING", "LOWCARDINALITY", "NESTED", "AGGREGATEFUNCTION",
+    "SIMPLEAGGREGATEFUNCTION", "TDIGEST", "UNKNOWN", "VECTOR", "DYNAMIC", "VOID",
+    "ALIAS", "ALTER", "ALWAYS", "ALL", "ANTI", "ANY",
+    "APPLY", "ARRAY", "ASC", "ASOF", "ATTACH", "AUTO_INCREMENT",
+    "BEGIN", "BETWEEN", "BULK_COLLECT_INTO", "CACHE", "CASE", "CHARACTER_SET",
+    "CLUSTER_BY", "COLLATE", "COMMAND", "COMMENT", "COMMIT", "CONNECT_BY",
+    "CONSTRAINT", "COPY", "CREATE", "CROSS", "CUBE", "CURRENT_DATE",
+    "CURRENT_DATETIME", "CURRENT_SCHEMA", "CURRENT_TIME", "CURRENT_TIMESTAMP", "CURRENT_USER", "DECLARE",
+    "DEFAULT", "DELETE", "DESC", "DESCRIBE", "DETACH", "DICTIONARY",
+    "DISTINCT", "DISTRIBUTE_BY", "DIV", "DROP", "ELSE", "END",
+    "ESCAPE", "EXCEPT", "EXECUTE", "EXISTS", "FALSE", "FETCH",
+    "FILE_FORMAT", "FILTER", "FINAL", "FIRST", "FOR", "FORCE",
+    "FOREIGN_KEY", "FORMAT", "FROM", "FULL", "FUNCTION", "GET",
+    "GLOB", "GLOBAL", "GRANT", "GROUP_BY", "GROUPING_SETS", "HAVING",
+    "HINT", "IGNORE", "ILIKE", "ILIKE_ANY", "IN", "INDEX",
+    "INNER", "INSERT", "INTERSECT", "INTERVAL", "INTO", "INTRODUCER",
+    "IRLIKE", "IS", "ISNULL", "JOIN", "JOIN_MARKER", "KEEP",
+    "KEY", "KILL", "LANGUAGE", "LATERAL", "LEFT", "LIKE",
+    "LIKE_ANY", "LIMIT", "LIST", "LOAD", "LOCK", "MAP",
+    "MATCH_CONDITION", "MATCH_RECOGNIZE", "MEMBER_OF", "MERGE", "MOD", "MODEL",
+    "NATURAL", "NEXT", "NOTHING", "NOTNULL", "NULL", "OBJECT_IDENTIFIER",
+    "OFFSET", "ON", "ONLY", "OPERATOR", "ORDER_BY", "ORDER_SIBLINGS_BY",
+    "ORDERED", "ORDINALITY", "OUTER", "OVER", "OVERLAPS", "OVERWRITE",
+    "PARTITION", "PARTITION_BY", "PERCENT", "PIVOT", "PLACEHOLDER", "POSITIONAL",
+    "PRAGMA", "PREWHERE", "PRIMARY_KEY", "PROCEDURE", "PROPERTIES", "PSEUDO_TYPE",
+    "PUT", "QUALIFY", "QUOTE", "RANGE", "RECURSIVE", "REFRESH",
+    "RENAME", "REPLACE", "RETURNING", "REFERENCES", "RIGHT", "RLIKE",
+    "ROLLBACK", "ROLLUP", "ROW", "ROWS", "SELECT", "SEMI",
+    "SEPARATOR", "SEQUENCE", "SERDE_PROPERTIES", "SET", "SETTINGS", "SHOW",
+    "SIMILAR_TO", "SOME", "SORT_BY", "START_WITH", "STORAGE_INTEGRATION", "STRAIGHT_JOIN",
+    "STRUCT", "SUMMARIZE", "TABLE_SAMPLE", "TAG", "TEMPORARY", "TOP",
+    "THEN", "TRUE", "TRUNCATE", "UNCACHE", "UNION", "UNNEST",
+    "UNPIVOT", "UPDATE", "USE", "USING", "VALUES", "VIEW",
+    "VOLATILE", "WHEN", "WHERE", "WINDOW", "WITH", "UNIQUE",
+    "VERSION_SNAPSHOT", "TIMESTAMP_SNAPSHOT", "OPTION", "SINK", "SOURCE", "ANALYZE",
+    "NAMESPACE", "EXPORT", "ERROR", "STANDALONE_COMMENT", "HINT_END", "HIVE_TOKEN_STREAM",
+];
+
+static DEFAULT: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('%', 298),
+        ('&', 29), ('\'', 188), ('(', 0), (')', 1),
+        ('*', 15), ('+', 9), (',', 6), ('-', 8),
+        ('.', 7), ('/', 17), (':', 10), (';', 14),
+        ('<', 18), ('=', 23), ('>', 20), ('?', 322),
+        ('@', 49), ('[', 2), ('\\', 16), (']', 3),
+        ('^', 35), ('`', 188), ('{', 4), ('|', 32),
+        ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("/*+", 264),
+        ("::", 12), (":=", 26), ("<->", 44), ("<=", 19),
+        ("<=>", 25), ("<>", 24), ("==", 23), ("=>", 40),
+        (">=", 21), ("??", 13), ("ALL", 195), ("ALTER", 193),
+        ("ALWAYS", 194), ("ANALYZE", 389), ("AND", 27), ("ANTI", 196),
+        ("ANY", 197), ("APPLY", 198), ("ARRAY", 199), ("AS", 192),
+        ("ASC", 200), ("ASOF", 201), ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203),
+        ("BEGIN", 204), ("BETWEEN", 205), ("BIGDECIMAL", 103), ("BIGINT", 88),
+        ("BIGNUMERIC", 103), ("BINARY", 118), ("BIT", 78), ("BLOB", 119),
+        ("BOOL", 79), ("BOOLEAN", 79), ("BPCHAR", 108), ("BYTE", 80),
+        ("BYTEA", 119), ("CACHE", 207), ("CALL", 212), ("CASE", 208),
+        ("CHAR", 104), ("CHAR VARYING", 106), ("CHARACTER", 104), ("CHARACTER SET", 209),
+        ("CHARACTER VARYING", 106), ("CLOB", 109), ("CLUSTER BY", 210), ("COLLATE", 211),
+        ("COLUMN", 63), ("COMMENT", 213), ("COMMIT", 214), ("CONNECT BY", 215),
+        ("CONSTRAINT", 216), ("COPY", 217), ("CREATE", 218), ("CROSS", 219),
+        ("CUBE", 220), ("CURRENT_DATE", 221), ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224),
+        ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226), ("DATABASE", 62), ("DATE", 135),
+        ("DATEMULTIRANGE", 148), ("DATERANGE", 147), ("DATETIME", 131), ("DEC", 97),
+        ("DECIMAL", 97), ("DECIMAL128", 100), ("DECIMAL256", 101), ("DECIMAL32", 98),
+        ("DECIMAL64", 99), ("DEFAULT", 228), ("DELETE", 229), ("DESC", 230),
+        ("DESCRIBE", 231), ("DISTINCT", 234), ("DISTRIBUTE BY", 235), ("DIV", 236),
+        ("DOUBLE", 95), ("DOUBLE PRECISION", 95), ("DROP", 237), ("ELSE", 238),
+        ("END", 239), ("ENUM", 179), ("ESCAPE", 240), ("EXCEPT", 241),
+        ("EXECUTE", 242), ("EXISTS", 243), ("EXPLAIN", 212), ("FALSE", 244),
+        ("FETCH", 245), ("FILTER", 247), ("FIRST", 249), ("FIXED", 97),
+        ("FLOAT", 94), ("FLOAT4", 94), ("FLOAT8", 95), ("FOR", 250),
+        ("FOR TIMESTAMP", 385), ("FOR VERSION", 384), ("FOREIGN KEY", 252), ("FORMAT", 253),
+        ("FROM", 254), ("FULL", 255), ("FUNCTION", 256), ("GEOGRAPHY", 150),
+        ("GEOMETRY", 152), ("GLOB", 258), ("GRANT", 260), ("GROUP BY", 261),
+        ("GROUPING SETS", 262), ("HAVING", 263), ("HUGEINT", 90), ("ILIKE", 266),
+        ("IN", 268), ("INDEX", 269), ("INET", 174), ("INNER", 270),
+        ("INSERT", 271), ("INT", 86), ("INT1", 80), ("INT128", 90),
+        ("INT16", 82), ("INT2", 82), ("INT256", 92), ("INT32", 86),
+        ("INT4", 86), ("INT4MULTIRANGE", 138), ("INT4RANGE", 137), ("INT64", 88),
+        ("INT8", 80), ("INT8MULTIRANGE", 140), ("INT8RANGE", 139), ("INTEGER", 86),
+        ("INTERSECT", 272), ("INTERVAL", 273), ("INTO", 274), ("IS", 277),
+        ("ISNULL", 278), ("JOIN", 279), ("JSON", 120), ("JSONB", 121),
+        ("KEEP", 281), ("KILL", 283), ("LATERAL", 285), ("LEFT", 286),
+        ("LIKE", 287), ("LIMIT", 289), ("LIST", 290), ("LOAD", 291),
+        ("LOCK", 292), ("LONG", 88), ("LONGBLOB", 114), ("LONGTEXT", 111),
+        ("LONGVARCHAR", 109), ("MAP", 293), ("MEDIUMBLOB", 113), ("MEDIUMINT", 84),
+        ("MEDIUMTEXT", 110), ("MERGE", 297), ("NAMESPACE", 390), ("NATURAL", 300),
+        ("NCHAR", 105), ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303),
+        ("NULL", 304), ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97),
+        ("NUMMULTIRANGE", 142), ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107),
+        ("OBJECT", 173), ("OFFSET", 306), ("ON", 307), ("OPTIMIZE", 212),
+        ("OR", 28), ("ORDER BY", 310), ("ORDINALITY", 313), ("OUTER", 314),
+        ("OVER", 315), ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318),
+        ("PARTITION BY", 319), ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320),
+        ("PIVOT", 321), ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326),
+        ("PROCEDURE", 327), ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94),
+        ("RECURSIVE", 334), ("REFERENCES", 339), ("REGEXP", 341), ("RENAME", 336),
+        ("REPLACE", 337), ("RETURNING", 338), ("RIGHT", 340), ("RLIKE", 341),
+        ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344), ("ROWS", 345),
+        ("SCHEMA", 65), ("SELECT", 346), ("SEMI", 347), ("SEQUENCE", 349),
+        ("SET", 351), ("SETTINGS", 352), ("SHORT", 82), ("SHOW", 353),
+        ("SIMILAR TO", 354), ("SMALLINT", 82), ("SOME", 355), ("SORT BY", 356),
+        ("START WITH", 357), ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109),
+        ("STRUCT", 360), ("TABLE", 66), ("TABLESAMPLE", 362), ("TEMP", 364),
+        ("TEMPORARY", 364), ("TEXT", 109), ("THEN", 366), ("TIME", 122),
+        ("TIMESTAMP", 124), ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125),
+        ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115),
+        ("TINYINT", 80), ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368),
+        ("TSMULTIRANGE", 144), ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145),
+        ("UHUGEINT", 91), ("UINT", 87), ("UINT128", 91), ("UINT256", 93),
+        ("UNCACHE", 369), ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188),
+        ("UNNEST", 371), ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374),
+        ("USER-DEFINED", 167), ("USING", 375), ("UUID", 149), ("VACUUM", 212),
+        ("VALUES", 376), ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106),
+        ("VARIANT", 172), ("VECTOR", 189), ("VIEW", 377), ("VOLATILE", 378),
+        ("WHEN", 379), ("WHERE", 380), ("WINDOW", 381), ("WITH", 382),
+        ("XOR", 52), ("{%", 55), ("{%+", 55), ("{%-", 55),
+        ("{{+", 55), ("{{-", 55), ("|>", 31), ("||", 30),
+        ("~*", 276), ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"')],
+    identifier_escapes: &[],
+    string_escapes: &['\''],
+    quotes: &[("'", "'")],
+    format_strings: &[("N'", "'", 74), ("n'", "'", 74)],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: false,
+    comments: &[
+        ("--", None), ("/*", Some("*/")), ("/*+", Some("*/")), ("{#", Some("#}")),
+    ],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: true,
+    hint_start: "/*+",
+};
+
+static BIGQUERY: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('%', 298),
+        ('&', 29), ('\'', 188), ('(', 0), (')', 1),
+        ('*', 15), ('+', 9), (',', 6), ('-', 8),
+        ('.', 7), ('/', 17), (':', 10), (';', 14),
+        ('<', 18), ('=', 23), ('>', 20), ('?', 322),
+        ('@', 49), ('[', 2), ('\\', 16), (']', 3),
+        ('^', 35), ('`', 188), ('{', 4), ('|', 32),
+        ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("::", 12),
+        (":=", 26), ("<->", 44), ("<=", 19), ("<=>", 25),
+        ("<>", 24), ("==", 23), ("=>", 40), (">=", 21),
+        ("??", 13), ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194),
+        ("ANALYZE", 389), ("AND", 27), ("ANTI", 196), ("ANY", 197),
+        ("ANY TYPE", 172), ("APPLY", 198), ("ARRAY", 199), ("AS", 192),
+        ("ASC", 200), ("ASOF", 201), ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203),
+        ("BEGIN", 212), ("BEGIN TRANSACTION", 204), ("BETWEEN", 205), ("BIGDECIMAL", 103),
+        ("BIGINT", 88), ("BIGNUMERIC", 103), ("BINARY", 118), ("BIT", 78),
+        ("BLOB", 119), ("BOOL", 79), ("BOOLEAN", 79), ("BPCHAR", 108),
+        ("BYTE", 80), ("BYTEA", 119), ("BYTEINT", 86), ("BYTES", 118),
+        ("CACHE", 207), ("CALL", 212), ("CASE", 208), ("CHAR", 104),
+        ("CHAR VARYING", 106), ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106),
+        ("CLOB", 109), ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63),
+        ("COMMENT", 213), ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216),
+        ("COPY", 217), ("CREATE", 218), ("CROSS", 219), ("CUBE", 220),
+        ("CURRENT_DATE", 221), ("CURRENT_DATETIME", 222), ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224),
+        ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226), ("DATABASE", 62), ("DATE", 135),
+        ("DATEMULTIRANGE", 148), ("DATERANGE", 147), ("DATETIME", 124), ("DEC", 97),
+        ("DECIMAL", 97), ("DECIMAL128", 100), ("DECIMAL256", 101), ("DECIMAL32", 98),
+        ("DECIMAL64", 99), ("DECLARE", 212), ("DEFAULT", 228), ("DELETE", 229),
+        ("DESC", 230), ("DESCRIBE", 231), ("DISTINCT", 234), ("DISTRIBUTE BY", 235),
+        ("DOUBLE", 95), ("DOUBLE PRECISION", 95), ("DROP", 237), ("ELSE", 238),
+        ("ELSEIF", 212), ("END", 239), ("ENUM", 179), ("ESCAPE", 240),
+        ("EXCEPT", 241), ("EXCEPTION", 212), ("EXECUTE", 242), ("EXISTS", 243),
+        ("EXPLAIN", 212), ("EXPORT", 391), ("FALSE", 244), ("FETCH", 245),
+        ("FILTER", 247), ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94),
+        ("FLOAT4", 94), ("FLOAT64", 95), ("FLOAT8", 95), ("FOR", 250),
+        ("FOR SYSTEM_TIME", 385), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384), ("FOREIGN KEY", 252),
+        ("FORMAT", 253), ("FROM", 254), ("FULL", 255), ("FUNCTION", 256),
+        ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258), ("GRANT", 260),
+        ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263), ("HUGEINT", 90),
+        ("ILIKE", 266), ("IN", 268), ("INDEX", 269), ("INET", 174),
+        ("INNER", 270), ("INSERT", 271), ("INT", 86), ("INT1", 80),
+        ("INT128", 90), ("INT16", 82), ("INT2", 82), ("INT256", 92),
+        ("INT32", 86), ("INT4", 86), ("INT4MULTIRANGE", 138), ("INT4RANGE", 137),
+        ("INT64", 88), ("INT8", 80), ("INT8MULTIRANGE", 140), ("INT8RANGE", 139),
+        ("INTEGER", 86), ("INTERSECT", 272), ("INTERVAL", 273), ("INTO", 274),
+        ("IS", 277), ("ISNULL", 278), ("JOIN", 279), ("JSON", 120),
+        ("JSONB", 121), ("KEEP", 281), ("KILL", 283), ("LATERAL", 285),
+        ("LEFT", 286), ("LIKE", 287), ("LIMIT", 289), ("LIST", 290),
+        ("LOAD", 291), ("LOCK", 292), ("LONG", 88), ("LONGBLOB", 114),
+        ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293), ("MEDIUMBLOB", 113),
+        ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MERGE", 297), ("MODEL", 299),
+        ("NAMESPACE", 390), ("NATURAL", 300), ("NCHAR", 105), ("NEXT", 301),
+        ("NOT", 22), ("NOT DETERMINISTIC", 378), ("NOTNULL", 303), ("NULL", 304),
+        ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142),
+        ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173),
+        ("OFFSET", 306), ("ON", 307), ("OPTIMIZE", 212), ("OR", 28),
+        ("ORDER BY", 310), ("ORDINALITY", 313), ("OUTER", 314), ("OVER", 315),
+        ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318), ("PARTITION BY", 319),
+        ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320), ("PIVOT", 321),
+        ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326), ("PROCEDURE", 327),
+        ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94), ("RECORD", 360),
+        ("RECURSIVE", 334), ("REFERENCES", 339), ("REGEXP", 341), ("RENAME", 336),
+        ("REPLACE", 337), ("RETURNING", 338), ("RIGHT", 340), ("RLIKE", 341),
+        ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344), ("ROWS", 345),
+        ("SCHEMA", 65), ("SELECT", 346), ("SEMI", 347), ("SEQUENCE", 349),
+        ("SET", 351), ("SETTINGS", 352), ("SHORT", 82), ("SHOW", 353),
+        ("SIMILAR TO", 354), ("SMALLINT", 82), ("SOME", 355), ("SORT BY", 356),
+        ("START WITH", 357), ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109),
+        ("STRUCT", 360), ("TABLE", 66), ("TABLESAMPLE", 362), ("TEMP", 364),
+        ("TEMPORARY", 364), ("TEXT", 109), ("THEN", 366), ("TIME", 122),
+        ("TIMESTAMP", 125), ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125),
+        ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115),
+        ("TINYINT", 80), ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368),
+        ("TSMULTIRANGE", 144), ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145),
+        ("UHUGEINT", 91), ("UINT", 87), ("UINT128", 91), ("UINT256", 93),
+        ("UNCACHE", 369), ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188),
+        ("UNNEST", 371), ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374),
+        ("USER-DEFINED", 167), ("USING", 375), ("UUID", 149), ("VACUUM", 212),
+        ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172),
+        ("VECTOR", 189), ("VIEW", 377), ("VOLATILE", 378), ("WHEN", 379),
+        ("WHERE", 380), ("WINDOW", 381), ("WITH", 382), ("XOR", 52),
+        ("{%", 55), ("{%+", 55), ("{%-", 55), ("{{+", 55),
+        ("{{-", 55), ("|>", 31), ("||", 30), ("~*", 276),
+        ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('`', '`')],
+    identifier_escapes: &[],
+    string_escapes: &['\\'],
+    quotes: &[("\"", "\""), ("\"\"\"", "\"\"\""), ("'", "'"), ("'''", "'''")],
+    format_strings: &[
+        ("0X", "", 72), ("0x", "", 72), ("B\"", "\"", 73), ("B\"\"\"", "\"\"\"", 73),
+        ("B'", "'", 73), ("B'''", "'''", 73), ("N\"", "\"", 74), ("N\"\"\"", "\"\"\"", 74),
+        ("N'", "'", 74), ("N'''", "'''", 74), ("R\"", "\"", 75), ("R\"\"\"", "\"\"\"", 75),
+        ("R'", "'", 75), ("R'''", "'''", 75), ("b\"", "\"", 73), ("b\"\"\"", "\"\"\"", 73),
+        ("b'", "'", 73), ("b'''", "'''", 73), ("n\"", "\"", 74), ("n\"\"\"", "\"\"\"", 74),
+        ("n'", "'", 74), ("n'''", "'''", 74), ("r\"", "\"", 75), ("r\"\"\"", "\"\"\"", 75),
+        ("r'", "'", 75), ("r'''", "'''", 75),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: true,
+    comments: &[("#", None), ("--", None), ("/*", Some("*/")), ("{#", Some("#}"))],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: false,
+    hint_start: "/*+",
+};
+
+static CLICKHOUSE: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('$', 76),
+        ('%', 298), ('&', 29), ('\'', 188), ('(', 0),
+        (')', 1), ('*', 15), ('+', 9), (',', 6),
+        ('-', 8), ('.', 7), ('/', 17), (':', 10),
+        (';', 14), ('<', 18), ('=', 23), ('>', 20),
+        ('?', 322), ('@', 49), ('[', 2), ('\\', 16),
+        (']', 3), ('^', 35), ('`', 188), ('{', 4),
+        ('|', 32), ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), (".:", 11),
+        ("::", 12), (":=", 26), ("<->", 44), ("<=", 19),
+        ("<=>", 25), ("<>", 24), ("==", 23), ("=>", 40),
+        (">=", 21), ("??", 13), ("AGGREGATEFUNCTION", 185), ("ALL", 195),
+        ("ALTER", 193), ("ALWAYS", 194), ("ANALYZE", 389), ("AND", 27),
+        ("ANTI", 196), ("ANY", 197), ("APPLY", 198), ("ARRAY", 199),
+        ("AS", 192), ("ASC", 200), ("ASOF", 201), ("ATTACH", 212),
+        ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 204), ("BETWEEN", 205),
+        ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103), ("BINARY", 118),
+        ("BIT", 78), ("BLOB", 119), ("BOOL", 79), ("BOOLEAN", 79),
+        ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119), ("CACHE", 207),
+        ("CALL", 212), ("CASE", 208), ("CHAR", 104), ("CHAR VARYING", 106),
+        ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106), ("CLOB", 109),
+        ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63), ("COMMENT", 213),
+        ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216), ("COPY", 217),
+        ("CREATE", 218), ("CROSS", 219), ("CUBE", 220), ("CURRENT_DATE", 221),
+        ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226),
+        ("DATABASE", 62), ("DATE", 135), ("DATE32", 136), ("DATEMULTIRANGE", 148),
+        ("DATERANGE", 147), ("DATETIME", 131), ("DATETIME64", 133), ("DEC", 97),
+        ("DECIMAL", 97), ("DECIMAL128", 100), ("DECIMAL256", 101), ("DECIMAL32", 98),
+        ("DECIMAL64", 99), ("DEFAULT", 228), ("DELETE", 229), ("DESC", 230),
+        ("DESCRIBE", 231), ("DICTIONARY", 233), ("DISTINCT", 234), ("DISTRIBUTE BY", 235),
+        ("DIV", 236), ("DOUBLE", 95), ("DOUBLE PRECISION", 95), ("DROP", 237),
+        ("DYNAMIC", 190), ("ELSE", 238), ("END", 239), ("ENUM", 179),
+        ("ENUM16", 181), ("ENUM8", 180), ("ESCAPE", 240), ("EXCEPT", 241),
+        ("EXCHANGE", 212), ("EXECUTE", 242), ("EXISTS", 243), ("EXPLAIN", 212),
+        ("FALSE", 244), ("FETCH", 245), ("FILTER", 247), ("FINAL", 248),
+        ("FIRST", 249), ("FIXED", 97), ("FIXEDSTRING", 182), ("FLOAT", 94),
+        ("FLOAT32", 94), ("FLOAT4", 94), ("FLOAT64", 95), ("FLOAT8", 95),
+        ("FOR", 250), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384), ("FOREIGN KEY", 252),
+        ("FORMAT", 253), ("FROM", 254), ("FULL", 255), ("FUNCTION", 256),
+        ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258), ("GLOBAL", 259),
+        ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263),
+        ("HUGEINT", 90), ("ILIKE", 266), ("IN", 268), ("INDEX", 269),
+        ("INET", 174), ("INNER", 270), ("INSERT", 271), ("INT", 86),
+        ("INT1", 80), ("INT128", 90), ("INT16", 82), ("INT2", 82),
+        ("INT256", 92), ("INT32", 86), ("INT4", 86), ("INT4MULTIRANGE", 138),
+        ("INT4RANGE", 137), ("INT64", 88), ("INT8", 80), ("INT8MULTIRANGE", 140),
+        ("INT8RANGE", 139), ("INTEGER", 86), ("INTERSECT", 272), ("INTERVAL", 273),
+        ("INTO", 274), ("IPV4", 177), ("IPV6", 178), ("IS", 277),
+        ("ISNULL", 278), ("JOIN", 279), ("JSON", 120), ("JSONB", 121),
+        ("KEEP", 281), ("KILL", 283), ("LATERAL", 285), ("LEFT", 286),
+        ("LIKE", 287), ("LIMIT", 289), ("LINESTRING", 155), ("LIST", 290),
+        ("LOAD", 291), ("LOCK", 292), ("LONG", 88), ("LONGBLOB", 114),
+        ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("LOWCARDINALITY", 183), ("MAP", 293),
+        ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MERGE", 297),
+        ("MULTILINESTRING", 156), ("MULTIPOLYGON", 158), ("NAMESPACE", 390), ("NATURAL", 300),
+        ("NCHAR", 105), ("NESTED", 184), ("NEXT", 301), ("NOT", 22),
+        ("NOTHING", 302), ("NOTNULL", 303), ("NULL", 304), ("NULLABLE", 151),
+        ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142), ("NUMRANGE", 141),
+        ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173), ("OFFSET", 306),
+        ("ON", 307), ("OPTIMIZE", 212), ("OR", 28), ("ORDER BY", 310),
+        ("ORDINALITY", 313), ("OUTER", 314), ("OVER", 315), ("OVERLAPS", 316),
+        ("OVERWRITE", 317), ("PARTITION", 318), ("PARTITION BY", 319), ("PARTITIONED BY", 319),
+        ("PARTITIONED_BY", 319), ("PERCENT", 320), ("PIVOT", 321), ("POINT", 153),
+        ("POLYGON", 157), ("PRAGMA", 324), ("PREPARE", 212), ("PREWHERE", 325),
+        ("PRIMARY KEY", 326), ("PROCEDURE", 327), ("QUALIFY", 331), ("RANGE", 333),
+        ("REAL", 94), ("RECURSIVE", 334), ("REFERENCES", 339), ("REGEXP", 341),
+        ("RENAME", 336), ("REPLACE", 337), ("RETURNING", 338), ("RIGHT", 340),
+        ("RING", 154), ("RLIKE", 341), ("ROLLBACK", 342), ("ROLLUP", 343),
+        ("ROW", 344), ("ROWS", 345), ("SAMPLE", 362), ("SCHEMA", 65),
+        ("SELECT", 346), ("SEMI", 347), ("SEQUENCE", 349), ("SET", 351),
+        ("SETTINGS", 352), ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354),
+        ("SIMPLEAGGREGATEFUNCTION", 186), ("SMALLINT", 82), ("SOME", 355), ("SORT BY", 356),
+        ("START WITH", 357), ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109),
+        ("STRUCT", 360), ("SYSTEM", 212), ("TABLE", 66), ("TABLESAMPLE", 362),
+        ("TEMP", 364), ("TEMPORARY", 364), ("TEXT", 109), ("THEN", 366),
+        ("TIME", 122), ("TIMESTAMP", 124), ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127),
+        ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123),
+        ("TINYBLOB", 115), ("TINYINT", 80), ("TINYTEXT", 116), ("TRUE", 367),
+        ("TRUNCATE", 368), ("TSMULTIRANGE", 144), ("TSRANGE", 143), ("TSTZMULTIRANGE", 146),
+        ("TSTZRANGE", 145), ("TUPLE", 360), ("UHUGEINT", 91), ("UINT", 87),
+        ("UINT128", 91), ("UINT16", 83), ("UINT256", 93), ("UINT32", 87),
+        ("UINT64", 89), ("UINT8", 81), ("UNCACHE", 369), ("UNION", 370),
+        ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371), ("UNPIVOT", 372),
+        ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167), ("USING", 375),
+        ("UUID", 149), ("VACUUM", 212), ("VALUES", 376), ("VARBINARY", 119),
+        ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172), ("VECTOR", 189),
+        ("VIEW", 377), ("VOLATILE", 378), ("WHEN", 379), ("WHERE", 380),
+        ("WINDOW", 381), ("WITH", 382), ("XOR", 52), ("{%", 55),
+        ("{%+", 55), ("{%-", 55), ("{{+", 55), ("{{-", 55),
+        ("|>", 31), ("||", 30), ("~*", 276), ("~~", 287),
+        ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"'), ('`', '`')],
+    identifier_escapes: &['\\'],
+    string_escapes: &['\'', '\\'],
+    quotes: &[("'", "'")],
+    format_strings: &[
+        ("$", "$", 76), ("0X", "", 72), ("0b", "", 71), ("0x", "", 72),
+        ("N'", "'", 74), ("n'", "'", 74),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: true,
+    has_hex_strings: true,
+    comments: &[
+        ("#", None), ("#!", None), ("--", None), ("/*", Some("*/")),
+        ("{#", Some("#}")),
+    ],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: true,
+    hint_start: "/*+",
+};
+
+static DATABRICKS: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('$', 49),
+        ('%', 298), ('&', 29), ('\'', 188), ('(', 0),
+        (')', 1), ('*', 15), ('+', 9), (',', 6),
+        ('-', 8), ('.', 7), ('/', 17), (':', 10),
+        (';', 14), ('<', 18), ('=', 23), ('>', 20),
+        ('?', 322), ('@', 49), ('[', 2), ('\\', 16),
+        (']', 3), ('^', 35), ('`', 188), ('{', 4),
+        ('|', 32), ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("/*+", 264),
+        ("::", 12), (":=", 26), ("<->", 44), ("<=", 19),
+        ("<=>", 25), ("<>", 24), ("==", 23), ("=>", 40),
+        (">=", 21), ("??", 13), ("ADD ARCHIVE", 212), ("ADD ARCHIVES", 212),
+        ("ADD FILE", 212), ("ADD FILES", 212), ("ADD JAR", 212), ("ADD JARS", 212),
+        ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194), ("ANALYZE", 389),
+        ("AND", 27), ("ANTI", 196), ("ANY", 197), ("APPLY", 198),
+        ("ARRAY", 199), ("AS", 192), ("ASC", 200), ("ASOF", 201),
+        ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 204), ("BETWEEN", 205),
+        ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103), ("BINARY", 118),
+        ("BIT", 78), ("BLOB", 119), ("BOOL", 79), ("BOOLEAN", 79),
+        ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119), ("CACHE", 207),
+        ("CALL", 212), ("CASE", 208), ("CHAR", 104), ("CHAR VARYING", 106),
+        ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106), ("CLOB", 109),
+        ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63), ("COMMENT", 213),
+        ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216), ("COPY", 217),
+        ("CREATE", 218), ("CROSS", 219), ("CUBE", 220), ("CURRENT_DATE", 221),
+        ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226),
+        ("DATABASE", 62), ("DATE", 135), ("DATEMULTIRANGE", 148), ("DATERANGE", 147),
+        ("DATETIME", 131), ("DEC", 97), ("DECIMAL", 97), ("DECIMAL128", 100),
+        ("DECIMAL256", 101), ("DECIMAL32", 98), ("DECIMAL64", 99), ("DEFAULT", 228),
+        ("DELETE", 229), ("DESC", 230), ("DESCRIBE", 231), ("DISTINCT", 234),
+        ("DISTRIBUTE BY", 235), ("DIV", 236), ("DOUBLE", 95), ("DOUBLE PRECISION", 95),
+        ("DROP", 237), ("ELSE", 238), ("END", 239), ("ENUM", 179),
+        ("ESCAPE", 240), ("EXCEPT", 241), ("EXECUTE", 242), ("EXISTS", 243),
+        ("EXPLAIN", 212), ("FALSE", 244), ("FETCH", 245), ("FILTER", 247),
+        ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94), ("FLOAT4", 94),
+        ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384),
+        ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254), ("FULL", 255),
+        ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258),
+        ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263),
+        ("HUGEINT", 90), ("ILIKE", 266), ("IN", 268), ("INDEX", 269),
+        ("INET", 174), ("INNER", 270), ("INSERT", 271), ("INT", 86),
+        ("INT1", 80), ("INT128", 90), ("INT16", 82), ("INT2", 82),
+        ("INT256", 92), ("INT32", 86), ("INT4", 86), ("INT4MULTIRANGE", 138),
+        ("INT4RANGE", 137), ("INT64", 88), ("INT8", 80), ("INT8MULTIRANGE", 140),
+        ("INT8RANGE", 139), ("INTEGER", 86), ("INTERSECT", 272), ("INTERVAL", 273),
+        ("INTO", 274), ("IS", 277), ("ISNULL", 278), ("JOIN", 279),
+        ("JSON", 120), ("JSONB", 121), ("KEEP", 281), ("KILL", 283),
+        ("LATERAL", 285), ("LEFT", 286), ("LIKE", 287), ("LIMIT", 289),
+        ("LIST", 290), ("LOAD", 291), ("LOCK", 292), ("LONG", 88),
+        ("LONGBLOB", 114), ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293),
+        ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MERGE", 297),
+        ("MINUS", 241), ("MSCK REPAIR", 212), ("NAMESPACE", 390), ("NATURAL", 300),
+        ("NCHAR", 105), ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303),
+        ("NULL", 304), ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97),
+        ("NUMMULTIRANGE", 142), ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107),
+        ("OBJECT", 173), ("OFFSET", 306), ("ON", 307), ("OPTIMIZE", 212),
+        ("OR", 28), ("ORDER BY", 310), ("ORDINALITY", 313), ("OUTER", 314),
+        ("OVER", 315), ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318),
+        ("PARTITION BY", 319), ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320),
+        ("PIVOT", 321), ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326),
+        ("PROCEDURE", 327), ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94),
+        ("RECURSIVE", 334), ("REFERENCES", 339), ("REFRESH", 335), ("REGEXP", 341),
+        ("RENAME", 336), ("REPLACE", 337), ("RETURNING", 338), ("RIGHT", 340),
+        ("RLIKE", 341), ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344),
+        ("ROWS", 345), ("SCHEMA", 65), ("SELECT", 346), ("SEMI", 347),
+        ("SEQUENCE", 349), ("SERDEPROPERTIES", 350), ("SET", 351), ("SETTINGS", 352),
+        ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354), ("SMALLINT", 82),
+        ("SOME", 355), ("SORT BY", 356), ("START WITH", 357), ("STR", 109),
+        ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360), ("TABLE", 66),
+        ("TABLESAMPLE", 362), ("TEMP", 364), ("TEMPORARY", 364), ("TEXT", 109),
+        ("THEN", 366), ("TIME", 122), ("TIMESTAMP", 125), ("TIMESTAMP AS OF", 385),
+        ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126),
+        ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115), ("TINYINT", 80),
+        ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368), ("TSMULTIRANGE", 144),
+        ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145), ("UHUGEINT", 91),
+        ("UINT", 87), ("UINT128", 91), ("UINT256", 93), ("UNCACHE", 369),
+        ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371),
+        ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167),
+        ("USING", 375), ("UUID", 149), ("VACUUM", 212), ("VALUES", 376),
+        ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172),
+        ("VECTOR", 189), ("VERSION AS OF", 384), ("VIEW", 377), ("VOID", 191),
+        ("VOLATILE", 378), ("WHEN", 379), ("WHERE", 380), ("WINDOW", 381),
+        ("WITH", 382), ("XOR", 52), ("{%", 55), ("{%+", 55),
+        ("{%-", 55), ("{{+", 55), ("{{-", 55), ("|>", 31),
+        ("||", 30), ("~*", 276), ("~~", 287), ("~~*", 266),
+        ("~~~", 258),
+    ],
+    numeric_literals: &[
+        ("BD", "DECIMAL"), ("D", "DOUBLE"), ("F", "FLOAT"), ("L", "BIGINT"),
+        ("S", "SMALLINT"), ("Y", "TINYINT"),
+    ],
+    identifiers: &[('`', '`')],
+    identifier_escapes: &[],
+    string_escapes: &['\\'],
+    quotes: &[("\"", "\""), ("'", "'")],
+    format_strings: &[
+        ("N\"", "\"", 74), ("N'", "'", 74), ("R\"", "\"", 75), ("R'", "'", 75),
+        ("X'", "'", 72), ("n\"", "\"", 74), ("n'", "'", 74), ("r\"", "\"", 75),
+        ("r'", "'", 75), ("x'", "'", 72),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: true,
+    comments: &[
+        ("--", None), ("/*", Some("*/")), ("/*+", Some("*/")), ("{#", Some("#}")),
+    ],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: false,
+    nested_comments: true,
+    hint_start: "/*+",
+};
+
+static DUCKDB: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('$', 49),
+        ('%', 298), ('&', 29), ('\'', 188), ('(', 0),
+        (')', 1), ('*', 15), ('+', 9), (',', 6),
+        ('-', 8), ('.', 7), ('/', 17), (':', 10),
+        (';', 14), ('<', 18), ('=', 23), ('>', 20),
+        ('?', 322), ('@', 49), ('[', 2), ('\\', 16),
+        (']', 3), ('^', 35), ('`', 188), ('{', 4),
+        ('|', 32), ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("**", 53), ("+%}", 56), ("+}}", 56),
+        ("-%}", 56), ("->", 38), ("->>", 39), ("-}}", 56),
+        ("//", 236), ("::", 12), (":=", 26), ("<->", 44),
+        ("<=", 19), ("<=>", 25), ("<>", 24), ("<@", 46),
+        ("==", 23), ("=>", 40), (">=", 21), ("??", 13),
+        ("@>", 47), ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194),
+        ("ANALYZE", 389), ("AND", 27), ("ANTI", 196), ("ANY", 197),
+        ("APPLY", 198), ("ARRAY", 199), ("AS", 192), ("ASC", 200),
+        ("ASOF", 201), ("ATTACH", 202), ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203),
+        ("BEGIN", 204), ("BETWEEN", 205), ("BIGDECIMAL", 103), ("BIGINT", 88),
+        ("BIGNUMERIC", 103), ("BINARY", 119), ("BIT", 78), ("BITSTRING", 78),
+        ("BLOB", 119), ("BOOL", 79), ("BOOLEAN", 79), ("BPCHAR", 109),
+        ("BYTE", 80), ("BYTEA", 119), ("CACHE", 207), ("CALL", 212),
+        ("CASE", 208), ("CHAR", 109), ("CHAR VARYING", 106), ("CHARACTER", 104),
+        ("CHARACTER SET", 209), ("CHARACTER VARYING", 106), ("CLOB", 109), ("CLUSTER BY", 210),
+        ("COLLATE", 211), ("COLUMN", 63), ("COMMENT", 213), ("COMMIT", 214),
+        ("CONNECT BY", 215), ("CONSTRAINT", 216), ("COPY", 217), ("CREATE", 218),
+        ("CROSS", 219), ("CUBE", 220), ("CURRENT_DATE", 221), ("CURRENT_SCHEMA", 223),
+        ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226), ("DATABASE", 62),
+        ("DATE", 135), ("DATEMULTIRANGE", 148), ("DATERANGE", 147), ("DATETIME", 127),
+        ("DEC", 97), ("DECIMAL", 97), ("DECIMAL128", 100), ("DECIMAL256", 101),
+        ("DECIMAL32", 98), ("DECIMAL64", 99), ("DEFAULT", 228), ("DELETE", 229),
+        ("DESC", 230), ("DESCRIBE", 231), ("DETACH", 232), ("DISTINCT", 234),
+        ("DISTRIBUTE BY", 235), ("DIV", 236), ("DOUBLE", 95), ("DOUBLE PRECISION", 95),
+        ("DROP", 237), ("ELSE", 238), ("END", 239), ("ENUM", 179),
+        ("ESCAPE", 240), ("EXCEPT", 241), ("EXCLUDE", 241), ("EXECUTE", 242),
+        ("EXISTS", 243), ("EXPLAIN", 212), ("FALSE", 244), ("FETCH", 245),
+        ("FILTER", 247), ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94),
+        ("FLOAT4", 94), ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385),
+        ("FOR VERSION", 384), ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254),
+        ("FULL", 255), ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152),
+        ("GLOB", 258), ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262),
+        ("HAVING", 263), ("HUGEINT", 90), ("ILIKE", 266), ("IN", 268),
+        ("INDEX", 269), ("INET", 174), ("INNER", 270), ("INSERT", 271),
+        ("INT", 86), ("INT1", 80), ("INT128", 90), ("INT16", 82),
+        ("INT2", 82), ("INT256", 92), ("INT32", 86), ("INT4", 86),
+        ("INT4MULTIRANGE", 138), ("INT4RANGE", 137), ("INT64", 88), ("INT8", 80),
+        ("INT8MULTIRANGE", 140), ("INT8RANGE", 139), ("INTEGER", 86), ("INTERSECT", 272),
+        ("INTERVAL", 273), ("INTO", 274), ("IS", 277), ("ISNULL", 278),
+        ("JOIN", 279), ("JSON", 120), ("JSONB", 121), ("KEEP", 281),
+        ("KILL", 283), ("LATERAL", 285), ("LEFT", 286), ("LIKE", 287),
+        ("LIMIT", 289), ("LIST", 290), ("LOAD", 291), ("LOCK", 292),
+        ("LOGICAL", 79), ("LONG", 88), ("LONGBLOB", 114), ("LONGTEXT", 111),
+        ("LONGVARCHAR", 109), ("MAP", 293), ("MEDIUMBLOB", 113), ("MEDIUMINT", 84),
+        ("MEDIUMTEXT", 110), ("MERGE", 297), ("NAMESPACE", 390), ("NATURAL", 300),
+        ("NCHAR", 105), ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303),
+        ("NULL", 304), ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97),
+        ("NUMMULTIRANGE", 142), ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107),
+        ("OBJECT", 173), ("OFFSET", 306), ("ON", 307), ("ONLY", 308),
+        ("OPTIMIZE", 212), ("OR", 28), ("ORDER BY", 310), ("ORDINALITY", 313),
+        ("OUTER", 314), ("OVER", 315), ("OVERLAPS", 316), ("OVERWRITE", 317),
+        ("PARTITION", 318), ("PARTITION BY", 319), ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319),
+        ("PERCENT", 320), ("PIVOT", 321), ("PIVOT_WIDER", 321), ("POSITIONAL", 323),
+        ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326), ("PROCEDURE", 327),
+        ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94), ("RECURSIVE", 334),
+        ("REFERENCES", 339), ("REGEXP", 341), ("RENAME", 336), ("REPLACE", 337),
+        ("RETURNING", 338), ("RIGHT", 340), ("RLIKE", 341), ("ROLLBACK", 342),
+        ("ROLLUP", 343), ("ROW", 344), ("ROWS", 345), ("SCHEMA", 65),
+        ("SELECT", 346), ("SEMI", 347), ("SEQUENCE", 349), ("SET", 351),
+        ("SETTINGS", 352), ("SHORT", 82), ("SHOW", 353), ("SIGNED", 86),
+        ("SIMILAR TO", 354), ("SMALLINT", 82), ("SOME", 355), ("SORT BY", 356),
+        ("START WITH", 357), ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109),
+        ("STRUCT", 360), ("SUMMARIZE", 361), ("TABLE", 66), ("TABLESAMPLE", 362),
+        ("TEMP", 364), ("TEMPORARY", 364), ("TEXT", 109), ("THEN", 366),
+        ("TIME", 122), ("TIMESTAMP", 127), ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127),
+        ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_MS", 129), ("TIMESTAMP_NS", 130),
+        ("TIMESTAMP_NTZ", 127), ("TIMESTAMP_S", 128), ("TIMESTAMP_US", 124), ("TIMETZ", 123),
+        ("TINYBLOB", 115), ("TINYINT", 80), ("TINYTEXT", 116), ("TRUE", 367),
+        ("TRUNCATE", 368), ("TSMULTIRANGE", 144), ("TSRANGE", 143), ("TSTZMULTIRANGE", 146),
+        ("TSTZRANGE", 145), ("UBIGINT", 89), ("UHUGEINT", 91), ("UINT", 87),
+        ("UINT128", 91), ("UINT256", 93), ("UINTEGER", 87), ("UNCACHE", 369),
+        ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371),
+        ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167),
+        ("USING", 375), ("USMALLINT", 83), ("UTINYINT", 81), ("UUID", 149),
+        ("VACUUM", 212), ("VALUES", 376), ("VARBINARY", 119), ("VARCHAR", 109),
+        ("VARCHAR2", 106), ("VARIANT", 172), ("VECTOR", 189), ("VIEW", 377),
+        ("VOLATILE", 378), ("WHEN", 379), ("WHERE", 380), ("WINDOW", 381),
+        ("WITH", 382), ("XOR", 52), ("^@", 36), ("{%", 55),
+        ("{%+", 55), ("{%-", 55), ("{{+", 55), ("{{-", 55),
+        ("|>", 31), ("||", 30), ("~*", 276), ("~~", 287),
+        ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"')],
+    identifier_escapes: &[],
+    string_escapes: &['\''],
+    quotes: &[("'", "'")],
+    format_strings: &[
+        ("$", "$", 76), ("E'", "'", 73), ("N'", "'", 74), ("e'", "'", 73),
+        ("n'", "'", 74),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: false,
+    comments: &[("--", None), ("/*", Some("*/")), ("{#", Some("#}"))],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: true,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: true,
+    hint_start: "/*+",
+};
+
+static HIVE: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('$', 49),
+        ('%', 298), ('&', 29), ('\'', 188), ('(', 0),
+        (')', 1), ('*', 15), ('+', 9), (',', 6),
+        ('-', 8), ('.', 7), ('/', 17), (':', 10),
+        (';', 14), ('<', 18), ('=', 23), ('>', 20),
+        ('?', 322), ('@', 49), ('[', 2), ('\\', 16),
+        (']', 3), ('^', 35), ('`', 188), ('{', 4),
+        ('|', 32), ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("/*+", 264),
+        ("::", 12), (":=", 26), ("<->", 44), ("<=", 19),
+        ("<=>", 25), ("<>", 24), ("==", 23), ("=>", 40),
+        (">=", 21), ("??", 13), ("ADD ARCHIVE", 212), ("ADD ARCHIVES", 212),
+        ("ADD FILE", 212), ("ADD FILES", 212), ("ADD JAR", 212), ("ADD JARS", 212),
+        ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194), ("ANALYZE", 389),
+        ("AND", 27), ("ANTI", 196), ("ANY", 197), ("APPLY", 198),
+        ("ARRAY", 199), ("AS", 192), ("ASC", 200), ("ASOF", 201),
+        ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 204), ("BETWEEN", 205),
+        ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103), ("BINARY", 118),
+        ("BIT", 78), ("BLOB", 119), ("BOOL", 79), ("BOOLEAN", 79),
+        ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119), ("CACHE", 207),
+        ("CALL", 212), ("CASE", 208), ("CHAR", 104), ("CHAR VARYING", 106),
+        ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106), ("CLOB", 109),
+        ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63), ("COMMENT", 213),
+        ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216), ("COPY", 217),
+        ("CREATE", 218), ("CROSS", 219), ("CUBE", 220), ("CURRENT_DATE", 221),
+        ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226),
+        ("DATABASE", 62), ("DATE", 135), ("DATEMULTIRANGE", 148), ("DATERANGE", 147),
+        ("DATETIME", 131), ("DEC", 97), ("DECIMAL", 97), ("DECIMAL128", 100),
+        ("DECIMAL256", 101), ("DECIMAL32", 98), ("DECIMAL64", 99), ("DEFAULT", 228),
+        ("DELETE", 229), ("DESC", 230), ("DESCRIBE", 231), ("DISTINCT", 234),
+        ("DISTRIBUTE BY", 235), ("DIV", 236), ("DOUBLE", 95), ("DOUBLE PRECISION", 95),
+        ("DROP", 237), ("ELSE", 238), ("END", 239), ("ENUM", 179),
+        ("ESCAPE", 240), ("EXCEPT", 241), ("EXECUTE", 242), ("EXISTS", 243),
+        ("EXPLAIN", 212), ("FALSE", 244), ("FETCH", 245), ("FILTER", 247),
+        ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94), ("FLOAT4", 94),
+        ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384),
+        ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254), ("FULL", 255),
+        ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258),
+        ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263),
+        ("HUGEINT", 90), ("ILIKE", 266), ("IN", 268), ("INDEX", 269),
+        ("INET", 174), ("INNER", 270), ("INSERT", 271), ("INT", 86),
+        ("INT1", 80), ("INT128", 90), ("INT16", 82), ("INT2", 82),
+        ("INT256", 92), ("INT32", 86), ("INT4", 86), ("INT4MULTIRANGE", 138),
+        ("INT4RANGE", 137), ("INT64", 88), ("INT8", 80), ("INT8MULTIRANGE", 140),
+        ("INT8RANGE", 139), ("INTEGER", 86), ("INTERSECT", 272), ("INTERVAL", 273),
+        ("INTO", 274), ("IS", 277), ("ISNULL", 278), ("JOIN", 279),
+        ("JSON", 120), ("JSONB", 121), ("KEEP", 281), ("KILL", 283),
+        ("LATERAL", 285), ("LEFT", 286), ("LIKE", 287), ("LIMIT", 289),
+        ("LIST", 290), ("LOAD", 291), ("LOCK", 292), ("LONG", 88),
+        ("LONGBLOB", 114), ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293),
+        ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MERGE", 297),
+        ("MINUS", 241), ("MSCK REPAIR", 212), ("NAMESPACE", 390), ("NATURAL", 300),
+        ("NCHAR", 105), ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303),
+        ("NULL", 304), ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97),
+        ("NUMMULTIRANGE", 142), ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107),
+        ("OBJECT", 173), ("OFFSET", 306), ("ON", 307), ("OPTIMIZE", 212),
+        ("OR", 28), ("ORDER BY", 310), ("ORDINALITY", 313), ("OUTER", 314),
+        ("OVER", 315), ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318),
+        ("PARTITION BY", 319), ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320),
+        ("PIVOT", 321), ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326),
+        ("PROCEDURE", 327), ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94),
+        ("RECURSIVE", 334), ("REFERENCES", 339), ("REFRESH", 335), ("REGEXP", 341),
+        ("RENAME", 336), ("REPLACE", 337), ("RETURNING", 338), ("RIGHT", 340),
+        ("RLIKE", 341), ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344),
+        ("ROWS", 345), ("SCHEMA", 65), ("SELECT", 346), ("SEMI", 347),
+        ("SEQUENCE", 349), ("SERDEPROPERTIES", 350), ("SET", 351), ("SETTINGS", 352),
+        ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354), ("SMALLINT", 82),
+        ("SOME", 355), ("SORT BY", 356), ("START WITH", 357), ("STR", 109),
+        ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360), ("TABLE", 66),
+        ("TABLESAMPLE", 362), ("TEMP", 364), ("TEMPORARY", 364), ("TEXT", 109),
+        ("THEN", 366), ("TIME", 122), ("TIMESTAMP", 124), ("TIMESTAMP AS OF", 385),
+        ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126),
+        ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115), ("TINYINT", 80),
+        ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368), ("TSMULTIRANGE", 144),
+        ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145), ("UHUGEINT", 91),
+        ("UINT", 87), ("UINT128", 91), ("UINT256", 93), ("UNCACHE", 369),
+        ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371),
+        ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167),
+        ("USING", 375), ("UUID", 149), ("VACUUM", 212), ("VALUES", 376),
+        ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172),
+        ("VECTOR", 189), ("VERSION AS OF", 384), ("VIEW", 377), ("VOLATILE", 378),
+        ("WHEN", 379), ("WHERE", 380), ("WINDOW", 381), ("WITH", 382),
+        ("XOR", 52), ("{%", 55), ("{%+", 55), ("{%-", 55),
+        ("{{+", 55), ("{{-", 55), ("|>", 31), ("||", 30),
+        ("~*", 276), ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[
+        ("BD", "DECIMAL"), ("D", "DOUBLE"), ("F", "FLOAT"), ("L", "BIGINT"),
+        ("S", "SMALLINT"), ("Y", "TINYINT"),
+    ],
+    identifiers: &[('`', '`')],
+    identifier_escapes: &[],
+    string_escapes: &['\\'],
+    quotes: &[("\"", "\""), ("'", "'")],
+    format_strings: &[("N\"", "\"", 74), ("N'", "'", 74), ("n\"", "\"", 74), ("n'", "'", 74)],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: false,
+    comments: &[
+        ("--", None), ("/*", Some("*/")), ("/*+", Some("*/")), ("{#", Some("#}")),
+    ],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: true,
+    hint_start: "/*+",
+};
+
+static MYSQL: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('%', 298),
+        ('&', 29), ('\'', 188), ('(', 0), (')', 1),
+        ('*', 15), ('+', 9), (',', 6), ('-', 8),
+        ('.', 7), ('/', 17), (':', 10), (';', 14),
+        ('<', 18), ('=', 23), ('>', 20), ('?', 322),
+        ('@', 49), ('[', 2), ('\\', 16), (']', 3),
+        ('^', 35), ('`', 188), ('{', 4), ('|', 32),
+        ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("/*+", 264),
+        ("::", 12), (":=", 26), ("<->", 44), ("<=", 19),
+        ("<=>", 25), ("<>", 24), ("==", 23), ("=>", 40),
+        (">=", 21), ("??", 13), ("@@", 50), ("ALL", 195),
+        ("ALTER", 193), ("ALWAYS", 194), ("ANALYZE", 389), ("AND", 27),
+        ("ANTI", 196), ("ANY", 197), ("APPLY", 198), ("ARRAY", 199),
+        ("AS", 192), ("ASC", 200), ("ASOF", 201), ("AUTOINCREMENT", 203),
+        ("AUTO_INCREMENT", 203), ("BEGIN", 204), ("BETWEEN", 205), ("BIGDECIMAL", 103),
+        ("BIGINT", 88), ("BIGNUMERIC", 103), ("BINARY", 118), ("BIT", 78),
+        ("BLOB", 112), ("BOOL", 79), ("BOOLEAN", 79), ("BPCHAR", 108),
+        ("BYTE", 80), ("BYTEA", 119), ("CACHE", 207), ("CALL", 212),
+        ("CASE", 208), ("CHAR", 104), ("CHAR VARYING", 106), ("CHARACTER", 104),
+        ("CHARACTER SET", 209), ("CHARACTER VARYING", 106), ("CHARSET", 209), ("CLOB", 109),
+        ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63), ("COMMENT", 213),
+        ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216), ("COPY", 217),
+        ("CREATE", 218), ("CROSS", 219), ("CUBE", 220), ("CURRENT_DATE", 221),
+        ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226),
+        ("DATABASE", 62), ("DATE", 135), ("DATEMULTIRANGE", 148), ("DATERANGE", 147),
+        ("DATETIME", 131), ("DEC", 97), ("DECIMAL", 97), ("DECIMAL128", 100),
+        ("DECIMAL256", 101), ("DECIMAL32", 98), ("DECIMAL64", 99), ("DEFAULT", 228),
+        ("DELETE", 229), ("DESC", 230), ("DESCRIBE", 231), ("DISTINCT", 234),
+        ("DISTRIBUTE BY", 235), ("DIV", 236), ("DOUBLE", 95), ("DOUBLE PRECISION", 95),
+        ("DROP", 237), ("ELSE", 238), ("END", 239), ("ENUM", 179),
+        ("ESCAPE", 240), ("EXCEPT", 241), ("EXECUTE", 242), ("EXISTS", 243),
+        ("EXPLAIN", 231), ("FALSE", 244), ("FETCH", 245), ("FILTER", 247),
+        ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94), ("FLOAT4", 94),
+        ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384),
+        ("FORCE", 251), ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254),
+        ("FULL", 255), ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152),
+        ("GLOB", 258), ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262),
+        ("HAVING", 263), ("HUGEINT", 90), ("IGNORE", 265), ("ILIKE", 266),
+        ("IN", 268), ("INDEX", 269), ("INET", 174), ("INNER", 270),
+        ("INSERT", 271), ("INT", 86), ("INT1", 80), ("INT128", 90),
+        ("INT16", 82), ("INT2", 82), ("INT256", 92), ("INT32", 86),
+        ("INT4", 86), ("INT4MULTIRANGE", 138), ("INT4RANGE", 137), ("INT64", 88),
+        ("INT8", 80), ("INT8MULTIRANGE", 140), ("INT8RANGE", 139), ("INTEGER", 86),
+        ("INTERSECT", 272), ("INTERVAL", 273), ("INTO", 274), ("IS", 277),
+        ("ISNULL", 278), ("JOIN", 279), ("JSON", 120), ("JSONB", 121),
+        ("KEEP", 281), ("KEY", 282), ("KILL", 283), ("LATERAL", 285),
+        ("LEFT", 286), ("LIKE", 287), ("LIMIT", 289), ("LIST", 290),
+        ("LOAD", 291), ("LOCK", 292), ("LOCK TABLES", 212), ("LONG", 88),
+        ("LONGBLOB", 114), ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293),
+        ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MEMBER OF", 296),
+        ("MERGE", 297), ("NAMESPACE", 390), ("NATURAL", 300), ("NCHAR", 105),
+        ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303), ("NULL", 304),
+        ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142),
+        ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173),
+        ("OFFSET", 306), ("ON", 307), ("OPTIMIZE", 212), ("OR", 28),
+        ("ORDER BY", 310), ("ORDINALITY", 313), ("OUTER", 314), ("OVER", 315),
+        ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318), ("PARTITION BY", 319),
+        ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320), ("PIVOT", 321),
+        ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326), ("PROCEDURE", 327),
+        ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94), ("RECURSIVE", 334),
+        ("REFERENCES", 339), ("REGEXP", 341), ("RENAME", 336), ("REPLACE", 337),
+        ("RETURNING", 338), ("RIGHT", 340), ("RLIKE", 341), ("ROLLBACK", 342),
+        ("ROLLUP", 343), ("ROW", 344), ("ROWS", 345), ("SCHEMA", 65),
+        ("SELECT", 346), ("SEMI", 347), ("SEPARATOR", 348), ("SEQUENCE", 349),
+        ("SERIAL", 162), ("SET", 351), ("SETTINGS", 352), ("SHORT", 82),
+        ("SHOW", 353), ("SIGNED", 88), ("SIGNED INTEGER", 88), ("SIMILAR TO", 354),
+        ("SMALLINT", 82), ("SOME", 355), ("SORT BY", 356), ("START", 204),
+        ("START WITH", 357), ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109),
+        ("STRUCT", 360), ("TABLE", 66), ("TABLESAMPLE", 362), ("TEMP", 364),
+        ("TEMPORARY", 364), ("TEXT", 109), ("THEN", 366), ("TIME", 122),
+        ("TIMESTAMP", 125), ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125),
+        ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115),
+        ("TINYINT", 80), ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368),
+        ("TSMULTIRANGE", 144), ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145),
+        ("UHUGEINT", 91), ("UINT", 87), ("UINT128", 91), ("UINT256", 93),
+        ("UNCACHE", 369), ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188),
+        ("UNLOCK TABLES", 212), ("UNNEST", 371), ("UNPIVOT", 372), ("UNSIGNED", 89),
+        ("UNSIGNED INTEGER", 89), ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167),
+        ("USING", 375), ("UUID", 149), ("VACUUM", 212), ("VALUES", 376),
+        ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172),
+        ("VECTOR", 189), ("VIEW", 377), ("VOLATILE", 378), ("WHEN", 379),
+        ("WHERE", 380), ("WINDOW", 381), ("WITH", 382), ("XOR", 52),
+        ("YEAR", 166), ("_ARMSCII8", 275), ("_ASCII", 275), ("_BIG5", 275),
+        ("_BINARY", 275), ("_CP1250", 275), ("_CP1251", 275), ("_CP1256", 275),
+        ("_CP1257", 275), ("_CP850", 275), ("_CP852", 275), ("_CP866", 275),
+        ("_CP932", 275), ("_DEC8", 275), ("_EUCJPMS", 275), ("_EUCKR", 275),
+        ("_GB18030", 275), ("_GB2312", 275), ("_GBK", 275), ("_GEOSTD8", 275),
+        ("_GREEK", 275), ("_HEBREW", 275), ("_HP8", 275), ("_KEYBCS2", 275),
+        ("_KOI8R", 275), ("_KOI8U", 275), ("_LATIN1", 275), ("_LATIN2", 275),
+        ("_LATIN5", 275), ("_LATIN7", 275), ("_MACCE", 275), ("_MACROMAN", 275),
+        ("_SJIS", 275), ("_SWE7", 275), ("_TIS620", 275), ("_UCS2", 275),
+        ("_UJIS", 275), ("_UTF16", 275), ("_UTF16LE", 275), ("_UTF32", 275),
+        ("_UTF8", 275), ("_UTF8MB3", 275), ("_UTF8MB4", 275), ("{%", 55),
+        ("{%+", 55), ("{%-", 55), ("{{+", 55), ("{{-", 55),
+        ("|>", 31), ("||", 30), ("~*", 276), ("~~", 287),
+        ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('`', '`')],
+    identifier_escapes: &[],
+    string_escapes: &['"', '\'', '\\'],
+    quotes: &[("\"", "\""), ("'", "'")],
+    format_strings: &[
+        ("0b", "", 71), ("0x", "", 72), ("B'", "'", 71), ("N\"", "\"", 74),
+        ("N'", "'", 74), ("X'", "'", 72), ("b'", "'", 71), ("n\"", "\"", 74),
+        ("n'", "'", 74), ("x'", "'", 72),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: true,
+    has_hex_strings: true,
+    comments: &[
+        ("#", None), ("--", None), ("/*", Some("*/")), ("/*+", Some("*/")),
+        ("{#", Some("#}")),
+    ],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 337],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: false,
+    hint_start: "/*+",
+};
+
+static ORACLE: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('%', 298),
+        ('&', 29), ('\'', 188), ('(', 0), (')', 1),
+        ('*', 15), ('+', 9), (',', 6), ('-', 8),
+        ('.', 7), ('/', 17), (':', 10), (';', 14),
+        ('<', 18), ('=', 23), ('>', 20), ('?', 322),
+        ('@', 49), ('[', 2), ('\\', 16), (']', 3),
+        ('^', 35), ('`', 188), ('{', 4), ('|', 32),
+        ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("(+)", 280), ("+%}", 56), ("+}}", 56),
+        ("-%}", 56), ("->", 38), ("->>", 39), ("-}}", 56),
+        ("/*+", 264), ("::", 12), (":=", 26), ("<->", 44),
+        ("<=", 19), ("<=>", 25), ("<>", 24), ("==", 23),
+        ("=>", 40), (">=", 21), ("??", 13), ("ALL", 195),
+        ("ALTER", 193), ("ALWAYS", 194), ("ANALYZE", 389), ("AND", 27),
+        ("ANTI", 196), ("ANY", 197), ("APPLY", 198), ("ARRAY", 199),
+        ("AS", 192), ("ASC", 200), ("ASOF", 201), ("AUTOINCREMENT", 203),
+        ("AUTO_INCREMENT", 203), ("BEGIN", 204), ("BETWEEN", 205), ("BIGDECIMAL", 103),
+        ("BIGINT", 88), ("BIGNUMERIC", 103), ("BINARY", 118), ("BINARY_DOUBLE", 95),
+        ("BINARY_FLOAT", 94), ("BIT", 78), ("BLOB", 119), ("BOOL", 79),
+        ("BOOLEAN", 79), ("BPCHAR", 108), ("BULK COLLECT INTO", 206), ("BYTE", 80),
+        ("BYTEA", 119), ("CACHE", 207), ("CALL", 212), ("CASE", 208),
+        ("CHAR", 104), ("CHAR VARYING", 106), ("CHARACTER", 104), ("CHARACTER SET", 209),
+        ("CHARACTER VARYING", 106), ("CLOB", 109), ("CLUSTER BY", 210), ("COLLATE", 211),
+        ("COLUMN", 63), ("COLUMNS", 63), ("COMMENT", 213), ("COMMIT", 214),
+        ("CONNECT BY", 215), ("CONSTRAINT", 216), ("COPY", 217), ("CREATE", 218),
+        ("CROSS", 219), ("CUBE", 220), ("CURRENT_DATE", 221), ("CURRENT_SCHEMA", 223),
+        ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226), ("DATABASE", 62),
+        ("DATE", 135), ("DATEMULTIRANGE", 148), ("DATERANGE", 147), ("DATETIME", 131),
+        ("DEC", 97), ("DECIMAL", 97), ("DECIMAL128", 100), ("DECIMAL256", 101),
+        ("DECIMAL32", 98), ("DECIMAL64", 99), ("DEFAULT", 228), ("DELETE", 229),
+        ("DESC", 230), ("DESCRIBE", 231), ("DISTINCT", 234), ("DISTRIBUTE BY", 235),
+        ("DIV", 236), ("DOUBLE", 95), ("DOUBLE PRECISION", 95), ("DROP", 237),
+        ("ELSE", 238), ("END", 239), ("ENUM", 179), ("ESCAPE", 240),
+        ("EXCEPT", 241), ("EXECUTE", 242), ("EXISTS", 243), ("EXPLAIN", 212),
+        ("FALSE", 244), ("FETCH", 245), ("FILTER", 247), ("FIRST", 249),
+        ("FIXED", 97), ("FLOAT", 94), ("FLOAT4", 94), ("FLOAT8", 95),
+        ("FOR", 250), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384), ("FOREIGN KEY", 252),
+        ("FORMAT", 253), ("FROM", 254), ("FULL", 255), ("FUNCTION", 256),
+        ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258), ("GRANT", 260),
+        ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263), ("HUGEINT", 90),
+        ("ILIKE", 266), ("IN", 268), ("INDEX", 269), ("INET", 174),
+        ("INNER", 270), ("INSERT", 271), ("INT", 86), ("INT1", 80),
+        ("INT128", 90), ("INT16", 82), ("INT2", 82), ("INT256", 92),
+        ("INT32", 86), ("INT4", 86), ("INT4MULTIRANGE", 138), ("INT4RANGE", 137),
+        ("INT64", 88), ("INT8", 80), ("INT8MULTIRANGE", 140), ("INT8RANGE", 139),
+        ("INTEGER", 86), ("INTERSECT", 272), ("INTERVAL", 273), ("INTO", 274),
+        ("IS", 277), ("ISNULL", 278), ("JOIN", 279), ("JSON", 120),
+        ("JSONB", 121), ("KEEP", 281), ("KILL", 283), ("LATERAL", 285),
+        ("LEFT", 286), ("LIKE", 287), ("LIMIT", 289), ("LIST", 290),
+        ("LOAD", 291), ("LOCK", 292), ("LONG", 88), ("LONGBLOB", 114),
+        ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293), ("MATCH_RECOGNIZE", 295),
+        ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MERGE", 297),
+        ("MINUS", 241), ("NAMESPACE", 390), ("NATURAL", 300), ("NCHAR", 105),
+        ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303), ("NULL", 304),
+        ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142),
+        ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173),
+        ("OFFSET", 306), ("ON", 307), ("OPTIMIZE", 212), ("OR", 28),
+        ("ORDER BY", 310), ("ORDER SIBLINGS BY", 311), ("ORDINALITY", 313), ("OUTER", 314),
+        ("OVER", 315), ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318),
+        ("PARTITION BY", 319), ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320),
+        ("PIVOT", 321), ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326),
+        ("PROCEDURE", 327), ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94),
+        ("RECURSIVE", 334), ("REFERENCES", 339), ("REGEXP", 341), ("RENAME", 336),
+        ("REPLACE", 337), ("RETURNING", 338), ("RIGHT", 340), ("RLIKE", 341),
+        ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344), ("ROWS", 345),
+        ("SAMPLE", 362), ("SCHEMA", 65), ("SELECT", 346), ("SEMI", 347),
+        ("SEQUENCE", 349), ("SET", 351), ("SETTINGS", 352), ("SHORT", 82),
+        ("SHOW", 353), ("SIMILAR TO", 354), ("SMALLINT", 82), ("SOME", 355),
+        ("SORT BY", 356), ("START", 204), ("START WITH", 357), ("STR", 109),
+        ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360), ("TABLE", 66),
+        ("TABLESAMPLE", 362), ("TEMP", 364), ("TEMPORARY", 364), ("TEXT", 109),
+        ("THEN", 366), ("TIME", 122), ("TIMESTAMP", 124), ("TIMESTAMPLTZ", 126),
+        ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_NTZ", 127),
+        ("TIMETZ", 123), ("TINYBLOB", 115), ("TINYINT", 80), ("TINYTEXT", 116),
+        ("TOP", 365), ("TRUE", 367), ("TRUNCATE", 368), ("TSMULTIRANGE", 144),
+        ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145), ("UHUGEINT", 91),
+        ("UINT", 87), ("UINT128", 91), ("UINT256", 93), ("UNCACHE", 369),
+        ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371),
+        ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167),
+        ("USING", 375), ("UUID", 149), ("VACUUM", 212), ("VALUES", 376),
+        ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172),
+        ("VECTOR", 189), ("VIEW", 377), ("VOLATILE", 378), ("WHEN", 379),
+        ("WHERE", 380), ("WINDOW", 381), ("WITH", 382), ("XOR", 52),
+        ("{%", 55), ("{%+", 55), ("{%-", 55), ("{{+", 55),
+        ("{{-", 55), ("|>", 31), ("||", 30), ("~*", 276),
+        ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"')],
+    identifier_escapes: &[],
+    string_escapes: &['\''],
+    quotes: &[("'", "'")],
+    format_strings: &[("N'", "'", 74), ("U'", "'", 77), ("n'", "'", 74), ("u'", "'", 77)],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: false,
+    comments: &[
+        ("--", None), ("/*", Some("*/")), ("/*+", Some("*/")), ("{#", Some("#}")),
+    ],
+    var_single_tokens: &['#', '$', '@'],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: false,
+    hint_start: "/*+",
+};
+
+static POSTGRES: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('$', 76),
+        ('%', 298), ('&', 29), ('\'', 188), ('(', 0),
+        (')', 1), ('*', 15), ('+', 9), (',', 6),
+        ('-', 8), ('.', 7), ('/', 17), (':', 10),
+        (';', 14), ('<', 18), ('=', 23), ('>', 20),
+        ('?', 322), ('@', 49), ('[', 2), ('\\', 16),
+        (']', 3), ('^', 35), ('`', 188), ('{', 4),
+        ('|', 32), ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("::", 12),
+        (":=", 26), ("<->", 44), ("<=", 19), ("<=>", 25),
+        ("<>", 24), ("<@", 46), ("==", 23), ("=>", 40),
+        (">=", 21), ("??", 13), ("@>", 47), ("@@", 45),
+        ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194), ("ANALYZE", 389),
+        ("AND", 27), ("ANTI", 196), ("ANY", 197), ("APPLY", 198),
+        ("ARRAY", 199), ("AS", 192), ("ASC", 200), ("ASOF", 201),
+        ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 212), ("BEGIN TRANSACTION", 204),
+        ("BETWEEN", 205), ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103),
+        ("BIGSERIAL", 164), ("BINARY", 118), ("BIT", 78), ("BLOB", 119),
+        ("BOOL", 79), ("BOOLEAN", 79), ("BPCHAR", 108), ("BYTE", 80),
+        ("BYTEA", 119), ("CACHE", 207), ("CALL", 212), ("CASE", 208),
+        ("CHAR", 104), ("CHAR VARYING", 106), ("CHARACTER", 104), ("CHARACTER SET", 209),
+        ("CHARACTER VARYING", 106), ("CLOB", 109), ("CLUSTER BY", 210), ("COLLATE", 211),
+        ("COLUMN", 63), ("COMMENT", 213), ("COMMIT", 214), ("CONNECT BY", 215),
+        ("CONSTRAINT", 216), ("CONSTRAINT TRIGGER", 212), ("COPY", 217), ("CREATE", 218),
+        ("CROSS", 219), ("CSTRING", 329), ("CUBE", 220), ("CURRENT_DATE", 221),
+        ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226),
+        ("DATABASE", 62), ("DATE", 135), ("DATEMULTIRANGE", 148), ("DATERANGE", 147),
+        ("DATETIME", 131), ("DEC", 97), ("DECIMAL", 97), ("DECIMAL128", 100),
+        ("DECIMAL256", 101), ("DECIMAL32", 98), ("DECIMAL64", 99), ("DECLARE", 212),
+        ("DEFAULT", 228), ("DELETE", 229), ("DESC", 230), ("DESCRIBE", 231),
+        ("DISTINCT", 234), ("DISTRIBUTE BY", 235), ("DO", 212), ("DOUBLE", 95),
+        ("DOUBLE PRECISION", 95), ("DROP", 237), ("ELSE", 238), ("END", 239),
+        ("ENUM", 179), ("ESCAPE", 240), ("EXCEPT", 241), ("EXEC", 212),
+        ("EXECUTE", 242), ("EXISTS", 243), ("EXPLAIN", 212), ("FALSE", 244),
+        ("FETCH", 245), ("FILTER", 247), ("FIRST", 249), ("FIXED", 97),
+        ("FLOAT", 95), ("FLOAT4", 94), ("FLOAT8", 95), ("FOR", 250),
+        ("FOR TIMESTAMP", 385), ("FOR VERSION", 384), ("FOREIGN KEY", 252), ("FORMAT", 253),
+        ("FROM", 254), ("FULL", 255), ("FUNCTION", 256), ("GEOGRAPHY", 150),
+        ("GEOMETRY", 152), ("GLOB", 258), ("GRANT", 260), ("GROUP BY", 261),
+        ("GROUPING SETS", 262), ("HAVING", 263), ("HSTORE", 160), ("HUGEINT", 90),
+        ("ILIKE", 266), ("IN", 268), ("INDEX", 269), ("INET", 174),
+        ("INNER", 270), ("INSERT", 271), ("INT", 86), ("INT1", 80),
+        ("INT128", 90), ("INT16", 82), ("INT2", 82), ("INT256", 92),
+        ("INT32", 86), ("INT4", 86), ("INT4MULTIRANGE", 138), ("INT4RANGE", 137),
+        ("INT64", 88), ("INT8", 88), ("INT8MULTIRANGE", 140), ("INT8RANGE", 139),
+        ("INTEGER", 86), ("INTERSECT", 272), ("INTERVAL", 273), ("INTO", 274),
+        ("IS", 277), ("ISNULL", 278), ("JOIN", 279), ("JSON", 120),
+        ("JSONB", 121), ("KEEP", 281), ("KILL", 283), ("LATERAL", 285),
+        ("LEFT", 286), ("LIKE", 287), ("LIMIT", 289), ("LIST", 290),
+        ("LOAD", 291), ("LOCK", 292), ("LONG", 88), ("LONGBLOB", 114),
+        ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293), ("MEDIUMBLOB", 113),
+        ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MERGE", 297), ("MONEY", 168),
+        ("NAME", 117), ("NAMESPACE", 390), ("NATURAL", 300), ("NCHAR", 105),
+        ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303), ("NULL", 304),
+        ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142),
+        ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173),
+        ("OFFSET", 306), ("OID", 305), ("ON", 307), ("ONLY", 308),
+        ("OPERATOR", 309), ("OPTIMIZE", 212), ("OR", 28), ("ORDER BY", 310),
+        ("ORDINALITY", 313), ("OUTER", 314), ("OVER", 315), ("OVERLAPS", 316),
+        ("OVERWRITE", 317), ("PARTITION", 318), ("PARTITION BY", 319), ("PARTITIONED BY", 319),
+        ("PARTITIONED_BY", 319), ("PERCENT", 320), ("PIVOT", 321), ("PRAGMA", 324),
+        ("PREPARE", 212), ("PRIMARY KEY", 326), ("PROCEDURE", 327), ("QUALIFY", 331),
+        ("RANGE", 333), ("REAL", 94), ("RECURSIVE", 334), ("REFERENCES", 339),
+        ("REFRESH", 212), ("REGCLASS", 305), ("REGCOLLATION", 305), ("REGCONFIG", 305),
+        ("REGDICTIONARY", 305), ("REGEXP", 341), ("REGNAMESPACE", 305), ("REGOPER", 305),
+        ("REGOPERATOR", 305), ("REGPROC", 305), ("REGPROCEDURE", 305), ("REGROLE", 305),
+        ("REGTYPE", 305), ("REINDEX", 212), ("RENAME", 336), ("REPLACE", 337),
+        ("RESET", 212), ("RETURNING", 338), ("REVOKE", 212), ("RIGHT", 340),
+        ("RLIKE", 341), ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344),
+        ("ROWS", 345), ("SCHEMA", 65), ("SELECT", 346), ("SEMI", 347),
+        ("SEQUENCE", 349), ("SERIAL", 162), ("SET", 351), ("SETTINGS", 352),
+        ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354), ("SMALLINT", 82),
+        ("SMALLSERIAL", 163), ("SOME", 355), ("SORT BY", 356), ("START WITH", 357),
+        ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360),
+        ("TABLE", 66), ("TABLESAMPLE", 362), ("TEMP", 364), ("TEMPORARY", 364),
+        ("TEXT", 109), ("THEN", 366), ("TIME", 122), ("TIMESTAMP", 124),
+        ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126),
+        ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115), ("TINYINT", 80),
+        ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368), ("TSMULTIRANGE", 144),
+        ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145), ("UHUGEINT", 91),
+        ("UINT", 87), ("UINT128", 91), ("UINT256", 93), ("UNCACHE", 369),
+        ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371),
+        ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167),
+        ("USING", 375), ("UUID", 149), ("VACUUM", 212), ("VALUES", 376),
+        ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172),
+        ("VECTOR", 189), ("VIEW", 377), ("VOLATILE", 378), ("WHEN", 379),
+        ("WHERE", 380), ("WINDOW", 381), ("WITH", 382), ("XML", 165),
+        ("XOR", 52), ("{%", 55), ("{%+", 55), ("{%-", 55),
+        ("{{+", 55), ("{{-", 55), ("|/", 33), ("|>", 31),
+        ("||", 30), ("||/", 34), ("~", 341), ("~*", 276),
+        ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"')],
+    identifier_escapes: &[],
+    string_escapes: &['\''],
+    quotes: &[("'", "'")],
+    format_strings: &[
+        ("$", "$", 76), ("B'", "'", 71), ("E'", "'", 73), ("N'", "'", 74),
+        ("X'", "'", 72), ("b'", "'", 71), ("e'", "'", 73), ("n'", "'", 74),
+        ("x'", "'", 72),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: true,
+    has_hex_strings: true,
+    comments: &[("--", None), ("/*", Some("*/")), ("{#", Some("#}"))],
+    var_single_tokens: &['$'],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: true,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: true,
+    hint_start: "/*+",
+};
+
+static PRESTO: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('%', 298),
+        ('&', 29), ('\'', 188), ('(', 0), (')', 1),
+        ('*', 15), ('+', 9), (',', 6), ('-', 8),
+        ('.', 7), ('/', 17), (':', 10), (';', 14),
+        ('<', 18), ('=', 23), ('>', 20), ('?', 322),
+        ('@', 49), ('[', 2), ('\\', 16), (']', 3),
+        ('^', 35), ('`', 188), ('{', 4), ('|', 32),
+        ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("::", 12),
+        (":=", 26), ("<->", 44), ("<=", 19), ("<=>", 25),
+        ("<>", 24), ("==", 23), ("=>", 40), (">=", 21),
+        ("??", 13), ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194),
+        ("ANALYZE", 389), ("AND", 27), ("ANTI", 196), ("ANY", 197),
+        ("APPLY", 198), ("ARRAY", 199), ("AS", 192), ("ASC", 200),
+        ("ASOF", 201), ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 204),
+        ("BETWEEN", 205), ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103),
+        ("BINARY", 118), ("BIT", 78), ("BLOB", 119), ("BOOL", 79),
+        ("BOOLEAN", 79), ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119),
+        ("CACHE", 207), ("CALL", 212), ("CASE", 208), ("CHAR", 104),
+        ("CHAR VARYING", 106), ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106),
+        ("CLOB", 109), ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63),
+        ("COMMENT", 213), ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216),
+        ("COPY", 217), ("CREATE", 218), ("CROSS", 219), ("CUBE", 220),
+        ("CURRENT_DATE", 221), ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225),
+        ("CURRENT_USER", 226), ("DATABASE", 62), ("DATE", 135), ("DATEMULTIRANGE", 148),
+        ("DATERANGE", 147), ("DATETIME", 131), ("DEALLOCATE PREPARE", 212), ("DEC", 97),
+        ("DECIMAL", 97), ("DECIMAL128", 100), ("DECIMAL256", 101), ("DECIMAL32", 98),
+        ("DECIMAL64", 99), ("DEFAULT", 228), ("DELETE", 229), ("DESC", 230),
+        ("DESCRIBE", 231), ("DESCRIBE INPUT", 212), ("DESCRIBE OUTPUT", 212), ("DISTINCT", 234),
+        ("DISTRIBUTE BY", 235), ("DIV", 236), ("DOUBLE", 95), ("DOUBLE PRECISION", 95),
+        ("DROP", 237), ("ELSE", 238), ("END", 239), ("ENUM", 179),
+        ("ESCAPE", 240), ("EXCEPT", 241), ("EXECUTE", 242), ("EXISTS", 243),
+        ("EXPLAIN", 212), ("FALSE", 244), ("FETCH", 245), ("FILTER", 247),
+        ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94), ("FLOAT4", 94),
+        ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384),
+        ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254), ("FULL", 255),
+        ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258),
+        ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263),
+        ("HUGEINT", 90), ("HYPERLOGLOG", 159), ("ILIKE", 266), ("IN", 268),
+        ("INDEX", 269), ("INET", 174), ("INNER", 270), ("INSERT", 271),
+        ("INT", 86), ("INT1", 80), ("INT128", 90), ("INT16", 82),
+        ("INT2", 82), ("INT256", 92), ("INT32", 86), ("INT4", 86),
+        ("INT4MULTIRANGE", 138), ("INT4RANGE", 137), ("INT64", 88), ("INT8", 80),
+        ("INT8MULTIRANGE", 140), ("INT8RANGE", 139), ("INTEGER", 86), ("INTERSECT", 272),
+        ("INTERVAL", 273), ("INTO", 274), ("IPADDRESS", 175), ("IPPREFIX", 176),
+        ("IS", 277), ("ISNULL", 278), ("JOIN", 279), ("JSON", 120),
+        ("JSONB", 121), ("KEEP", 281), ("KILL", 283), ("LATERAL", 285),
+        ("LEFT", 286), ("LIKE", 287), ("LIMIT", 289), ("LIST", 290),
+        ("LOAD", 291), ("LOCK", 292), ("LONG", 88), ("LONGBLOB", 114),
+        ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293), ("MATCH_RECOGNIZE", 295),
+        ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MERGE", 297),
+        ("NAMESPACE", 390), ("NATURAL", 300), ("NCHAR", 105), ("NEXT", 301),
+        ("NOT", 22), ("NOTNULL", 303), ("NULL", 304), ("NULLABLE", 151),
+        ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142), ("NUMRANGE", 141),
+        ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173), ("OFFSET", 306),
+        ("ON", 307), ("OPTIMIZE", 212), ("OR", 28), ("ORDER BY", 310),
+        ("ORDINALITY", 313), ("OUTER", 314), ("OVER", 315), ("OVERLAPS", 316),
+        ("OVERWRITE", 317), ("PARTITION", 318), ("PARTITION BY", 319), ("PARTITIONED BY", 319),
+        ("PARTITIONED_BY", 319), ("PERCENT", 320), ("PIVOT", 321), ("PRAGMA", 324),
+        ("PREPARE", 212), ("PRIMARY KEY", 326), ("PROCEDURE", 327), ("RANGE", 333),
+        ("REAL", 94), ("RECURSIVE", 334), ("REFERENCES", 339), ("REGEXP", 341),
+        ("RENAME", 336), ("REPLACE", 337), ("RESET SESSION", 212), ("RETURNING", 338),
+        ("RIGHT", 340), ("RLIKE", 341), ("ROLLBACK", 342), ("ROLLUP", 343),
+        ("ROW", 360), ("ROWS", 345), ("SCHEMA", 65), ("SELECT", 346),
+        ("SEMI", 347), ("SEQUENCE", 349), ("SET", 351), ("SETTINGS", 352),
+        ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354), ("SMALLINT", 82),
+        ("SOME", 355), ("SORT BY", 356), ("START", 204), ("START WITH", 357),
+        ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360),
+        ("TABLE", 66), ("TABLESAMPLE", 362), ("TDIGEST", 187), ("TEMP", 364),
+        ("TEMPORARY", 364), ("TEXT", 109), ("THEN", 366), ("TIME", 122),
+        ("TIMESTAMP", 124), ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125),
+        ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115),
+        ("TINYINT", 80), ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368),
+        ("TSMULTIRANGE", 144), ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145),
+        ("UHUGEINT", 91), ("UINT", 87), ("UINT128", 91), ("UINT256", 93),
+        ("UNCACHE", 369), ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188),
+        ("UNNEST", 371), ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374),
+        ("USER-DEFINED", 167), ("USING", 375), ("UUID", 149), ("VACUUM", 212),
+        ("VALUES", 376), ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106),
+        ("VARIANT", 172), ("VECTOR", 189), ("VIEW", 377), ("VOLATILE", 378),
+        ("WHEN", 379), ("WHERE", 380), ("WINDOW", 381), ("WITH", 382),
+        ("XOR", 52), ("{%", 55), ("{%+", 55), ("{%-", 55),
+        ("{{+", 55), ("{{-", 55), ("|>", 31), ("||", 30),
+        ("~*", 276), ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"')],
+    identifier_escapes: &[],
+    string_escapes: &['\''],
+    quotes: &[("'", "'")],
+    format_strings: &[
+        ("N'", "'", 74), ("U&'", "'", 77), ("X'", "'", 72), ("n'", "'", 74),
+        ("u&'", "'", 77), ("x'", "'", 72),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: true,
+    comments: &[("--", None), ("/*", Some("*/")), ("{#", Some("#}"))],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: false,
+    hint_start: "/*+",
+};
+
+static REDSHIFT: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('$', 76), ('%', 298),
+        ('&', 29), ('\'', 188), ('(', 0), (')', 1),
+        ('*', 15), ('+', 9), (',', 6), ('-', 8),
+        ('.', 7), ('/', 17), (':', 10), (';', 14),
+        ('<', 18), ('=', 23), ('>', 20), ('?', 322),
+        ('@', 49), ('[', 2), ('\\', 16), (']', 3),
+        ('^', 35), ('`', 188), ('{', 4), ('|', 32),
+        ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("(+)", 280), ("+%}", 56), ("+}}", 56),
+        ("-%}", 56), ("->", 38), ("->>", 39), ("-}}", 56),
+        ("::", 12), (":=", 26), ("<->", 44), ("<=", 19),
+        ("<=>", 25), ("<>", 24), ("<@", 46), ("==", 23),
+        ("=>", 40), (">=", 21), ("??", 13), ("@>", 47),
+        ("@@", 45), ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194),
+        ("ANALYZE", 389), ("AND", 27), ("ANTI", 196), ("ANY", 197),
+        ("APPLY", 198), ("ARRAY", 199), ("AS", 192), ("ASC", 200),
+        ("ASOF", 201), ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 212),
+        ("BEGIN TRANSACTION", 204), ("BETWEEN", 205), ("BIGDECIMAL", 103), ("BIGINT", 88),
+        ("BIGNUMERIC", 103), ("BIGSERIAL", 164), ("BINARY", 118), ("BINARY VARYING", 119),
+        ("BIT", 78), ("BLOB", 119), ("BOOL", 79), ("BOOLEAN", 79),
+        ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119), ("CACHE", 207),
+        ("CALL", 212), ("CASE", 208), ("CHAR", 104), ("CHAR VARYING", 106),
+        ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106), ("CLOB", 109),
+        ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63), ("COMMENT", 213),
+        ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216), ("CONSTRAINT TRIGGER", 212),
+        ("COPY", 217), ("CREATE", 218), ("CROSS", 219), ("CSTRING", 329),
+        ("CUBE", 220), ("CURRENT_DATE", 221), ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224),
+        ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226), ("DATABASE", 62), ("DATE", 135),
+        ("DATEMULTIRANGE", 148), ("DATERANGE", 147), ("DATETIME", 131), ("DEC", 97),
+        ("DECIMAL", 97), ("DECIMAL128", 100), ("DECIMAL256", 101), ("DECIMAL32", 98),
+        ("DECIMAL64", 99), ("DECLARE", 212), ("DEFAULT", 228), ("DELETE", 229),
+        ("DESC", 230), ("DESCRIBE", 231), ("DISTINCT", 234), ("DISTRIBUTE BY", 235),
+        ("DO", 212), ("DOUBLE", 95), ("DOUBLE PRECISION", 95), ("DROP", 237),
+        ("ELSE", 238), ("END", 239), ("ENUM", 179), ("ESCAPE", 240),
+        ("EXCEPT", 241), ("EXEC", 212), ("EXECUTE", 242), ("EXISTS", 243),
+        ("EXPLAIN", 212), ("FALSE", 244), ("FETCH", 245), ("FILTER", 247),
+        ("FIRST", 249), ("FIXED", 97), ("FLOAT", 95), ("FLOAT4", 94),
+        ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384),
+        ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254), ("FULL", 255),
+        ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258),
+        ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263),
+        ("HLLSKETCH", 159), ("HSTORE", 160), ("HUGEINT", 90), ("ILIKE", 266),
+        ("IN", 268), ("INDEX", 269), ("INET", 174), ("INNER", 270),
+        ("INSERT", 271), ("INT", 86), ("INT1", 80), ("INT128", 90),
+        ("INT16", 82), ("INT2", 82), ("INT256", 92), ("INT32", 86),
+        ("INT4", 86), ("INT4MULTIRANGE", 138), ("INT4RANGE", 137), ("INT64", 88),
+        ("INT8", 88), ("INT8MULTIRANGE", 140), ("INT8RANGE", 139), ("INTEGER", 86),
+        ("INTERSECT", 272), ("INTERVAL", 273), ("INTO", 274), ("IS", 277),
+        ("ISNULL", 278), ("JOIN", 279), ("JSON", 120), ("JSONB", 121),
+        ("KEEP", 281), ("KILL", 283), ("LATERAL", 285), ("LEFT", 286),
+        ("LIKE", 287), ("LIMIT", 289), ("LIST", 290), ("LOAD", 291),
+        ("LOCK", 292), ("LONG", 88), ("LONGBLOB", 114), ("LONGTEXT", 111),
+        ("LONGVARCHAR", 109), ("MAP", 293), ("MEDIUMBLOB", 113), ("MEDIUMINT", 84),
+        ("MEDIUMTEXT", 110), ("MERGE", 297), ("MINUS", 241), ("MONEY", 168),
+        ("NAME", 117), ("NAMESPACE", 390), ("NATURAL", 300), ("NCHAR", 105),
+        ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303), ("NULL", 304),
+        ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142),
+        ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173),
+        ("OFFSET", 306), ("OID", 305), ("ON", 307), ("ONLY", 308),
+        ("OPERATOR", 309), ("OPTIMIZE", 212), ("OR", 28), ("ORDER BY", 310),
+        ("ORDINALITY", 313), ("OUTER", 314), ("OVER", 315), ("OVERLAPS", 316),
+        ("OVERWRITE", 317), ("PARTITION", 318), ("PARTITION BY", 319), ("PARTITIONED BY", 319),
+        ("PARTITIONED_BY", 319), ("PERCENT", 320), ("PIVOT", 321), ("PRAGMA", 324),
+        ("PREPARE", 212), ("PRIMARY KEY", 326), ("PROCEDURE", 327), ("QUALIFY", 331),
+        ("RANGE", 333), ("REAL", 94), ("RECURSIVE", 334), ("REFERENCES", 339),
+        ("REFRESH", 212), ("REGCLASS", 305), ("REGCOLLATION", 305), ("REGCONFIG", 305),
+        ("REGDICTIONARY", 305), ("REGEXP", 341), ("REGNAMESPACE", 305), ("REGOPER", 305),
+        ("REGOPERATOR", 305), ("REGPROC", 305), ("REGPROCEDURE", 305), ("REGROLE", 305),
+        ("REGTYPE", 305), ("REINDEX", 212), ("RENAME", 336), ("REPLACE", 337),
+        ("RESET", 212), ("RETURNING", 338), ("REVOKE", 212), ("RIGHT", 340),
+        ("RLIKE", 341), ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344),
+        ("ROWS", 345), ("SCHEMA", 65), ("SELECT", 346), ("SEMI", 347),
+        ("SEQUENCE", 349), ("SERIAL", 162), ("SET", 351), ("SETTINGS", 352),
+        ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354), ("SMALLINT", 82),
+        ("SMALLSERIAL", 163), ("SOME", 355), ("SORT BY", 356), ("START WITH", 357),
+        ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360),
+        ("SUPER", 161), ("TABLE", 66), ("TABLESAMPLE", 362), ("TEMP", 364),
+        ("TEMPORARY", 364), ("TEXT", 109), ("THEN", 366), ("TIME", 122),
+        ("TIMESTAMP", 124), ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125),
+        ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115),
+        ("TINYINT", 80), ("TINYTEXT", 116), ("TOP", 365), ("TRUE", 367),
+        ("TRUNCATE", 368), ("TSMULTIRANGE", 144), ("TSRANGE", 143), ("TSTZMULTIRANGE", 146),
+        ("TSTZRANGE", 145), ("UHUGEINT", 91), ("UINT", 87), ("UINT128", 91),
+        ("UINT256", 93), ("UNCACHE", 369), ("UNION", 370), ("UNIQUE", 383),
+        ("UNKNOWN", 188), ("UNLOAD", 212), ("UNNEST", 371), ("UNPIVOT", 372),
+        ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167), ("USING", 375),
+        ("UUID", 149), ("VACUUM", 212), ("VARBINARY", 119), ("VARBYTE", 119),
+        ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172), ("VECTOR", 189),
+        ("VIEW", 377), ("VOLATILE", 378), ("WHEN", 379), ("WHERE", 380),
+        ("WINDOW", 381), ("WITH", 382), ("XML", 165), ("XOR", 52),
+        ("{%", 55), ("{%+", 55), ("{%-", 55), ("{{+", 55),
+        ("{{-", 55), ("|/", 33), ("|>", 31), ("||", 30),
+        ("||/", 34), ("~", 341), ("~*", 276), ("~~", 287),
+        ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"')],
+    identifier_escapes: &[],
+    string_escapes: &['\'', '\\'],
+    quotes: &[("'", "'")],
+    format_strings: &[
+        ("$", "$", 76), ("E'", "'", 73), ("N'", "'", 74), ("e'", "'", 73),
+        ("n'", "'", 74),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: false,
+    comments: &[("--", None), ("/*", Some("*/")), ("{#", Some("#}"))],
+    var_single_tokens: &['$'],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: true,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: true,
+    hint_start: "/*+",
+};
+
+static SNOWFLAKE: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('$', 49),
+        ('%', 298), ('&', 29), ('\'', 188), ('(', 0),
+        (')', 1), ('*', 15), ('+', 9), (',', 6),
+        ('-', 8), ('.', 7), ('/', 17), (':', 10),
+        (';', 14), ('<', 18), ('=', 23), ('>', 20),
+        ('?', 322), ('@', 49), ('[', 2), ('\\', 16),
+        (']', 3), ('^', 35), ('`', 188), ('{', 4),
+        ('|', 32), ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("::", 12),
+        (":=", 26), ("<->", 44), ("<=", 19), ("<=>", 25),
+        ("<>", 24), ("==", 23), ("=>", 40), (">=", 21),
+        ("??", 13), ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194),
+        ("ANALYZE", 389), ("AND", 27), ("ANTI", 196), ("ANY", 197),
+        ("APPLY", 198), ("ARRAY", 199), ("AS", 192), ("ASC", 200),
+        ("ASOF", 201), ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 204),
+        ("BETWEEN", 205), ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103),
+        ("BINARY", 118), ("BIT", 78), ("BLOB", 119), ("BOOL", 79),
+        ("BOOLEAN", 79), ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119),
+        ("BYTEINT", 86), ("CACHE", 207), ("CALL", 212), ("CASE", 208),
+        ("CHAR", 104), ("CHAR VARYING", 106), ("CHARACTER", 104), ("CHARACTER SET", 209),
+        ("CHARACTER VARYING", 106), ("CLOB", 109), ("CLUSTER BY", 210), ("COLLATE", 211),
+        ("COLUMN", 63), ("COMMENT", 213), ("COMMIT", 214), ("CONNECT BY", 215),
+        ("CONSTRAINT", 216), ("COPY", 217), ("CREATE", 218), ("CROSS", 219),
+        ("CUBE", 220), ("CURRENT_DATE", 221), ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224),
+        ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226), ("DATABASE", 62), ("DATE", 135),
+        ("DATEMULTIRANGE", 148), ("DATERANGE", 147), ("DATETIME", 131), ("DEC", 97),
+        ("DECIMAL", 97), ("DECIMAL128", 100), ("DECIMAL256", 101), ("DECIMAL32", 98),
+        ("DECIMAL64", 99), ("DEFAULT", 228), ("DELETE", 229), ("DESC", 230),
+        ("DESCRIBE", 231), ("DISTINCT", 234), ("DISTRIBUTE BY", 235), ("DIV", 236),
+        ("DOUBLE", 95), ("DOUBLE PRECISION", 95), ("DROP", 237), ("ELSE", 238),
+        ("END", 239), ("ENUM", 179), ("ESCAPE", 240), ("EXCEPT", 241),
+        ("EXCLUDE", 241), ("EXECUTE", 242), ("EXISTS", 243), ("EXPLAIN", 212),
+        ("FALSE", 244), ("FETCH", 245), ("FILE FORMAT", 246), ("FILE://", 54),
+        ("FILTER", 247), ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94),
+        ("FLOAT4", 94), ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385),
+        ("FOR VERSION", 384), ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254),
+        ("FULL", 255), ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152),
+        ("GET", 257), ("GLOB", 258), ("GRANT", 260), ("GROUP BY", 261),
+        ("GROUPING SETS", 262), ("HAVING", 263), ("HUGEINT", 90), ("ILIKE", 266),
+        ("ILIKE ANY", 267), ("IN", 268), ("INDEX", 269), ("INET", 174),
+        ("INNER", 270), ("INSERT", 271), ("INT", 86), ("INT1", 80),
+        ("INT128", 90), ("INT16", 82), ("INT2", 82), ("INT256", 92),
+        ("INT32", 86), ("INT4", 86), ("INT4MULTIRANGE", 138), ("INT4RANGE", 137),
+        ("INT64", 88), ("INT8", 80), ("INT8MULTIRANGE", 140), ("INT8RANGE", 139),
+        ("INTEGER", 86), ("INTERSECT", 272), ("INTERVAL", 273), ("INTO", 274),
+        ("IS", 277), ("ISNULL", 278), ("JOIN", 279), ("JSON", 120),
+        ("JSONB", 121), ("KEEP", 281), ("KILL", 283), ("LATERAL", 285),
+        ("LEFT", 286), ("LIKE", 287), ("LIKE ANY", 288), ("LIMIT", 289),
+        ("LIST", 290), ("LOAD", 291), ("LOCK", 292), ("LONG", 88),
+        ("LONGBLOB", 114), ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293),
+        ("MATCH_CONDITION", 294), ("MATCH_RECOGNIZE", 295), ("MEDIUMBLOB", 113), ("MEDIUMINT", 84),
+        ("MEDIUMTEXT", 110), ("MERGE", 297), ("MINUS", 241), ("NAMESPACE", 390),
+        ("NATURAL", 300), ("NCHAR", 105), ("NCHAR VARYING", 106), ("NEXT", 301),
+        ("NOT", 22), ("NOTNULL", 303), ("NULL", 304), ("NULLABLE", 151),
+        ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142), ("NUMRANGE", 141),
+        ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173), ("OFFSET", 306),
+        ("ON", 307), ("OPTIMIZE", 212), ("OR", 28), ("ORDER BY", 310),
+        ("ORDINALITY", 313), ("OUTER", 314), ("OVER", 315), ("OVERLAPS", 316),
+        ("OVERWRITE", 317), ("PARTITION", 318), ("PARTITION BY", 319), ("PARTITIONED BY", 319),
+        ("PARTITIONED_BY", 319), ("PERCENT", 320), ("PIVOT", 321), ("PRAGMA", 324),
+        ("PREPARE", 212), ("PRIMARY KEY", 326), ("PROCEDURE", 327), ("PUT", 330),
+        ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94), ("RECURSIVE", 334),
+        ("REFERENCES", 339), ("REGEXP", 341), ("REMOVE", 212), ("RENAME", 336),
+        ("REPLACE", 337), ("RETURNING", 338), ("RIGHT", 340), ("RLIKE", 341),
+        ("RM", 212), ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344),
+        ("ROWS", 345), ("SAMPLE", 362), ("SCHEMA", 65), ("SELECT", 346),
+        ("SEMI", 347), ("SEQUENCE", 349), ("SET", 351), ("SETTINGS", 352),
+        ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354), ("SMALLINT", 82),
+        ("SOME", 355), ("SORT BY", 356), ("SQL_DOUBLE", 95), ("SQL_VARCHAR", 106),
+        ("STAGE", 68), ("START WITH", 357), ("STORAGE INTEGRATION", 358), ("STR", 109),
+        ("STRAIGHT_JOIN", 359), ("STREAMLIT", 69), ("STRING", 109), ("STRUCT", 360),
+        ("TABLE", 66), ("TABLESAMPLE", 362), ("TAG", 363), ("TEMP", 364),
+        ("TEMPORARY", 364), ("TEXT", 109), ("THEN", 366), ("TIME", 122),
+        ("TIMESTAMP", 124), ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125),
+        ("TIMESTAMP_LTZ", 126), ("TIMESTAMP_NTZ", 127), ("TIMESTAMP_TZ", 125), ("TIMETZ", 123),
+        ("TINYBLOB", 115), ("TINYINT", 80), ("TINYTEXT", 116), ("TOP", 365),
+        ("TRUE", 367), ("TRUNCATE", 368), ("TSMULTIRANGE", 144), ("TSRANGE", 143),
+        ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145), ("UHUGEINT", 91), ("UINT", 87),
+        ("UINT128", 91), ("UINT256", 93), ("UNCACHE", 369), ("UNION", 370),
+        ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371), ("UNPIVOT", 372),
+        ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167), ("USING", 375),
+        ("UUID", 149), ("VACUUM", 212), ("VALUES", 376), ("VARBINARY", 119),
+        ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172), ("VECTOR", 189),
+        ("VIEW", 377), ("VOLATILE", 378), ("WAREHOUSE", 67), ("WHEN", 379),
+        ("WHERE", 380), ("WINDOW", 381), ("WITH", 382), ("XOR", 52),
+        ("{%", 55), ("{%+", 55), ("{%-", 55), ("{{+", 55),
+        ("{{-", 55), ("|>", 31), ("||", 30), ("~*", 276),
+        ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"')],
+    identifier_escapes: &[],
+    string_escapes: &['\'', '\\'],
+    quotes: &[("'", "'")],
+    format_strings: &[
+        ("$$", "$$", 75), ("N'", "'", 74), ("X'", "'", 72), ("n'", "'", 74),
+        ("x'", "'", 72),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: true,
+    comments: &[("--", None), ("/*", Some("*/")), ("//", None), ("{#", Some("#}"))],
+    var_single_tokens: &['$'],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: false,
+    hint_start: "/*+",
+};
+
+static SPARK: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('$', 49),
+        ('%', 298), ('&', 29), ('\'', 188), ('(', 0),
+        (')', 1), ('*', 15), ('+', 9), (',', 6),
+        ('-', 8), ('.', 7), ('/', 17), (':', 10),
+        (';', 14), ('<', 18), ('=', 23), ('>', 20),
+        ('?', 322), ('@', 49), ('[', 2), ('\\', 16),
+        (']', 3), ('^', 35), ('`', 188), ('{', 4),
+        ('|', 32), ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("/*+", 264),
+        ("::", 12), (":=", 26), ("<->", 44), ("<=", 19),
+        ("<=>", 25), ("<>", 24), ("==", 23), ("=>", 40),
+        (">=", 21), ("??", 13), ("ADD ARCHIVE", 212), ("ADD ARCHIVES", 212),
+        ("ADD FILE", 212), ("ADD FILES", 212), ("ADD JAR", 212), ("ADD JARS", 212),
+        ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194), ("ANALYZE", 389),
+        ("AND", 27), ("ANTI", 196), ("ANY", 197), ("APPLY", 198),
+        ("ARRAY", 199), ("AS", 192), ("ASC", 200), ("ASOF", 201),
+        ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 204), ("BETWEEN", 205),
+        ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103), ("BINARY", 118),
+        ("BIT", 78), ("BLOB", 119), ("BOOL", 79), ("BOOLEAN", 79),
+        ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119), ("CACHE", 207),
+        ("CALL", 212), ("CASE", 208), ("CHAR", 104), ("CHAR VARYING", 106),
+        ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106), ("CLOB", 109),
+        ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63), ("COMMENT", 213),
+        ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216), ("COPY", 217),
+        ("CREATE", 218), ("CROSS", 219), ("CUBE", 220), ("CURRENT_DATE", 221),
+        ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226),
+        ("DATABASE", 62), ("DATE", 135), ("DATEMULTIRANGE", 148), ("DATERANGE", 147),
+        ("DATETIME", 131), ("DEC", 97), ("DECIMAL", 97), ("DECIMAL128", 100),
+        ("DECIMAL256", 101), ("DECIMAL32", 98), ("DECIMAL64", 99), ("DEFAULT", 228),
+        ("DELETE", 229), ("DESC", 230), ("DESCRIBE", 231), ("DISTINCT", 234),
+        ("DISTRIBUTE BY", 235), ("DIV", 236), ("DOUBLE", 95), ("DOUBLE PRECISION", 95),
+        ("DROP", 237), ("ELSE", 238), ("END", 239), ("ENUM", 179),
+        ("ESCAPE", 240), ("EXCEPT", 241), ("EXECUTE", 242), ("EXISTS", 243),
+        ("EXPLAIN", 212), ("FALSE", 244), ("FETCH", 245), ("FILTER", 247),
+        ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94), ("FLOAT4", 94),
+        ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384),
+        ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254), ("FULL", 255),
+        ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258),
+        ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263),
+        ("HUGEINT", 90), ("ILIKE", 266), ("IN", 268), ("INDEX", 269),
+        ("INET", 174), ("INNER", 270), ("INSERT", 271), ("INT", 86),
+        ("INT1", 80), ("INT128", 90), ("INT16", 82), ("INT2", 82),
+        ("INT256", 92), ("INT32", 86), ("INT4", 86), ("INT4MULTIRANGE", 138),
+        ("INT4RANGE", 137), ("INT64", 88), ("INT8", 80), ("INT8MULTIRANGE", 140),
+        ("INT8RANGE", 139), ("INTEGER", 86), ("INTERSECT", 272), ("INTERVAL", 273),
+        ("INTO", 274), ("IS", 277), ("ISNULL", 278), ("JOIN", 279),
+        ("JSON", 120), ("JSONB", 121), ("KEEP", 281), ("KILL", 283),
+        ("LATERAL", 285), ("LEFT", 286), ("LIKE", 287), ("LIMIT", 289),
+        ("LIST", 290), ("LOAD", 291), ("LOCK", 292), ("LONG", 88),
+        ("LONGBLOB", 114), ("LONGTEXT", 111), ("LONGVARCHAR", 109), ("MAP", 293),
+        ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110), ("MERGE", 297),
+        ("MINUS", 241), ("MSCK REPAIR", 212), ("NAMESPACE", 390), ("NATURAL", 300),
+        ("NCHAR", 105), ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303),
+        ("NULL", 304), ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97),
+        ("NUMMULTIRANGE", 142), ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107),
+        ("OBJECT", 173), ("OFFSET", 306), ("ON", 307), ("OPTIMIZE", 212),
+        ("OR", 28), ("ORDER BY", 310), ("ORDINALITY", 313), ("OUTER", 314),
+        ("OVER", 315), ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318),
+        ("PARTITION BY", 319), ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320),
+        ("PIVOT", 321), ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326),
+        ("PROCEDURE", 327), ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94),
+        ("RECURSIVE", 334), ("REFERENCES", 339), ("REFRESH", 335), ("REGEXP", 341),
+        ("RENAME", 336), ("REPLACE", 337), ("RETURNING", 338), ("RIGHT", 340),
+        ("RLIKE", 341), ("ROLLBACK", 342), ("ROLLUP", 343), ("ROW", 344),
+        ("ROWS", 345), ("SCHEMA", 65), ("SELECT", 346), ("SEMI", 347),
+        ("SEQUENCE", 349), ("SERDEPROPERTIES", 350), ("SET", 351), ("SETTINGS", 352),
+        ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354), ("SMALLINT", 82),
+        ("SOME", 355), ("SORT BY", 356), ("START WITH", 357), ("STR", 109),
+        ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360), ("TABLE", 66),
+        ("TABLESAMPLE", 362), ("TEMP", 364), ("TEMPORARY", 364), ("TEXT", 109),
+        ("THEN", 366), ("TIME", 122), ("TIMESTAMP", 125), ("TIMESTAMP AS OF", 385),
+        ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126),
+        ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115), ("TINYINT", 80),
+        ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368), ("TSMULTIRANGE", 144),
+        ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145), ("UHUGEINT", 91),
+        ("UINT", 87), ("UINT128", 91), ("UINT256", 93), ("UNCACHE", 369),
+        ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371),
+        ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167),
+        ("USING", 375), ("UUID", 149), ("VACUUM", 212), ("VALUES", 376),
+        ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172),
+        ("VECTOR", 189), ("VERSION AS OF", 384), ("VIEW", 377), ("VOLATILE", 378),
+        ("WHEN", 379), ("WHERE", 380), ("WINDOW", 381), ("WITH", 382),
+        ("XOR", 52), ("{%", 55), ("{%+", 55), ("{%-", 55),
+        ("{{+", 55), ("{{-", 55), ("|>", 31), ("||", 30),
+        ("~*", 276), ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[
+        ("BD", "DECIMAL"), ("D", "DOUBLE"), ("F", "FLOAT"), ("L", "BIGINT"),
+        ("S", "SMALLINT"), ("Y", "TINYINT"),
+    ],
+    identifiers: &[('`', '`')],
+    identifier_escapes: &[],
+    string_escapes: &['\\'],
+    quotes: &[("\"", "\""), ("'", "'")],
+    format_strings: &[
+        ("N\"", "\"", 74), ("N'", "'", 74), ("R\"", "\"", 75), ("R'", "'", 75),
+        ("X'", "'", 72), ("n\"", "\"", 74), ("n'", "'", 74), ("r\"", "\"", 75),
+        ("r'", "'", 75), ("x'", "'", 72),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: true,
+    comments: &[
+        ("--", None), ("/*", Some("*/")), ("/*+", Some("*/")), ("{#", Some("#}")),
+    ],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: false,
+    nested_comments: true,
+    hint_start: "/*+",
+};
+
+static SQLITE: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('%', 298),
+        ('&', 29), ('\'', 188), ('(', 0), (')', 1),
+        ('*', 15), ('+', 9), (',', 6), ('-', 8),
+        ('.', 7), ('/', 17), (':', 10), (';', 14),
+        ('<', 18), ('=', 23), ('>', 20), ('?', 322),
+        ('@', 49), ('[', 2), ('\\', 16), (']', 3),
+        ('^', 35), ('`', 188), ('{', 4), ('|', 32),
+        ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("::", 12),
+        (":=", 26), ("<->", 44), ("<=", 19), ("<=>", 25),
+        ("<>", 24), ("==", 23), ("=>", 40), (">=", 21),
+        ("??", 13), ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194),
+        ("ANALYZE", 389), ("AND", 27), ("ANTI", 196), ("ANY", 197),
+        ("APPLY", 198), ("ARRAY", 199), ("AS", 192), ("ASC", 200),
+        ("ASOF", 201), ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 204),
+        ("BETWEEN", 205), ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103),
+        ("BINARY", 118), ("BIT", 78), ("BLOB", 119), ("BOOL", 79),
+        ("BOOLEAN", 79), ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119),
+        ("CACHE", 207), ("CALL", 212), ("CASE", 208), ("CHAR", 104),
+        ("CHAR VARYING", 106), ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106),
+        ("CLOB", 109), ("CLUSTER BY", 210), ("COLLATE", 211), ("COLUMN", 63),
+        ("COMMENT", 213), ("COMMIT", 214), ("CONNECT BY", 215), ("CONSTRAINT", 216),
+        ("COPY", 217), ("CREATE", 218), ("CROSS", 219), ("CUBE", 220),
+        ("CURRENT_DATE", 221), ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224), ("CURRENT_TIMESTAMP", 225),
+        ("CURRENT_USER", 226), ("DATABASE", 62), ("DATE", 135), ("DATEMULTIRANGE", 148),
+        ("DATERANGE", 147), ("DATETIME", 131), ("DEC", 97), ("DECIMAL", 97),
+        ("DECIMAL128", 100), ("DECIMAL256", 101), ("DECIMAL32", 98), ("DECIMAL64", 99),
+        ("DEFAULT", 228), ("DELETE", 229), ("DESC", 230), ("DESCRIBE", 231),
+        ("DISTINCT", 234), ("DISTRIBUTE BY", 235), ("DIV", 236), ("DOUBLE", 95),
+        ("DOUBLE PRECISION", 95), ("DROP", 237), ("ELSE", 238), ("END", 239),
+        ("ENUM", 179), ("ESCAPE", 240), ("EXCEPT", 241), ("EXECUTE", 242),
+        ("EXISTS", 243), ("EXPLAIN", 212), ("FALSE", 244), ("FETCH", 245),
+        ("FILTER", 247), ("FIRST", 249), ("FIXED", 97), ("FLOAT", 94),
+        ("FLOAT4", 94), ("FLOAT8", 95), ("FOR", 250), ("FOR TIMESTAMP", 385),
+        ("FOR VERSION", 384), ("FOREIGN KEY", 252), ("FORMAT", 253), ("FROM", 254),
+        ("FULL", 255), ("FUNCTION", 256), ("GEOGRAPHY", 150), ("GEOMETRY", 152),
+        ("GLOB", 258), ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262),
+        ("HAVING", 263), ("HUGEINT", 90), ("ILIKE", 266), ("IN", 268),
+        ("INDEX", 269), ("INET", 174), ("INNER", 270), ("INSERT", 271),
+        ("INT", 86), ("INT1", 80), ("INT128", 90), ("INT16", 82),
+        ("INT2", 82), ("INT256", 92), ("INT32", 86), ("INT4", 86),
+        ("INT4MULTIRANGE", 138), ("INT4RANGE", 137), ("INT64", 88), ("INT8", 80),
+        ("INT8MULTIRANGE", 140), ("INT8RANGE", 139), ("INTEGER", 86), ("INTERSECT", 272),
+        ("INTERVAL", 273), ("INTO", 274), ("IS", 277), ("ISNULL", 278),
+        ("JOIN", 279), ("JSON", 120), ("JSONB", 121), ("KEEP", 281),
+        ("KILL", 283), ("LATERAL", 285), ("LEFT", 286), ("LIKE", 287),
+        ("LIMIT", 289), ("LIST", 290), ("LOAD", 291), ("LOCK", 292),
+        ("LONG", 88), ("LONGBLOB", 114), ("LONGTEXT", 111), ("LONGVARCHAR", 109),
+        ("MAP", 293), ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110),
+        ("MERGE", 297), ("NAMESPACE", 390), ("NATURAL", 300), ("NCHAR", 105),
+        ("NEXT", 301), ("NOT", 22), ("NOTNULL", 303), ("NULL", 304),
+        ("NULLABLE", 151), ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142),
+        ("NUMRANGE", 141), ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173),
+        ("OFFSET", 306), ("ON", 307), ("OPTIMIZE", 212), ("OR", 28),
+        ("ORDER BY", 310), ("ORDINALITY", 313), ("OUTER", 314), ("OVER", 315),
+        ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318), ("PARTITION BY", 319),
+        ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320), ("PIVOT", 321),
+        ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326), ("PROCEDURE", 327),
+        ("QUALIFY", 331), ("RANGE", 333), ("REAL", 94), ("RECURSIVE", 334),
+        ("REFERENCES", 339), ("REGEXP", 341), ("RENAME", 336), ("REPLACE", 337),
+        ("RETURNING", 338), ("RIGHT", 340), ("RLIKE", 341), ("ROLLBACK", 342),
+        ("ROLLUP", 343), ("ROW", 344), ("ROWS", 345), ("SCHEMA", 65),
+        ("SELECT", 346), ("SEMI", 347), ("SEQUENCE", 349), ("SET", 351),
+        ("SETTINGS", 352), ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354),
+        ("SMALLINT", 82), ("SOME", 355), ("SORT BY", 356), ("START WITH", 357),
+        ("STR", 109), ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360),
+        ("TABLE", 66), ("TABLESAMPLE", 362), ("TEMP", 364), ("TEMPORARY", 364),
+        ("TEXT", 109), ("THEN", 366), ("TIME", 122), ("TIMESTAMP", 124),
+        ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126),
+        ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115), ("TINYINT", 80),
+        ("TINYTEXT", 116), ("TRUE", 367), ("TRUNCATE", 368), ("TSMULTIRANGE", 144),
+        ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145), ("UHUGEINT", 91),
+        ("UINT", 87), ("UINT128", 91), ("UINT256", 93), ("UNCACHE", 369),
+        ("UNION", 370), ("UNIQUE", 383), ("UNKNOWN", 188), ("UNNEST", 371),
+        ("UNPIVOT", 372), ("UPDATE", 373), ("USE", 374), ("USER-DEFINED", 167),
+        ("USING", 375), ("UUID", 149), ("VACUUM", 212), ("VALUES", 376),
+        ("VARBINARY", 119), ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172),
+        ("VECTOR", 189), ("VIEW", 377), ("VOLATILE", 378), ("WHEN", 379),
+        ("WHERE", 380), ("WINDOW", 381), ("WITH", 382), ("XOR", 52),
+        ("{%", 55), ("{%+", 55), ("{%-", 55), ("{{+", 55),
+        ("{{-", 55), ("|>", 31), ("||", 30), ("~*", 276),
+        ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"'), ('[', ']'), ('`', '`')],
+    identifier_escapes: &[],
+    string_escapes: &['\''],
+    quotes: &[("'", "'")],
+    format_strings: &[
+        ("0X", "", 72), ("0x", "", 72), ("N'", "'", 74), ("X'", "'", 72),
+        ("n'", "'", 74), ("x'", "'", 72),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: true,
+    comments: &[("--", None), ("/*", Some("*/")), ("{#", Some("#}"))],
+    var_single_tokens: &[],
+    placeholder_styles: &[],
+    commands: &[212, 242, 245, 336, 337, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: false,
+    hint_start: "/*+",
+};
+
+static TSQL: SettingsPreset = SettingsPreset {
+    white_space: &[('\t', 57), ('\n', 58), ('\r', 58), (' ', 57)],
+    single_tokens: &[
+        ('!', 22), ('"', 188), ('#', 41), ('%', 298),
+        ('&', 29), ('\'', 188), ('(', 0), (')', 1),
+        ('*', 15), ('+', 9), (',', 6), ('-', 8),
+        ('.', 7), ('/', 17), (':', 10), (';', 14),
+        ('<', 18), ('=', 23), ('>', 20), ('?', 322),
+        ('@', 49), ('[', 2), ('\\', 16), (']', 3),
+        ('^', 35), ('`', 188), ('{', 4), ('|', 32),
+        ('}', 5), ('~', 37),
+    ],
+    keywords: &[
+        ("!=", 24), ("#>", 42), ("#>>", 43), ("%}", 56),
+        ("&&", 51), ("+%}", 56), ("+}}", 56), ("-%}", 56),
+        ("->", 38), ("->>", 39), ("-}}", 56), ("::", 12),
+        (":=", 26), ("<->", 44), ("<=", 19), ("<=>", 25),
+        ("<>", 24), ("==", 23), ("=>", 40), (">=", 21),
+        ("??", 13), ("ALL", 195), ("ALTER", 193), ("ALWAYS", 194),
+        ("ANALYZE", 389), ("AND", 27), ("ANTI", 196), ("ANY", 197),
+        ("APPLY", 198), ("ARRAY", 199), ("AS", 192), ("ASC", 200),
+        ("ASOF", 201), ("AUTOINCREMENT", 203), ("AUTO_INCREMENT", 203), ("BEGIN", 204),
+        ("BETWEEN", 205), ("BIGDECIMAL", 103), ("BIGINT", 88), ("BIGNUMERIC", 103),
+        ("BINARY", 118), ("BIT", 78), ("BLOB", 119), ("BOOL", 79),
+        ("BOOLEAN", 79), ("BPCHAR", 108), ("BYTE", 80), ("BYTEA", 119),
+        ("CACHE", 207), ("CALL", 212), ("CASE", 208), ("CHAR", 104),
+        ("CHAR VARYING", 106), ("CHARACTER", 104), ("CHARACTER SET", 209), ("CHARACTER VARYING", 106),
+        ("CLOB", 109), ("CLUSTER BY", 210), ("CLUSTERED INDEX", 269), ("COLLATE", 211),
+        ("COLUMN", 63), ("COMMENT", 213), ("COMMIT", 214), ("CONNECT BY", 215),
+        ("CONSTRAINT", 216), ("COPY", 217), ("CREATE", 218), ("CROSS", 219),
+        ("CUBE", 220), ("CURRENT_DATE", 221), ("CURRENT_SCHEMA", 223), ("CURRENT_TIME", 224),
+        ("CURRENT_TIMESTAMP", 225), ("CURRENT_USER", 226), ("DATABASE", 62), ("DATE", 135),
+        ("DATEMULTIRANGE", 148), ("DATERANGE", 147), ("DATETIME", 131), ("DATETIME2", 132),
+        ("DATETIMEOFFSET", 125), ("DEC", 97), ("DECIMAL", 97), ("DECIMAL128", 100),
+        ("DECIMAL256", 101), ("DECIMAL32", 98), ("DECIMAL64", 99), ("DECLARE", 227),
+        ("DEFAULT", 228), ("DELETE", 229), ("DESC", 230), ("DESCRIBE", 231),
+        ("DISTINCT", 234), ("DISTRIBUTE BY", 235), ("DIV", 236), ("DOUBLE", 95),
+        ("DOUBLE PRECISION", 95), ("DROP", 237), ("ELSE", 238), ("END", 239),
+        ("ENUM", 179), ("ESCAPE", 240), ("EXCEPT", 241), ("EXEC", 212),
+        ("EXECUTE", 242), ("EXISTS", 243), ("EXPLAIN", 212), ("FALSE", 244),
+        ("FETCH", 245), ("FILTER", 247), ("FIRST", 249), ("FIXED", 97),
+        ("FLOAT", 94), ("FLOAT4", 94), ("FLOAT8", 95), ("FOR", 250),
+        ("FOR SYSTEM_TIME", 385), ("FOR TIMESTAMP", 385), ("FOR VERSION", 384), ("FOREIGN KEY", 252),
+        ("FORMAT", 253), ("FROM", 254), ("FULL", 255), ("FUNCTION", 256),
+        ("GEOGRAPHY", 150), ("GEOMETRY", 152), ("GLOB", 258), ("GO", 212),
+        ("GRANT", 260), ("GROUP BY", 261), ("GROUPING SETS", 262), ("HAVING", 263),
+        ("HUGEINT", 90), ("ILIKE", 266), ("IMAGE", 171), ("IN", 268),
+        ("INDEX", 269), ("INET", 174), ("INNER", 270), ("INSERT", 271),
+        ("INT", 86), ("INT1", 80), ("INT128", 90), ("INT16", 82),
+        ("INT2", 82), ("INT256", 92), ("INT32", 86), ("INT4", 86),
+        ("INT4MULTIRANGE", 138), ("INT4RANGE", 137), ("INT64", 88), ("INT8", 80),
+        ("INT8MULTIRANGE", 140), ("INT8RANGE", 139), ("INTEGER", 86), ("INTERSECT", 272),
+        ("INTERVAL", 273), ("INTO", 274), ("IS", 277), ("ISNULL", 278),
+        ("JOIN", 279), ("JSON", 120), ("JSONB", 121), ("KEEP", 281),
+        ("KILL", 283), ("LATERAL", 285), ("LEFT", 286), ("LIKE", 287),
+        ("LIMIT", 289), ("LIST", 290), ("LOAD", 291), ("LOCK", 292),
+        ("LONG", 88), ("LONGBLOB", 114), ("LONGTEXT", 111), ("LONGVARCHAR", 109),
+        ("MAP", 293), ("MEDIUMBLOB", 113), ("MEDIUMINT", 84), ("MEDIUMTEXT", 110),
+        ("MERGE", 297), ("MONEY", 168), ("NAMESPACE", 390), ("NATURAL", 300),
+        ("NCHAR", 105), ("NEXT", 301), ("NONCLUSTERED INDEX", 269), ("NOT", 22),
+        ("NOTNULL", 303), ("NTEXT", 109), ("NULL", 304), ("NULLABLE", 151),
+        ("NUMBER", 97), ("NUMERIC", 97), ("NUMMULTIRANGE", 142), ("NUMRANGE", 141),
+        ("NVARCHAR", 107), ("NVARCHAR2", 107), ("OBJECT", 173), ("OFFSET", 306),
+        ("ON", 307), ("OPTIMIZE", 212), ("OPTION", 386), ("OR", 28),
+        ("ORDER BY", 310), ("ORDINALITY", 313), ("OUTER", 314), ("OUTPUT", 338),
+        ("OVER", 315), ("OVERLAPS", 316), ("OVERWRITE", 317), ("PARTITION", 318),
+        ("PARTITION BY", 319), ("PARTITIONED BY", 319), ("PARTITIONED_BY", 319), ("PERCENT", 320),
+        ("PIVOT", 321), ("PRAGMA", 324), ("PREPARE", 212), ("PRIMARY KEY", 326),
+        ("PRINT", 212), ("PROC", 327), ("PROCEDURE", 327), ("QUALIFY", 331),
+        ("RANGE", 333), ("REAL", 94), ("RECURSIVE", 334), ("REFERENCES", 339),
+        ("REGEXP", 341), ("RENAME", 336), ("REPLACE", 337), ("RETURNING", 338),
+        ("RIGHT", 340), ("RLIKE", 341), ("ROLLBACK", 342), ("ROLLUP", 343),
+        ("ROW", 344), ("ROWS", 345), ("ROWVERSION", 170), ("SCHEMA", 65),
+        ("SELECT", 346), ("SEMI", 347), ("SEQUENCE", 349), ("SET", 351),
+        ("SETTINGS", 352), ("SHORT", 82), ("SHOW", 353), ("SIMILAR TO", 354),
+        ("SMALLDATETIME", 134), ("SMALLINT", 82), ("SMALLMONEY", 169), ("SOME", 355),
+        ("SORT BY", 356), ("SQL_VARIANT", 172), ("START WITH", 357), ("STR", 109),
+        ("STRAIGHT_JOIN", 359), ("STRING", 109), ("STRUCT", 360), ("SYSTEM_USER", 226),
+        ("TABLE", 66), ("TABLESAMPLE", 362), ("TEMP", 364), ("TEMPORARY", 364),
+        ("TEXT", 109), ("THEN", 366), ("TIME", 122), ("TIMESTAMP", 170),
+        ("TIMESTAMPLTZ", 126), ("TIMESTAMPNTZ", 127), ("TIMESTAMPTZ", 125), ("TIMESTAMP_LTZ", 126),
+        ("TIMESTAMP_NTZ", 127), ("TIMETZ", 123), ("TINYBLOB", 115), ("TINYINT", 81),
+        ("TINYTEXT", 116), ("TOP", 365), ("TRUE", 367), ("TRUNCATE", 368),
+        ("TSMULTIRANGE", 144), ("TSRANGE", 143), ("TSTZMULTIRANGE", 146), ("TSTZRANGE", 145),
+        ("UHUGEINT", 91), ("UINT", 87), ("UINT128", 91), ("UINT256", 93),
+        ("UNCACHE", 369), ("UNION", 370), ("UNIQUE", 383), ("UNIQUEIDENTIFIER", 149),
+        ("UNKNOWN", 188), ("UNNEST", 371), ("UNPIVOT", 372), ("UPDATE", 373),
+        ("UPDATE STATISTICS", 212), ("USE", 374), ("USER-DEFINED", 167), ("USING", 375),
+        ("UUID", 149), ("VACUUM", 212), ("VALUES", 376), ("VARBINARY", 119),
+        ("VARCHAR", 106), ("VARCHAR2", 106), ("VARIANT", 172), ("VECTOR", 189),
+        ("VIEW", 377), ("VOLATILE", 378), ("WHEN", 379), ("WHERE", 380),
+        ("WINDOW", 381), ("WITH", 382), ("XML", 165), ("XOR", 52),
+        ("{%", 55), ("{%+", 55), ("{%-", 55), ("{{+", 55),
+        ("{{-", 55), ("|>", 31), ("||", 30), ("~*", 276),
+        ("~~", 287), ("~~*", 266), ("~~~", 258),
+    ],
+    numeric_literals: &[],
+    identifiers: &[('"', '"'), ('[', ']')],
+    identifier_escapes: &[],
+    string_escapes: &['\''],
+    quotes: &[("\"", "\""), ("'", "'")],
+    format_strings: &[
+        ("0X", "", 72), ("0x", "", 72), ("N\"", "\"", 74), ("N'", "'", 74),
+        ("n\"", "\"", 74), ("n'", "'", 74),
+    ],
+    verbatim_strings: &[],
+    has_bit_strings: false,
+    has_hex_strings: true,
+    comments: &[("--", None), ("/*", Some("*/")), ("{#", Some("#}"))],
+    var_single_tokens: &['#', '$', '@'],
+    placeholder_styles: &[],
+    commands: &[212, 239, 242, 245, 336, 353],
+    command_prefix_tokens: &[14, 204],
+    tokens_preceding_hint: &[229, 271, 346, 373],
+    heredoc_tag_is_identifier: false,
+    string_escapes_allowed_in_raw_strings: true,
+    nested_comments: true,
+    hint_start: "/*+",
+};
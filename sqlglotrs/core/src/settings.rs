@@ -0,0 +1,212 @@
+use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::FxHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+pub type TokenType = u16;
+
+/// Token type ids below this value are reserved for the builtin members of sqlglot's `TokenType`
+/// enum. The ids from here up to `TokenType::MAX` are never assigned to a builtin member and can
+/// be used for custom token types.
+pub const CUSTOM_TOKEN_TYPE_START: TokenType = 1 << 15;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenTypeSettings {
+    pub bit_string: TokenType,
+    pub break_: TokenType,
+    pub dcolon: TokenType,
+    pub heredoc_string: TokenType,
+    pub raw_string: TokenType,
+    pub hex_string: TokenType,
+    pub identifier: TokenType,
+    pub number: TokenType,
+    pub parameter: TokenType,
+    pub semicolon: TokenType,
+    pub string: TokenType,
+    pub var: TokenType,
+    pub heredoc_string_alternative: TokenType,
+    pub hint: TokenType,
+    pub error: TokenType,
+    pub comment: TokenType,
+    pub placeholder: TokenType,
+    pub hint_end: TokenType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+// Tables are reference counted so that settings derived from one another, e.g. via `overlay`,
+// share every table they don't modify.
+pub struct TokenizerSettings {
+    pub white_space: Arc<HashMap<char, TokenType>>,
+    pub single_tokens: Arc<HashMap<char, TokenType>>,
+    pub keywords: Arc<HashMap<String, TokenType>>,
+    pub numeric_literals: Arc<HashMap<String, String>>,
+    pub identifiers: Arc<HashMap<char, char>>,
+    pub identifier_escapes: Arc<HashSet<char>>,
+    pub string_escapes: Arc<HashSet<char>>,
+    pub quotes: Arc<HashMap<String, String>>,
+    pub format_strings: Arc<HashMap<String, (String, TokenType)>>,
+    /// The starts of the format strings whose contents are taken verbatim, e.g. BigQuery's `r'`
+    pub verbatim_strings: Arc<HashSet<String>>,
+    pub has_bit_strings: bool,
+    pub has_hex_strings: bool,
+    pub comments: Arc<HashMap<String, Option<String>>>,
+    pub var_single_tokens: Arc<HashSet<char>>,
+    /// The names of the placeholder styles scanned as single placeholder tokens, e.g. `pyformat`
+    /// for `%(name)s`
+    pub placeholder_styles: Arc<HashSet<String>>,
+    pub commands: Arc<HashSet<TokenType>>,
+    pub command_prefix_tokens: Arc<HashSet<TokenType>>,
+    pub tokens_preceding_hint: Arc<HashSet<TokenType>>,
+    pub heredoc_tag_is_identifier: bool,
+    pub string_escapes_allowed_in_raw_strings: bool,
+    pub nested_comments: bool,
+    pub hint_start: String,
+}
+
+impl TokenizerSettings {
+    /// A hash of the settings that doesn't depend on the order their tables were filled in, so
+    /// that equal settings always hash the same.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = FxHasher::default();
+        unordered_hash(self.white_space.iter()).hash(&mut hasher);
+        unordered_hash(self.single_tokens.iter()).hash(&mut hasher);
+        unordered_hash(self.keywords.iter()).hash(&mut hasher);
+        unordered_hash(self.numeric_literals.iter()).hash(&mut hasher);
+        unordered_hash(self.identifiers.iter()).hash(&mut hasher);
+        unordered_hash(self.identifier_escapes.iter()).hash(&mut hasher);
+        unordered_hash(self.string_escapes.iter()).hash(&mut hasher);
+        unordered_hash(self.quotes.iter()).hash(&mut hasher);
+        unordered_hash(self.format_strings.iter()).hash(&mut hasher);
+        unordered_hash(self.verbatim_strings.iter()).hash(&mut hasher);
+        self.has_bit_strings.hash(&mut hasher);
+        self.has_hex_strings.hash(&mut hasher);
+        unordered_hash(self.comments.iter()).hash(&mut hasher);
+        unordered_hash(self.var_single_tokens.iter()).hash(&mut hasher);
+        unordered_hash(self.placeholder_styles.iter()).hash(&mut hasher);
+        unordered_hash(self.commands.iter()).hash(&mut hasher);
+        unordered_hash(self.command_prefix_tokens.iter()).hash(&mut hasher);
+        unordered_hash(self.tokens_preceding_hint.iter()).hash(&mut hasher);
+        self.heredoc_tag_is_identifier.hash(&mut hasher);
+        self.string_escapes_allowed_in_raw_strings.hash(&mut hasher);
+        self.nested_comments.hash(&mut hasher);
+        self.hint_start.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+// Sums the hashes of the entries, which is commutative, along with their count
+fn unordered_hash<T: Hash>(entries: impl ExactSizeIterator<Item = T>) -> (usize, u64) {
+    let count = entries.len();
+    let sum = entries
+        .map(|entry| {
+            let mut hasher = FxHasher::default();
+            entry.hash(&mut hasher);
+            hasher.finish()
+        })
+        .fold(0, u64::wrapping_add);
+    (count, sum)
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TokenizerDialectSettings {
+    pub unescaped_sequences: HashMap<String, String>,
+    pub identifiers_can_start_with_digit: bool,
+    pub numbers_can_be_underscore_separated: bool,
+    /// Whether unquoted identifiers follow Unicode's XID rules past ASCII: a non-ASCII char that
+    /// can't continue an identifier, e.g. a symbol or full-width punctuation, ends the identifier
+    /// before it and is scanned as a token of its own, rather than as part of it.
+    pub unicode_identifiers: bool,
+    /// The server version that MySQL's versioned comments, e.g. `/*!40101 ... */`, are scanned
+    /// for, encoded as sqlglot's `Version` is, i.e. 4.1.1 as 4001001. The contents of those for
+    /// this version or an earlier one, and of those without a version, are scanned as SQL, while
+    /// the others are kept as comments, as all of them are if this isn't set.
+    pub versioned_comments_version: Option<u64>,
+    pub control_characters: ControlCharacters,
+    pub nul_bytes: NulBytes,
+    /// The most bytes a query can have, past which it's rejected before anything is scanned.
+    pub max_input_bytes: Option<usize>,
+    /// The most tokens a scan can produce, which fails at the token past them.
+    pub max_tokens: Option<usize>,
+    /// Whether comments are emitted as tokens of their own, of the `comment` token type, where
+    /// they were written rather than attached to the tokens around them. Hints are still hint
+    /// tokens and the other tokens are scanned as they would be without this.
+    pub comment_tokens: bool,
+    /// Whether the bodies of hints are scanned as tokens between their hint token, which only
+    /// spans the hint's start, and a `hint_end` one, rather than being kept as its comment.
+    pub hint_tokens: bool,
+    /// The units that columns are counted in, e.g. UTF-16 code units as LSP and Monaco count
+    /// them, while lines and offsets are always counted in lines and chars.
+    pub position_encoding: PositionEncoding,
+}
+
+/// The units of the columns of tokens and errors, i.e. what a char on the line counts for.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PositionEncoding {
+    /// UTF-8 bytes, so that a column is a byte offset into the line
+    Utf8,
+    /// UTF-16 code units, so that a char outside the basic multilingual plane, e.g. an emoji,
+    /// counts twice
+    Utf16,
+    /// Chars, i.e. Unicode code points, which is how sqlglot counts them
+    #[default]
+    Utf32,
+}
+
+impl PositionEncoding {
+    /// The number of units that `c` counts for.
+    pub fn width(self, c: char) -> usize {
+        match self {
+            PositionEncoding::Utf8 => c.len_utf8(),
+            PositionEncoding::Utf16 => c.len_utf16(),
+            PositionEncoding::Utf32 => 1,
+        }
+    }
+}
+
+/// What the scanner does with control chars and invisible formatting ones, e.g. zero-width
+/// spaces, where a token could start. Those in strings, quoted identifiers and comments are
+/// always kept, as is the byte order mark a query can start with, which is always skipped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum ControlCharacters {
+    /// Scan them as any other char, so that they end up in vars, e.g. `a\u{200b}b`
+    #[default]
+    Keep,
+    /// Skip them as white space, which also ends the var before them
+    Skip,
+    /// Fail the scan at them
+    Error,
+}
+
+/// What the scanner does with NUL chars, e.g. the padding left behind by binary-safe dumps.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum NulBytes {
+    /// Scan them as any other char, i.e. as control chars where a token could start and as part
+    /// of the text of the tokens they're in otherwise
+    #[default]
+    Text,
+    /// Skip them as white space where a token could start and leave them out of the texts of
+    /// strings, quoted identifiers and comments, so that no token has one while token positions
+    /// are still those of the query
+    Strip,
+    /// Reject the query before anything is scanned, at the first one
+    Error,
+}
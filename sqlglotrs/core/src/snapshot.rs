@@ -0,0 +1,323 @@
+//! A compact binary form of a tokenizer definition, i.e. its settings along with the keyword
+//! automaton built from them, see `TokenizerConfig::to_snapshot`. Loading one skips building the
+//! automaton from thousands of keywords, which short-lived workers would otherwise do on every
+//! start, so snapshots can be embedded in a program or shipped next to it.
+//!
+//! All numbers are little-endian and all lengths are `u32`s. Tables are written sorted by key, so
+//! equal definitions always give the same bytes.
+
+use crate::dafsa::Dafsa;
+use crate::settings::{TokenType, TokenTypeSettings, TokenizerSettings};
+use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::FxHashSet as HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::Arc;
+
+const MAGIC: &[u8; 4] = b"SGRS";
+// Bumped whenever the layout changes, as snapshots of another layout can't be read
+const VERSION: u16 = 5;
+
+// The scanner features the automaton depends on, since it only has the format strings they enable
+fn features() -> u8 {
+    u8::from(cfg!(feature = "format-strings")) | u8::from(cfg!(feature = "heredoc-strings")) << 1
+}
+
+/// Why a snapshot couldn't be loaded.
+#[derive(Debug)]
+pub struct SnapshotError(String);
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid tokenizer snapshot: {}", self.0)
+    }
+}
+
+impl SnapshotError {
+    pub(crate) fn new(message: impl Into<String>) -> SnapshotError {
+        SnapshotError(message.into())
+    }
+}
+
+pub(crate) fn write(
+    settings: &TokenizerSettings,
+    token_types: &TokenTypeSettings,
+    keyword_automaton: &Dafsa,
+) -> Vec<u8> {
+    let mut w = Writer::default();
+    w.bytes.extend_from_slice(MAGIC);
+    w.u16(&VERSION);
+    w.u8(features());
+
+    w.map(&settings.white_space, Writer::char, Writer::u16);
+    w.map(&settings.single_tokens, Writer::char, Writer::u16);
+    w.map(&settings.keywords, Writer::str, Writer::u16);
+    w.map(&settings.numeric_literals, Writer::str, Writer::str);
+    w.map(&settings.identifiers, Writer::char, Writer::char);
+    w.set(&settings.identifier_escapes, Writer::char);
+    w.set(&settings.string_escapes, Writer::char);
+    w.map(&settings.quotes, Writer::str, Writer::str);
+    w.map(
+        &settings.format_strings,
+        Writer::str,
+        |w, (end, token_type)| {
+            w.str(end);
+            w.u16(token_type);
+        },
+    );
+    w.set(&settings.verbatim_strings, Writer::str);
+    w.bool(&settings.has_bit_strings);
+    w.bool(&settings.has_hex_strings);
+    w.map(&settings.comments, Writer::str, |w, end| match end {
+        Some(end) => {
+            w.bool(&true);
+            w.str(end);
+        }
+        None => w.bool(&false),
+    });
+    w.set(&settings.var_single_tokens, Writer::char);
+    w.set(&settings.placeholder_styles, Writer::str);
+    w.set(&settings.commands, Writer::u16);
+    w.set(&settings.command_prefix_tokens, Writer::u16);
+    w.set(&settings.tokens_preceding_hint, Writer::u16);
+    w.bool(&settings.heredoc_tag_is_identifier);
+    w.bool(&settings.string_escapes_allowed_in_raw_strings);
+    w.bool(&settings.nested_comments);
+    w.str(&settings.hint_start);
+
+    for token_type in [
+        token_types.bit_string,
+        token_types.break_,
+        token_types.dcolon,
+        token_types.heredoc_string,
+        token_types.raw_string,
+        token_types.hex_string,
+        token_types.identifier,
+        token_types.number,
+        token_types.parameter,
+        token_types.semicolon,
+        token_types.string,
+        token_types.var,
+        token_types.heredoc_string_alternative,
+        token_types.hint,
+        token_types.error,
+        token_types.comment,
+        token_types.placeholder,
+        token_types.hint_end,
+    ] {
+        w.u16(&token_type);
+    }
+
+    keyword_automaton.write(&mut w);
+    w.bytes
+}
+
+pub(crate) fn read(
+    bytes: &[u8],
+) -> Result<(TokenizerSettings, TokenTypeSettings, Dafsa), SnapshotError> {
+    let mut r = Reader { bytes, position: 0 };
+    if r.take(MAGIC.len())? != MAGIC {
+        return Err(SnapshotError::new("not a tokenizer snapshot"));
+    }
+    let version = r.u16()?;
+    if version != VERSION {
+        return Err(SnapshotError::new(format!(
+            "version {version} isn't supported, only {VERSION} is"
+        )));
+    }
+    if r.u8()? != features() {
+        return Err(SnapshotError::new(
+            "it was written by a build with other scanner features",
+        ));
+    }
+
+    let settings = TokenizerSettings {
+        white_space: Arc::new(r.map(Reader::char, Reader::u16)?),
+        single_tokens: Arc::new(r.map(Reader::char, Reader::u16)?),
+        keywords: Arc::new(r.map(Reader::string, Reader::u16)?),
+        numeric_literals: Arc::new(r.map(Reader::string, Reader::string)?),
+        identifiers: Arc::new(r.map(Reader::char, Reader::char)?),
+        identifier_escapes: Arc::new(r.set(Reader::char)?),
+        string_escapes: Arc::new(r.set(Reader::char)?),
+        quotes: Arc::new(r.map(Reader::string, Reader::string)?),
+        format_strings: Arc::new(r.map(Reader::string, |r| Ok((r.string()?, r.u16()?)))?),
+        verbatim_strings: Arc::new(r.set(Reader::string)?),
+        has_bit_strings: r.bool()?,
+        has_hex_strings: r.bool()?,
+        comments: Arc::new(r.map(Reader::string, |r| {
+            Ok(if r.bool()? { Some(r.string()?) } else { None })
+        })?),
+        var_single_tokens: Arc::new(r.set(Reader::char)?),
+        placeholder_styles: Arc::new(r.set(Reader::string)?),
+        commands: Arc::new(r.set(Reader::u16)?),
+        command_prefix_tokens: Arc::new(r.set(Reader::u16)?),
+        tokens_preceding_hint: Arc::new(r.set(Reader::u16)?),
+        heredoc_tag_is_identifier: r.bool()?,
+        string_escapes_allowed_in_raw_strings: r.bool()?,
+        nested_comments: r.bool()?,
+        hint_start: r.string()?,
+    };
+
+    let token_types = TokenTypeSettings {
+        bit_string: r.u16()?,
+        break_: r.u16()?,
+        dcolon: r.u16()?,
+        heredoc_string: r.u16()?,
+        raw_string: r.u16()?,
+        hex_string: r.u16()?,
+        identifier: r.u16()?,
+        number: r.u16()?,
+        parameter: r.u16()?,
+        semicolon: r.u16()?,
+        string: r.u16()?,
+        var: r.u16()?,
+        heredoc_string_alternative: r.u16()?,
+        hint: r.u16()?,
+        error: r.u16()?,
+        comment: r.u16()?,
+        placeholder: r.u16()?,
+        hint_end: r.u16()?,
+    };
+
+    let keyword_automaton = Dafsa::read(&mut r)?;
+    if r.position != bytes.len() {
+        return Err(SnapshotError::new("it has trailing bytes"));
+    }
+    Ok((settings, token_types, keyword_automaton))
+}
+
+#[derive(Default)]
+pub(crate) struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    pub(crate) fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    pub(crate) fn bool(&mut self, value: &bool) {
+        self.u8(u8::from(*value));
+    }
+
+    pub(crate) fn u16(&mut self, value: &TokenType) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn len(&mut self, len: usize) {
+        self.u32(u32::try_from(len).expect("tables have fewer than 2^32 entries"));
+    }
+
+    pub(crate) fn char(&mut self, value: &char) {
+        self.u32(*value as u32);
+    }
+
+    fn str(&mut self, value: &String) {
+        self.len(value.len());
+        self.bytes.extend_from_slice(value.as_bytes());
+    }
+
+    fn map<K: Ord, V>(
+        &mut self,
+        map: &HashMap<K, V>,
+        key: impl Fn(&mut Self, &K),
+        value: impl Fn(&mut Self, &V),
+    ) {
+        let mut entries: Vec<_> = map.iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        self.len(entries.len());
+        for (k, v) in entries {
+            key(self, k);
+            value(self, v);
+        }
+    }
+
+    fn set<T: Ord>(&mut self, set: &HashSet<T>, item: impl Fn(&mut Self, &T)) {
+        let mut items: Vec<_> = set.iter().collect();
+        items.sort_unstable();
+        self.len(items.len());
+        for i in items {
+            item(self, i);
+        }
+    }
+}
+
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, size: usize) -> Result<&[u8], SnapshotError> {
+        let end = self
+            .position
+            .checked_add(size)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| SnapshotError::new("it ends unexpectedly"))?;
+        let taken = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(taken)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, SnapshotError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn bool(&mut self) -> Result<bool, SnapshotError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            other => Err(SnapshotError::new(format!("{other} isn't a boolean"))),
+        }
+    }
+
+    fn u16(&mut self) -> Result<TokenType, SnapshotError> {
+        Ok(u16::from_le_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, SnapshotError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    /// Reads a length, which can't be more than the bytes left, so that a corrupted one never
+    /// makes the reader allocate much more than the snapshot's size.
+    pub(crate) fn len(&mut self) -> Result<usize, SnapshotError> {
+        let len = self.u32()? as usize;
+        if len > self.bytes.len() - self.position {
+            return Err(SnapshotError::new("it ends unexpectedly"));
+        }
+        Ok(len)
+    }
+
+    pub(crate) fn char(&mut self) -> Result<char, SnapshotError> {
+        let code = self.u32()?;
+        char::from_u32(code).ok_or_else(|| SnapshotError::new(format!("{code} isn't a char")))
+    }
+
+    fn string(&mut self) -> Result<String, SnapshotError> {
+        let len = self.len()?;
+        String::from_utf8(self.take(len)?.to_vec())
+            .map_err(|_| SnapshotError::new("it has a string that isn't UTF-8"))
+    }
+
+    fn map<K: Eq + Hash, V>(
+        &mut self,
+        key: impl Fn(&mut Self) -> Result<K, SnapshotError>,
+        value: impl Fn(&mut Self) -> Result<V, SnapshotError>,
+    ) -> Result<HashMap<K, V>, SnapshotError> {
+        (0..self.len()?)
+            .map(|_| Ok((key(self)?, value(self)?)))
+            .collect()
+    }
+
+    fn set<T: Eq + Hash>(
+        &mut self,
+        item: impl Fn(&mut Self) -> Result<T, SnapshotError>,
+    ) -> Result<HashSet<T>, SnapshotError> {
+        (0..self.len()?).map(|_| item(self)).collect()
+    }
+}
@@ -0,0 +1,81 @@
+use crate::token::RawToken;
+use std::ops::Range;
+
+/// A replacement for a half-open range of chars. Empty ranges insert text.
+pub type Edit = (Range<usize>, String);
+
+/// Maps the char offsets stored on tokens back to the SQL they were scanned from, so that
+/// helpers rewriting a query can splice replacements into the original text.
+pub struct Source<'a> {
+    sql: &'a str,
+    // Byte offset of every char, plus one past the end
+    offsets: Vec<usize>,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(sql: &'a str) -> Source<'a> {
+        let mut offsets: Vec<usize> = sql.char_indices().map(|(i, _)| i).collect();
+        offsets.push(sql.len());
+        Source { sql, offsets }
+    }
+
+    /// The text a token was scanned from, including any quotes or delimiters.
+    pub fn slice(&self, token: &RawToken) -> &'a str {
+        &self.sql[self.offsets[token.start]..self.offsets[token.end + 1]]
+    }
+
+    /// The text spanned by a half-open range of chars.
+    pub fn text(&self, chars: Range<usize>) -> &'a str {
+        &self.sql[self.offsets[chars.start]..self.offsets[chars.end]]
+    }
+
+    /// The number of chars in the query.
+    pub fn char_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// The byte offset at which the char at the given offset starts, or the length of the query
+    /// for the offset one past its end.
+    pub fn byte_offset(&self, char: usize) -> usize {
+        self.offsets[char]
+    }
+
+    /// Whether the token's text is exactly what appears in the query. This is false for strings,
+    /// quoted identifiers and the like, which tells them apart from bare punctuation and words.
+    pub fn is_verbatim(&self, token: &RawToken) -> bool {
+        self.slice(token) == &*token.text
+    }
+
+    /// Whether the token's text is what appears in the query up to whitespace, which also holds
+    /// for keywords made of several words such as `GROUP  BY`, whose text is single-spaced.
+    pub fn is_verbatim_words(&self, token: &RawToken) -> bool {
+        self.slice(token)
+            .split_whitespace()
+            .eq(token.text.split(' '))
+    }
+
+    /// Makes the given edits, which must not overlap.
+    pub fn splice(&self, mut edits: Vec<Edit>) -> String {
+        edits.sort_by_key(|(range, _)| range.start);
+
+        let mut out = String::with_capacity(self.sql.len());
+        let mut position = 0;
+        for (range, replacement) in edits {
+            out.push_str(&self.sql[position..self.offsets[range.start]]);
+            out.push_str(&replacement);
+            position = self.offsets[range.end];
+        }
+        out.push_str(&self.sql[position..]);
+        out
+    }
+}
+
+/// The chars a token was scanned from, as a half-open range.
+pub fn span(token: &RawToken) -> Range<usize> {
+    token.start..token.end + 1
+}
+
+/// Whether `b` starts right where `a` ends, with no whitespace or comments in between.
+pub fn adjacent(a: &RawToken, b: &RawToken) -> bool {
+    a.end + 1 == b.start
+}
@@ -0,0 +1,52 @@
+use crate::words::Words;
+use std::ops::Range;
+
+/// Splits a token stream into the token ranges of its non-empty statements. Semicolons only end
+/// a statement outside of parentheses and procedural `BEGIN ... END` blocks.
+pub fn statements(words: &Words) -> Vec<Range<usize>> {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut depth = 0;
+    // BEGIN and CASE blocks that are still open, both being closed by END
+    let mut blocks = 0;
+
+    for i in 0..=words.len() {
+        if i == words.len() || (words.is(i, ";") && depth == 0 && blocks == 0) {
+            if start < i {
+                statements.push(start..i);
+            }
+            start = i + 1;
+        } else if words.is(i, "(") {
+            depth += 1;
+        } else if words.is(i, ")") {
+            depth -= 1;
+        } else if words.is(i, "CASE") || (words.is(i, "BEGIN") && opens_block(words, i, start)) {
+            blocks += 1;
+        } else if words.is(i, "END") && blocks > 0 && !words.is_any(i + 1, &END_LOOPS) {
+            blocks -= 1;
+        }
+    }
+    statements
+}
+
+// Procedural constructs whose END isn't matched by a BEGIN or CASE, e.g. `END IF`
+const END_LOOPS: [&str; 5] = ["IF", "LOOP", "WHILE", "REPEAT", "FOR"];
+// What may follow a BEGIN that starts a transaction rather than a block
+const BEGIN_TRANSACTION: [&str; 9] = [
+    "TRANSACTION",
+    "TRAN",
+    "WORK",
+    "ISOLATION",
+    "READ",
+    "DEFERRED",
+    "IMMEDIATE",
+    "EXCLUSIVE",
+    "DISTRIBUTED",
+];
+
+fn opens_block(words: &Words, i: usize, statement_start: usize) -> bool {
+    i > statement_start
+        || !(i + 1 == words.len()
+            || words.is(i + 1, ";")
+            || words.is_any(i + 1, &BEGIN_TRANSACTION))
+}
@@ -0,0 +1,134 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+// Durations are bucketed by powers of two of nanoseconds, the last bucket taking everything from
+// about 9 minutes up
+const BUCKETS: usize = 40;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static QUERIES: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static NANOS: AtomicU64 = AtomicU64::new(0);
+static DURATIONS: [AtomicU64; BUCKETS] = [const { AtomicU64::new(0) }; BUCKETS];
+// Errors are rare enough that a lock is cheaper than giving every kind a counter of its own
+static ERRORS: Mutex<Vec<(&'static str, u64)>> = Mutex::new(Vec::new());
+
+/// The counts recorded since stats were enabled or last reset.
+#[derive(Clone, Debug, Default)]
+pub struct Stats {
+    /// The number of queries tokenized
+    pub queries: u64,
+    /// The number of bytes scanned
+    pub bytes: u64,
+    /// The number of failed scans by error kind
+    pub errors: Vec<(&'static str, u64)>,
+    /// The time spent scanning
+    pub total: Duration,
+    /// The estimated duration below which 95% of the scans finished
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn mean(&self) -> Duration {
+        if self.queries == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.queries as f64)
+        }
+    }
+}
+
+/// The counts of a single scan, see `TokenizerConfig::tokenize_with_stats`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ScanStats {
+    /// The time the scan took
+    pub elapsed: Duration,
+    /// The number of bytes scanned
+    pub bytes: usize,
+    /// The number of tokens produced
+    pub tokens: usize,
+    /// The number of times the keyword automaton, probed at the start of a token, matched a
+    /// keyword or a quote or comment delimiter. It only has the keywords with spaces or
+    /// single-token chars in them, so e.g. `SELECT` is a miss that's then scanned as a var.
+    pub keyword_hits: usize,
+    /// The number of times it didn't, leaving a single-char token or a var
+    pub keyword_misses: usize,
+    /// The number of texts of tokens and comments allocated on their own rather than shared with
+    /// the query or with tokens of the same text
+    pub allocations: usize,
+}
+
+/// Whether scans should be recorded, which is off unless `enable` was called.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Starts counting the queries tokenized anywhere in the process, e.g. to export them as metrics.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stops counting tokenized queries. The counts so far are kept until `reset` is called.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Clears all counts.
+pub fn reset() {
+    QUERIES.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    NANOS.store(0, Ordering::Relaxed);
+    for count in &DURATIONS {
+        count.store(0, Ordering::Relaxed);
+    }
+    ERRORS.lock().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// Returns the counts recorded so far.
+pub fn snapshot() -> Stats {
+    let counts = std::array::from_fn(|i| DURATIONS[i].load(Ordering::Relaxed));
+    Stats {
+        queries: QUERIES.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        errors: ERRORS.lock().unwrap_or_else(|e| e.into_inner()).clone(),
+        total: Duration::from_nanos(NANOS.load(Ordering::Relaxed)),
+        p95: quantile(&counts, 0.95),
+    }
+}
+
+/// Records a scan of `bytes` bytes, along with the kind of error it stopped at, if any.
+pub(crate) fn record(bytes: usize, elapsed: Duration, error: Option<&'static str>) {
+    let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+    QUERIES.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(bytes as u64, Ordering::Relaxed);
+    NANOS.fetch_add(nanos, Ordering::Relaxed);
+    DURATIONS[bucket(nanos)].fetch_add(1, Ordering::Relaxed);
+
+    if let Some(kind) = error {
+        let mut errors = ERRORS.lock().unwrap_or_else(|e| e.into_inner());
+        match errors.iter_mut().find(|(k, _)| *k == kind) {
+            Some((_, count)) => *count += 1,
+            None => errors.push((kind, 1)),
+        }
+    }
+}
+
+fn bucket(nanos: u64) -> usize {
+    (u64::BITS - nanos.leading_zeros()).min(BUCKETS as u32 - 1) as usize
+}
+
+/// Estimates the duration below which the given share of scans finished, as the upper bound of
+/// the bucket that share falls into.
+fn quantile(counts: &[u64; BUCKETS], share: f64) -> Duration {
+    let total: u64 = counts.iter().sum();
+    let target = (total as f64 * share).ceil() as u64;
+    let mut seen = 0;
+    for (i, count) in counts.iter().enumerate() {
+        seen += count;
+        if seen >= target && seen > 0 {
+            return Duration::from_nanos(1u64 << i);
+        }
+    }
+    Duration::ZERO
+}
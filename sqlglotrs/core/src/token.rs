@@ -0,0 +1,201 @@
+use crate::settings::TokenType;
+use rustc_hash::FxHashSet as HashSet;
+use std::borrow::{Borrow, Cow};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Deref, Range};
+use std::sync::Arc;
+
+/// A token as produced by the scanner, before it is converted into a Python `Token`. Texts and
+/// comments are reference counted so that copies of a token, or of a whole token stream, share
+/// them, see `TokenText`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawToken {
+    pub token_type: TokenType,
+    pub text: TokenText,
+    // The line and column of the token's last char, as in sqlglot
+    pub line: usize,
+    pub col: usize,
+    // Those of its first char
+    pub start_line: usize,
+    pub start_col: usize,
+    pub start: usize,
+    pub end: usize,
+    // The same span in bytes of the query's UTF-8, with an exclusive end unlike `end`
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub comments: Vec<Arc<str>>,
+    /// The chars written before a string's opening quote, e.g. the `N` of `N'abc'`, as they were
+    /// cased, which other tokens have none of
+    pub prefix: Option<TokenText>,
+}
+
+impl RawToken {
+    pub fn append_comments(&mut self, comments: &mut Vec<Arc<str>>) {
+        self.comments.append(comments);
+    }
+}
+
+/// The text of a token, which derefs to a `str`. Most tokens are written in the query exactly as
+/// their text reads, so their text is a span of a copy of the query that all of its tokens share,
+/// which also keeps that copy alive. Texts that differ from the query, e.g. the contents of
+/// strings with escapes, are allocated on their own.
+#[derive(Clone)]
+pub struct TokenText {
+    source: Arc<str>,
+    span: Range<usize>,
+}
+
+impl TokenText {
+    /// The text of the given byte span of `source`.
+    pub fn span(source: &Arc<str>, span: Range<usize>) -> TokenText {
+        assert!(source.is_char_boundary(span.start) && source.is_char_boundary(span.end));
+        TokenText {
+            source: source.clone(),
+            span,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source[self.span.clone()]
+    }
+}
+
+impl Deref for TokenText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for TokenText {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for TokenText {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Default for TokenText {
+    fn default() -> TokenText {
+        TokenText::from("")
+    }
+}
+
+impl From<Arc<str>> for TokenText {
+    fn from(text: Arc<str>) -> TokenText {
+        TokenText {
+            span: 0..text.len(),
+            source: text,
+        }
+    }
+}
+
+impl From<&str> for TokenText {
+    fn from(text: &str) -> TokenText {
+        Arc::<str>::from(text).into()
+    }
+}
+
+impl From<String> for TokenText {
+    fn from(text: String) -> TokenText {
+        Arc::<str>::from(text).into()
+    }
+}
+
+impl From<Cow<'_, str>> for TokenText {
+    fn from(text: Cow<'_, str>) -> TokenText {
+        Arc::<str>::from(text).into()
+    }
+}
+
+impl PartialEq for TokenText {
+    fn eq(&self, other: &TokenText) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for TokenText {}
+
+impl PartialEq<str> for TokenText {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TokenText {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl Hash for TokenText {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl fmt::Debug for TokenText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for TokenText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TokenText {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TokenText {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<TokenText, D::Error> {
+        String::deserialize(deserializer).map(TokenText::from)
+    }
+}
+
+/// Texts longer than this many bytes, e.g. most string literals, rarely repeat, so they aren't
+/// worth hashing and are never interned.
+pub const MAX_INTERNED_LEN: usize = 64;
+
+/// Hands out one shared allocation for every occurrence of a text within a scan, for the texts
+/// that aren't spans of the query, e.g. identifiers that are written quoted.
+#[derive(Debug, Default)]
+pub struct Interner {
+    texts: HashSet<Arc<str>>,
+    allocations: usize,
+}
+
+impl Interner {
+    pub fn intern(&mut self, text: &str) -> TokenText {
+        if text.len() > MAX_INTERNED_LEN {
+            self.allocations += 1;
+            return text.into();
+        }
+        if let Some(interned) = self.texts.get(text) {
+            return interned.clone().into();
+        }
+        self.allocations += 1;
+        let interned: Arc<str> = text.into();
+        self.texts.insert(interned.clone());
+        interned.into()
+    }
+
+    /// The number of texts allocated so far, i.e. those that weren't interned yet.
+    pub fn allocations(&self) -> usize {
+        self.allocations
+    }
+}
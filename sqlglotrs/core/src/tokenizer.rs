@@ -0,0 +1,2625 @@
+use crate::dafsa::{Dafsa, Match};
+use crate::settings::{
+    ControlCharacters, NulBytes, PositionEncoding, TokenType, TokenTypeSettings,
+    TokenizerDialectSettings, TokenizerSettings,
+};
+use crate::snapshot::{self, SnapshotError};
+use crate::stats::{self, ScanStats};
+use crate::token::{Interner, RawToken, TokenText};
+use crate::trace::{trace_event, TraceKind, TraceStep};
+use rustc_hash::FxHashSet as HashSet;
+use rustc_hash::FxHasher;
+use std::cell::Cell;
+use std::cmp::{max, min};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Read};
+use std::ops::Range;
+use std::sync::{Arc, Mutex, OnceLock, Weak};
+use std::time::Instant;
+
+/// What made a scan fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenizerErrorKind {
+    /// The scanner ran past the end of the query, which a well-formed definition never leads to
+    OutOfBounds,
+    /// A multi-char token matched the keyword automaton without being a keyword
+    UnexpectedKeyword,
+    /// A hex or bit string contains digits outside of its radix
+    InvalidNumber,
+    /// A string, identifier or comment is missing its closing delimiter
+    Unterminated,
+    /// A control or zero-width char where the dialect settings don't allow one
+    UnexpectedCharacter,
+    /// The query has more bytes than the dialect settings' `max_input_bytes`
+    InputTooLarge,
+    /// The scan got past the dialect settings' `max_tokens`
+    TooManyTokens,
+}
+
+impl TokenizerErrorKind {
+    /// A short name for the kind, e.g. "unterminated", used to group errors in stats.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TokenizerErrorKind::OutOfBounds => "out_of_bounds",
+            TokenizerErrorKind::UnexpectedKeyword => "unexpected_keyword",
+            TokenizerErrorKind::InvalidNumber => "invalid_number",
+            TokenizerErrorKind::Unterminated => "unterminated",
+            TokenizerErrorKind::UnexpectedCharacter => "unexpected_character",
+            TokenizerErrorKind::InputTooLarge => "input_too_large",
+            TokenizerErrorKind::TooManyTokens => "too_many_tokens",
+        }
+    }
+
+    /// Whether the scan failed at one of the dialect settings' limits rather than at the query
+    /// itself, which even lenient scans stop at.
+    pub fn is_limit(self) -> bool {
+        matches!(
+            self,
+            TokenizerErrorKind::InputTooLarge | TokenizerErrorKind::TooManyTokens
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct TokenizerError {
+    pub kind: TokenizerErrorKind,
+    /// The 1-based line and the column the scanner stopped at.
+    pub line: usize,
+    pub column: usize,
+    /// The char offset of the token being scanned.
+    pub start: usize,
+    /// The char the scanner stopped at, if it got to one.
+    pub character: Option<char>,
+    message: String,
+    context: String,
+}
+
+impl TokenizerError {
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Up to 50 chars on either side of where the scan failed.
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+}
+
+impl fmt::Display for TokenizerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error tokenizing '{}': {}", self.context, self.message)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScanMode {
+    Full,
+    KeywordsOnly,
+    Lenient,
+}
+
+/// Everything a scan reads, built together so that a reload can never pair the settings of one
+/// definition with the keyword automaton of another.
+#[derive(Debug)]
+pub struct TokenizerConfig {
+    pub settings: TokenizerSettings,
+    pub token_types: TokenTypeSettings,
+    keyword_automaton: Dafsa,
+    pub keyword_types: HashSet<TokenType>,
+    // Every keyword that reads as a word, which the scan doesn't need, so it's only built for
+    // the first suggestion
+    keyword_dictionary: OnceLock<Dafsa>,
+}
+
+// The configs handed out by `TokenizerConfig::cached` along with the hash of their definition.
+// There are only ever as many as distinct tokenizers in use, few enough to be searched in order.
+static CACHED_CONFIGS: Mutex<Vec<(u64, Weak<TokenizerConfig>)>> = Mutex::new(Vec::new());
+
+/// Whether the scanner recognizes format strings of the given type, which the `format-strings`
+/// and `heredoc-strings` features decide.
+fn is_supported_format_string(token_type: TokenType, token_types: &TokenTypeSettings) -> bool {
+    cfg!(feature = "format-strings")
+        && (cfg!(feature = "heredoc-strings") || token_type != token_types.heredoc_string)
+}
+
+impl TokenizerConfig {
+    pub fn new(settings: TokenizerSettings, token_types: TokenTypeSettings) -> TokenizerConfig {
+        let is_multi_token = |key: &&String| {
+            key.contains(" ") || settings.single_tokens.keys().any(|&t| key.contains(t))
+        };
+
+        let format_strings = settings
+            .format_strings
+            .iter()
+            .filter(|(_, (_, token_type))| is_supported_format_string(*token_type, &token_types))
+            .map(|(start, _)| start);
+        let keyword_automaton = Dafsa::new(
+            settings
+                .keywords
+                .keys()
+                .chain(settings.comments.keys())
+                .chain(settings.quotes.keys())
+                .chain(format_strings)
+                .filter(is_multi_token)
+                .map(String::as_str),
+        );
+
+        TokenizerConfig::from_parts(settings, token_types, keyword_automaton)
+    }
+
+    fn from_parts(
+        settings: TokenizerSettings,
+        token_types: TokenTypeSettings,
+        keyword_automaton: Dafsa,
+    ) -> TokenizerConfig {
+        let keyword_types = settings.keywords.values().copied().collect();
+
+        TokenizerConfig {
+            settings,
+            token_types,
+            keyword_automaton,
+            keyword_types,
+            keyword_dictionary: OnceLock::new(),
+        }
+    }
+
+    /// Serializes the definition along with its keyword automaton, see `snapshot`.
+    pub fn to_snapshot(&self) -> Vec<u8> {
+        snapshot::write(&self.settings, &self.token_types, &self.keyword_automaton)
+    }
+
+    /// Loads a definition serialized by `to_snapshot`, without rebuilding its keyword automaton.
+    /// Snapshots written by another version of the format or a build with other scanner features
+    /// are rejected.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<TokenizerConfig, SnapshotError> {
+        let (settings, token_types, keyword_automaton) = snapshot::read(bytes)?;
+        Ok(TokenizerConfig::from_parts(
+            settings,
+            token_types,
+            keyword_automaton,
+        ))
+    }
+
+    /// Like `new`, but returns the config that an earlier call built from the same definition
+    /// if it's still in use, so that tokenizers with equal settings, e.g. those of dialects that
+    /// inherit them, share a single keyword automaton instead of building one each.
+    pub fn cached(
+        settings: TokenizerSettings,
+        token_types: TokenTypeSettings,
+    ) -> Arc<TokenizerConfig> {
+        let mut hasher = FxHasher::default();
+        settings.content_hash().hash(&mut hasher);
+        token_types.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        // The lock is held while building a config, so that racing callers still share it
+        let mut cached = CACHED_CONFIGS.lock().unwrap_or_else(|e| e.into_inner());
+        cached.retain(|(_, config)| config.strong_count() > 0);
+        let existing = cached
+            .iter()
+            .filter(|&&(cached_hash, _)| cached_hash == hash)
+            .filter_map(|(_, config)| config.upgrade())
+            .find(|config| config.settings == settings && config.token_types == token_types);
+        if let Some(config) = existing {
+            return config;
+        }
+
+        let config = Arc::new(TokenizerConfig::new(settings, token_types));
+        cached.push((hash, Arc::downgrade(&config)));
+        config
+    }
+
+    pub fn tokenize_raw(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<RawToken>, Option<TokenizerError>) {
+        self.scan(sql, dialect_settings, ScanMode::Full)
+    }
+
+    /// Fails before anything is scanned if `sql` has more bytes than `max_input_bytes` or has a
+    /// NUL char that the dialect settings reject, in which case it fails at the first one.
+    fn check_input(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> Result<(), TokenizerError> {
+        check_input_size(sql, dialect_settings)?;
+        if dialect_settings.nul_bytes != NulBytes::Error {
+            return Ok(());
+        }
+        let Some(at) = sql.find('\0') else {
+            return Ok(());
+        };
+
+        // The line and column are counted as the scanner would have advanced to the char, which
+        // counts for a column in any encoding
+        let is_line_break =
+            |c: &char| self.settings.white_space.get(c) == Some(&self.token_types.break_);
+        let before = sql[..at].strip_prefix('\u{feff}').unwrap_or(&sql[..at]);
+        let (mut line, mut column) = (1, 1);
+        let mut chars = before.chars().peekable();
+        while let Some(c) = chars.next() {
+            if !is_line_break(&c) {
+                column += dialect_settings.position_encoding.width(c);
+            } else if !(c == '\r' && chars.peek() == Some(&'\n')) {
+                column = 1;
+                line += 1;
+            }
+        }
+        let start = sql[..at].chars().count();
+        Err(TokenizerError {
+            kind: TokenizerErrorKind::UnexpectedCharacter,
+            line,
+            column,
+            start,
+            character: Some('\0'),
+            message: "Unexpected character U+0000".to_string(),
+            context: sql
+                .chars()
+                .skip(start.saturating_sub(50))
+                .take(100)
+                .collect(),
+        })
+    }
+
+    /// The keywords within `max_distance` edits of `text`, closest first, e.g. `GROUP BY` for
+    /// `GROUP BT`, see `Dafsa::suggest`. Only keywords that start like a word are suggested.
+    pub fn suggest_keyword(&self, text: &str, max_distance: usize) -> Vec<String> {
+        let dictionary = self.keyword_dictionary.get_or_init(|| {
+            Dafsa::new(
+                self.settings
+                    .keywords
+                    .keys()
+                    .filter(|keyword| keyword.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+                    .map(String::as_str),
+            )
+        });
+        dictionary
+            .suggest(text, max_distance)
+            .into_iter()
+            .map(|(keyword, _)| keyword)
+            .collect()
+    }
+
+    /// Like `tokenize_raw`, but whatever can't be scanned becomes an error token and the scan
+    /// resumes right after it, so that it only fails at the dialect settings' limits.
+    pub fn tokenize_lenient_raw(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<RawToken>, Option<TokenizerError>) {
+        let (tokens, error) = self.scan(sql, dialect_settings, ScanMode::Lenient);
+        debug_assert!(
+            error.as_ref().is_none_or(|error| error.kind.is_limit()),
+            "lenient scans only fail at limits"
+        );
+        (tokens, error)
+    }
+
+    pub fn tokenize_keywords_raw(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<RawToken>, Option<TokenizerError>) {
+        let (mut tokens, error) = self.scan(sql, dialect_settings, ScanMode::KeywordsOnly);
+        // Every token other than keywords and semicolons was added without any text
+        tokens.retain(|token| !token.text.is_empty());
+        (tokens, error)
+    }
+
+    /// Rescans `sql` after an edit replaced the chars `edit` of the query that `previous` was
+    /// scanned from with `inserted` chars, reusing the tokens on either side of the edit. The scan
+    /// restarts a few tokens before the edit, since typing can extend a token or merge it into a
+    /// keyword such as `GROUP BY`, and stops once it's back in step with the previous tokens,
+    /// which must come from a scan that didn't fail.
+    pub fn retokenize_raw<P: PreviousTokens + ?Sized>(
+        &self,
+        sql: &str,
+        previous: &P,
+        edit: Range<usize>,
+        inserted: usize,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> Retokenized {
+        if let Err(error) = self.check_input(sql, dialect_settings) {
+            return Retokenized {
+                kept: 0,
+                tokens: Vec::new(),
+                resumed: None,
+                error: Some(error),
+            };
+        }
+
+        let mut state = TokenizerState::new(
+            sql,
+            &self.settings,
+            &self.token_types,
+            dialect_settings,
+            &self.keyword_automaton,
+        );
+        let is_command = |token: &RawToken| self.settings.commands.contains(&token.token_type);
+
+        // The number of tokens that end before the edit without touching it, as typing right
+        // after a token extends it
+        let (mut low, mut high) = (0, previous.len());
+        while low < high {
+            let mid = (low + high) / 2;
+            if previous.span(mid).1 + 1 < edit.start {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        let lookback = self
+            .settings
+            .keywords
+            .keys()
+            .map(|keyword| keyword.split(' ').count())
+            .max()
+            .unwrap_or(1);
+        let mut kept = low.saturating_sub(lookback);
+
+        // Whether a token is within a versioned comment that is scanned as SQL, or within a hint
+        // whose body is, depends on everything before it, as does whether it's past the token
+        // limit, so such queries are always scanned in full, as are those whose comments are
+        // tokens, which the previous tokens can't be resumed from
+        let full_scan = dialect_settings.max_tokens.is_some()
+            || dialect_settings.comment_tokens
+            || (dialect_settings.versioned_comments_version.is_some() && sql.contains("/*!"))
+            || (dialect_settings.hint_tokens && sql.contains(self.settings.hint_start.as_str()));
+        if full_scan {
+            kept = 0;
+        }
+
+        // Restarting after a token is only safe where nothing but whitespace follows it, as
+        // comments may be attached to it, and outside of commands, whose rest is a single token
+        while kept > 0 {
+            let last = previous.get(kept - 1);
+            if !is_command(&last)
+                && (kept == 1 || !is_command(&previous.get(kept - 2)))
+                && state.is_blank(last.end + 1..previous.span(kept).0)
+            {
+                break;
+            }
+            kept -= 1;
+        }
+
+        // Comments and commands depend on the two tokens before, so the scan resumes with those
+        if kept > 0 {
+            let last = previous.get(kept - 1);
+            state.current = last.end + 1;
+            state.line = last.line;
+            state.column = last.col;
+            state.previous_token_line = Some(last.line);
+            state.is_end = state.current >= state.size;
+            state.current_char = state.sql.get(last.end).unwrap_or('\0');
+            state.peek_char = state.sql.get(state.current).unwrap_or('\0');
+            if kept > 1 {
+                state.tokens.push(previous.get(kept - 2));
+            }
+            state.tokens.push(last);
+            state.drained = kept - state.tokens.len();
+        }
+        let seeded = state.tokens.len();
+
+        let edit_end = edit.start + inserted;
+        let delta = inserted as isize - edit.len() as isize;
+        let mut next = kept;
+        let mut resumed = None;
+        let result = loop {
+            if state.size == 0 || state.is_end {
+                break Ok(());
+            }
+            let count = state.tokens.len();
+            match state.scan_next() {
+                Ok(true) => {}
+                Ok(false) => break Ok(()),
+                Err(error) => break Err(error),
+            }
+            if state.tokens.len() == count {
+                continue;
+            }
+            let token = state.tokens.last().unwrap();
+            if token.start < edit_end {
+                continue;
+            }
+
+            // The scan is back in step once it ends a token where the previous scan did, at a
+            // point it was safe to restart from
+            let start = token.start.wrapping_add_signed(-delta);
+            while next < previous.len() && previous.span(next).0 < start {
+                next += 1;
+            }
+            if next + 1 >= previous.len() || previous.span(next).0 != start {
+                continue;
+            }
+            let old = previous.get(next);
+            let gap = token.end + 1..previous.span(next + 1).0.wrapping_add_signed(delta);
+            if old.token_type == token.token_type
+                && old.text == token.text
+                && old.end.wrapping_add_signed(delta) == token.end
+                && !is_command(&old)
+                && (next == 0 || !is_command(&previous.get(next - 1)))
+                && state.is_blank(gap)
+                && !full_scan
+            {
+                let shift = TokenShift {
+                    chars: delta,
+                    bytes: token.start_byte as isize - old.start_byte as isize,
+                    lines: token.line as isize - old.line as isize,
+                    line: old.line,
+                    columns: token.col as isize - old.col as isize,
+                };
+                resumed = Some((next + 1, shift));
+                break Ok(());
+            }
+        };
+
+        let error = match result {
+            Ok(()) => {
+                state.attach_trailing_comments();
+                None
+            }
+            Err(error) => Some(error),
+        };
+        Retokenized {
+            kept,
+            tokens: state.take_tokens(state.tokens.len()).split_off(seeded),
+            resumed,
+            error,
+        }
+    }
+
+    /// Like `tokenize_raw`, but also returns the counts of the scan, e.g. to compare how dialect
+    /// settings fare on the same queries. Counting is cheap enough that scans always count.
+    pub fn tokenize_with_stats(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<RawToken>, Option<TokenizerError>, ScanStats) {
+        let started = Instant::now();
+        let mut scan_stats = ScanStats::default();
+        let (tokens, error) = self.scan_instrumented(
+            sql,
+            dialect_settings,
+            ScanMode::Full,
+            Some(&mut scan_stats),
+            None,
+        );
+        scan_stats.elapsed = started.elapsed();
+        scan_stats.bytes = sql.len();
+        scan_stats.tokens = tokens.len();
+        (tokens, error, scan_stats)
+    }
+
+    /// Like `tokenize_raw`, but also records the scanner's decisions, i.e. which token it starts
+    /// scanning where, what the keyword automaton matches and what the scanner backtracks over.
+    pub fn tokenize_with_trace(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<RawToken>, Option<TokenizerError>, Vec<TraceStep>) {
+        let mut trace = Vec::new();
+        let (tokens, error) = self.scan_instrumented(
+            sql,
+            dialect_settings,
+            ScanMode::Full,
+            None,
+            Some(&mut trace),
+        );
+        (tokens, error, trace)
+    }
+
+    fn scan(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+        mode: ScanMode,
+    ) -> (Vec<RawToken>, Option<TokenizerError>) {
+        self.scan_instrumented(sql, dialect_settings, mode, None, None)
+    }
+
+    fn scan_instrumented(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+        mode: ScanMode,
+        scan_stats: Option<&mut ScanStats>,
+        trace: Option<&mut Vec<TraceStep>>,
+    ) -> (Vec<RawToken>, Option<TokenizerError>) {
+        let started = stats::enabled().then(Instant::now);
+        let result = match self.check_input(sql, dialect_settings) {
+            Ok(()) => {
+                let mut state = TokenizerState::new(
+                    sql,
+                    &self.settings,
+                    &self.token_types,
+                    dialect_settings,
+                    &self.keyword_automaton,
+                );
+                state.keyword_types =
+                    (mode == ScanMode::KeywordsOnly).then_some(&self.keyword_types);
+                state.lenient = mode == ScanMode::Lenient;
+                state.trace = trace.is_some().then(Vec::new);
+                let result = match state.tokenize() {
+                    Ok(tokens) => (tokens, None),
+                    Err(e) => (state.take_tokens(state.tokens.len()), Some(e)),
+                };
+                if let Some(scan_stats) = scan_stats {
+                    scan_stats.keyword_hits = state.keyword_probes - state.keyword_misses;
+                    scan_stats.keyword_misses = state.keyword_misses;
+                    scan_stats.allocations = state.texts.allocations() + state.comment_allocations;
+                }
+                if let (Some(trace), Some(steps)) = (trace, state.trace.take()) {
+                    *trace = steps;
+                }
+                result
+            }
+            Err(e) => (Vec::new(), Some(e)),
+        };
+        if let Some(started) = started {
+            let error = result.1.as_ref().map(|e| e.kind.as_str());
+            stats::record(sql.len(), started.elapsed(), error);
+        }
+        result
+    }
+}
+
+fn strip_nul_bytes(text: String) -> String {
+    if text.contains('\0') {
+        text.replace('\0', "")
+    } else {
+        text
+    }
+}
+
+/// Fails if `sql` has more bytes than `max_input_bytes`, which is checked before the query is
+/// copied for scanning.
+fn check_input_size(
+    sql: &str,
+    dialect_settings: &TokenizerDialectSettings,
+) -> Result<(), TokenizerError> {
+    match dialect_settings.max_input_bytes {
+        Some(max_input_bytes) if sql.len() > max_input_bytes => Err(TokenizerError {
+            kind: TokenizerErrorKind::InputTooLarge,
+            line: 1,
+            column: 0,
+            start: 0,
+            character: None,
+            message: format!(
+                "The query has {} bytes, more than max_input_bytes of {}",
+                sql.len(),
+                max_input_bytes
+            ),
+            context: sql.chars().take(50).collect(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+// Comments and commands can still change the last token or depend on the one before it, so a
+// batch scan only hands these out once it's done
+const RETAINED_TOKENS: usize = 2;
+
+/// Scans a query a batch of tokens at a time, so that huge scripts never have all of their tokens
+/// in memory at once. It doesn't borrow from the config, which has to be passed to every call
+/// along with the same dialect settings.
+#[derive(Debug)]
+pub struct BatchScan {
+    // Unset once the scan is done
+    cursor: Option<Cursor>,
+    // Why the scan failed before it started
+    error: Option<TokenizerError>,
+}
+
+impl BatchScan {
+    pub fn new(
+        config: &TokenizerConfig,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> BatchScan {
+        if let Err(error) = config.check_input(sql, dialect_settings) {
+            return BatchScan {
+                cursor: None,
+                error: Some(error),
+            };
+        }
+
+        let cursor = TokenizerState::new(
+            sql,
+            &config.settings,
+            &config.token_types,
+            dialect_settings,
+            &config.keyword_automaton,
+        )
+        .suspend();
+        BatchScan {
+            cursor: Some(cursor),
+            error: None,
+        }
+    }
+
+    /// Scans about `size` more tokens, returning them along with the error the scan stopped at,
+    /// if any. An empty batch without an error means the scan is done.
+    pub fn next_batch(
+        &mut self,
+        config: &TokenizerConfig,
+        dialect_settings: &TokenizerDialectSettings,
+        size: usize,
+    ) -> (Vec<RawToken>, Option<TokenizerError>) {
+        let Some(cursor) = self.cursor.take() else {
+            return (Vec::new(), self.error.take());
+        };
+        let mut state = TokenizerState::resume(cursor, config, dialect_settings);
+
+        let result = loop {
+            if state.size == 0 || state.is_end {
+                break Ok(true);
+            }
+            if state.tokens.len() >= size.max(1) + RETAINED_TOKENS {
+                break Ok(false);
+            }
+            match state.scan_next() {
+                Ok(true) => {}
+                Ok(false) => break Ok(true),
+                Err(error) => break Err(error),
+            }
+        };
+
+        match result {
+            Ok(false) => {
+                let tokens = state.take_tokens(state.tokens.len() - RETAINED_TOKENS);
+                self.cursor = Some(state.suspend());
+                (tokens, None)
+            }
+            Ok(true) => {
+                state.attach_trailing_comments();
+                (state.take_tokens(state.tokens.len()), None)
+            }
+            Err(error) => (state.take_tokens(state.tokens.len()), Some(error)),
+        }
+    }
+}
+
+/// Scans the SQL read from a reader, e.g. a pipe or a network stream, in bounded memory. Chunks
+/// of `chunk_size` bytes are read as the scan needs them and only the text since the last
+/// complete statement is kept, so strings and comments that span chunks are simply scanned again
+/// once the rest of them was read. Like `BatchScan`, it doesn't borrow from the config.
+///
+/// A statement is complete once the two tokens after its semicolon were scanned, with nothing but
+/// whitespace before the first one, which is where the next scan restarts from, as nothing carries
+/// over from a statement to the next one there. The first token could otherwise be the start of a
+/// comment cut short, e.g. the `-` of a `--`, that's attached to the semicolon. A statement that
+/// never gets there, e.g. one that's unterminated, is buffered until the end of the input. The
+/// dialect settings' `max_input_bytes` applies to the text being buffered, while `max_tokens`
+/// applies to the whole input.
+pub struct ReaderScan<R> {
+    reader: R,
+    chunk_size: usize,
+    // The bytes read past the last complete char
+    undecoded: Vec<u8>,
+    // The text read since the last complete statement, which is where `shift` moves its tokens
+    text: String,
+    // The text the last batch was scanned from
+    scanned: String,
+    shift: TokenShift,
+    // How long the text has to get before it's scanned again, which doubles each time a scan
+    // finds no complete statement so that a long one isn't scanned once per chunk
+    scan_at: usize,
+    emitted: usize,
+    is_eof: bool,
+    is_done: bool,
+}
+
+impl<R: Read> ReaderScan<R> {
+    pub fn new(reader: R, chunk_size: usize) -> ReaderScan<R> {
+        let chunk_size = chunk_size.max(1);
+        ReaderScan {
+            reader,
+            chunk_size,
+            undecoded: Vec::new(),
+            text: String::new(),
+            scanned: String::new(),
+            shift: TokenShift {
+                chars: 0,
+                bytes: 0,
+                lines: 0,
+                line: 1,
+                columns: 0,
+            },
+            scan_at: chunk_size,
+            emitted: 0,
+            is_eof: false,
+            is_done: false,
+        }
+    }
+
+    /// Scans the next complete statements, reading as much as that takes, and returns their
+    /// tokens along with the error the scan stopped at, if any. An empty batch without an error
+    /// means the scan is done. Reading fails on invalid UTF-8 as well as on the reader's errors.
+    pub fn next_batch(
+        &mut self,
+        config: &TokenizerConfig,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> io::Result<(Vec<RawToken>, Option<TokenizerError>)> {
+        if self.is_done {
+            self.scanned.clear();
+            return Ok((Vec::new(), None));
+        }
+
+        let limited;
+        let dialect_settings = match dialect_settings.max_tokens {
+            Some(max_tokens) => {
+                limited = TokenizerDialectSettings {
+                    max_tokens: Some(max_tokens.saturating_sub(self.emitted)),
+                    ..dialect_settings.clone()
+                };
+                &limited
+            }
+            None => dialect_settings,
+        };
+
+        loop {
+            while !self.is_eof && self.text.len() < self.scan_at {
+                self.read_chunk()?;
+            }
+            let (mut tokens, error) = config.tokenize_raw(&self.text, dialect_settings);
+
+            // The scan may only have failed because the text ends within a token
+            let is_final = self.is_eof
+                || error.as_ref().is_some_and(|error| {
+                    !matches!(
+                        error.kind,
+                        TokenizerErrorKind::Unterminated
+                            | TokenizerErrorKind::OutOfBounds
+                            | TokenizerErrorKind::UnexpectedKeyword
+                    )
+                });
+            if is_final {
+                self.is_done = true;
+                self.scanned = std::mem::take(&mut self.text);
+                tokens.iter_mut().for_each(|token| self.shift.apply(token));
+                let error = error.map(|error| shift_error(&self.shift, error));
+                return Ok((tokens, error));
+            }
+
+            match self.statements_end(config, dialect_settings, &tokens) {
+                Some(end) => {
+                    tokens.truncate(end);
+                    self.scanned.clear();
+                    self.scanned
+                        .extend(self.text.drain(..tokens[end - 1].end_byte));
+                    tokens.iter_mut().for_each(|token| self.shift.apply(token));
+                    let semicolon = &tokens[end - 1];
+                    self.shift = TokenShift {
+                        chars: (semicolon.end + 1) as isize,
+                        bytes: semicolon.end_byte as isize,
+                        lines: semicolon.line as isize - 1,
+                        line: 1,
+                        columns: semicolon.col as isize,
+                    };
+                    self.emitted += tokens.len();
+                    self.scan_at = self.text.len() + self.chunk_size;
+                    return Ok((tokens, None));
+                }
+                None => self.scan_at = max(self.text.len() * 2, self.text.len() + self.chunk_size),
+            }
+        }
+    }
+
+    /// The text that the last batch was scanned from, which starts where the batch before it
+    /// ended, so that the texts of all batches make up the whole input. Its tokens are spans of it
+    /// once moved back by the chars and bytes of the batches before.
+    pub fn scanned_text(&self) -> &str {
+        &self.scanned
+    }
+
+    /// The number of tokens up to the semicolon ending the last complete statement, if any.
+    fn statements_end(
+        &self,
+        config: &TokenizerConfig,
+        dialect_settings: &TokenizerDialectSettings,
+        tokens: &[RawToken],
+    ) -> Option<usize> {
+        let token_types = &config.token_types;
+        let mut open_hints: usize = 0;
+        let mut end = None;
+        for (i, window) in tokens.windows(3).enumerate() {
+            let (token, next) = (&window[0], &window[1]);
+            if token.token_type == token_types.hint {
+                open_hints += 1;
+            } else if token.token_type == token_types.hint_end {
+                open_hints = open_hints.saturating_sub(1);
+            }
+            if token.token_type == token_types.semicolon
+                && (!dialect_settings.hint_tokens || open_hints == 0)
+                && self.text[token.end_byte..next.start_byte]
+                    .chars()
+                    .all(char::is_whitespace)
+                && !self.in_versioned_comment(dialect_settings, token.end_byte)
+            {
+                end = Some(i + 1);
+            }
+        }
+        end
+    }
+
+    // Versioned comments that are scanned as SQL leave no token behind, so whether the text is
+    // within one is told from the last one opened before it
+    fn in_versioned_comment(&self, dialect_settings: &TokenizerDialectSettings, at: usize) -> bool {
+        if dialect_settings.versioned_comments_version.is_none() {
+            return false;
+        }
+        let before = &self.text[..at];
+        before
+            .rfind("/*!")
+            .is_some_and(|start| !before[start..].contains("*/"))
+    }
+
+    fn read_chunk(&mut self) -> io::Result<()> {
+        let read = self.undecoded.len();
+        self.undecoded.resize(read + self.chunk_size, 0);
+        let count = loop {
+            match self.reader.read(&mut self.undecoded[read..]) {
+                Ok(count) => break count,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => {
+                    self.undecoded.truncate(read);
+                    return Err(error);
+                }
+            }
+        };
+        self.undecoded.truncate(read + count);
+        self.is_eof = count == 0;
+
+        let valid = match std::str::from_utf8(&self.undecoded) {
+            Ok(text) => text.len(),
+            // The last char may be completed by the next chunk
+            Err(error) if error.error_len().is_none() && !self.is_eof => error.valid_up_to(),
+            Err(error) => return Err(io::Error::new(io::ErrorKind::InvalidData, error)),
+        };
+        let decoded = std::str::from_utf8(&self.undecoded[..valid])
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        self.text.push_str(decoded);
+        self.undecoded.drain(..valid);
+        Ok(())
+    }
+}
+
+fn shift_error(shift: &TokenShift, mut error: TokenizerError) -> TokenizerError {
+    if error.line == shift.line {
+        error.column = error.column.wrapping_add_signed(shift.columns);
+    }
+    error.line = error.line.wrapping_add_signed(shift.lines);
+    error.start = error.start.wrapping_add_signed(shift.chars);
+    // Messages may end with the line and char offset the scan stopped at, e.g. `Missing ' from 1:9`
+    if let Some((message, position)) = error.message.rsplit_once(" from ") {
+        if let Some((Ok(line), Ok(current))) = position
+            .split_once(':')
+            .map(|(line, current)| (line.parse::<usize>(), current.parse::<usize>()))
+        {
+            error.message = format!(
+                "{} from {}:{}",
+                message,
+                line.wrapping_add_signed(shift.lines),
+                current.wrapping_add_signed(shift.chars)
+            );
+        }
+    }
+    error
+}
+
+/// The tokens of a previous scan as `TokenizerConfig::retokenize_raw` reads them. Only the few
+/// tokens around an edit are ever converted, so callers holding tokens in another form, e.g. as
+/// Python objects, don't have to convert all of them. `get` may leave out comments.
+pub trait PreviousTokens {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The first and last char offsets of the token at `i`.
+    fn span(&self, i: usize) -> (usize, usize);
+
+    fn get(&self, i: usize) -> RawToken;
+}
+
+impl PreviousTokens for [RawToken] {
+    fn len(&self) -> usize {
+        <[RawToken]>::len(self)
+    }
+
+    fn span(&self, i: usize) -> (usize, usize) {
+        (self[i].start, self[i].end)
+    }
+
+    fn get(&self, i: usize) -> RawToken {
+        self[i].clone()
+    }
+}
+
+/// How far the tokens after an edit moved from where a previous scan found them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TokenShift {
+    pub chars: isize,
+    pub bytes: isize,
+    pub lines: isize,
+    /// Tokens starting or ending on this line, which the edit ended on, also moved by `columns`
+    pub line: usize,
+    pub columns: isize,
+}
+
+impl TokenShift {
+    pub fn apply(&self, token: &mut RawToken) {
+        if token.line == self.line {
+            token.col = token.col.wrapping_add_signed(self.columns);
+        }
+        token.line = token.line.wrapping_add_signed(self.lines);
+        if token.start_line == self.line {
+            token.start_col = token.start_col.wrapping_add_signed(self.columns);
+        }
+        token.start_line = token.start_line.wrapping_add_signed(self.lines);
+        token.start = token.start.wrapping_add_signed(self.chars);
+        token.end = token.end.wrapping_add_signed(self.chars);
+        token.start_byte = token.start_byte.wrapping_add_signed(self.bytes);
+        token.end_byte = token.end_byte.wrapping_add_signed(self.bytes);
+    }
+}
+
+/// What `TokenizerConfig::retokenize_raw` found: the first `kept` previous tokens are unchanged
+/// and followed by `tokens`, then, if the scan got back in step with the previous one, by the
+/// previous tokens from `resumed` on, moved by its shift.
+#[derive(Debug)]
+pub struct Retokenized {
+    pub kept: usize,
+    pub tokens: Vec<RawToken>,
+    pub resumed: Option<(usize, TokenShift)>,
+    pub error: Option<TokenizerError>,
+}
+
+impl Retokenized {
+    /// Puts the tokens of the edited query together, given the previous ones.
+    pub fn into_tokens(self, previous: &[RawToken]) -> (Vec<RawToken>, Option<TokenizerError>) {
+        let mut tokens = previous[..self.kept].to_vec();
+        tokens.extend(self.tokens);
+        if let Some((resumed, shift)) = self.resumed {
+            tokens.extend(previous[resumed..].iter().map(|token| {
+                let mut token = token.clone();
+                shift.apply(&mut token);
+                token
+            }));
+        }
+        (tokens, self.error)
+    }
+}
+
+/// The part of a `TokenizerState` that a `BatchScan` keeps between batches, since the state
+/// itself borrows from the config. The query is only kept once, as the copy that token texts are
+/// spans of.
+#[derive(Debug)]
+struct Cursor {
+    source: Arc<str>,
+    char_index: CharIndex,
+    tokens: Vec<RawToken>,
+    start: usize,
+    start_line: usize,
+    start_column: usize,
+    current: usize,
+    line: usize,
+    column: usize,
+    comments: Vec<Arc<str>>,
+    comment_tokens: Vec<RawToken>,
+    is_end: bool,
+    current_char: char,
+    peek_char: char,
+    previous_token_line: Option<usize>,
+    drained: usize,
+    drained_comment_tokens: usize,
+    texts: Interner,
+    in_versioned_comment: bool,
+    in_hint: bool,
+}
+
+/// Where the scanner was before it started on a token, so that it can go back if the token turns
+/// out to be invalid.
+#[derive(Clone, Copy, Debug)]
+struct Checkpoint {
+    current: usize,
+    line: usize,
+    column: usize,
+    tokens: usize,
+    comments: usize,
+    comment_tokens: usize,
+}
+
+// How many chars apart the chars are whose byte offsets a `CharIndex` keeps
+const CHAR_BLOCK: usize = 64;
+
+/// The byte offsets of every `CHAR_BLOCK`th char of a query that isn't ASCII, which chars are
+/// looked up from, so that the index only takes an eighth of the query's size at most. ASCII
+/// queries, which are the vast majority, don't need one at all.
+#[derive(Debug)]
+struct CharIndex {
+    chars: usize,
+    // Empty for ASCII queries
+    blocks: Vec<usize>,
+}
+
+impl CharIndex {
+    fn new(sql: &str) -> CharIndex {
+        if sql.is_ascii() {
+            return CharIndex {
+                chars: sql.len(),
+                blocks: Vec::new(),
+            };
+        }
+        let mut blocks = Vec::with_capacity(sql.len() / CHAR_BLOCK + 1);
+        let mut chars = 0;
+        for (i, (byte, _)) in sql.char_indices().enumerate() {
+            if i % CHAR_BLOCK == 0 {
+                blocks.push(byte);
+            }
+            chars = i + 1;
+        }
+        CharIndex { chars, blocks }
+    }
+}
+
+/// The query being scanned, indexed by char while it's kept as UTF-8, in the copy that the texts
+/// of tokens are spans of, see `TokenText`. ASCII queries are indexed
+/// by byte, while chars in any others are found from the closest char that the `CharIndex` or the
+/// last lookup knows the byte offset of. The scanner mostly moves a char at a time, so that's
+/// rarely more than a char or two away.
+#[derive(Debug)]
+struct ScanText {
+    text: Arc<str>,
+    index: CharIndex,
+    // The char that was last looked up and its byte offset
+    last: Cell<(usize, usize)>,
+}
+
+impl ScanText {
+    fn new(sql: &str) -> ScanText {
+        ScanText::with_index(sql.into(), CharIndex::new(sql))
+    }
+
+    fn with_index(text: Arc<str>, index: CharIndex) -> ScanText {
+        ScanText {
+            text,
+            index,
+            last: Cell::new((0, 0)),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.index.chars
+    }
+
+    fn is_ascii(&self) -> bool {
+        self.index.blocks.is_empty()
+    }
+
+    /// The byte offset at which the char at `index` starts, or the length of the query for
+    /// indexes past its end.
+    fn byte_offset(&self, index: usize) -> usize {
+        if index >= self.index.chars {
+            return self.text.len();
+        }
+        if self.is_ascii() {
+            return index;
+        }
+        let bytes = self.text.as_bytes();
+        let block = index / CHAR_BLOCK;
+        let (mut at, mut byte) = self.last.get();
+        if at > index && at - index < index - block * CHAR_BLOCK {
+            while at > index {
+                byte -= 1;
+                // Continuation bytes are 0b10xxxxxx
+                while bytes[byte] & 0xC0 == 0x80 {
+                    byte -= 1;
+                }
+                at -= 1;
+            }
+        } else {
+            if at > index || at < block * CHAR_BLOCK {
+                (at, byte) = (block * CHAR_BLOCK, self.index.blocks[block]);
+            }
+            while at < index {
+                byte += utf8_width(bytes[byte]);
+                at += 1;
+            }
+        }
+        self.last.set((at, byte));
+        byte
+    }
+
+    fn get(&self, index: usize) -> Option<char> {
+        if self.is_ascii() {
+            return self.text.as_bytes().get(index).map(|&b| b as char);
+        }
+        if index >= self.index.chars {
+            return None;
+        }
+        self.text[self.byte_offset(index)..].chars().next()
+    }
+
+    /// The number of units of `encoding` that the chars in `chars` count for, which is their
+    /// number for ASCII text.
+    fn width(&self, chars: Range<usize>, encoding: PositionEncoding) -> usize {
+        if self.is_ascii() || encoding == PositionEncoding::Utf32 || chars.end > self.len() {
+            return chars.len();
+        }
+        self.slice(chars).chars().map(|c| encoding.width(c)).sum()
+    }
+
+    fn slice(&self, chars: Range<usize>) -> &str {
+        &self.text[self.byte_offset(chars.start)..self.byte_offset(chars.end)]
+    }
+
+    /// The index of the first char from `from` on that is one of `needles`, which is found with
+    /// memchr in ASCII queries as long as there are at most three of them.
+    fn find_any(&self, from: usize, needles: &[char]) -> Option<usize> {
+        if !self.is_ascii() {
+            return self.text[self.byte_offset(from)..]
+                .chars()
+                .position(|c| needles.contains(&c))
+                .map(|i| from + i);
+        }
+        let bytes = self.text.as_bytes();
+        let haystack = &bytes[from.min(bytes.len())..];
+        // A char outside of ASCII can't occur in an ASCII query
+        let mut ascii = needles.iter().filter(|c| c.is_ascii()).map(|&c| c as u8);
+        let found = match (ascii.next(), ascii.next(), ascii.next(), ascii.next()) {
+            (None, ..) => None,
+            (Some(a), None, ..) => memchr::memchr(a, haystack),
+            (Some(a), Some(b), None, _) => memchr::memchr2(a, b, haystack),
+            (Some(a), Some(b), Some(c), None) => memchr::memchr3(a, b, c, haystack),
+            _ => haystack
+                .iter()
+                .position(|&b| needles.contains(&(b as char))),
+        };
+        found.map(|i| from + i)
+    }
+}
+
+/// The number of bytes of the UTF-8 char that starts with `byte`.
+fn utf8_width(byte: u8) -> usize {
+    match byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+#[derive(Debug)]
+struct TokenizerState<'a> {
+    sql: ScanText,
+    size: usize,
+    tokens: Vec<RawToken>,
+    start: usize,
+    start_line: usize,
+    start_column: usize,
+    current: usize,
+    line: usize,
+    column: usize,
+    comments: Vec<Arc<str>>,
+    // The comments scanned as tokens of their own, which are kept apart from `tokens` until they
+    // are taken out, so that the tokens around them are scanned as if they were attached
+    comment_tokens: Vec<RawToken>,
+    is_end: bool,
+    current_char: char,
+    peek_char: char,
+    previous_token_line: Option<usize>,
+    // Minimal dialects often define no comments or format strings, in which case the lookups
+    // for them can be skipped altogether
+    has_comments: bool,
+    has_format_strings: bool,
+    // The chars that `advance` counts as line breaks, i.e. the whitespace the settings map to
+    // the break token type
+    line_breaks: Vec<char>,
+    // The chars in the longest of the dialect settings' unescaped sequences, which are matched
+    // longest first
+    unescaped_sequence_size: usize,
+    #[cfg(feature = "tracing")]
+    statement_span: Option<tracing::span::EnteredSpan>,
+    #[cfg(feature = "tracing")]
+    statement_index: usize,
+    // Set when only keywords are requested, in which case every other token is added without text
+    keyword_types: Option<&'a HashSet<TokenType>>,
+    // Set when errors should be turned into error tokens rather than end the scan
+    lenient: bool,
+    // The number of tokens a stream has already taken out of `tokens` and `comment_tokens`
+    drained: usize,
+    drained_comment_tokens: usize,
+    // The texts of the tokens scanned so far, which tokens with the same text share
+    texts: Interner,
+    // Set while scanning the contents of a versioned comment as SQL, whose end is then skipped
+    in_versioned_comment: bool,
+    // Set while scanning the body of a hint as tokens, whose end is then a `hint_end` token
+    in_hint: bool,
+    // What `ScanStats` counts besides the texts' allocations, which `texts` counts itself
+    keyword_probes: usize,
+    keyword_misses: usize,
+    comment_allocations: usize,
+    // Set when the scanner's decisions should be recorded, see `tokenize_with_trace`
+    trace: Option<Vec<TraceStep>>,
+    keyword_automaton: &'a Dafsa,
+    settings: &'a TokenizerSettings,
+    dialect_settings: &'a TokenizerDialectSettings,
+    token_types: &'a TokenTypeSettings,
+}
+
+impl<'a> TokenizerState<'a> {
+    fn new(
+        sql: &'a str,
+        settings: &'a TokenizerSettings,
+        token_types: &'a TokenTypeSettings,
+        dialect_settings: &'a TokenizerDialectSettings,
+        keyword_automaton: &'a Dafsa,
+    ) -> TokenizerState<'a> {
+        let text = ScanText::new(sql);
+        let size = text.len();
+        // A byte order mark isn't part of the query, which starts right after it
+        let current = usize::from(text.get(0) == Some('\u{feff}'));
+        TokenizerState {
+            sql: text,
+            size,
+            tokens: Vec::new(),
+            start: 0,
+            start_line: 1,
+            start_column: 0,
+            current,
+            line: 1,
+            column: 0,
+            comments: Vec::new(),
+            comment_tokens: Vec::new(),
+            is_end: size > 0 && current == size,
+            current_char: '\0',
+            peek_char: '\0',
+            previous_token_line: None,
+            has_comments: !settings.comments.is_empty(),
+            has_format_strings: cfg!(feature = "format-strings")
+                && !settings.format_strings.is_empty(),
+            line_breaks: settings
+                .white_space
+                .iter()
+                .filter(|&(_, &token_type)| token_type == token_types.break_)
+                .map(|(&c, _)| c)
+                .collect(),
+            unescaped_sequence_size: dialect_settings
+                .unescaped_sequences
+                .keys()
+                .map(|sequence| sequence.chars().count())
+                .max()
+                .unwrap_or(0),
+            #[cfg(feature = "tracing")]
+            statement_span: None,
+            #[cfg(feature = "tracing")]
+            statement_index: 0,
+            keyword_types: None,
+            lenient: false,
+            drained: 0,
+            drained_comment_tokens: 0,
+            texts: Interner::default(),
+            in_versioned_comment: false,
+            in_hint: false,
+            keyword_probes: 0,
+            keyword_misses: 0,
+            comment_allocations: 0,
+            trace: None,
+            keyword_automaton,
+            settings,
+            dialect_settings,
+            token_types,
+        }
+    }
+
+    fn resume(
+        cursor: Cursor,
+        config: &'a TokenizerConfig,
+        dialect_settings: &'a TokenizerDialectSettings,
+    ) -> TokenizerState<'a> {
+        let mut state = TokenizerState::new(
+            "",
+            &config.settings,
+            &config.token_types,
+            dialect_settings,
+            &config.keyword_automaton,
+        );
+        state.sql = ScanText::with_index(cursor.source, cursor.char_index);
+        state.size = state.sql.len();
+        state.tokens = cursor.tokens;
+        state.start = cursor.start;
+        state.start_line = cursor.start_line;
+        state.start_column = cursor.start_column;
+        state.current = cursor.current;
+        state.line = cursor.line;
+        state.column = cursor.column;
+        state.comments = cursor.comments;
+        state.comment_tokens = cursor.comment_tokens;
+        state.is_end = cursor.is_end;
+        state.current_char = cursor.current_char;
+        state.peek_char = cursor.peek_char;
+        state.previous_token_line = cursor.previous_token_line;
+        state.drained = cursor.drained;
+        state.drained_comment_tokens = cursor.drained_comment_tokens;
+        state.texts = cursor.texts;
+        state.in_versioned_comment = cursor.in_versioned_comment;
+        state.in_hint = cursor.in_hint;
+        state
+    }
+
+    fn suspend(self) -> Cursor {
+        Cursor {
+            source: self.sql.text,
+            char_index: self.sql.index,
+            tokens: self.tokens,
+            start: self.start,
+            start_line: self.start_line,
+            start_column: self.start_column,
+            current: self.current,
+            line: self.line,
+            column: self.column,
+            comments: self.comments,
+            comment_tokens: self.comment_tokens,
+            is_end: self.is_end,
+            current_char: self.current_char,
+            peek_char: self.peek_char,
+            previous_token_line: self.previous_token_line,
+            drained: self.drained,
+            drained_comment_tokens: self.drained_comment_tokens,
+            texts: self.texts,
+            in_versioned_comment: self.in_versioned_comment,
+            in_hint: self.in_hint,
+        }
+    }
+
+    fn tokenize(&mut self) -> Result<Vec<RawToken>, TokenizerError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("tokenize", size = self.size).entered();
+        #[cfg(feature = "tracing")]
+        self.enter_statement();
+
+        let result = self.scan(None);
+
+        #[cfg(feature = "tracing")]
+        self.statement_span.take();
+
+        result?;
+        Ok(self.take_tokens(self.tokens.len()))
+    }
+
+    /// Takes the first `count` tokens out, along with the comment tokens before the first token
+    /// left, or all of them if there are none left.
+    fn take_tokens(&mut self, count: usize) -> Vec<RawToken> {
+        self.drained += count;
+        if self.comment_tokens.is_empty() {
+            return self.tokens.drain(..count).collect();
+        }
+
+        let next_start = self
+            .tokens
+            .get(count)
+            .map_or(usize::MAX, |token| token.start);
+        let comment_count = self
+            .comment_tokens
+            .partition_point(|comment| comment.start < next_start);
+        self.drained_comment_tokens += comment_count;
+
+        let mut taken = Vec::with_capacity(count + comment_count);
+        let mut comments = self.comment_tokens.drain(..comment_count).peekable();
+        for token in self.tokens.drain(..count) {
+            while let Some(comment) = comments.next_if(|comment| comment.start < token.start) {
+                taken.push(comment);
+            }
+            taken.push(token);
+        }
+        taken.extend(comments);
+        taken
+    }
+
+    #[cfg(feature = "tracing")]
+    fn enter_statement(&mut self) {
+        // The previous statement's span has to be exited before the next one is entered
+        self.statement_span.take();
+        self.statement_span =
+            Some(tracing::debug_span!("statement", index = self.statement_index).entered());
+        self.statement_index += 1;
+    }
+
+    fn scan(&mut self, until_peek_char: Option<char>) -> Result<(), TokenizerError> {
+        while self.size > 0 && !self.is_end {
+            if !self.scan_next()? {
+                break;
+            }
+
+            if let Some(c) = until_peek_char {
+                if self.peek_char == c {
+                    break;
+                }
+            }
+        }
+        self.attach_trailing_comments();
+        Ok(())
+    }
+
+    /// Scans whatever comes next, returning false if the scan should stop there.
+    fn scan_next(&mut self) -> Result<bool, TokenizerError> {
+        let mut current = self.current;
+
+        // Skip spaces here rather than iteratively calling advance() for performance reasons
+        while current < self.size {
+            let ch = self.char_at(current)?;
+
+            if ch == ' ' || ch == '\t' {
+                current += 1;
+            } else {
+                break;
+            }
+        }
+
+        let offset = if current > self.current {
+            current - self.current
+        } else {
+            1
+        };
+
+        self.start = current;
+        self.advance(offset as isize)?;
+        // A token starts at the first unit of its first char, which is wider than one unit in
+        // some encodings
+        let encoding = self.dialect_settings.position_encoding;
+        let width = self.sql.width(self.start..self.start + 1, encoding);
+        (self.start_line, self.start_column) = (self.line, (self.column + 1).wrapping_sub(width));
+
+        if self.in_hint && self.current_char == '*' && self.peek_char == '/' {
+            self.advance(1)?;
+            self.in_hint = false;
+            self.add(self.token_types.hint_end, None)?;
+            return Ok(true);
+        }
+
+        if self.in_versioned_comment && self.current_char == '*' && self.peek_char == '/' {
+            self.advance(1)?;
+            self.in_versioned_comment = false;
+            return Ok(true);
+        }
+
+        if !self.current_char.is_whitespace() {
+            let checkpoint = self.checkpoint();
+            let result = if self.is_handled_control_char(self.current_char) {
+                self.scan_control_char()
+            } else if self.current_char.is_ascii_digit() {
+                self.scan_number()
+            } else if let Some(identifier_end) = self.settings.identifiers.get(&self.current_char) {
+                self.scan_identifier(&identifier_end.to_string())
+            } else {
+                self.scan_keyword()
+            };
+            match result {
+                Err(error) if self.lenient && !error.kind.is_limit() => {
+                    self.recover(checkpoint, error)?
+                }
+                result => result?,
+            }
+        }
+        Ok(true)
+    }
+
+    fn attach_trailing_comments(&mut self) {
+        if !self.tokens.is_empty() && !self.comments.is_empty() {
+            self.tokens
+                .last_mut()
+                .unwrap()
+                .append_comments(&mut self.comments);
+        }
+    }
+
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            current: self.current,
+            line: self.line,
+            column: self.column,
+            tokens: self.tokens.len(),
+            comments: self.comments.len(),
+            comment_tokens: self.comment_tokens.len(),
+        }
+    }
+
+    /// Rewinds to the token that failed to scan and adds an error token in its place, which runs
+    /// up to the next whitespace or single-char token such as a comma. The scanner gives up on
+    /// unterminated strings and the like at the end of the query, so these only cover their
+    /// first word, while the rest of an invalid token, e.g. a hex string with bad digits, is
+    /// covered in full.
+    fn recover(
+        &mut self,
+        checkpoint: Checkpoint,
+        error: TokenizerError,
+    ) -> Result<(), TokenizerError> {
+        trace_event!(kind = error.kind.as_str(), "error token added");
+        let stop = if error.kind == TokenizerErrorKind::Unterminated {
+            checkpoint.current
+        } else {
+            self.current.max(checkpoint.current)
+        };
+
+        self.tokens.truncate(checkpoint.tokens);
+        self.comments.truncate(checkpoint.comments);
+        self.comment_tokens.truncate(checkpoint.comment_tokens);
+        self.current = checkpoint.current;
+        self.line = checkpoint.line;
+        self.column = checkpoint.column;
+        self.is_end = self.current >= self.size;
+        self.current_char = self.char_at(self.current - 1)?;
+        self.peek_char = self.sql.get(self.current).unwrap_or('\0');
+
+        while !self.is_end
+            && (self.current < stop
+                || !(self.peek_char.is_whitespace()
+                    || self.settings.single_tokens.contains_key(&self.peek_char)))
+        {
+            self.advance(1)?;
+        }
+        self.add(self.token_types.error, None)
+    }
+
+    fn advance(&mut self, i: isize) -> Result<(), TokenizerError> {
+        // The columns the chars moved over count for, which is their number unless columns are
+        // counted in code units
+        let encoding = self.dialect_settings.position_encoding;
+        let columns = if encoding == PositionEncoding::Utf32 {
+            i
+        } else if i >= 0 {
+            let end = self.current + i as usize;
+            self.sql.width(self.current..end, encoding) as isize
+        } else {
+            let start = self.current.saturating_sub(i.unsigned_abs());
+            -(self.sql.width(start..self.current, encoding) as isize)
+        };
+
+        if Some(&self.token_types.break_) == self.settings.white_space.get(&self.current_char) {
+            // Ensures we don't count an extra line if we get a \r\n line break sequence.
+            if !(self.current_char == '\r' && self.peek_char == '\n') {
+                self.column = columns as usize;
+                self.line += 1;
+            }
+        } else {
+            self.column = self.column.wrapping_add_signed(columns);
+        }
+
+        self.current = self.current.wrapping_add_signed(i);
+        self.is_end = self.current >= self.size;
+        self.current_char = self.char_at(self.current - 1)?;
+        self.peek_char = if self.is_end {
+            '\0'
+        } else {
+            self.char_at(self.current)?
+        };
+        Ok(())
+    }
+
+    /// Moves forward to the char at `index` in one go, tracking lines and columns exactly as
+    /// advancing one char at a time would, so that runs of chars that need no handling of their
+    /// own, e.g. in long strings and comments, can be skipped quickly.
+    fn advance_to(&mut self, index: usize) -> Result<(), TokenizerError> {
+        let encoding = self.dialect_settings.position_encoding;
+        for i in self.current - 1..index {
+            let c = self.char_at(i)?;
+            if !self.line_breaks.contains(&c) {
+                // Moving backwards over a line break can leave the column negative, as in sqlglot
+                self.column = self
+                    .column
+                    .wrapping_add(self.sql.width(i + 1..i + 2, encoding));
+            } else if !(c == '\r' && self.sql.get(i + 1) == Some('\n')) {
+                self.column = self.sql.width(i + 1..i + 2, encoding);
+                self.line += 1;
+            }
+        }
+
+        self.current = index + 1;
+        self.is_end = self.current >= self.size;
+        self.current_char = self.char_at(index)?;
+        self.peek_char = self.sql.get(self.current).unwrap_or('\0');
+        Ok(())
+    }
+
+    fn chars(&self, size: usize) -> &str {
+        let start = self.current - 1;
+        let end = start + size;
+        if end <= self.size {
+            self.sql.slice(start..end)
+        } else {
+            ""
+        }
+    }
+
+    fn char_at(&self, index: usize) -> Result<char, TokenizerError> {
+        self.sql.get(index).ok_or_else(|| {
+            self.error(
+                TokenizerErrorKind::OutOfBounds,
+                format!("Index {} is out of bound (size {})", index, self.size),
+            )
+        })
+    }
+
+    fn text(&self) -> String {
+        self.sql.slice(self.start..self.current).to_string()
+    }
+
+    /// The chars from the start of the token up to `end` as `scan_keyword` matches them, i.e. with
+    /// every run of whitespace collapsed into a single space.
+    fn keyword_text(&self, end: usize) -> String {
+        let mut text = String::with_capacity(end - self.start);
+        let mut prev_space = false;
+        for c in (self.start..end).filter_map(|i| self.sql.get(i)) {
+            let is_space = c.is_whitespace();
+            if !is_space {
+                text.push(c);
+            } else if !prev_space {
+                text.push(' ');
+            }
+            prev_space = is_space;
+        }
+        text
+    }
+
+    /// Whether the given chars of the query are all whitespace, if there are any.
+    fn is_blank(&self, chars: Range<usize>) -> bool {
+        let end = min(chars.end, self.size);
+        self.sql
+            .slice(min(chars.start, end)..end)
+            .chars()
+            .all(char::is_whitespace)
+    }
+
+    /// The byte offset in the query at which the char at the given offset starts.
+    fn byte_offset(&self, char: usize) -> usize {
+        self.sql.byte_offset(char)
+    }
+
+    fn check_max_tokens(&self) -> Result<(), TokenizerError> {
+        if let Some(max_tokens) = self.dialect_settings.max_tokens {
+            let count = self.drained
+                + self.tokens.len()
+                + self.drained_comment_tokens
+                + self.comment_tokens.len();
+            if count >= max_tokens {
+                return self.error_result(
+                    TokenizerErrorKind::TooManyTokens,
+                    format!("The query has more tokens than max_tokens of {max_tokens}"),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Records a step of the trace at the start of the token being scanned, if the scan is
+    /// traced. The detail is only computed then.
+    fn record(&mut self, kind: TraceKind, detail: impl FnOnce(&Self) -> String) {
+        if let Some(mut trace) = self.trace.take() {
+            trace.push(TraceStep {
+                kind,
+                position: self.start,
+                detail: detail(self),
+            });
+            self.trace = Some(trace);
+        }
+    }
+
+    fn add_comment_token(&mut self, comment: &str) -> Result<(), TokenizerError> {
+        self.check_max_tokens()?;
+        let start_byte = self.byte_offset(self.start);
+        let end_byte = self.byte_offset(self.current);
+        let text = self.texts.intern(comment);
+        self.comment_tokens.push(RawToken {
+            token_type: self.token_types.comment,
+            text,
+            line: self.line,
+            col: self.column,
+            start_line: self.start_line,
+            start_col: self.start_column,
+            start: self.start,
+            end: self.current - 1,
+            start_byte,
+            end_byte,
+            comments: Vec::new(),
+            prefix: None,
+        });
+        Ok(())
+    }
+
+    fn add(&mut self, token_type: TokenType, text: Option<String>) -> Result<(), TokenizerError> {
+        self.check_max_tokens()?;
+
+        // Only strings, quoted identifiers and commands can have a NUL char in their text, as it
+        // ends every other token when it's stripped
+        let text = match text {
+            Some(text) if self.dialect_settings.nul_bytes == NulBytes::Strip => {
+                Some(strip_nul_bytes(text))
+            }
+            text => text,
+        };
+
+        self.previous_token_line = Some(self.line);
+
+        if !self.comments.is_empty()
+            && !self.tokens.is_empty()
+            && token_type == self.token_types.semicolon
+        {
+            trace_event!(
+                count = self.comments.len(),
+                "comments attached to token preceding semicolon"
+            );
+            self.tokens
+                .last_mut()
+                .unwrap()
+                .append_comments(&mut self.comments);
+        }
+
+        if !self.comments.is_empty() {
+            trace_event!(
+                count = self.comments.len(),
+                token_type,
+                "comments attached to succeeding token"
+            );
+        }
+
+        let start_byte = self.byte_offset(self.start);
+        let end_byte = self.byte_offset(self.current);
+        let text = match self.keyword_types {
+            Some(keyword_types)
+                if token_type != self.token_types.semicolon
+                    && !(keyword_types.contains(&token_type)
+                        && self.sql.get(self.start).is_some_and(char::is_alphabetic)) =>
+            {
+                self.texts.intern("")
+            }
+            _ => match text {
+                Some(text) => self.texts.intern(&text),
+                None => TokenText::span(&self.sql.text, start_byte..end_byte),
+            },
+        };
+        self.tokens.push(RawToken {
+            token_type,
+            text,
+            line: self.line,
+            col: self.column,
+            start_line: self.start_line,
+            start_col: self.start_column,
+            start: self.start,
+            end: self.current - 1,
+            start_byte,
+            end_byte,
+            comments: std::mem::take(&mut self.comments),
+            prefix: None,
+        });
+
+        #[cfg(feature = "tracing")]
+        if token_type == self.token_types.semicolon {
+            self.enter_statement();
+        }
+
+        // If we have either a semicolon or a begin token before the command's token, we'll parse
+        // whatever follows the command's token as a string.
+        if self.settings.commands.contains(&token_type)
+            && self.peek_char != ';'
+            && (self.drained + self.tokens.len() == 1
+                || self
+                    .settings
+                    .command_prefix_tokens
+                    .contains(&self.tokens[self.tokens.len() - 2].token_type))
+        {
+            let start = self.current;
+            let tokens_len = self.tokens.len();
+            let comment_tokens_len = self.comment_tokens.len();
+            self.scan(Some(';'))?;
+            self.tokens.truncate(tokens_len);
+            self.comment_tokens.truncate(comment_tokens_len);
+            let text = self.sql.slice(start..self.current).trim().to_string();
+            if !text.is_empty() {
+                self.add(self.token_types.string, Some(text))?;
+            }
+        }
+        Ok(())
+    }
+
+    // The automaton folds case as it steps, so the chars are only collected into a word once one
+    // has matched, which keeps the scan of every var and keyword from allocating here
+    fn scan_keyword(&mut self) -> Result<(), TokenizerError> {
+        if !self.settings.placeholder_styles.is_empty() {
+            if let Some(end) = self.placeholder_end() {
+                self.advance((end - self.current) as isize)?;
+                return self.add(self.token_types.placeholder, None);
+            }
+        }
+
+        let mut size: usize = 0;
+        let mut word_end: Option<usize> = None;
+        let mut chars_length = 1;
+        let mut current_char;
+        let mut is_word_end = false;
+        let mut prev_space = false;
+        let mut skip;
+        let mut is_single_token = self.settings.single_tokens.contains_key(&self.current_char);
+
+        self.keyword_probes += 1;
+        self.record(TraceKind::ScanKeyword, |_| String::new());
+        let (mut result, mut state) = self.keyword_automaton.step(Dafsa::ROOT, self.current_char);
+
+        loop {
+            if let Match::Failed = result {
+                break;
+            } else if let Match::Exists = result {
+                word_end = Some(self.current + size);
+            }
+
+            let end = self.current + size;
+            size += 1;
+
+            if end < self.size {
+                current_char = self.char_at(end)?;
+                is_single_token =
+                    is_single_token || self.settings.single_tokens.contains_key(&current_char);
+                let is_space = current_char.is_whitespace();
+
+                if !is_space || !prev_space {
+                    if is_space {
+                        current_char = ' ';
+                    }
+                    chars_length += 1;
+                    prev_space = is_space;
+                    skip = false;
+                } else {
+                    skip = true;
+                }
+            } else {
+                is_word_end = true;
+                break;
+            }
+
+            if skip {
+                result = Match::Prefix;
+            } else if chars_length > self.keyword_automaton.max_key_length {
+                // Long words can't be keywords, so the automaton isn't probed for them
+                result = Match::Failed;
+            } else {
+                (result, state) = self.keyword_automaton.step(state, current_char);
+            }
+        }
+
+        if let Some(word_end) = word_end {
+            let unwrapped_word = self.keyword_text(word_end);
+            self.record(TraceKind::KeywordMatched, |_| unwrapped_word.clone());
+            if self.scan_string(&unwrapped_word)? {
+                return Ok(());
+            }
+            if self.scan_comment(&unwrapped_word)? {
+                return Ok(());
+            }
+            if prev_space || is_single_token || is_word_end {
+                self.advance((size - 1) as isize)?;
+                let normalized_word = unwrapped_word.to_uppercase();
+                let keyword_token =
+                    *self
+                        .settings
+                        .keywords
+                        .get(&normalized_word)
+                        .ok_or_else(|| {
+                            self.error(
+                                TokenizerErrorKind::UnexpectedKeyword,
+                                format!("Unexpected keyword '{}'", &normalized_word),
+                            )
+                        })?;
+                trace_event!(
+                    word = %unwrapped_word,
+                    token_type = keyword_token,
+                    "keyword matched"
+                );
+                self.add(keyword_token, Some(unwrapped_word))?;
+                return Ok(());
+            }
+            self.record(TraceKind::Backtrack, |_| unwrapped_word);
+        } else {
+            self.record(TraceKind::KeywordMissed, |_| String::new());
+        }
+
+        self.keyword_misses += 1;
+        match self.settings.single_tokens.get(&self.current_char) {
+            Some(token_type) => self.add(*token_type, Some(self.current_char.to_string())),
+            None => self.scan_var(),
+        }
+    }
+
+    /// Starts scanning the contents of a versioned comment as SQL if the dialect settings' version
+    /// is at least the comment's, as MySQL executes `/*!` comments only on servers that are.
+    fn scan_versioned_comment(&mut self, comment_start: &str) -> Result<bool, TokenizerError> {
+        let Some(max_version) = self.dialect_settings.versioned_comments_version else {
+            return Ok(false);
+        };
+        if comment_start != "/*"
+            || self.in_versioned_comment
+            || self.sql.get(self.current + 1) != Some('!')
+        {
+            return Ok(false);
+        }
+
+        // Versions are five digits, e.g. 40101 for 4.1.1, or six from 10.0 on
+        let digits_start = self.current + 2;
+        let mut digits = (digits_start..digits_start + 6)
+            .take_while(|&i| self.sql.get(i).is_some_and(|c| c.is_ascii_digit()))
+            .count();
+        if digits < 5 {
+            digits = 0;
+        } else {
+            let version: u64 = self
+                .sql
+                .slice(digits_start..digits_start + digits)
+                .parse()
+                .unwrap();
+            let version = version / 10000 * 1_000_000 + version / 100 % 100 * 1000 + version % 100;
+            if version > max_version {
+                return Ok(false);
+            }
+        }
+
+        trace_event!(digits, "versioned comment opened");
+        self.advance((2 + digits) as isize)?;
+        self.in_versioned_comment = true;
+        Ok(true)
+    }
+
+    fn scan_comment(&mut self, comment_start: &str) -> Result<bool, TokenizerError> {
+        if !self.has_comments || !self.settings.comments.contains_key(comment_start) {
+            return Ok(false);
+        }
+
+        self.record(TraceKind::ScanComment, |_| comment_start.to_string());
+        if self.scan_versioned_comment(comment_start)? {
+            return Ok(true);
+        }
+
+        let is_hint = comment_start == self.settings.hint_start
+            && self.tokens.last().is_some_and(|token| {
+                self.settings
+                    .tokens_preceding_hint
+                    .contains(&token.token_type)
+            });
+        // Sizes are in chars, as the delimiters need not be ASCII
+        let comment_start_size = comment_start.chars().count();
+        if is_hint && self.dialect_settings.hint_tokens {
+            // The hint's body is scanned as SQL, up to the `hint_end` token that closes it
+            self.advance((comment_start_size - 1) as isize)?;
+            self.add(self.token_types.hint, None)?;
+            self.in_hint = true;
+            return Ok(true);
+        }
+
+        let comment_start_line = self.line;
+        let comment_tokens = self.dialect_settings.comment_tokens;
+        let mut comment: Option<Arc<str>> = None;
+
+        if let Some(comment_end) = self.settings.comments.get(comment_start).unwrap() {
+            // Skip the comment's start delimiter.
+            self.advance(comment_start_size as isize)?;
+
+            let mut comment_count = 1;
+            let comment_end_size = comment_end.chars().count();
+
+            // Only these chars can end a comment or nest another one within it
+            let mut stops = comment_end.chars().take(1).collect::<Vec<_>>();
+            if self.settings.nested_comments {
+                stops.extend(comment_start.chars().take(1));
+            }
+
+            while !self.is_end {
+                if self.chars(comment_end_size) == *comment_end {
+                    comment_count -= 1;
+                    if comment_count == 0 {
+                        break;
+                    }
+                }
+
+                let next = self.sql.find_any(self.current, &stops);
+                self.advance_to(next.unwrap_or(self.size - 1))?;
+
+                // Nested comments are allowed by some dialects, e.g. databricks, duckdb, postgres
+                if self.settings.nested_comments
+                    && !self.is_end
+                    && self.chars(comment_start_size) == comment_start
+                {
+                    self.advance(comment_start_size as isize)?;
+                    comment_count += 1
+                }
+            }
+
+            trace_event!(start = %comment_start, line = comment_start_line, "block comment scanned");
+            if cfg!(feature = "comments") || comment_tokens {
+                // An unterminated comment ends at the end of the query rather than at its end
+                // delimiter, whose size is dropped from its last chars all the same
+                let end = (self.current + 1).saturating_sub(comment_end_size);
+                let start = (self.start + comment_start_size).min(end);
+                comment = Some(self.sql.slice(start..end).into());
+            }
+            self.advance((comment_end_size - 1) as isize)?;
+        } else {
+            // The comment runs up to the next line break, which is left as the peek char, and is
+            // empty if its start delimiter ends the query
+            let line_break = self.sql.find_any(self.current, &self.line_breaks);
+            let end = line_break.unwrap_or(self.size) - 1;
+            if end >= self.current {
+                self.advance_to(end)?;
+            }
+            trace_event!(start = %comment_start, line = comment_start_line, "line comment scanned");
+            if cfg!(feature = "comments") || comment_tokens {
+                let start = (self.start + comment_start_size).min(self.current);
+                comment = Some(self.sql.slice(start..self.current).into());
+            }
+        }
+
+        if self.dialect_settings.nul_bytes == NulBytes::Strip {
+            comment = comment.map(|comment| {
+                if comment.contains('\0') {
+                    comment.replace('\0', "").into()
+                } else {
+                    comment
+                }
+            });
+        }
+        self.comment_allocations += usize::from(comment.is_some());
+        if comment_tokens && !is_hint {
+            if let Some(comment) = comment {
+                self.add_comment_token(&comment)?;
+            }
+            return Ok(true);
+        }
+
+        if cfg!(feature = "comments") {
+            self.comments.extend(comment);
+        }
+        if is_hint {
+            self.add(self.token_types.hint, None)?;
+        }
+
+        // Leading comment is attached to the succeeding token, whilst trailing comment to the preceding.
+        // Multiple consecutive comments are preserved by appending them to the current comments list.
+        if Some(comment_start_line) == self.previous_token_line {
+            trace_event!(
+                count = self.comments.len(),
+                "comments attached to preceding token"
+            );
+            self.tokens
+                .last_mut()
+                .unwrap()
+                .append_comments(&mut self.comments);
+            self.previous_token_line = Some(self.line);
+        }
+
+        Ok(true)
+    }
+
+    fn scan_string(&mut self, start: &String) -> Result<bool, TokenizerError> {
+        let (start_line, start_column) = (self.line, self.column);
+        let (base, token_type, end) = if let Some(end) = self.settings.quotes.get(start) {
+            (None, self.token_types.string, end.clone())
+        } else if let Some((ref end, token_type)) = self
+            .has_format_strings
+            .then(|| self.settings.format_strings.get(start))
+            .flatten()
+            .filter(|(_, token_type)| is_supported_format_string(*token_type, self.token_types))
+        {
+            if *token_type == self.token_types.hex_string {
+                (Some(16), *token_type, end.clone())
+            } else if *token_type == self.token_types.bit_string {
+                (Some(2), *token_type, end.clone())
+            } else if *token_type == self.token_types.heredoc_string {
+                let tag = if self.settings.heredoc_tag_is_identifier {
+                    let Some(tag) = self.heredoc_tag(start, end) else {
+                        self.add(self.token_types.heredoc_string_alternative, None)?;
+                        return Ok(true);
+                    };
+                    self.advance(tag.chars().count() as isize + 1)?;
+                    tag
+                } else {
+                    self.advance(1)?;
+                    if self.current_char.to_string() == *end {
+                        String::new()
+                    } else {
+                        self.extract_string(end, false, true, false, true)?
+                    }
+                };
+
+                (None, *token_type, format!("{}{}{}", start, tag, end))
+            } else {
+                (None, *token_type, end.clone())
+            }
+        } else {
+            return Ok(false);
+        };
+
+        trace_event!(start = %start, end = %end, token_type, "string opened");
+        self.record(TraceKind::ScanString, |_| start.clone());
+        self.advance(start.len() as isize)?;
+        let raw_string = token_type == self.token_types.raw_string;
+        let verbatim = self.settings.verbatim_strings.contains(start);
+        let text = match self.extract_string(&end, false, raw_string, verbatim, true) {
+            // The body of a dollar-quoted function may well contain other tags, so point at
+            // the tag that was never closed rather than at the end of the query
+            Err(error)
+                if token_type == self.token_types.heredoc_string
+                    && error.kind == TokenizerErrorKind::Unterminated =>
+            {
+                return self.error_result(
+                    TokenizerErrorKind::Unterminated,
+                    format!(
+                        "Missing {} to close the string from {}:{}",
+                        end, start_line, start_column
+                    ),
+                );
+            }
+            result => result?,
+        };
+        trace_event!(length = text.len(), "string closed");
+
+        if let Some(b) = base {
+            if u128::from_str_radix(&text, b).is_err() {
+                return self.error_result(
+                    TokenizerErrorKind::InvalidNumber,
+                    format!(
+                        "Numeric string contains invalid characters from {}:{}",
+                        self.line, self.start
+                    ),
+                );
+            }
+        }
+
+        self.add(token_type, Some(text))?;
+        if let Some(quote) = end.chars().next() {
+            let prefix_size = start.chars().take_while(|&c| c != quote).count();
+            if prefix_size > 0 {
+                let prefix_end = self.byte_offset(self.start + prefix_size);
+                let token = self.tokens.last_mut().unwrap();
+                token.prefix = Some(TokenText::span(
+                    &self.sql.text,
+                    token.start_byte..prefix_end,
+                ));
+            }
+        }
+        Ok(true)
+    }
+
+    fn scan_number(&mut self) -> Result<(), TokenizerError> {
+        self.record(TraceKind::ScanNumber, |_| String::new());
+        if self.current_char == '0' {
+            let peek_char = self.peek_char.to_ascii_uppercase();
+            if peek_char == 'B' {
+                if self.settings.has_bit_strings {
+                    self.scan_bits()?;
+                } else {
+                    self.add(self.token_types.number, None)?;
+                }
+                return Ok(());
+            } else if peek_char == 'X' {
+                if self.settings.has_hex_strings {
+                    self.scan_hex()?;
+                } else {
+                    self.add(self.token_types.number, None)?;
+                }
+                return Ok(());
+            }
+        }
+
+        let mut decimal = false;
+        let mut scientific = 0;
+
+        loop {
+            if self.peek_char.is_ascii_digit() {
+                self.advance(1)?;
+            } else if self.peek_char == '.' && !decimal {
+                if self.tokens.last().map(|t| t.token_type) == Some(self.token_types.parameter) {
+                    return self.add(self.token_types.number, None);
+                }
+                decimal = true;
+                self.advance(1)?;
+            } else if ((self.peek_char == '-' || self.peek_char == '+') && scientific == 1)
+                || (self.peek_char.eq_ignore_ascii_case(&'E') && scientific == 0)
+            {
+                scientific += 1;
+                self.advance(1)?;
+            } else if self.is_alphabetic_or_underscore(self.peek_char) {
+                let number_text = self.text();
+                let mut literal = String::new();
+
+                while !self.peek_char.is_whitespace()
+                    && !self.is_end
+                    && !self.settings.single_tokens.contains_key(&self.peek_char)
+                    && self.is_identifier_char(self.peek_char)
+                    && !self.is_handled_control_char(self.peek_char)
+                {
+                    literal.push(self.peek_char);
+                    self.advance(1)?;
+                }
+
+                let token_type = self
+                    .settings
+                    .keywords
+                    .get(
+                        self.settings
+                            .numeric_literals
+                            .get(&literal.to_uppercase())
+                            .unwrap_or(&String::new()),
+                    )
+                    .copied();
+
+                let replaced = literal.replace("_", "");
+
+                if let Some(unwrapped_token_type) = token_type {
+                    self.add(self.token_types.number, Some(number_text))?;
+                    self.add(self.token_types.dcolon, Some("::".to_string()))?;
+                    self.add(unwrapped_token_type, Some(literal))?;
+                } else if self.dialect_settings.numbers_can_be_underscore_separated
+                    && self.is_numeric(&replaced)
+                {
+                    self.add(self.token_types.number, Some(number_text + &replaced))?;
+                } else if self.dialect_settings.identifiers_can_start_with_digit {
+                    self.add(self.token_types.var, None)?;
+                } else {
+                    self.advance(-(literal.chars().count() as isize))?;
+                    self.record(TraceKind::Backtrack, |_| literal.clone());
+                    self.add(self.token_types.number, Some(number_text))?;
+                }
+                return Ok(());
+            } else {
+                return self.add(self.token_types.number, None);
+            }
+        }
+    }
+
+    fn scan_bits(&mut self) -> Result<(), TokenizerError> {
+        self.scan_radix_string(2, self.token_types.bit_string)
+    }
+
+    fn scan_hex(&mut self) -> Result<(), TokenizerError> {
+        self.scan_radix_string(16, self.token_types.hex_string)
+    }
+
+    fn scan_radix_string(
+        &mut self,
+        radix: u32,
+        radix_token_type: TokenType,
+    ) -> Result<(), TokenizerError> {
+        self.advance(1)?;
+        let value = self.extract_value()?[2..].to_string();
+
+        // Validate if the string consists only of valid hex digits
+        if value.chars().all(|c| c.is_digit(radix)) {
+            self.add(radix_token_type, Some(value))
+        } else {
+            self.add(self.token_types.identifier, None)
+        }
+    }
+
+    fn scan_var(&mut self) -> Result<(), TokenizerError> {
+        // A char that can't be part of an identifier is a var of its own
+        if self.is_identifier_char(self.current_char) {
+            loop {
+                let peek_char = self.peek_char;
+                if !self.is_end
+                    && !peek_char.is_whitespace()
+                    && (self.settings.var_single_tokens.contains(&peek_char)
+                        || !self.settings.single_tokens.contains_key(&peek_char))
+                    && self.is_identifier_char(peek_char)
+                    && !self.is_handled_control_char(peek_char)
+                {
+                    self.advance(1)?;
+                } else {
+                    break;
+                }
+            }
+        }
+
+        let token_type =
+            if self.tokens.last().map(|t| t.token_type) == Some(self.token_types.parameter) {
+                self.token_types.var
+            } else {
+                self.settings
+                    .keywords
+                    .get(&self.text().to_uppercase())
+                    .copied()
+                    .unwrap_or(self.token_types.var)
+            };
+        if token_type != self.token_types.var {
+            trace_event!(word = %self.text(), token_type, "keyword matched");
+        }
+        self.record(TraceKind::ScanVar, |state| state.text());
+        self.add(token_type, None)
+    }
+
+    fn scan_identifier(&mut self, identifier_end: &str) -> Result<(), TokenizerError> {
+        self.record(TraceKind::ScanIdentifier, |_| String::new());
+        self.advance(1)?;
+        let text = self.extract_string(identifier_end, true, false, false, true)?;
+        self.add(self.token_types.identifier, Some(text))
+    }
+
+    fn extract_string(
+        &mut self,
+        delimiter: &str,
+        use_identifier_escapes: bool,
+        raw_string: bool,
+        verbatim: bool,
+        raise_unmatched: bool,
+    ) -> Result<String, TokenizerError> {
+        let mut text = String::new();
+        let delimiter_size = delimiter.chars().count();
+        let mut combined_identifier_escapes = None;
+        if use_identifier_escapes {
+            let mut tmp = (*self.settings.identifier_escapes).clone();
+            tmp.extend(delimiter.chars());
+            combined_identifier_escapes = Some(tmp);
+        }
+        let escapes = match combined_identifier_escapes {
+            Some(ref v) => v,
+            None => &self.settings.string_escapes,
+        };
+
+        // Only these chars can get any other handling than being copied to the text, so the
+        // chars between them are skipped over at once
+        let mut stops = delimiter.chars().take(1).collect::<Vec<_>>();
+        let escapes_allowed =
+            !verbatim && (self.settings.string_escapes_allowed_in_raw_strings || !raw_string);
+        let unescapes_allowed =
+            !verbatim && !raw_string && !self.dialect_settings.unescaped_sequences.is_empty();
+        if escapes_allowed {
+            stops.extend(escapes.iter());
+        }
+        if unescapes_allowed {
+            stops.extend(self.settings.string_escapes.iter());
+        }
+        stops.sort_unstable();
+        stops.dedup();
+
+        loop {
+            if unescapes_allowed
+                && !self.peek_char.is_whitespace()
+                && self.settings.string_escapes.contains(&self.current_char)
+            {
+                let unescaped_sequences = &self.dialect_settings.unescaped_sequences;
+                let sequence = (2..=self.unescaped_sequence_size)
+                    .rev()
+                    .find_map(|size| unescaped_sequences.get_key_value(self.chars(size)));
+                if let Some((sequence, unescaped_sequence)) = sequence {
+                    self.advance(sequence.chars().count() as isize)?;
+                    text.push_str(unescaped_sequence);
+                    continue;
+                }
+            }
+
+            if escapes_allowed
+                && escapes.contains(&self.current_char)
+                && (self.current_char == self.peek_char
+                    || !self
+                        .settings
+                        .quotes
+                        .contains_key(&self.current_char.to_string()))
+            {
+                let peek_char_str = self.peek_char.to_string();
+                let equal_delimiter = delimiter == peek_char_str;
+                if equal_delimiter || escapes.contains(&self.peek_char) {
+                    if equal_delimiter {
+                        text.push(self.peek_char);
+                    } else {
+                        text.push(self.current_char);
+                        text.push(self.peek_char);
+                    }
+                    if self.current + 1 < self.size {
+                        self.advance(2)?;
+                    } else {
+                        return self.error_result(
+                            TokenizerErrorKind::Unterminated,
+                            format!("Missing {} from {}:{}", delimiter, self.line, self.current),
+                        );
+                    }
+                    continue;
+                }
+            }
+            if self.chars(delimiter_size) == delimiter {
+                if delimiter_size > 1 {
+                    self.advance((delimiter_size - 1) as isize)?;
+                }
+                break;
+            }
+            if self.is_end {
+                if !raise_unmatched {
+                    text.push(self.current_char);
+                    return Ok(text);
+                }
+
+                return self.error_result(
+                    TokenizerErrorKind::Unterminated,
+                    format!("Missing {} from {}:{}", delimiter, self.line, self.current),
+                );
+            }
+
+            let current = self.current - 1;
+            let next = self.sql.find_any(self.current, &stops);
+            self.advance_to(next.unwrap_or(self.size - 1))?;
+            text.push_str(self.sql.slice(current..self.current - 1));
+        }
+        Ok(text)
+    }
+
+    fn scan_control_char(&mut self) -> Result<(), TokenizerError> {
+        match self.dialect_settings.control_characters {
+            _ if self.current_char == '\0'
+                && self.dialect_settings.nul_bytes == NulBytes::Strip =>
+            {
+                Ok(())
+            }
+            ControlCharacters::Error => self.error_result(
+                TokenizerErrorKind::UnexpectedCharacter,
+                format!("Unexpected character U+{:04X}", self.current_char as u32),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether `c` is a control char or an invisible formatting one that the
+    /// `control_characters` setting applies to, or a NUL char that the `nul_bytes` one strips,
+    /// rather than one that is scanned as any other.
+    fn is_handled_control_char(&self, c: char) -> bool {
+        (c == '\0' && self.dialect_settings.nul_bytes == NulBytes::Strip) || self.is_control_char(c)
+    }
+
+    fn is_control_char(&self, c: char) -> bool {
+        self.dialect_settings.control_characters != ControlCharacters::Keep
+            && !c.is_whitespace()
+            && (c.is_control()
+                || matches!(
+                    c,
+                    '\u{ad}'
+                        | '\u{61c}'
+                        | '\u{180e}'
+                        | '\u{200b}'..='\u{200f}'
+                        | '\u{202a}'..='\u{202e}'
+                        | '\u{2060}'..='\u{2064}'
+                        | '\u{2066}'..='\u{206f}'
+                        | '\u{feff}'
+                ))
+    }
+
+    fn is_alphabetic_or_underscore(&self, name: char) -> bool {
+        if self.dialect_settings.unicode_identifiers {
+            name == '_' || unicode_ident::is_xid_start(name)
+        } else {
+            name.is_alphabetic() || name == '_'
+        }
+    }
+
+    /// The end of the placeholder of one of the placeholder styles that starts at the current char,
+    /// if any. A placeholder can't follow its own sigil, e.g. the second `:` of `::name`, nor run
+    /// into a word.
+    fn placeholder_end(&self) -> Option<usize> {
+        let start = self.current - 1;
+        let sigil = self.current_char;
+        if start > 0 && self.sql.get(start - 1) == Some(sigil) {
+            return None;
+        }
+
+        // The end of the run of ASCII word chars, or of ASCII digits, that starts at `i`
+        let word_end = |mut i: usize, digits: bool| {
+            while self.sql.get(i).is_some_and(|c| {
+                c.is_ascii_digit() || !digits && (c.is_ascii_alphabetic() || c == '_')
+            }) {
+                i += 1;
+            }
+            i
+        };
+        let i = self.current;
+        let next = self.sql.get(i);
+        let is_name_start = next.is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+
+        let (style, end) = match sigil {
+            ':' if word_end(i, true) == i && is_name_start => ("named", word_end(i, false)),
+            ':' => ("numeric", word_end(i, true)),
+            '$' => ("dollar", word_end(i, true)),
+            '@' if is_name_start => ("at", word_end(i, false)),
+            '{' if is_name_start => {
+                let name_end = word_end(i, false);
+                (self.sql.get(name_end) == Some('}')).then_some(("brace", name_end + 1))?
+            }
+            '%' if next == Some('s') => ("format", i + 1),
+            '%' if next == Some('(') => {
+                let name_end = word_end(i + 1, false);
+                let is_closed =
+                    self.sql.get(name_end) == Some(')') && self.sql.get(name_end + 1) == Some('s');
+                (name_end > i + 1 && is_closed).then_some(("pyformat", name_end + 2))?
+            }
+            _ => return None,
+        };
+        (end > i && self.settings.placeholder_styles.contains(style) && word_end(end, false) == end)
+            .then_some(end)
+    }
+
+    /// Whether `c` can be part of an unquoted identifier, which only rules out the non-ASCII
+    /// chars that aren't XID_Continue, and only with `unicode_identifiers`.
+    fn is_identifier_char(&self, c: char) -> bool {
+        c.is_ascii()
+            || !self.dialect_settings.unicode_identifiers
+            || unicode_ident::is_xid_continue(c)
+    }
+
+    /// The tag of the heredoc string at the current char, which is looked ahead without advancing,
+    /// or None if it isn't an identifier followed by `end`, e.g. in postgres' `$1`.
+    fn heredoc_tag(&self, start: &str, end: &str) -> Option<String> {
+        let tag_start = self.current + start.chars().count() - 1;
+        let mut i = tag_start;
+        while let Some(c) = self.sql.get(i) {
+            let is_tag_char = if i == tag_start {
+                self.is_alphabetic_or_underscore(c)
+            } else {
+                self.is_identifier_continue(c)
+            };
+            if !is_tag_char {
+                break;
+            }
+            i += 1;
+        }
+        let is_followed_by_end = end
+            .chars()
+            .enumerate()
+            .all(|(j, c)| self.sql.get(i + j) == Some(c));
+        is_followed_by_end.then(|| self.sql.slice(tag_start..i).to_string())
+    }
+
+    fn is_identifier_continue(&self, c: char) -> bool {
+        if self.dialect_settings.unicode_identifiers {
+            unicode_ident::is_xid_continue(c)
+        } else {
+            self.is_alphabetic_or_underscore(c) || c.is_ascii_digit()
+        }
+    }
+
+    fn is_numeric(&self, s: &str) -> bool {
+        s.chars().all(|c| c.is_ascii_digit())
+    }
+
+    fn extract_value(&mut self) -> Result<String, TokenizerError> {
+        loop {
+            if !self.peek_char.is_whitespace()
+                && !self.is_end
+                && !self.settings.single_tokens.contains_key(&self.peek_char)
+            {
+                self.advance(1)?;
+            } else {
+                break;
+            }
+        }
+        Ok(self.text())
+    }
+
+    fn error(&self, kind: TokenizerErrorKind, message: String) -> TokenizerError {
+        let start = max((self.current as isize) - 50, 0);
+        let end = min(self.current + 50, self.size - 1);
+        let context = self.sql.slice(start as usize..end).to_string();
+        TokenizerError {
+            kind,
+            line: self.line,
+            column: self.column,
+            start: self.start,
+            character: self.current.checked_sub(1).and_then(|i| self.sql.get(i)),
+            message,
+            context,
+        }
+    }
+
+    fn error_result<T>(
+        &self,
+        kind: TokenizerErrorKind,
+        message: String,
+    ) -> Result<T, TokenizerError> {
+        Err(self.error(kind, message))
+    }
+}
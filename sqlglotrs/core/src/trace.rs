@@ -0,0 +1,63 @@
+// Scanner instrumentation compiles down to nothing unless the `tracing` feature is enabled.
+#[cfg(feature = "tracing")]
+macro_rules! trace_event {
+    ($($arg:tt)*) => { tracing::debug!($($arg)*) };
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! trace_event {
+    ($($arg:tt)*) => {};
+}
+
+pub(crate) use trace_event;
+
+/// A scanner decision recorded by `TokenizerConfig::tokenize_with_trace`, e.g. to see why a
+/// dialect's settings scan a query the way they do.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep {
+    pub kind: TraceKind,
+    /// The char offset of the token being scanned
+    pub position: usize,
+    /// What the step was about, e.g. the word the keyword automaton matched, or empty
+    pub detail: String,
+}
+
+/// What a `TraceStep` records.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceKind {
+    /// A number starts here
+    ScanNumber,
+    /// A quoted identifier starts here
+    ScanIdentifier,
+    /// The keyword automaton is probed here
+    ScanKeyword,
+    /// The automaton matched the keyword, quote or comment delimiter in the detail
+    KeywordMatched,
+    /// The automaton matched nothing, so a single-char token or a var starts here
+    KeywordMissed,
+    /// A string starting with the delimiter in the detail is scanned
+    ScanString,
+    /// A comment starting with the delimiter in the detail is scanned
+    ScanComment,
+    /// The word in the detail is scanned as a var or a keyword
+    ScanVar,
+    /// The scanner gave up on the text in the detail, e.g. a keyword match that the word goes on
+    /// past or a number's suffix that isn't a type
+    Backtrack,
+}
+
+impl TraceKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TraceKind::ScanNumber => "scan_number",
+            TraceKind::ScanIdentifier => "scan_identifier",
+            TraceKind::ScanKeyword => "scan_keyword",
+            TraceKind::KeywordMatched => "keyword_matched",
+            TraceKind::KeywordMissed => "keyword_missed",
+            TraceKind::ScanString => "scan_string",
+            TraceKind::ScanComment => "scan_comment",
+            TraceKind::ScanVar => "scan_var",
+            TraceKind::Backtrack => "backtrack",
+        }
+    }
+}
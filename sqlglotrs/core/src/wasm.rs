@@ -0,0 +1,121 @@
+//! A small JavaScript API over the scanner, so that web-based SQL editors can tokenize exactly as
+//! sqlglot does. It's only compiled with the `wasm` feature and meant for `wasm32-unknown-unknown`,
+//! where the module and its JS glue can be built with
+//!
+//! ```text
+//! cargo rustc -p sqlglotrs-core --release --target wasm32-unknown-unknown --features wasm \
+//!     --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/sqlglotrs_core.wasm
+//! ```
+//!
+//! Positions are char offsets into the query, as in sqlglot, rather than UTF-16 offsets.
+
+use crate::presets;
+use crate::settings::TokenizerDialectSettings;
+use crate::token::RawToken;
+use crate::tokenizer::{TokenizerConfig, TokenizerError};
+use std::sync::Arc;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Tokenizer {
+    config: Arc<TokenizerConfig>,
+    dialect_settings: TokenizerDialectSettings,
+}
+
+#[wasm_bindgen]
+impl Tokenizer {
+    /// Creates a tokenizer from the preset of a builtin dialect, named as in sqlglot, e.g.
+    /// `"postgres"`, where the default dialect is `""`.
+    #[wasm_bindgen(js_name = forDialect)]
+    pub fn for_dialect(dialect: &str) -> Result<Tokenizer, JsError> {
+        let (Some(settings), Some(token_types), Some(dialect_settings)) = (
+            presets::tokenizer_settings(dialect),
+            presets::token_type_settings(dialect),
+            presets::dialect_settings(dialect),
+        ) else {
+            return Err(JsError::new(&format!("No preset for dialect '{dialect}'")));
+        };
+        Ok(Tokenizer {
+            config: TokenizerConfig::cached(settings, token_types),
+            dialect_settings,
+        })
+    }
+
+    /// Creates a tokenizer from a snapshot of a definition, e.g. of a custom dialect, as written
+    /// by `Tokenizer.to_snapshot` in Python. It scans with the default dialect settings, or with
+    /// those of the builtin dialect `dialect` if one is given.
+    #[wasm_bindgen(js_name = fromSnapshot)]
+    pub fn from_snapshot(snapshot: &[u8], dialect: Option<String>) -> Result<Tokenizer, JsError> {
+        let config = TokenizerConfig::from_snapshot(snapshot)
+            .map_err(|error| JsError::new(&error.to_string()))?;
+        let dialect_settings = match dialect {
+            Some(dialect) => presets::dialect_settings(&dialect)
+                .ok_or_else(|| JsError::new(&format!("No preset for dialect '{dialect}'")))?,
+            None => TokenizerDialectSettings::default(),
+        };
+        Ok(Tokenizer {
+            config: Arc::new(config),
+            dialect_settings,
+        })
+    }
+
+    /// Returns the tokens of `sql`, throwing an error if it can't be tokenized.
+    pub fn tokenize(&self, sql: &str) -> Result<Vec<Token>, JsError> {
+        match self.config.tokenize_raw(sql, &self.dialect_settings) {
+            (tokens, None) => Ok(tokens.into_iter().map(Token::from).collect()),
+            (_, Some(error)) => Err(js_error(error)),
+        }
+    }
+
+    /// Like `tokenize`, but whatever can't be scanned becomes an `ERROR` token, e.g. for editors
+    /// tokenizing incomplete SQL as it's typed.
+    #[wasm_bindgen(js_name = tokenizeLenient)]
+    pub fn tokenize_lenient(&self, sql: &str) -> Result<Vec<Token>, JsError> {
+        match self
+            .config
+            .tokenize_lenient_raw(sql, &self.dialect_settings)
+        {
+            (tokens, None) => Ok(tokens.into_iter().map(Token::from).collect()),
+            (_, Some(error)) => Err(js_error(error)),
+        }
+    }
+}
+
+/// A token as handed to JavaScript.
+#[wasm_bindgen(getter_with_clone)]
+pub struct Token {
+    /// The id of the token's sqlglot `TokenType`
+    #[wasm_bindgen(js_name = tokenType)]
+    pub token_type: u16,
+    /// The name of the token's `TokenType`, e.g. `"SELECT"`, which is empty for custom ones
+    #[wasm_bindgen(js_name = typeName)]
+    pub type_name: String,
+    pub text: String,
+    pub line: usize,
+    pub col: usize,
+    pub start: usize,
+    pub end: usize,
+    pub comments: Vec<String>,
+}
+
+impl From<RawToken> for Token {
+    fn from(token: RawToken) -> Token {
+        Token {
+            token_type: token.token_type,
+            type_name: presets::token_type_name(token.token_type)
+                .unwrap_or_default()
+                .to_string(),
+            text: token.text.to_string(),
+            line: token.line,
+            col: token.col,
+            start: token.start,
+            end: token.end,
+            comments: token.comments.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+fn js_error(error: TokenizerError) -> JsError {
+    JsError::new(&error.to_string())
+}
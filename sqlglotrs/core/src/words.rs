@@ -0,0 +1,140 @@
+use crate::settings::TokenTypeSettings;
+use crate::source::Source;
+use crate::token::RawToken;
+use std::ops::Range;
+
+/// Word-level view of a token stream that the extraction heuristics are written against.
+pub struct Words<'a> {
+    source: &'a Source<'a>,
+    tokens: &'a [RawToken],
+    token_types: &'a TokenTypeSettings,
+}
+
+impl<'a> Words<'a> {
+    pub fn new(
+        source: &'a Source<'a>,
+        tokens: &'a [RawToken],
+        token_types: &'a TokenTypeSettings,
+    ) -> Words<'a> {
+        Words {
+            source,
+            tokens,
+            token_types,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    pub fn token(&self, i: usize) -> &'a RawToken {
+        &self.tokens[i]
+    }
+
+    /// The index of the token that starts at the char offset `start`, if any.
+    pub fn index_at(&self, start: usize) -> Option<usize> {
+        let i = self.tokens.partition_point(|token| token.start < start);
+        self.tokens
+            .get(i)
+            .is_some_and(|token| token.start == start)
+            .then_some(i)
+    }
+
+    /// The query text spanned by a non-empty range of tokens, as written.
+    pub fn text(&self, tokens: Range<usize>) -> &'a str {
+        let start = self.tokens[tokens.start].start;
+        self.source.text(start..self.tokens[tokens.end - 1].end + 1)
+    }
+
+    /// Whether the token at `i` is the given punctuation or keyword, ignoring case.
+    pub fn is(&self, i: usize, text: &str) -> bool {
+        self.tokens.get(i).is_some_and(|token| {
+            token.text.eq_ignore_ascii_case(text) && self.source.is_verbatim(token)
+        })
+    }
+
+    /// Whether the token at `i` ends an operand, so that a name following it can only be an alias.
+    pub fn ends_operand(&self, i: usize) -> bool {
+        self.tokens.get(i).is_some_and(|token| {
+            self.is_name(i)
+                || token.token_type == self.token_types.number
+                || self.is(i, ")")
+                || !self.source.is_verbatim(token)
+        })
+    }
+
+    /// If the token at `i` holds the rest of a command, e.g. what follows `CALL` in dialects that
+    /// treat it as one, which the scanner leaves unscanned as a single string, returns the char
+    /// offset that text starts at. The token's own start isn't meaningful in that case.
+    pub fn command_start(&self, i: usize) -> Option<usize> {
+        let token = self.tokens.get(i)?;
+        let previous = self.tokens.get(i.checked_sub(1)?)?;
+        if token.token_type != self.token_types.string || token.end <= previous.end {
+            return None;
+        }
+        let rest = self.source.text(previous.end + 1..token.end + 1);
+        let start = previous.end + 1 + rest.chars().take_while(|c| c.is_whitespace()).count();
+        (rest.trim() == &*token.text).then_some(start)
+    }
+
+    pub fn is_any(&self, i: usize, texts: &[&str]) -> bool {
+        texts.iter().any(|text| self.is(i, text))
+    }
+
+    /// Whether the token at `i` is a name, i.e. an unquoted word that isn't a keyword or a
+    /// quoted identifier.
+    pub fn is_name(&self, i: usize) -> bool {
+        self.tokens.get(i).is_some_and(|token| {
+            token.token_type == self.token_types.var
+                || token.token_type == self.token_types.identifier
+        })
+    }
+
+    /// Whether the token at `i` is a bare word, which includes keywords.
+    pub fn is_word(&self, i: usize) -> bool {
+        self.is_name(i)
+            || self.tokens.get(i).is_some_and(|token| {
+                token
+                    .text
+                    .starts_with(|c: char| c.is_alphabetic() || c == '_')
+                    && self.source.is_verbatim(token)
+            })
+    }
+
+    /// Reads a dotted name such as `db.schema.t` starting at `i`. Keywords are accepted as parts
+    /// of a name with more than one part, e.g. `t.order`, but never on their own. Returns the
+    /// index past the name, the name and whether all of its parts were names.
+    pub fn dotted_name(&self, i: usize) -> Option<(usize, String, bool)> {
+        self.name_parts(i)
+            .map(|(j, parts, confident)| (j, parts.join("."), confident))
+    }
+
+    /// Like `dotted_name`, but returns the parts of the name separately, e.g. for telling the
+    /// schema of a qualified name apart from its catalog.
+    pub fn name_parts(&self, i: usize) -> Option<(usize, Vec<&'a str>, bool)> {
+        let mut j = i;
+        let mut parts = Vec::new();
+        let mut confident = true;
+        loop {
+            if !self.is_word(j) {
+                return None;
+            }
+            confident &= self.is_name(j);
+            parts.push(&*self.tokens[j].text);
+            j += 1;
+            if !self.is(j, ".") {
+                break;
+            }
+            j += 1;
+        }
+
+        if parts.len() == 1 && !self.is_name(i) {
+            return None;
+        }
+        Some((j, parts, confident))
+    }
+}
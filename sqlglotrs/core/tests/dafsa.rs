@@ -0,0 +1,56 @@
+//! Checks the keyword automaton on its own, outside of any scan.
+
+use sqlglotrs_core::dafsa::{Dafsa, Match};
+
+fn dafsa() -> Dafsa {
+    Dafsa::new(["GROUP BY", "ORDER BY", "ORDER", "/*", "--", "STRASSE"])
+}
+
+#[test]
+fn test_walk() {
+    let dafsa = dafsa();
+    assert_eq!(dafsa.max_key_length, 8);
+
+    let matches = |text: &str| dafsa.walk(Dafsa::ROOT, text).0;
+    assert_eq!(matches("ORDER"), Match::Exists);
+    assert_eq!(matches("ORDER BY"), Match::Exists);
+    assert_eq!(matches("ORDER B"), Match::Prefix);
+    assert_eq!(matches("GROUP"), Match::Prefix);
+    assert_eq!(matches("GROUPS"), Match::Failed);
+    assert_eq!(matches("/*"), Match::Exists);
+    assert_eq!(matches(""), Match::Failed);
+
+    // Keys that share a suffix share its states, but only match with their own prefix
+    assert_eq!(matches("GROUP BY"), Match::Exists);
+    assert_eq!(matches("BY"), Match::Failed);
+    assert_eq!(matches("ORDER BYE"), Match::Failed);
+
+    // Text is uppercased as it's matched, which can turn a char into several
+    assert_eq!(matches("Order by"), Match::Exists);
+    assert_eq!(matches("straße"), Match::Exists);
+    assert_eq!(matches("STRAẞE"), Match::Failed);
+}
+
+#[test]
+fn test_step() {
+    let dafsa = dafsa();
+
+    // A failed step stays in the state it was taken from, so the match can go on from there
+    let (result, state) = dafsa.walk(Dafsa::ROOT, "ORDER ");
+    assert_eq!(result, Match::Prefix);
+    assert_eq!(dafsa.step(state, 'X'), (Match::Failed, state));
+    assert_eq!(dafsa.step(state, 'b').0, Match::Prefix);
+    assert_eq!(dafsa.walk(state, "BY").0, Match::Exists);
+    assert_eq!(dafsa.step(Dafsa::ROOT, '-').0, Match::Prefix);
+}
+
+#[test]
+fn test_suggest() {
+    let dafsa = dafsa();
+    assert_eq!(dafsa.suggest("oder", 1), [("ORDER".to_string(), 1)]);
+    assert_eq!(
+        dafsa.suggest("ORDER B", 2),
+        [("ORDER BY".to_string(), 1), ("ORDER".to_string(), 2)]
+    );
+    assert!(dafsa.suggest("SELECT", 2).is_empty());
+}
@@ -0,0 +1,87 @@
+//! Checks what the scanner does with each of its optional features, e.g. with
+//! `cargo test -p sqlglotrs-core --no-default-features --features presets`.
+#![cfg(feature = "presets")]
+
+use sqlglotrs_core::presets;
+use sqlglotrs_core::token::RawToken;
+use sqlglotrs_core::tokenizer::TokenizerConfig;
+
+fn tokenize(dialect: &str, sql: &str) -> Vec<RawToken> {
+    let config = TokenizerConfig::new(
+        presets::tokenizer_settings(dialect).unwrap(),
+        presets::token_type_settings(dialect).unwrap(),
+    );
+    let (tokens, error) = config.tokenize_raw(sql, &presets::dialect_settings(dialect).unwrap());
+    assert!(error.is_none(), "{:?}", error);
+    tokens
+}
+
+fn texts(tokens: &[RawToken]) -> Vec<&str> {
+    tokens.iter().map(|token| &*token.text).collect()
+}
+
+#[test]
+fn test_comments() {
+    let tokens = tokenize("", "SELECT 1 /* c */ -- d");
+    let comments: Vec<&str> = tokens[1].comments.iter().map(|c| &**c).collect();
+    if cfg!(feature = "comments") {
+        assert_eq!(comments, [" c ", " d"]);
+    } else {
+        assert!(comments.is_empty());
+    }
+}
+
+#[test]
+fn test_format_strings() {
+    let tokens = tokenize("bigquery", "SELECT b'01'");
+    if cfg!(feature = "format-strings") {
+        assert_eq!(texts(&tokens), ["SELECT", "01"]);
+    } else {
+        assert_eq!(texts(&tokens), ["SELECT", "b", "01"]);
+    }
+}
+
+#[test]
+fn test_heredoc_strings() {
+    let tokens = tokenize("postgres", "SELECT $a$ hi $a$, E'x'");
+    if cfg!(feature = "heredoc-strings") {
+        assert_eq!(texts(&tokens), ["SELECT", " hi ", ",", "x"]);
+    } else if cfg!(feature = "format-strings") {
+        assert_eq!(
+            texts(&tokens),
+            ["SELECT", "$", "a$", "hi", "$", "a$", ",", "x"]
+        );
+    } else {
+        assert_eq!(
+            texts(&tokens),
+            ["SELECT", "$", "a$", "hi", "$", "a$", ",", "E", "x"]
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    use sqlglotrs_core::settings::{TokenTypeSettings, TokenizerSettings};
+
+    // Settings loaded back from JSON scan the same tokens
+    let settings = presets::tokenizer_settings("postgres").unwrap();
+    let token_types = presets::token_type_settings("postgres").unwrap();
+    let settings: TokenizerSettings =
+        serde_json::from_str(&serde_json::to_string(&settings).unwrap()).unwrap();
+    let token_types: TokenTypeSettings =
+        serde_json::from_str(&serde_json::to_string(&token_types).unwrap()).unwrap();
+    let config = TokenizerConfig::new(settings, token_types);
+    let sql = "SELECT 'é', $a$ x $a$ /* c */ FROM t";
+    let (tokens, error) = config.tokenize_raw(sql, &presets::dialect_settings("postgres").unwrap());
+    assert!(error.is_none(), "{:?}", error);
+    let expected = serde_json::to_value(tokenize("postgres", sql)).unwrap();
+    assert_eq!(serde_json::to_value(&tokens).unwrap(), expected);
+
+    // Texts are written as strings, and tokens read back from JSON write the same JSON
+    assert_eq!(expected[1]["text"], "é");
+    assert_eq!(expected[1]["start_byte"], 7);
+    let tokens: Vec<RawToken> = serde_json::from_value(expected.clone()).unwrap();
+    assert_eq!(texts(&tokens)[1], "é");
+    assert_eq!(serde_json::to_value(&tokens).unwrap(), expected);
+}
@@ -0,0 +1,176 @@
+//! Calls the C ABI the way a host program would, e.g. with
+//! `cargo test -p sqlglotrs-core --features ffi`.
+#![cfg(all(feature = "ffi", feature = "presets"))]
+
+use sqlglotrs_core::ffi::*;
+use sqlglotrs_core::presets;
+use sqlglotrs_core::tokenizer::TokenizerConfig;
+use std::ffi::{c_char, CStr};
+use std::ptr;
+
+unsafe fn text<'a>(data: *const c_char, len: usize) -> &'a str {
+    std::str::from_utf8(std::slice::from_raw_parts(data.cast(), len)).unwrap()
+}
+
+unsafe fn c_str<'a>(data: *const c_char) -> Option<&'a str> {
+    (!data.is_null()).then(|| CStr::from_ptr(data).to_str().unwrap())
+}
+
+/// The type name, text, position and comments of every token of a scan.
+unsafe fn summary(tokens: *const SqlglotTokens) -> Vec<(String, String, [usize; 4], Vec<String>)> {
+    (0..sqlglot_tokens_len(tokens))
+        .map(|i| {
+            let mut len = 0;
+            let token_text = text(sqlglot_token_text(tokens, i, &mut len), len).to_string();
+            let mut position = [0; 4];
+            let [line, col, start, end] = &mut position;
+            sqlglot_token_position(tokens, i, line, col, start, end);
+            let comments = (0..sqlglot_token_comments_len(tokens, i))
+                .map(|j| text(sqlglot_token_comment(tokens, i, j, &mut len), len).to_string())
+                .collect();
+            let type_name = c_str(sqlglot_token_type_name(sqlglot_token_type(tokens, i)));
+            (
+                type_name.unwrap().to_string(),
+                token_text,
+                position,
+                comments,
+            )
+        })
+        .collect()
+}
+
+unsafe fn tokenize(tokenizer: *const SqlglotTokenizer, sql: &str) -> *mut SqlglotTokens {
+    sqlglot_tokenize(tokenizer, sql.as_ptr().cast(), sql.len())
+}
+
+#[test]
+fn test_tokenize() {
+    unsafe {
+        let tokenizer = sqlglot_tokenizer_for_dialect(c"postgres".as_ptr());
+        assert!(!tokenizer.is_null());
+
+        let tokens = tokenize(tokenizer, "SELECT 'é' /* c */\nFROM t");
+        assert_eq!(c_str(sqlglot_tokens_error(tokens)), None);
+        assert_eq!(
+            summary(tokens),
+            [
+                ("SELECT".into(), "SELECT".into(), [1, 6, 0, 5], vec![]),
+                (
+                    "STRING".into(),
+                    "é".into(),
+                    [1, 10, 7, 9],
+                    vec![" c ".into()]
+                ),
+                ("FROM".into(), "FROM".into(), [2, 4, 19, 22], vec![]),
+                ("VAR".into(), "t".into(), [2, 6, 24, 24], vec![]),
+            ]
+        );
+        sqlglot_tokens_free(tokens);
+
+        // Failed scans keep the tokens before their error
+        let tokens = tokenize(tokenizer, "SELECT 1, 'x");
+        assert_eq!(sqlglot_tokens_len(tokens), 3);
+        assert_eq!(
+            c_str(sqlglot_tokens_error_kind(tokens)),
+            Some("unterminated")
+        );
+        assert!(c_str(sqlglot_tokens_error(tokens))
+            .unwrap()
+            .ends_with("Missing ' from 1:12"));
+        sqlglot_tokens_free(tokens);
+
+        let tokens = sqlglot_tokenize(tokenizer, b"SELECT \xff".as_ptr().cast(), 8);
+        assert_eq!(sqlglot_tokens_len(tokens), 0);
+        assert_eq!(
+            c_str(sqlglot_tokens_error_kind(tokens)),
+            Some("invalid_utf8")
+        );
+        sqlglot_tokens_free(tokens);
+
+        let tokens = sqlglot_tokenize(tokenizer, ptr::null(), 0);
+        assert_eq!(sqlglot_tokens_len(tokens), 0);
+        assert_eq!(c_str(sqlglot_tokens_error(tokens)), None);
+        sqlglot_tokens_free(tokens);
+
+        sqlglot_tokenizer_free(tokenizer);
+        sqlglot_tokens_free(ptr::null_mut());
+        sqlglot_tokenizer_free(ptr::null_mut());
+    }
+}
+
+#[test]
+fn test_settings() {
+    unsafe {
+        assert!(sqlglot_tokenizer_for_dialect(c"nope".as_ptr()).is_null());
+        let tokenizer = sqlglot_tokenizer_for_dialect(c"".as_ptr());
+
+        sqlglot_tokenizer_set_comment_tokens(tokenizer, true);
+        let tokens = tokenize(tokenizer, "SELECT 1 -- c");
+        let comment = &summary(tokens)[2];
+        assert_eq!(
+            (comment.0.as_str(), comment.1.as_str()),
+            ("STANDALONE_COMMENT", " c")
+        );
+        sqlglot_tokens_free(tokens);
+        sqlglot_tokenizer_set_comment_tokens(tokenizer, false);
+
+        // Columns are counted in the given units, while offsets stay in chars
+        assert!(!sqlglot_tokenizer_set_position_encoding(
+            tokenizer,
+            c"utf7".as_ptr()
+        ));
+        assert!(sqlglot_tokenizer_set_position_encoding(
+            tokenizer,
+            c"utf16".as_ptr()
+        ));
+        let tokens = tokenize(tokenizer, "SELECT '😀'");
+        assert_eq!(summary(tokens)[1].2, [1, 11, 7, 9]);
+        sqlglot_tokens_free(tokens);
+
+        sqlglot_tokenizer_set_limits(tokenizer, 0, 2);
+        let tokens = tokenize(tokenizer, "SELECT 1, 2");
+        assert_eq!(
+            c_str(sqlglot_tokens_error_kind(tokens)),
+            Some("too_many_tokens")
+        );
+        sqlglot_tokens_free(tokens);
+        sqlglot_tokenizer_set_limits(tokenizer, 0, 0);
+        let tokens = tokenize(tokenizer, "SELECT 1, 2");
+        assert_eq!(sqlglot_tokens_len(tokens), 4);
+        sqlglot_tokens_free(tokens);
+
+        sqlglot_tokenizer_free(tokenizer);
+    }
+}
+
+#[test]
+fn test_from_snapshot() {
+    let snapshot = TokenizerConfig::new(
+        presets::tokenizer_settings("mysql").unwrap(),
+        presets::token_type_settings("mysql").unwrap(),
+    )
+    .to_snapshot();
+
+    unsafe {
+        let tokenizer =
+            sqlglot_tokenizer_from_snapshot(snapshot.as_ptr(), snapshot.len(), c"mysql".as_ptr());
+        let tokens = tokenize(tokenizer, "SELECT `a` # c");
+        assert_eq!(
+            summary(tokens)[1],
+            (
+                "IDENTIFIER".into(),
+                "a".into(),
+                [1, 10, 7, 9],
+                vec![" c".into()]
+            )
+        );
+        sqlglot_tokens_free(tokens);
+        sqlglot_tokenizer_free(tokenizer);
+
+        let tokenizer = sqlglot_tokenizer_from_snapshot(snapshot.as_ptr(), 3, ptr::null());
+        assert!(tokenizer.is_null());
+        let tokenizer =
+            sqlglot_tokenizer_from_snapshot(snapshot.as_ptr(), snapshot.len(), c"nope".as_ptr());
+        assert!(tokenizer.is_null());
+    }
+}
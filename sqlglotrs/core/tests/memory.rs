@@ -0,0 +1,115 @@
+//! Bounds the memory that scans take, with an allocator that tracks the most of it in use at once.
+#![cfg(feature = "presets")]
+
+use sqlglotrs_core::input::SqlFile;
+use sqlglotrs_core::presets;
+use sqlglotrs_core::tokenizer::{BatchScan, ReaderScan, TokenizerConfig};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+struct PeakAllocator;
+
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for PeakAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let in_use = IN_USE.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+        PEAK.fetch_max(in_use, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        IN_USE.fetch_sub(layout.size(), Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAllocator = PeakAllocator;
+
+// Tests measuring at the same time would count each other's allocations
+static MEASURING: Mutex<()> = Mutex::new(());
+
+/// The most memory that `f` had in use at once on top of what was in use before.
+fn peak_allocated<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let _guard = MEASURING.lock().unwrap_or_else(|error| error.into_inner());
+    let before = IN_USE.load(Ordering::SeqCst);
+    PEAK.store(before, Ordering::SeqCst);
+    let result = f();
+    (result, PEAK.load(Ordering::SeqCst) - before)
+}
+
+fn config() -> TokenizerConfig {
+    TokenizerConfig::new(
+        presets::tokenizer_settings("").unwrap(),
+        presets::token_type_settings("").unwrap(),
+    )
+}
+
+#[test]
+fn test_unicode_scan() {
+    let config = config();
+    let dialect_settings = presets::dialect_settings("").unwrap();
+    let sql = format!("SELECT '{}', x FROM t", "é".repeat(500_000));
+
+    // Queries that aren't ASCII are scanned as UTF-8 rather than decoded into chars, which
+    // would take twice their size here on top of the copy that texts are spans of and of the
+    // string's text as it's built
+    let ((tokens, error), peak) = peak_allocated(|| config.tokenize_raw(&sql, &dialect_settings));
+    assert!(error.is_none());
+    assert_eq!(tokens[1].text.chars().count(), 500_000);
+    assert!(peak < sql.len() * 4, "{} bytes for {}", peak, sql.len());
+}
+
+#[test]
+fn test_batch_scan() {
+    let config = config();
+    let dialect_settings = presets::dialect_settings("").unwrap();
+    let sql = "SELECT a, 'b' FROM t;\n".repeat(50_000);
+
+    // The query is only kept once between batches, as the copy that texts are spans of
+    let (count, peak) = peak_allocated(|| {
+        let mut scan = BatchScan::new(&config, &sql, &dialect_settings);
+        let mut count = 0;
+        loop {
+            let (tokens, error) = scan.next_batch(&config, &dialect_settings, 1000);
+            assert!(error.is_none());
+            if tokens.is_empty() {
+                break count;
+            }
+            count += tokens.len();
+        }
+    });
+    assert_eq!(count, 350_000);
+    assert!(peak < sql.len() * 3 / 2, "{} bytes for {}", peak, sql.len());
+}
+
+#[test]
+fn test_file_scan() {
+    let config = config();
+    let dialect_settings = presets::dialect_settings("").unwrap();
+    let sql = "SELECT a, 'é' FROM t;\n".repeat(400_000);
+    let path = std::env::temp_dir().join(format!("sqlglotrs-memory-{}.sql", std::process::id()));
+    std::fs::write(&path, &sql).unwrap();
+
+    // Files are read, decoded and scanned a chunk at a time, so that only the text since the last
+    // complete statement and its tokens are in memory rather than a copy of the whole file, which
+    // takes the same here however long the file is
+    let (count, peak) = peak_allocated(|| {
+        let mut scan = ReaderScan::new(SqlFile::open(&path).unwrap(), 16 * 1024);
+        let mut count = 0;
+        loop {
+            let (tokens, error) = scan.next_batch(&config, &dialect_settings).unwrap();
+            assert!(error.is_none());
+            if tokens.is_empty() {
+                break count;
+            }
+            count += tokens.len();
+        }
+    });
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(count, 2_800_000);
+    assert!(peak < sql.len() / 2, "{} bytes for {}", peak, sql.len());
+}
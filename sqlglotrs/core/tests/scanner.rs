@@ -0,0 +1,250 @@
+//! Uses the scanner the way Rust programs embedding it do, without any Python.
+#![cfg(feature = "presets")]
+
+use sqlglotrs_core::presets;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::statement::statements;
+use sqlglotrs_core::token::{RawToken, TokenText};
+use sqlglotrs_core::tokenizer::{ReaderScan, TokenizerConfig};
+use sqlglotrs_core::words::Words;
+use std::sync::Arc;
+
+fn config(dialect: &str) -> TokenizerConfig {
+    TokenizerConfig::new(
+        presets::tokenizer_settings(dialect).unwrap(),
+        presets::token_type_settings(dialect).unwrap(),
+    )
+}
+
+#[test]
+fn test_tokenize() {
+    let config = config("postgres");
+    let dialect_settings = presets::dialect_settings("postgres").unwrap();
+    let (tokens, error) = config.tokenize_raw("SELECT 'é', $1\nFROM t", &dialect_settings);
+    assert!(error.is_none());
+
+    let positions: Vec<_> = tokens
+        .iter()
+        .map(|token| {
+            (
+                &*token.text,
+                token.start_line,
+                token.start_col,
+                token.line,
+                token.col,
+                token.start,
+                token.end,
+                token.start_byte,
+                token.end_byte,
+            )
+        })
+        .collect();
+    assert_eq!(
+        positions,
+        [
+            ("SELECT", 1, 1, 1, 6, 0, 5, 0, 6),
+            ("é", 1, 8, 1, 10, 7, 9, 7, 11),
+            (",", 1, 11, 1, 11, 10, 10, 11, 12),
+            ("$", 1, 13, 1, 13, 12, 12, 13, 14),
+            ("1", 1, 14, 1, 14, 13, 13, 14, 15),
+            ("FROM", 2, 1, 2, 4, 15, 18, 16, 20),
+            ("t", 2, 6, 2, 6, 20, 20, 21, 22),
+        ]
+    );
+    assert_eq!(tokens[1].token_type, config.token_types.string);
+
+    let (tokens, error) = config.tokenize_raw("SELECT 'x", &dialect_settings);
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+        error.unwrap().to_string(),
+        "Error tokenizing 'SELECT '': Missing ' from 1:9"
+    );
+}
+
+#[test]
+fn test_statements() {
+    let config = config("");
+    let sql = "SELECT 1; ; BEGIN SELECT 2; END; SELECT (3;)";
+    let (tokens, _) = config.tokenize_raw(sql, &presets::dialect_settings("").unwrap());
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+
+    let texts: Vec<&str> = statements(&words)
+        .into_iter()
+        .map(|statement| words.text(statement))
+        .collect();
+    assert_eq!(texts, ["SELECT 1", "BEGIN SELECT 2; END", "SELECT (3;)"]);
+}
+
+#[test]
+fn test_reader_scan() {
+    let config = config("");
+    let dialect_settings = presets::dialect_settings("").unwrap();
+    let sql = "SELECT 1;\nSELECT 'é';  SELECT 3; SELECT 4";
+    let mut scan = ReaderScan::new(sql.as_bytes(), 4);
+
+    // The texts of the batches make up the input, and their tokens are spans of them once moved
+    // back by the batches before
+    let (mut chars, mut bytes) = (0, 0);
+    let mut texts = Vec::new();
+    loop {
+        let (tokens, error) = scan.next_batch(&config, &dialect_settings).unwrap();
+        assert!(error.is_none());
+        if tokens.is_empty() {
+            break;
+        }
+        let text = scan.scanned_text();
+        let source = Source::new(text);
+        for token in &tokens {
+            assert_eq!(
+                &text[token.start_byte - bytes..token.end_byte - bytes],
+                source.text(token.start - chars..token.end + 1 - chars)
+            );
+        }
+        chars += source.char_count();
+        bytes += text.len();
+        texts.push(text.to_string());
+    }
+    assert_eq!(texts, ["SELECT 1;\nSELECT 'é';", "  SELECT 3; SELECT 4"]);
+}
+
+#[test]
+fn test_long_bodies() {
+    // Bodies are skipped over up to the next delimiter, escape or line break, which mustn't throw
+    // off the positions of what follows them
+    let body = "ab\r\n".repeat(500);
+    let tokenize = |dialect: &str, sql: &str| {
+        let (tokens, error) =
+            config(dialect).tokenize_raw(sql, &presets::dialect_settings(dialect).unwrap());
+        assert!(error.is_none(), "{:?}", error);
+        tokens
+    };
+    let position = |token: &RawToken| (token.line, token.col, token.start, token.end);
+
+    let tokens = tokenize("", &format!("SELECT '{}it''s\\\\', x", body));
+    assert_eq!(tokens[1].text.len(), 2006);
+    assert!(tokens[1].text.ends_with("\r\nit's\\\\"));
+    assert_eq!(position(&tokens[1]), (501, 8, 7, 2015));
+    assert_eq!(position(&tokens[3]), (501, 11, 2018, 2018));
+
+    let tokens = tokenize("", &format!("SELECT 'é{}', x", body));
+    assert_eq!(position(&tokens[1]), (501, 1, 7, 2009));
+    assert_eq!(tokens[1].end_byte, 2011);
+
+    // Comments stop at their end, at nested comments and at line breaks
+    let sql = format!(
+        "SELECT /* {} /* é */ */ 1 -- {}\n, y",
+        body,
+        "z".repeat(300)
+    );
+    let tokens = tokenize("postgres", &sql);
+    assert_eq!(tokens[0].comments[0].len(), 2011);
+    assert!(tokens[0].comments[0].ends_with("\n /* é */ "));
+    assert_eq!(position(&tokens[1]), (501, 13, 2022, 2022));
+    assert_eq!(&*tokens[1].comments[0], format!(" {}", "z".repeat(300)));
+    assert_eq!(position(&tokens[3]), (502, 3, 2330, 2330));
+
+    let (tokens, error) = config("").tokenize_raw(
+        &format!("SELECT 1, '{}", body),
+        &presets::dialect_settings("").unwrap(),
+    );
+    assert_eq!(tokens.len(), 3);
+    assert!(error
+        .unwrap()
+        .to_string()
+        .ends_with("Missing ' from 500:2011"));
+}
+
+#[test]
+fn test_token_texts() {
+    let config = config("");
+    let sql = "SELECT a, \"b\"\"c\", 'it''s', 'é' FROM t";
+    let (tokens, error) = config.tokenize_raw(sql, &presets::dialect_settings("").unwrap());
+    assert!(error.is_none());
+
+    // Words are spans of one copy of the query, at their byte offsets, while texts that differ
+    // from the query, e.g. those of strings with escapes, are allocated on their own
+    let base = tokens[0].text.as_ptr() as usize;
+    let is_span = |token: &RawToken| {
+        (token.text.as_ptr() as usize).wrapping_sub(base) == token.start_byte
+            && token.text.len() == token.end_byte - token.start_byte
+    };
+    let spans: Vec<bool> = tokens.iter().map(is_span).collect();
+    assert_eq!(
+        [spans[0], spans[1], spans[3], spans[5], spans[8], spans[9]],
+        [true, true, false, false, true, true]
+    );
+    let texts: Vec<&str> = tokens.iter().map(|token| &*token.text).collect();
+    assert_eq!(
+        texts,
+        ["SELECT", "a", ",", "b\"c", ",", "it's", ",", "é", "FROM", "t"]
+    );
+
+    // Texts compare by their chars wherever they're from
+    let copy = TokenText::from(String::from("it's"));
+    assert_eq!(tokens[5].text, copy);
+    assert_eq!(tokens[5].text, "it's");
+    let span = TokenText::span(&Arc::from(sql), 18..22);
+    assert_eq!((&*span, span.len()), ("'it'", 4));
+    assert_eq!(TokenText::default(), "");
+}
+
+#[test]
+fn test_keywords() {
+    let config = config("");
+    let keyword = |key: &str| config.settings.keywords[key];
+    let scan = |sql: &str| {
+        let (tokens, error) = config.tokenize_raw(sql, &presets::dialect_settings("").unwrap());
+        assert!(error.is_none(), "{:?}", error);
+        tokens
+            .into_iter()
+            .map(|token| {
+                (
+                    token.token_type,
+                    token.text.to_string(),
+                    token.start,
+                    token.end,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Keywords match in any case and across runs of whitespace, which their texts collapse
+    let tokens = scan("select a fRoM t Group\n  bY a ORDER  by b");
+    assert_eq!(tokens[0], (keyword("SELECT"), "select".to_string(), 0, 5));
+    assert_eq!(tokens[2], (keyword("FROM"), "fRoM".to_string(), 9, 12));
+    assert_eq!(
+        tokens[4],
+        (keyword("GROUP BY"), "Group bY".to_string(), 16, 25)
+    );
+    assert_eq!(
+        tokens[6],
+        (keyword("ORDER BY"), "ORDER by".to_string(), 29, 37)
+    );
+
+    // Words that only start like a keyword are vars, as are the words of a multi-word keyword
+    // whose last word runs on
+    let (var, comma) = (config.token_types.var, config.settings.single_tokens[&',']);
+    let tokens = scan("SELECT groups, order_by, é, orderby FROM t order byx");
+    let texts: Vec<(u16, &str)> = tokens
+        .iter()
+        .map(|(token_type, text, ..)| (*token_type, text.as_str()))
+        .collect();
+    assert_eq!(
+        texts,
+        [
+            (keyword("SELECT"), "SELECT"),
+            (var, "groups"),
+            (comma, ","),
+            (var, "order_by"),
+            (comma, ","),
+            (var, "é"),
+            (comma, ","),
+            (var, "orderby"),
+            (keyword("FROM"), "FROM"),
+            (var, "t"),
+            (var, "order"),
+            (var, "byx"),
+        ]
+    );
+}
@@ -0,0 +1,98 @@
+//! Checks the JavaScript API natively, e.g. with `cargo test -p sqlglotrs-core --features wasm`.
+//! Errors can only be created on wasm targets, so only scans that succeed are checked here.
+#![cfg(all(feature = "wasm", feature = "presets"))]
+
+use sqlglotrs_core::presets;
+use sqlglotrs_core::tokenizer::TokenizerConfig;
+use sqlglotrs_core::wasm::{Token, Tokenizer};
+
+fn summary(tokens: &[Token]) -> Vec<(&str, &str, usize, usize, usize, usize)> {
+    tokens
+        .iter()
+        .map(|token| {
+            (
+                token.type_name.as_str(),
+                token.text.as_str(),
+                token.line,
+                token.col,
+                token.start,
+                token.end,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_tokenize() {
+    let Ok(tokenizer) = Tokenizer::for_dialect("postgres") else {
+        panic!("postgres has a preset");
+    };
+    let Ok(tokens) = tokenizer.tokenize("SELECT 'é' /* c */\nFROM t") else {
+        panic!("the query can be tokenized");
+    };
+
+    // Positions are char offsets, as in sqlglot
+    assert_eq!(
+        summary(&tokens),
+        [
+            ("SELECT", "SELECT", 1, 6, 0, 5),
+            ("STRING", "é", 1, 10, 7, 9),
+            ("FROM", "FROM", 2, 4, 19, 22),
+            ("VAR", "t", 2, 6, 24, 24),
+        ]
+    );
+    assert_eq!(tokens[1].comments, [" c "]);
+    assert_eq!(
+        Some(tokens[0].token_type),
+        presets::tokenizer_settings("postgres")
+            .unwrap()
+            .keywords
+            .get("SELECT")
+            .copied()
+    );
+}
+
+#[test]
+fn test_tokenize_lenient() {
+    let Ok(tokenizer) = Tokenizer::for_dialect("mysql") else {
+        panic!("mysql has a preset");
+    };
+    let Ok(tokens) = tokenizer.tokenize_lenient("SELECT 'abc FROM t") else {
+        panic!("lenient scans only fail on limits");
+    };
+    assert_eq!(
+        summary(&tokens),
+        [
+            ("SELECT", "SELECT", 1, 6, 0, 5),
+            ("ERROR", "'abc", 1, 11, 7, 10),
+            ("FROM", "FROM", 1, 16, 12, 15),
+            ("VAR", "t", 1, 18, 17, 17),
+        ]
+    );
+}
+
+#[test]
+fn test_from_snapshot() {
+    let config = TokenizerConfig::new(
+        presets::tokenizer_settings("mysql").unwrap(),
+        presets::token_type_settings("mysql").unwrap(),
+    );
+    let snapshot = config.to_snapshot();
+
+    // Backticks quote identifiers in MySQL, and `#` only starts comments with its settings
+    let sql = "SELECT `a` # c";
+    let Ok(tokenizer) = Tokenizer::from_snapshot(&snapshot, Some("mysql".to_string())) else {
+        panic!("the snapshot can be read");
+    };
+    let Ok(tokens) = tokenizer.tokenize(sql) else {
+        panic!("the query can be tokenized");
+    };
+    assert_eq!(
+        summary(&tokens),
+        [
+            ("SELECT", "SELECT", 1, 6, 0, 5),
+            ("IDENTIFIER", "a", 1, 10, 7, 9),
+        ]
+    );
+    assert_eq!(tokens[1].comments, [" c"]);
+}
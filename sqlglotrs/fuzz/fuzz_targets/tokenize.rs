@@ -0,0 +1,39 @@
+//! Scans arbitrary bytes with the settings of a builtin dialect, which the first byte picks, with
+//! and without comments as tokens.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sqlglotrs_core::presets;
+use sqlglotrs_core::settings::TokenizerDialectSettings;
+use sqlglotrs_core::tokenizer::TokenizerConfig;
+use sqlglotrs_fuzz::check_scans;
+use std::sync::{Arc, OnceLock};
+
+static DIALECTS: OnceLock<Vec<(Arc<TokenizerConfig>, TokenizerDialectSettings)>> = OnceLock::new();
+
+fuzz_target!(|data: &[u8]| {
+    let dialects = DIALECTS.get_or_init(|| {
+        presets::dialects()
+            .map(|dialect| {
+                let config = TokenizerConfig::cached(
+                    presets::tokenizer_settings(dialect).unwrap(),
+                    presets::token_type_settings(dialect).unwrap(),
+                );
+                (config, presets::dialect_settings(dialect).unwrap())
+            })
+            .collect()
+    });
+    let Some((&dialect, data)) = data.split_first() else {
+        return;
+    };
+    let (config, dialect_settings) = &dialects[dialect as usize % dialects.len()];
+    let sql = String::from_utf8_lossy(data);
+
+    check_scans(config, &sql, dialect_settings);
+    let dialect_settings = TokenizerDialectSettings {
+        comment_tokens: true,
+        ..dialect_settings.clone()
+    };
+    check_scans(config, &sql, &dialect_settings);
+});
@@ -0,0 +1,113 @@
+//! Scans arbitrary queries with arbitrary permutations of a builtin dialect's settings, i.e. with
+//! its flags flipped and extra quotes, comments, identifiers and escapes, so that combinations no
+//! dialect has are covered too.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use sqlglotrs_core::presets;
+use sqlglotrs_core::settings::{ControlCharacters, NulBytes};
+use sqlglotrs_core::tokenizer::TokenizerConfig;
+use sqlglotrs_fuzz::check_scans;
+use std::sync::Arc;
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    dialect: u8,
+    sql: String,
+    settings: Settings,
+    dialect_settings: DialectSettings,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Settings {
+    nested_comments: bool,
+    heredoc_tag_is_identifier: bool,
+    string_escapes_allowed_in_raw_strings: bool,
+    has_bit_strings: bool,
+    has_hex_strings: bool,
+    quotes: Vec<(String, String)>,
+    comments: Vec<(String, Option<String>)>,
+    identifiers: Vec<(char, char)>,
+    string_escapes: Vec<char>,
+    identifier_escapes: Vec<char>,
+    hint_start: Option<String>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct DialectSettings {
+    unescaped_sequences: Vec<(String, String)>,
+    identifiers_can_start_with_digit: bool,
+    numbers_can_be_underscore_separated: bool,
+    unicode_identifiers: bool,
+    versioned_comments_version: Option<u32>,
+    control_characters: u8,
+    nul_bytes: u8,
+    max_tokens: Option<u8>,
+    comment_tokens: bool,
+}
+
+// Keeps the definitions small, as the automaton is rebuilt for each input
+const MAX_EXTRAS: usize = 4;
+
+fuzz_target!(|input: Input| {
+    let dialects: Vec<&str> = presets::dialects().collect();
+    let dialect = dialects[input.dialect as usize % dialects.len()];
+    let mut settings = presets::tokenizer_settings(dialect).unwrap();
+    let mut dialect_settings = presets::dialect_settings(dialect).unwrap();
+
+    let extra = input.settings;
+    settings.nested_comments = extra.nested_comments;
+    settings.heredoc_tag_is_identifier = extra.heredoc_tag_is_identifier;
+    settings.string_escapes_allowed_in_raw_strings = extra.string_escapes_allowed_in_raw_strings;
+    settings.has_bit_strings = extra.has_bit_strings;
+    settings.has_hex_strings = extra.has_hex_strings;
+    let non_empty = |(start, end): &(String, String)| !start.is_empty() && !end.is_empty();
+    Arc::make_mut(&mut settings.quotes)
+        .extend(extra.quotes.into_iter().filter(non_empty).take(MAX_EXTRAS));
+    Arc::make_mut(&mut settings.comments).extend(
+        extra
+            .comments
+            .into_iter()
+            .filter(|(start, end)| !start.is_empty() && end.as_ref().is_none_or(|e| !e.is_empty()))
+            .take(MAX_EXTRAS),
+    );
+    Arc::make_mut(&mut settings.identifiers).extend(extra.identifiers.into_iter().take(MAX_EXTRAS));
+    Arc::make_mut(&mut settings.string_escapes)
+        .extend(extra.string_escapes.into_iter().take(MAX_EXTRAS));
+    Arc::make_mut(&mut settings.identifier_escapes)
+        .extend(extra.identifier_escapes.into_iter().take(MAX_EXTRAS));
+    if let Some(hint_start) = extra.hint_start.filter(|hint_start| !hint_start.is_empty()) {
+        settings.hint_start = hint_start;
+    }
+
+    let extra = input.dialect_settings;
+    dialect_settings.unescaped_sequences.extend(
+        extra
+            .unescaped_sequences
+            .into_iter()
+            .filter(|(sequence, _)| !sequence.is_empty())
+            .take(MAX_EXTRAS),
+    );
+    dialect_settings.identifiers_can_start_with_digit = extra.identifiers_can_start_with_digit;
+    dialect_settings.numbers_can_be_underscore_separated =
+        extra.numbers_can_be_underscore_separated;
+    dialect_settings.unicode_identifiers = extra.unicode_identifiers;
+    dialect_settings.versioned_comments_version = extra.versioned_comments_version.map(u64::from);
+    dialect_settings.control_characters = match extra.control_characters % 3 {
+        0 => ControlCharacters::Keep,
+        1 => ControlCharacters::Skip,
+        _ => ControlCharacters::Error,
+    };
+    dialect_settings.nul_bytes = match extra.nul_bytes % 3 {
+        0 => NulBytes::Text,
+        1 => NulBytes::Strip,
+        _ => NulBytes::Error,
+    };
+    dialect_settings.max_tokens = extra.max_tokens.map(usize::from);
+    dialect_settings.comment_tokens = extra.comment_tokens;
+
+    let config = TokenizerConfig::new(settings, presets::token_type_settings(dialect).unwrap());
+    check_scans(&config, &input.sql, &dialect_settings);
+});
@@ -0,0 +1,103 @@
+//! The checks the fuzz targets run on every scan, which are run with e.g.
+//!
+//! ```text
+//! cargo +nightly fuzz run tokenize
+//! cargo +nightly fuzz run tokenize_settings
+//! ```
+//!
+//! from the `sqlglotrs` directory. Besides not panicking, a scan has to produce tokens whose spans
+//! are in the query and in order, and that tile it when comments are tokens of their own, i.e.
+//! only whitespace is left between them.
+
+use sqlglotrs_core::settings::{ControlCharacters, NulBytes, TokenizerDialectSettings};
+use sqlglotrs_core::token::RawToken;
+use sqlglotrs_core::tokenizer::{TokenizerConfig, TokenizerError};
+
+/// Scans `sql` both strictly and leniently and checks the results.
+pub fn check_scans(
+    config: &TokenizerConfig,
+    sql: &str,
+    dialect_settings: &TokenizerDialectSettings,
+) {
+    let (tokens, error) = config.tokenize_raw(sql, dialect_settings);
+    check_tokens(sql, dialect_settings, &tokens, error.as_ref());
+
+    let (tokens, error) = config.tokenize_lenient_raw(sql, dialect_settings);
+    if let Some(error) = &error {
+        assert!(
+            error.kind.is_limit(),
+            "lenient scans only fail at limits: {error}"
+        );
+    }
+    check_tokens(sql, dialect_settings, &tokens, error.as_ref());
+}
+
+/// Checks the tokens of a scan of `sql`, which are those before the error if it failed.
+pub fn check_tokens(
+    sql: &str,
+    dialect_settings: &TokenizerDialectSettings,
+    tokens: &[RawToken],
+    error: Option<&TokenizerError>,
+) {
+    // Skipped control and NUL chars and the delimiters of versioned comments scanned as SQL
+    // aren't part of any token, so only whitespace is left between tokens without them
+    let tiles = dialect_settings.comment_tokens
+        && dialect_settings.control_characters == ControlCharacters::Keep
+        && dialect_settings.nul_bytes == NulBytes::Text
+        && dialect_settings.versioned_comments_version.is_none();
+
+    let char_offsets: Vec<usize> = sql.char_indices().map(|(i, _)| i).collect();
+    let mut covered = usize::from(sql.starts_with('\u{feff}')) * '\u{feff}'.len_utf8();
+    let mut previous: Option<&RawToken> = None;
+
+    for token in tokens {
+        assert!(
+            token.start_byte <= token.end_byte && token.end_byte <= sql.len(),
+            "{token:?} isn't in the query"
+        );
+        assert!(
+            sql.is_char_boundary(token.start_byte) && sql.is_char_boundary(token.end_byte),
+            "{token:?} doesn't start or end at a char"
+        );
+        assert_eq!(
+            char_offsets.get(token.start).copied().unwrap_or(sql.len()),
+            token.start_byte,
+            "{token:?} starts at another char than its byte span"
+        );
+
+        // Numbers with a type suffix, e.g. hive's `1L`, are split into tokens that share a span
+        if previous.is_some_and(|previous| {
+            (previous.start_byte, previous.end_byte) == (token.start_byte, token.end_byte)
+        }) {
+            continue;
+        }
+        assert!(
+            token.start_byte >= covered,
+            "{token:?} overlaps the token before it, {previous:?}"
+        );
+        if tiles {
+            assert!(
+                check_gap(sql, covered, token.start_byte),
+                "{token:?} was scanned past a NUL the scan should have stopped at"
+            );
+        }
+        covered = token.end_byte;
+        previous = Some(token);
+    }
+
+    if tiles && error.is_none() {
+        check_gap(sql, covered, sql.len());
+    }
+}
+
+/// Checks that only whitespace is between two tokens, returning false if the scan stopped in
+/// between, at a NUL where a token would start, which the scanner reads as the end of the query.
+fn check_gap(sql: &str, start: usize, end: usize) -> bool {
+    let gap = &sql[start..end];
+    let scanned = gap.find('\0').map_or(gap, |nul| &gap[..nul]);
+    assert!(
+        scanned.chars().all(char::is_whitespace),
+        "{gap:?} at byte {start} is neither whitespace nor part of a token"
+    );
+    scanned.len() == gap.len()
+}
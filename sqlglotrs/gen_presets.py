@@ -0,0 +1,236 @@
+"""
+Generates core/src/presets/data.rs, the settings of the builtin dialects that sqlglotrs embeds.
+
+Run it from the repository root with `python sqlglotrs/gen_presets.py` after changing a dialect's
+tokenizer or the members of `TokenType`, since the presets refer to token types by their ids.
+"""
+
+import os
+
+# The presets are generated from the Python definitions, whichever sqlglotrs is installed
+os.environ["SQLGLOTRS_TOKENIZER"] = "0"
+
+from sqlglot.dialects.dialect import Dialect  # noqa: E402
+from sqlglot.tokens import (  # noqa: E402
+    _TOKEN_TYPE_NAMES,
+    _rs_dialect_settings,
+    _rs_token_type_settings,
+    _rs_tokenizer_settings,
+)
+
+DIALECTS = [
+    "",
+    "bigquery",
+    "clickhouse",
+    "databricks",
+    "duckdb",
+    "hive",
+    "mysql",
+    "oracle",
+    "postgres",
+    "presto",
+    "redshift",
+    "snowflake",
+    "spark",
+    "sqlite",
+    "trino",
+    "tsql",
+]
+
+PATH = os.path.join(os.path.dirname(__file__), "core", "src", "presets", "data.rs")
+
+# The order of the fields of TokenTypeSettings
+TOKEN_TYPE_FIELDS = [
+    "bit_string",
+    "break_",
+    "dcolon",
+    "heredoc_string",
+    "raw_string",
+    "hex_string",
+    "identifier",
+    "number",
+    "parameter",
+    "semicolon",
+    "string",
+    "var",
+    "heredoc_string_alternative",
+    "hint",
+    "error",
+    "comment",
+    "placeholder",
+    "hint_end",
+]
+
+
+def escape(text, quote):
+    escaped = []
+    for c in text:
+        if c in ("\\", quote):
+            escaped.append("\\" + c)
+        elif c == "\n":
+            escaped.append("\\n")
+        elif c == "\r":
+            escaped.append("\\r")
+        elif c == "\t":
+            escaped.append("\\t")
+        elif c < " " or c == "\x7f":
+            escaped.append(f"\\u{{{ord(c):x}}}")
+        else:
+            escaped.append(c)
+    return "".join(escaped)
+
+
+def string(text):
+    return f'"{escape(text, chr(34))}"'
+
+
+def char(text):
+    assert len(text) == 1, text
+    return f"'{escape(text, chr(39))}'"
+
+
+def value(v):
+    if isinstance(v, bool):
+        return "true" if v else "false"
+    if isinstance(v, int):
+        return str(v)
+    if v is None:
+        return "None"
+    if isinstance(v, str):
+        return string(v)
+    raise TypeError(v)
+
+
+def items(entries, per_line=4):
+    inline = ", ".join(entries)
+    if len(inline) <= 72:
+        return f"&[{inline}]"
+    lines = [
+        "    " + " ".join(f"{entry}," for entry in entries[i : i + per_line])
+        for i in range(0, len(entries), per_line)
+    ]
+    return "&[\n" + "\n".join(lines) + "\n]"
+
+
+def char_map(table, to_value=value):
+    return items([f"({char(k)}, {to_value(v)})" for k, v in sorted(table.items())])
+
+
+def str_map(table, to_value=value):
+    return items([f"({string(k)}, {to_value(v)})" for k, v in sorted(table.items())])
+
+
+def settings_preset(name, settings):
+    fields = {
+        "white_space": char_map(settings["white_space"]),
+        "single_tokens": char_map(settings["single_tokens"]),
+        "keywords": str_map(settings["keywords"]),
+        "numeric_literals": str_map(settings["numeric_literals"]),
+        "identifiers": char_map(settings["identifiers"], char),
+        "identifier_escapes": items([char(c) for c in sorted(settings["identifier_escapes"])]),
+        "string_escapes": items([char(c) for c in sorted(settings["string_escapes"])]),
+        "quotes": str_map(settings["quotes"]),
+        "format_strings": items(
+            [
+                f"({string(k)}, {string(end)}, {token_type})"
+                for k, (end, token_type) in sorted(settings["format_strings"].items())
+            ]
+        ),
+        "verbatim_strings": items([string(k) for k in sorted(settings["verbatim_strings"])]),
+        "has_bit_strings": value(settings["has_bit_strings"]),
+        "has_hex_strings": value(settings["has_hex_strings"]),
+        "comments": str_map(
+            settings["comments"], lambda end: "None" if end is None else f"Some({string(end)})"
+        ),
+        "var_single_tokens": items([char(c) for c in sorted(settings["var_single_tokens"])]),
+        "placeholder_styles": items([string(k) for k in sorted(settings["placeholder_styles"])]),
+        "commands": items([value(t) for t in sorted(settings["commands"])], 16),
+        "command_prefix_tokens": items(
+            [value(t) for t in sorted(settings["command_prefix_tokens"])], 16
+        ),
+        "tokens_preceding_hint": items(
+            [value(t) for t in sorted(settings["tokens_preceding_hint"])], 16
+        ),
+        "heredoc_tag_is_identifier": value(settings["heredoc_tag_is_identifier"]),
+        "string_escapes_allowed_in_raw_strings": value(
+            settings["string_escapes_allowed_in_raw_strings"]
+        ),
+        "nested_comments": value(settings["nested_comments"]),
+        "hint_start": value(settings["hint_start"]),
+    }
+    body = "\n".join(f"{k}: {v}," for k, v in fields.items())
+    return f"static {name}: SettingsPreset = SettingsPreset {{\n{indent(body)}\n}};"
+
+
+def indent(text, prefix="    "):
+    return "\n".join(prefix + line if line.strip() else line for line in text.split("\n"))
+
+
+def preset(name, dialect, settings_name, token_types):
+    dialect_settings = _rs_dialect_settings(dialect)
+    token_type_body = "\n".join(f"{field}: {token_types[field]}," for field in TOKEN_TYPE_FIELDS)
+    fields = [
+        f"dialect: {string(name)},",
+        f"settings: &{settings_name},",
+        f"token_types: TokenTypeSettings {{\n{indent(token_type_body)}\n}},",
+        "unescaped_sequences: "
+        + str_map(dialect_settings["unescaped_sequences"])
+        + ",",
+        "identifiers_can_start_with_digit: "
+        + value(dialect_settings["identifiers_can_start_with_digit"])
+        + ",",
+        "numbers_can_be_underscore_separated: "
+        + value(dialect_settings["numbers_can_be_underscore_separated"])
+        + ",",
+        "unicode_identifiers: " + value(dialect_settings["unicode_identifiers"]) + ",",
+    ]
+    return "Preset {\n" + indent("\n".join(fields)) + "\n},"
+
+
+def generate():
+    settings_names = {}
+    statics = []
+    presets = []
+
+    for name in DIALECTS:
+        dialect = Dialect.get_or_raise(name)
+        tokenizer = dialect.tokenizer_class
+        # Dialects that don't define a tokenizer share their base's preset, see _Tokenizer
+        key = tokenizer._RS_PRESET
+        assert key in DIALECTS, f"The tokenizer of '{name}' would look up '{key}'"
+
+        if key not in settings_names:
+            settings_names[key] = (key or "default").upper()
+            statics.append(
+                settings_preset(settings_names[key], _rs_tokenizer_settings(tokenizer))
+            )
+
+        presets.append(
+            preset(name, dialect, settings_names[key], _rs_token_type_settings(tokenizer))
+        )
+
+    return "\n".join(
+        [
+            "// @generated by gen_presets.py, don't edit this file by hand",
+            "",
+            "use super::{Preset, SettingsPreset};",
+            "use crate::settings::TokenTypeSettings;",
+            "",
+            "pub(super) static PRESETS: &[Preset] = &[",
+            indent("\n".join(presets)),
+            "];",
+            "",
+            "pub(super) static TOKEN_TYPE_NAMES: &[&str] = "
+            + items([string(name) for name in _TOKEN_TYPE_NAMES], per_line=6)
+            + ";",
+            "",
+            "\n\n".join(statics),
+            "",
+        ]
+    )
+
+
+if __name__ == "__main__":
+    os.makedirs(os.path.dirname(PATH), exist_ok=True)
+    with open(PATH, "w", encoding="utf-8") as file:
+        file.write(generate())
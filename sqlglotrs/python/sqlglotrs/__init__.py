@@ -0,0 +1 @@
+from sqlglotrs.sqlglotrs import *  # noqa: F401,F403
@@ -0,0 +1,120 @@
+"""
+Measures sqlglotrs tokenizer throughput on this machine.
+
+Usage: python -m sqlglotrs.bench [--iterations N] [--dialect DIALECT ...]
+
+Two numbers are reported per dialect: the "rust" path is timed inside Rust around the scan itself,
+while the "python" path is timed from Python around `Tokenizer.tokenize` calls and so also includes
+argument extraction and the conversion of the result into Python objects.
+"""
+
+from __future__ import annotations
+
+import argparse
+import time
+import typing as t
+
+from sqlglotrs import time_tokenize
+
+ANALYTICS = """
+SELECT
+  "e"."employee_id" AS "Employee #",
+  "e"."first_name" || ' ' || "e"."last_name" AS "Name",
+  TO_CHAR("e"."hire_date", 'MM/DD/YYYY') AS "Hire Date",
+  'works as ' || "j"."job_title" || ' in ' || "d"."department_name" AS "Current Job",
+  CASE WHEN 1 THEN 1 WHEN 2 THEN 2 ELSE a(b(c + 1 * 3 % 4)) END /* trailing comment */
+FROM "employees" AS e
+JOIN "jobs" AS j
+  ON "e"."job_id" = "j"."job_id"
+LEFT JOIN "departments" AS d -- department lookup
+  ON "d"."department_id" = "e"."department_id"
+WHERE "e"."salary" BETWEEN 1000.50 AND 2E5
+ORDER BY
+  "e"."employee_id"
+"""
+
+CORPUS: t.Dict[str, t.List[str]] = {
+    "": [
+        ANALYTICS,
+        "INSERT INTO t (a, b) VALUES (1, 'x'), (2, 'y'); UPDATE t SET a = a + 1 WHERE b IS NOT NULL",
+    ],
+    "postgres": [
+        ANALYTICS,
+        "SELECT $body$ SELECT 1 $body$, E'\\n', x::INT, data->>'key' FROM t WHERE a ~* 'b'",
+        "CREATE FUNCTION f() RETURNS INT AS $$ SELECT 1 $$ LANGUAGE sql",
+    ],
+    "mysql": [
+        ANALYTICS.replace('"', "`"),
+        "SELECT `a`, \"b\", 'c\\'d', 0x1F, b'101' FROM t LIMIT 10 # trailing comment",
+    ],
+    "snowflake": [
+        ANALYTICS,
+        "SELECT v:payload.id::STRING, $1 FROM @stage (FILE_FORMAT => 'csv') QUALIFY ROW_NUMBER() OVER (ORDER BY 1) = 1",
+    ],
+    "bigquery": [
+        ANALYTICS.replace('"', "`"),
+        "SELECT r'raw\\string', b'bytes', ARRAY<INT64>[1, 2], STRUCT(1 AS a) FROM `project.dataset.table`",
+    ],
+    "duckdb": [
+        ANALYTICS,
+        "SELECT * EXCLUDE (a), list_transform([1, 2], x -> x + 1), 1_000_000 FROM 'file.parquet'",
+    ],
+}
+
+
+def _rs_tokenizer(dialect: str) -> t.Tuple[t.Any, t.Any]:
+    from sqlglot.dialects.dialect import Dialect
+
+    tokenizer = Dialect.get_or_raise(dialect).tokenizer()
+    if not tokenizer.use_rs_tokenizer or not tokenizer._RS_TOKENIZER:
+        raise RuntimeError("sqlglot is not configured to use the sqlglotrs tokenizer")
+
+    return tokenizer._RS_TOKENIZER, tokenizer._rs_dialect_settings
+
+
+def _time_python(
+    tokenizer: t.Any, queries: t.List[str], settings: t.Any, iterations: int
+) -> t.Tuple[int, int, int]:
+    elapsed = 0
+    size = 0
+    count = 0
+
+    for _ in range(iterations):
+        for sql in queries:
+            start = time.perf_counter_ns()
+            tokens, _ = tokenizer.tokenize(sql, settings)
+            elapsed += time.perf_counter_ns() - start
+            size += len(sql.encode())
+            count += len(tokens)
+
+    return elapsed, size, count
+
+
+def _format(path: str, dialect: str, elapsed: int, size: int, count: int) -> str:
+    seconds = max(elapsed, 1) / 1e9
+    mb_per_second = size / seconds / 1e6
+    tokens_per_second = count / seconds
+    return f"{dialect or 'sqlglot':<12} {path:<8} {mb_per_second:>10.2f} {tokens_per_second:>14,.0f}"
+
+
+def main(argv: t.Optional[t.List[str]] = None) -> None:
+    parser = argparse.ArgumentParser(description=__doc__.strip().splitlines()[0])
+    parser.add_argument("--iterations", type=int, default=1000)
+    parser.add_argument("--dialect", action="append", choices=sorted(CORPUS), dest="dialects")
+    args = parser.parse_args(argv)
+
+    print(f"{'dialect':<12} {'path':<8} {'MB/s':>10} {'tokens/s':>14}")
+
+    for dialect in args.dialects or CORPUS:
+        queries = CORPUS[dialect]
+        tokenizer, settings = _rs_tokenizer(dialect)
+
+        rust = time_tokenize(tokenizer, queries, settings, args.iterations)
+        python = _time_python(tokenizer, queries, settings, args.iterations)
+
+        print(_format("rust", dialect, *rust))
+        print(_format("python", dialect, *python))
+
+
+if __name__ == "__main__":
+    main()
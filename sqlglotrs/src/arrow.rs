@@ -0,0 +1,301 @@
+//! Tokens as an Arrow record batch, for pipelines that tokenize millions of queries and want
+//! columns rather than millions of Python tokens. The batch is exported through the Arrow C data
+//! interface and its PyCapsule protocol, so pyarrow, polars or duckdb can import it without
+//! copying and without this crate depending on an Arrow implementation.
+
+use crate::token::RawToken;
+use pyo3::exceptions::PyOverflowError;
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+use std::ffi::{c_char, c_void, CStr};
+use std::ptr;
+use std::sync::Arc;
+
+/// The columns of the batch, whose names and formats are in `FIELDS`.
+enum Column {
+    Int64(Vec<i64>),
+    Utf8 { offsets: Vec<i32>, data: Vec<u8> },
+}
+
+const FIELDS: [(&CStr, &CStr); 6] = [
+    (c"token_type", c"u"),
+    (c"text", c"u"),
+    (c"line", c"l"),
+    (c"col", c"l"),
+    (c"start", c"l"),
+    (c"end", c"l"),
+];
+
+/// The tokens of a scan as Arrow columns of `token_type`, i.e. the type's name, `text`, `line`,
+/// `col`, `start` and `end`, all non-nullable, which `pyarrow.record_batch` imports.
+#[pyclass(frozen)]
+pub struct ArrowTokens {
+    len: usize,
+    columns: Arc<Vec<Column>>,
+}
+
+impl ArrowTokens {
+    /// Types without a name in `token_type_names`, which is indexed by token type, are written
+    /// by index.
+    pub fn new<S: AsRef<str>>(
+        tokens: &[RawToken],
+        token_type_names: Option<&[S]>,
+    ) -> PyResult<ArrowTokens> {
+        let mut token_types = Utf8Builder::default();
+        let mut texts = Utf8Builder::default();
+        for token in tokens {
+            match token_type_names.and_then(|names| names.get(token.token_type as usize)) {
+                Some(name) => token_types.push(name.as_ref())?,
+                None => token_types.push(&token.token_type.to_string())?,
+            }
+            texts.push(&token.text)?;
+        }
+        let int64 = |value: fn(&RawToken) -> usize| {
+            Column::Int64(tokens.iter().map(|token| value(token) as i64).collect())
+        };
+        let columns = vec![
+            token_types.finish(),
+            texts.finish(),
+            int64(|token| token.line),
+            int64(|token| token.col),
+            int64(|token| token.start),
+            int64(|token| token.end),
+        ];
+        Ok(ArrowTokens {
+            len: tokens.len(),
+            columns: Arc::new(columns),
+        })
+    }
+}
+
+#[pymethods]
+impl ArrowTokens {
+    fn __len__(&self) -> usize {
+        self.len
+    }
+
+    fn __arrow_c_schema__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyCapsule>> {
+        schema_capsule(py)
+    }
+
+    /// Exports the batch as a struct array along with its schema. A requested schema is ignored,
+    /// which the protocol allows, as the columns only have one representation.
+    #[pyo3(signature = (requested_schema = None))]
+    fn __arrow_c_array__<'py>(
+        &self,
+        py: Python<'py>,
+        requested_schema: Option<Bound<'py, PyAny>>,
+    ) -> PyResult<(Bound<'py, PyCapsule>, Bound<'py, PyCapsule>)> {
+        let _ = requested_schema;
+        let array = Exported(export_batch(self.len, &self.columns));
+        let array = PyCapsule::new_with_destructor(
+            py,
+            array,
+            Some(c"arrow_array".to_owned()),
+            |mut array, _| {
+                // Consumers that imported the array have already released our copy of it
+                if let Some(release) = array.0.release {
+                    unsafe { release(&mut array.0) };
+                }
+            },
+        )?;
+        Ok((schema_capsule(py)?, array))
+    }
+}
+
+#[derive(Default)]
+struct Utf8Builder {
+    offsets: Vec<i32>,
+    data: Vec<u8>,
+}
+
+impl Utf8Builder {
+    fn push(&mut self, text: &str) -> PyResult<()> {
+        if self.offsets.is_empty() {
+            self.offsets.push(0);
+        }
+        self.data.extend_from_slice(text.as_bytes());
+        let offset = i32::try_from(self.data.len())
+            .map_err(|_| PyOverflowError::new_err("The tokens have more than 2 GiB of text"))?;
+        self.offsets.push(offset);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Column {
+        if self.offsets.is_empty() {
+            self.offsets.push(0);
+        }
+        Column::Utf8 {
+            offsets: self.offsets,
+            data: self.data,
+        }
+    }
+}
+
+// The structs of the Arrow C data interface, see https://arrow.apache.org/docs/format/CDataInterface.html
+
+#[repr(C)]
+struct ArrowSchema {
+    format: *const c_char,
+    name: *const c_char,
+    metadata: *const c_char,
+    flags: i64,
+    n_children: i64,
+    children: *mut *mut ArrowSchema,
+    dictionary: *mut ArrowSchema,
+    release: Option<unsafe extern "C" fn(*mut ArrowSchema)>,
+    private_data: *mut c_void,
+}
+
+#[repr(C)]
+struct ArrowArray {
+    length: i64,
+    null_count: i64,
+    offset: i64,
+    n_buffers: i64,
+    n_children: i64,
+    buffers: *mut *const c_void,
+    children: *mut *mut ArrowArray,
+    dictionary: *mut ArrowArray,
+    release: Option<unsafe extern "C" fn(*mut ArrowArray)>,
+    private_data: *mut c_void,
+}
+
+// The structs only point to memory owned by their private data, which any thread can free
+struct Exported<T>(T);
+
+unsafe impl<T> Send for Exported<T> {}
+
+fn schema_capsule(py: Python<'_>) -> PyResult<Bound<'_, PyCapsule>> {
+    PyCapsule::new_with_destructor(
+        py,
+        Exported(export_schema()),
+        Some(c"arrow_schema".to_owned()),
+        |mut schema, _| {
+            if let Some(release) = schema.0.release {
+                unsafe { release(&mut schema.0) };
+            }
+        },
+    )
+}
+
+// What an exported schema or array owns, which its release callback frees
+struct SchemaData {
+    children: Vec<*mut ArrowSchema>,
+}
+
+struct ArrayData {
+    // Keeps the buffers alive
+    _columns: Arc<Vec<Column>>,
+    buffers: Vec<*const c_void>,
+    children: Vec<*mut ArrowArray>,
+}
+
+fn export_schema() -> ArrowSchema {
+    let children = FIELDS
+        .iter()
+        .map(|(name, format)| {
+            Box::into_raw(Box::new(ArrowSchema {
+                format: format.as_ptr(),
+                name: name.as_ptr(),
+                metadata: ptr::null(),
+                flags: 0,
+                n_children: 0,
+                children: ptr::null_mut(),
+                dictionary: ptr::null_mut(),
+                release: Some(release_schema),
+                private_data: ptr::null_mut(),
+            }))
+        })
+        .collect();
+    let mut data = Box::new(SchemaData { children });
+    ArrowSchema {
+        format: c"+s".as_ptr(),
+        name: c"".as_ptr(),
+        metadata: ptr::null(),
+        flags: 0,
+        n_children: data.children.len() as i64,
+        children: data.children.as_mut_ptr(),
+        dictionary: ptr::null_mut(),
+        release: Some(release_schema),
+        private_data: Box::into_raw(data).cast(),
+    }
+}
+
+unsafe extern "C" fn release_schema(schema: *mut ArrowSchema) {
+    let schema = &mut *schema;
+    if !schema.private_data.is_null() {
+        let data = Box::from_raw(schema.private_data.cast::<SchemaData>());
+        for child in data.children {
+            // Consumers may have moved a child out, releasing our copy of it
+            if let Some(release) = (*child).release {
+                release(child);
+            }
+            drop(Box::from_raw(child));
+        }
+    }
+    schema.release = None;
+}
+
+fn export_batch(len: usize, columns: &Arc<Vec<Column>>) -> ArrowArray {
+    let children = columns
+        .iter()
+        .map(|column| {
+            // Every buffer list starts with the validity bitmap, which non-nullable columns omit
+            let buffers = match column {
+                Column::Int64(values) => vec![ptr::null(), values.as_ptr().cast()],
+                Column::Utf8 { offsets, data } => {
+                    vec![ptr::null(), offsets.as_ptr().cast(), data.as_ptr().cast()]
+                }
+            };
+            Box::into_raw(Box::new(export_array(
+                len,
+                ArrayData {
+                    _columns: columns.clone(),
+                    buffers,
+                    children: Vec::new(),
+                },
+            )))
+        })
+        .collect();
+    export_array(
+        len,
+        ArrayData {
+            _columns: columns.clone(),
+            buffers: vec![ptr::null()],
+            children,
+        },
+    )
+}
+
+fn export_array(len: usize, data: ArrayData) -> ArrowArray {
+    let mut data = Box::new(data);
+    ArrowArray {
+        length: len as i64,
+        null_count: 0,
+        offset: 0,
+        n_buffers: data.buffers.len() as i64,
+        n_children: data.children.len() as i64,
+        buffers: data.buffers.as_mut_ptr(),
+        children: if data.children.is_empty() {
+            ptr::null_mut()
+        } else {
+            data.children.as_mut_ptr()
+        },
+        dictionary: ptr::null_mut(),
+        release: Some(release_array),
+        private_data: Box::into_raw(data).cast(),
+    }
+}
+
+unsafe extern "C" fn release_array(array: *mut ArrowArray) {
+    let array = &mut *array;
+    let data = Box::from_raw(array.private_data.cast::<ArrayData>());
+    for child in data.children {
+        if let Some(release) = (*child).release {
+            release(child);
+        }
+        drop(Box::from_raw(child));
+    }
+    array.release = None;
+}
@@ -0,0 +1,37 @@
+use crate::settings::TokenizerDialectSettings;
+use crate::tokenizer::Tokenizer;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::time::Instant;
+
+/// Tokenizes every query `iterations` times, timing the scan on the Rust side so that the cost
+/// of crossing the Python boundary is excluded. Returns the elapsed nanoseconds, the number of
+/// bytes scanned and the number of tokens produced.
+#[pyfunction]
+pub fn time_tokenize(
+    tokenizer: PyRef<'_, Tokenizer>,
+    queries: Vec<String>,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    iterations: usize,
+) -> PyResult<(u64, usize, usize)> {
+    let mut elapsed = 0;
+    let mut bytes = 0;
+    let mut tokens = 0;
+
+    for _ in 0..iterations {
+        for sql in &queries {
+            let start = Instant::now();
+            let (result, error) = tokenizer.tokenize_raw(sql, &dialect_settings);
+            elapsed += start.elapsed().as_nanos() as u64;
+
+            if let Some(error) = error {
+                return Err(PyValueError::new_err(error.to_string()));
+            }
+
+            bytes += sql.len();
+            tokens += result.len();
+        }
+    }
+
+    Ok((elapsed, bytes, tokens))
+}
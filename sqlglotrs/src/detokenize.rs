@@ -0,0 +1,333 @@
+use crate::token::{RawToken, Token};
+use crate::tokenizer::{Tokenizer, TokenizerConfig};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::FxHashSet as HashSet;
+use sqlglotrs_core::settings::TokenType;
+use std::borrow::Cow;
+
+const NO_SPACE_BEFORE: [&str; 6] = [",", ")", "]", ".", ";", "::"];
+const NO_SPACE_AFTER: [&str; 4] = ["(", "[", ".", "::"];
+
+/// How `detokenize` separates the tokens it joins.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Spacing {
+    /// A single space between tokens, except inside parentheses and brackets, around dots and
+    /// `::`, before commas and semicolons, and between a function's name and its arguments
+    Compact,
+    /// The line breaks and indentation of the query the tokens were scanned from, and a single
+    /// space between any other tokens that weren't adjacent in it
+    Preserve,
+}
+
+impl Spacing {
+    pub fn from_name(name: &str) -> Option<Spacing> {
+        match name {
+            "compact" => Some(Spacing::Compact),
+            "preserve" => Some(Spacing::Preserve),
+            _ => None,
+        }
+    }
+}
+
+/// The case `detokenize` writes keywords in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeywordCase {
+    Upper,
+    Lower,
+}
+
+impl KeywordCase {
+    pub fn from_name(name: &str) -> Option<KeywordCase> {
+        match name {
+            "upper" => Some(KeywordCase::Upper),
+            "lower" => Some(KeywordCase::Lower),
+            _ => None,
+        }
+    }
+}
+
+/// Writes tokens back as SQL. Tokens only keep the contents of strings and quoted identifiers,
+/// so those are quoted again with the delimiters the tokenizer's settings define, preferring `'`
+/// for strings and `"` for identifiers. Everything else is written as scanned.
+pub struct Detokenizer<'a> {
+    config: &'a TokenizerConfig,
+    quote: (&'a str, &'a str),
+    identifier: (String, String),
+    // The delimiters of each kind of prefixed string, e.g. `N'` and `'` for national strings
+    delimiters: HashMap<TokenType, Vec<(&'a str, &'a str)>>,
+    spacing: Spacing,
+    keyword_case: Option<KeywordCase>,
+    comments: bool,
+}
+
+impl<'a> Detokenizer<'a> {
+    pub fn new(
+        config: &'a TokenizerConfig,
+        spacing: Spacing,
+        keyword_case: Option<KeywordCase>,
+        comments: bool,
+    ) -> Detokenizer<'a> {
+        let settings = &config.settings;
+        let quote = settings
+            .quotes
+            .get_key_value("'")
+            .or_else(|| settings.quotes.iter().min())
+            .map_or(("'", "'"), |(start, end)| (start.as_str(), end.as_str()));
+        let identifier = settings
+            .identifiers
+            .get_key_value(&'"')
+            .or_else(|| settings.identifiers.iter().min())
+            .map_or(('"', '"'), |(&start, &end)| (start, end));
+        let identifier = (identifier.0.to_string(), identifier.1.to_string());
+
+        let mut delimiters: HashMap<TokenType, Vec<(&str, &str)>> = HashMap::default();
+        for (start, (end, token_type)) in settings.format_strings.iter() {
+            delimiters
+                .entry(*token_type)
+                .or_default()
+                .push((start.as_str(), end.as_str()));
+        }
+        // Delimiters that end with a quote come first, e.g. `X'` rather than `0x` for hex strings,
+        // and `'` first among those
+        let is_quote = |end: &str| settings.quotes.values().any(|quote| quote == end);
+        for candidates in delimiters.values_mut() {
+            candidates.sort_by_key(|&(start, end)| (!is_quote(end), end != "'", start));
+        }
+
+        Detokenizer {
+            config,
+            quote,
+            identifier,
+            delimiters,
+            spacing,
+            keyword_case,
+            comments,
+        }
+    }
+
+    pub fn render(&self, tokens: &[RawToken]) -> String {
+        let mut out = String::new();
+        let mut prev: Option<(&RawToken, Cow<str>)> = None;
+
+        for token in tokens {
+            let text = self.token_text(token, prev.as_ref().map(|(prev, _)| *prev));
+            if let Some((prev, prev_text)) = &prev {
+                self.push_separator(&mut out, prev, prev_text, token, &text);
+            }
+            out.push_str(&text);
+            self.push_comments(&mut out, token);
+            prev = Some((token, text));
+        }
+        out
+    }
+
+    /// Writes a token's comments as block comments following it, if comments are kept.
+    pub(crate) fn push_comments(&self, out: &mut String, token: &RawToken) {
+        // Hints keep their body as a comment too, which their text already includes
+        if !self.comments || token.token_type == self.config.token_types.hint {
+            return;
+        }
+        for comment in &token.comments {
+            out.push_str(" /*");
+            if self.config.settings.nested_comments && is_balanced(comment) {
+                out.push_str(comment);
+            } else {
+                out.push_str(&comment.replace("*/", "* /"));
+            }
+            out.push_str("*/");
+        }
+    }
+
+    /// The SQL a token is written as, given the token written before it, if any.
+    pub(crate) fn token_text<'t>(
+        &self,
+        token: &'t RawToken,
+        prev: Option<&RawToken>,
+    ) -> Cow<'t, str> {
+        let settings = &self.config.settings;
+        let token_types = &self.config.token_types;
+        let token_type = token.token_type;
+        let text = &*token.text;
+
+        if token_type == token_types.identifier {
+            let (start, end) = &self.identifier;
+            Cow::Owned(quote(text, start, end, &settings.identifier_escapes))
+        } else if token_type == token_types.string {
+            // The rest of a command, e.g. `EXEC`, is kept as a string of the SQL as written
+            if prev.is_some_and(|prev| settings.commands.contains(&prev.token_type)) {
+                return Cow::Borrowed(text);
+            }
+            let (start, end) = self.quote;
+            Cow::Owned(quote(text, start, end, &settings.string_escapes))
+        } else if token_type == token_types.heredoc_string {
+            // The tag isn't kept, so pick one that doesn't occur in the string
+            let mut tag = String::from("$");
+            let mut n = 0;
+            while text.contains(&format!("{}$", tag)) {
+                n += 1;
+                tag = format!("$t{}", n);
+            }
+            Cow::Owned(format!("{tag}${text}{tag}$"))
+        } else if let Some(candidates) = self.delimiters.get(&token_type) {
+            // Prefer delimiters that don't need escaping, as raw strings can't escape them
+            let (start, end) = candidates
+                .iter()
+                .find(|(_, end)| end.is_empty() || !text.contains(end))
+                .unwrap_or(&candidates[0]);
+            if settings.verbatim_strings.contains(*start) {
+                return Cow::Owned(format!("{start}{text}{end}"));
+            }
+            Cow::Owned(quote(text, start, end, &settings.string_escapes))
+        } else {
+            match self.keyword_case {
+                // Keywords are names too after a dot, e.g. `t.name`
+                Some(case)
+                    if self.is_keyword(token) && prev.is_none_or(|prev| &*prev.text != ".") =>
+                {
+                    Cow::Owned(match case {
+                        KeywordCase::Upper => text.to_uppercase(),
+                        KeywordCase::Lower => text.to_lowercase(),
+                    })
+                }
+                _ => Cow::Borrowed(text),
+            }
+        }
+    }
+
+    fn is_keyword(&self, token: &RawToken) -> bool {
+        token.token_type != self.config.token_types.var
+            && self.config.keyword_types.contains(&token.token_type)
+            && token
+                .text
+                .starts_with(|c: char| c.is_alphabetic() || c == '_')
+    }
+
+    /// Writes whatever separates two consecutive tokens, given the text they're written as.
+    pub(crate) fn push_separator(
+        &self,
+        out: &mut String,
+        prev: &RawToken,
+        prev_text: &str,
+        token: &RawToken,
+        text: &str,
+    ) {
+        match self.spacing {
+            Spacing::Compact => {
+                let is_call = text == "("
+                    && (prev.token_type == self.config.token_types.var
+                        || prev.token_type == self.config.token_types.identifier);
+                if !(NO_SPACE_BEFORE.contains(&text)
+                    || NO_SPACE_AFTER.contains(&prev_text)
+                    || is_call)
+                {
+                    out.push(' ');
+                }
+            }
+            Spacing::Preserve => {
+                // Lines and columns are those of a token's last char
+                let newlines = text.matches('\n').count();
+                let line = token.line.saturating_sub(newlines);
+                if line > prev.line {
+                    out.extend(std::iter::repeat_n('\n', line - prev.line));
+                    if newlines == 0 {
+                        let col = token
+                            .col
+                            .saturating_sub(token.end.saturating_sub(token.start));
+                        out.extend(std::iter::repeat_n(' ', col.saturating_sub(1)));
+                    }
+                } else if token.start != prev.end + 1
+                    // Two quoted tokens could read as one once their delimiters have changed,
+                    // e.g. `[a][b]` as `"a""b"`
+                    || (prev_text != &*prev.text && text != &*token.text)
+                {
+                    out.push(' ');
+                }
+            }
+        }
+    }
+}
+
+/// Whether every `/*` in a comment is closed by a `*/` within it, so that it can be written as is
+/// in dialects that nest comments.
+fn is_balanced(comment: &str) -> bool {
+    let mut depth: usize = 0;
+    let mut rest = comment;
+    while let Some(i) = rest.find(['/', '*']) {
+        if rest[i..].starts_with("/*") {
+            depth += 1;
+        } else if rest[i..].starts_with("*/") {
+            let Some(d) = depth.checked_sub(1) else {
+                return false;
+            };
+            depth = d;
+        } else {
+            rest = &rest[i + 1..];
+            continue;
+        }
+        rest = &rest[i + 2..];
+    }
+    depth == 0
+}
+
+/// Delimits `text` with `start` and `end`, escaping the first char of `end` inside it by doubling
+/// it, or with a backslash in dialects where that's the only escape, such as BigQuery. Backslashes
+/// are escaped too wherever they are escapes.
+fn quote(text: &str, start: &str, end: &str, escapes: &HashSet<char>) -> String {
+    let mut quoted = String::with_capacity(text.len() + start.len() + end.len());
+    quoted.push_str(start);
+    match end.chars().next() {
+        Some(close) => {
+            let backslash = escapes.contains(&'\\');
+            let escape = if escapes.contains(&close) || !backslash {
+                close
+            } else {
+                '\\'
+            };
+            for c in text.chars() {
+                if c == close {
+                    quoted.push(escape);
+                } else if backslash && c == '\\' {
+                    quoted.push('\\');
+                }
+                quoted.push(c);
+            }
+        }
+        None => quoted.push_str(text),
+    }
+    quoted.push_str(end);
+    quoted
+}
+
+/// Writes tokens back as SQL, e.g. after dropping or reordering some of them, without going
+/// through sqlglot's generator. `spacing` is either "compact", which separates tokens by single
+/// spaces except around punctuation, or "preserve", which keeps the line breaks and indentation
+/// of the original query. Keywords are written in `keyword_case`, "upper" or "lower", if given,
+/// and comments as block comments after their token unless `comments` is false.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, tokens, spacing = "compact", keyword_case = None, comments = true))]
+pub fn detokenize(
+    py: Python<'_>,
+    tokenizer: PyRef<'_, Tokenizer>,
+    tokens: Vec<PyRef<'_, Token>>,
+    spacing: &str,
+    keyword_case: Option<&str>,
+    comments: bool,
+) -> PyResult<String> {
+    let spacing = Spacing::from_name(spacing)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown spacing '{}'", spacing)))?;
+    let keyword_case = keyword_case
+        .map(|case| {
+            KeywordCase::from_name(case)
+                .ok_or_else(|| PyValueError::new_err(format!("Unknown keyword case '{}'", case)))
+        })
+        .transpose()?;
+
+    let tokens = tokens
+        .iter()
+        .map(|token| token.to_raw(py))
+        .collect::<PyResult<Vec<_>>>()?;
+    let config = tokenizer.config();
+    Ok(Detokenizer::new(&config, spacing, keyword_case, comments).render(&tokens))
+}
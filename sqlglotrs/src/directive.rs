@@ -0,0 +1,125 @@
+use crate::settings::TokenizerDialectSettings;
+use crate::tokenizer::{Tokenizer, TryTokenize};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::statement::statements;
+use sqlglotrs_core::words::Words;
+
+/// A directive found by `parse_directives`: (statement index, key, value). Flags such as
+/// `no_optimize` have no value.
+pub type Directive = (usize, String, Option<String>);
+
+const DIRECTIVE_PREFIX: &str = "sqlglot:";
+
+/// Returns the body of a directive comment, i.e. whatever follows `sqlglot:`, or None if the
+/// comment isn't a directive.
+pub fn directive_body(comment: &str) -> Option<&str> {
+    let comment = comment.trim_start();
+    let prefix = comment.get(..DIRECTIVE_PREFIX.len())?;
+    prefix
+        .eq_ignore_ascii_case(DIRECTIVE_PREFIX)
+        .then(|| &comment[DIRECTIVE_PREFIX.len()..])
+}
+
+/// Parses a comma-separated list of `key=value` settings and bare `flag`s. Values may be quoted
+/// with `'` or `"` to include commas or surrounding whitespace, a doubled quote standing for the
+/// quote itself.
+pub fn parse_directive(body: &str) -> Result<Vec<(String, Option<String>)>, String> {
+    let mut settings = Vec::new();
+    let mut chars = body.chars().peekable();
+
+    loop {
+        let mut key = String::new();
+        while let Some(c) = chars.next_if(|&c| c != '=' && c != ',') {
+            key.push(c);
+        }
+
+        let key = key.trim();
+        let is_key = key.starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && key
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-'));
+
+        let value = if chars.next_if_eq(&'=').is_some() {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            let mut value = String::new();
+            let quote = chars.next_if(|&c| c == '\'' || c == '"');
+            match quote {
+                Some(quote) => loop {
+                    match chars.next() {
+                        Some(c) if c == quote && chars.next_if_eq(&quote).is_none() => break,
+                        Some(c) => value.push(c),
+                        None => return Err(format!("Missing closing quote for '{}'", key)),
+                    }
+                },
+                None => {
+                    while let Some(c) = chars.next_if(|&c| c != ',') {
+                        value.push(c);
+                    }
+                    value = value.trim_end().to_string();
+                }
+            }
+
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            if !matches!(chars.peek(), None | Some(',')) {
+                return Err(format!("Unexpected text after the value of '{}'", key));
+            }
+            if value.is_empty() && quote.is_none() {
+                return Err(format!("Missing value for '{}'", key));
+            }
+            Some(value)
+        } else {
+            None
+        };
+
+        if is_key {
+            settings.push((key.to_string(), value));
+        } else if !key.is_empty() || value.is_some() {
+            return Err(format!("Invalid key '{}'", key));
+        }
+
+        if chars.next().is_none() {
+            return Ok(settings);
+        }
+    }
+}
+
+/// Collects the directives in the comments of a token stream, attributing each to the statement
+/// whose tokens carry the comment. Comments attached to a semicolon precede the next statement,
+/// so they're attributed to it unless the semicolon ends the script.
+pub fn find_directives(words: &Words) -> Result<Vec<Directive>, String> {
+    let statements = statements(words);
+    let mut directives = Vec::new();
+    let mut statement = 0;
+
+    for i in 0..words.len() {
+        while statement + 1 < statements.len() && statements[statement].end <= i {
+            statement += 1;
+        }
+        for comment in &words.token(i).comments {
+            if let Some(body) = directive_body(comment) {
+                for (key, value) in parse_directive(body)? {
+                    directives.push((statement, key, value));
+                }
+            }
+        }
+    }
+    Ok(directives)
+}
+
+/// Parses `/* sqlglot: key=value, flag */` directives out of the comments in `sql`, returning
+/// every setting along with the index of the statement it's attached to. Malformed directives
+/// raise a ValueError rather than being ignored.
+#[pyfunction]
+pub fn parse_directives(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<Directive>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    find_directives(&Words::new(&source, &tokens, &config.token_types))
+        .map_err(|e| PyValueError::new_err(format!("Invalid sqlglot directive: {}", e)))
+}
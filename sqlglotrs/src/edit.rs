@@ -0,0 +1,115 @@
+use pyo3::prelude::*;
+use sqlglotrs_core::source::{Edit, Source};
+use std::ops::Range;
+
+/// Where a replaced range of chars ended up after an edit.
+#[derive(Clone, Debug)]
+struct Shift {
+    old: Range<usize>,
+    new: Range<usize>,
+}
+
+/// Records the edits made by a series of rewrites so that char offsets can be mapped between
+/// the original query and the rewritten one. Each rewrite is a pass of non-overlapping edits
+/// made against the output of the previous one. Offsets inside a replaced range map to the start
+/// of its replacement, so spans are best mapped using exclusive ends.
+#[derive(Clone, Debug, Default)]
+#[pyclass]
+pub struct EditLog {
+    passes: Vec<Vec<Shift>>,
+}
+
+impl EditLog {
+    /// Records a pass of edits, given as half-open char ranges of the text before the pass and
+    /// their replacements.
+    pub fn record(&mut self, edits: &[Edit]) {
+        let mut order: Vec<&Edit> = edits.iter().collect();
+        order.sort_by_key(|(range, _)| range.start);
+
+        let mut shifts = Vec::with_capacity(order.len());
+        // How far the text after the previous edit moved, which can be negative
+        let mut delta = 0isize;
+        for (range, replacement) in order {
+            let start = range.start.saturating_add_signed(delta);
+            let new = start..start + replacement.chars().count();
+            delta += new.len() as isize - range.len() as isize;
+            shifts.push(Shift {
+                old: range.clone(),
+                new,
+            });
+        }
+        self.passes.push(shifts);
+    }
+}
+
+#[pymethods]
+impl EditLog {
+    #[new]
+    pub fn new() -> EditLog {
+        EditLog::default()
+    }
+
+    /// Splices the edits into `sql`, which must be the output of the last recorded pass, and
+    /// records them. Edits are (start, end, replacement) with half-open char ranges, so that
+    /// `start == end` inserts text.
+    pub fn apply(&mut self, sql: &str, edits: Vec<(usize, usize, String)>) -> String {
+        let edits: Vec<Edit> = edits
+            .into_iter()
+            .map(|(start, end, replacement)| (start..end, replacement))
+            .collect();
+        self.record(&edits);
+        Source::new(sql).splice(edits)
+    }
+
+    /// Maps a char offset in the original query to the final one.
+    pub fn to_new(&self, offset: usize) -> usize {
+        self.passes.iter().fold(offset, |offset, shifts| {
+            map_offset(shifts, offset, |shift| (&shift.old, &shift.new))
+        })
+    }
+
+    /// Maps a char offset in the final query back to the original one.
+    pub fn to_original(&self, offset: usize) -> usize {
+        self.passes.iter().rev().fold(offset, |offset, shifts| {
+            map_offset(shifts, offset, |shift| (&shift.new, &shift.old))
+        })
+    }
+
+    pub fn clear(&mut self) {
+        self.passes.clear();
+    }
+
+    /// The number of passes recorded.
+    pub fn __len__(&self) -> usize {
+        self.passes.len()
+    }
+}
+
+fn map_offset(
+    shifts: &[Shift],
+    offset: usize,
+    ranges: impl Fn(&Shift) -> (&Range<usize>, &Range<usize>),
+) -> usize {
+    // Edits that end at or before the offset, including insertions right at it, move it along
+    let i = shifts.partition_point(|shift| ranges(shift).0.end <= offset);
+    match (
+        shifts.get(i).map(&ranges),
+        i.checked_sub(1).map(|i| ranges(&shifts[i])),
+    ) {
+        (Some((from, to)), _) if from.start <= offset => to.start,
+        (_, Some((from, to))) => offset - from.end + to.end,
+        _ => offset,
+    }
+}
+
+/// Splices `edits` into the query behind `source`, recording them in `log` if one is given.
+pub fn apply_edits(
+    source: &Source,
+    edits: Vec<Edit>,
+    log: Option<PyRefMut<'_, EditLog>>,
+) -> String {
+    if let Some(mut log) = log {
+        log.record(&edits);
+    }
+    source.splice(edits)
+}
@@ -0,0 +1,773 @@
+use crate::rewrite::find_all_placeholders;
+use crate::settings::TokenizerDialectSettings;
+use crate::tokenizer::{Tokenizer, TokenizerConfig, TryTokenize};
+use pyo3::prelude::*;
+use rustc_hash::FxHashSet as HashSet;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::statement::statements;
+use sqlglotrs_core::words::Words;
+use std::ops::Range;
+
+/// A table found by `extract_tables`: (name, kind, start, end, confident). The kind is "table",
+/// or "cte" for CTE definitions and references to them, and the span covers the whole dotted
+/// name. Names made up of keywords, or introduced by an ambiguous keyword such as `USING`, are
+/// reported with `confident` set to false.
+pub type TableReference = (String, &'static str, usize, usize, bool);
+
+/// A table found by `extract_table_names`: (parts, start, end), where the parts are the names
+/// the table is qualified by followed by its own, e.g. `["db", "t"]` for `db.t`. Quoted parts are
+/// unquoted but kept whole, so `"a.b"` is a single part. The span covers the whole dotted name.
+pub type TableName = (Vec<String>, usize, usize);
+
+/// A name found by `index_ctes`: (name, kind, start, end, definition start, definition end). The
+/// kind is "cte" for the CTEs of a `WITH` clause, whose definition is their parenthesized query,
+/// or "alias" for the aliased projections of a statement's top-level select list, whose
+/// definition is the aliased expression.
+pub type IndexedName = (String, &'static str, usize, usize, usize, usize);
+
+/// A column found by `extract_columns`: (name, start, end), where the name is the dotted chain
+/// as written, e.g. `t.a`.
+pub type ColumnReference = (String, usize, usize);
+
+/// A `*` found by `find_select_star`: (statement index, start, end). Qualified stars such as
+/// `t.*` span the whole name.
+pub type SelectStar = (usize, usize, usize);
+
+/// A setting found by `extract_settings`: (key, value, start, end). Both the key and the value
+/// are the SQL as written, e.g. `@@session.sql_mode` and `'UTC'`, and the value is None for
+/// pragmas that are only queried. The span covers the whole assignment.
+pub type SessionSetting = (String, Option<String>, usize, usize);
+
+/// A call found by `detect_functions`: (name, start, end), where the name is as written and the
+/// span covers it, but not the schema qualifying it, if any.
+pub type FunctionCall = (String, usize, usize);
+
+/// A placeholder found by `extract_parameters`: (style, key, start, end). The style is one of
+/// those `convert_placeholders` understands, and the key is the parameter's name or number as
+/// written, e.g. `1` for `$1`, or its 1-based position among the placeholders of its style for
+/// `?` and `%s`.
+pub type Parameter = (&'static str, String, usize, usize);
+
+const TABLE_KEYWORDS: [&str; 6] = ["FROM", "JOIN", "INTO", "UPDATE", "USING", "TABLE"];
+const STATEMENT_KEYWORDS: [&str; 5] = ["SELECT", "INSERT", "UPDATE", "DELETE", "MERGE"];
+// Scopes that may precede the variable being set, e.g. `SET GLOBAL max_connections = 10`
+const SET_SCOPES: [&str; 6] = [
+    "SESSION",
+    "LOCAL",
+    "GLOBAL",
+    "PERSIST",
+    "PERSIST_ONLY",
+    "VARIABLE",
+];
+const ASSIGNMENT_OPERATORS: [&str; 3] = ["=", ":=", "TO"];
+// The keywords that end a select list, which multi-word ones such as `GROUP BY` start with
+const SELECT_LIST_ENDS: [&str; 13] = [
+    "FROM",
+    "INTO",
+    "WHERE",
+    "GROUP",
+    "HAVING",
+    "WINDOW",
+    "QUALIFY",
+    "ORDER",
+    "LIMIT",
+    "FETCH",
+    "UNION",
+    "INTERSECT",
+    "EXCEPT",
+];
+
+/// What `find_tables` tracks for each level of parentheses, the outermost being the statement's.
+#[derive(Default)]
+struct TableScope {
+    // Whether these are the parentheses of a function call whose arguments are being read, where
+    // keywords such as the `FROM` of `EXTRACT(YEAR FROM d)` don't introduce tables. A subquery
+    // argument, e.g. `ARRAY(SELECT ...)`, ends them.
+    in_arguments: bool,
+    // Whether a FROM clause is being read, where commas separate table references, including
+    // after joins and subqueries
+    in_from: bool,
+}
+
+/// Finds the tables a query reads from or writes to by looking at the names that follow `FROM`,
+/// `JOIN`, `INTO`, `UPDATE`, `USING` and `TABLE`, or commas in a FROM clause, as well as the names
+/// defined in `WITH` clauses.
+pub fn find_tables(words: &Words) -> Vec<TableReference> {
+    let mut tables = Vec::new();
+    let mut ctes = HashSet::default();
+    let mut scopes = vec![TableScope::default()];
+    // The paren depth of the WITH clause whose CTEs are being defined, if any
+    let mut with_depth = None;
+
+    let mut i = 0;
+    while i < words.len() {
+        let depth = scopes.len() - 1;
+        if words.is(i, ";") {
+            ctes.clear();
+            with_depth = None;
+            scopes.truncate(1);
+            scopes[0] = TableScope::default();
+        } else if words.is(i, "(") {
+            scopes.push(TableScope {
+                in_arguments: i > 0 && words.is_name(i - 1),
+                in_from: false,
+            });
+        } else if words.is(i, ")") {
+            if scopes.len() > 1 {
+                scopes.pop();
+            }
+        } else if words.is(i, "WITH") {
+            with_depth = Some(depth);
+            let j = if words.is(i + 1, "RECURSIVE") {
+                i + 2
+            } else {
+                i + 1
+            };
+            define_cte(words, j, &mut ctes, &mut tables);
+        } else if with_depth == Some(depth) && words.is(i, ",") {
+            define_cte(words, i + 1, &mut ctes, &mut tables);
+        } else if with_depth == Some(depth) && words.is_any(i, &STATEMENT_KEYWORDS) {
+            with_depth = None;
+        }
+
+        let scope = scopes
+            .last_mut()
+            .expect("the statement's scope is never popped");
+        if words.is_any(i, &["SELECT", "SET"]) || starts_any(words, i, &SELECT_LIST_ENDS) {
+            scope.in_arguments &= !words.is(i, "SELECT");
+            scope.in_from = false;
+        }
+
+        let starts_reference = words.is_any(i, &TABLE_KEYWORDS) && !scope.in_arguments
+            || scope.in_from && words.is(i, ",");
+        if starts_reference {
+            scope.in_from |= words.is(i, "FROM");
+            i = table_references(words, i, &ctes, &mut tables);
+        } else {
+            i += 1;
+        }
+    }
+    tables
+}
+
+/// Finds the tables a query reads from or writes to as `find_tables` does, but leaves out CTEs and
+/// returns the parts of each name, e.g. for checking them against access rules.
+pub fn find_table_names(words: &Words) -> Vec<TableName> {
+    find_tables(words)
+        .into_iter()
+        .filter(|&(_, kind, ..)| kind == "table")
+        .filter_map(|(_, _, start, end, _)| {
+            let (_, parts, _) = words.name_parts(words.index_at(start)?)?;
+            Some((parts.into_iter().map(str::to_string).collect(), start, end))
+        })
+        .collect()
+}
+
+fn define_cte(
+    words: &Words,
+    i: usize,
+    ctes: &mut HashSet<String>,
+    tables: &mut Vec<TableReference>,
+) {
+    if words.is_word(i) && (words.is(i + 1, "AS") || words.is(i + 1, "(")) {
+        let token = words.token(i);
+        ctes.insert(token.text.to_lowercase());
+        tables.push((token.text.to_string(), "cte", token.start, token.end, true));
+    }
+}
+
+/// Reads the table reference following the keyword or comma at `i`, and returns the index to
+/// resume scanning from.
+fn table_references(
+    words: &Words,
+    i: usize,
+    ctes: &HashSet<String>,
+    tables: &mut Vec<TableReference>,
+) -> usize {
+    let ambiguous = words.is(i, "USING");
+    // Names are followed by a column list here, rather than being table-valued functions
+    let has_columns = words.is_any(i, &["INTO", "TABLE"]);
+    let mut j = i + 1;
+
+    if words.is(i, "TABLE") {
+        if words.is(j, "IF") {
+            j += 1;
+        }
+        if words.is(j, "NOT") {
+            j += 1;
+        }
+        if words.is(j, "EXISTS") {
+            j += 1;
+        }
+    }
+
+    let Some((end, name, confident)) = words.dotted_name(j) else {
+        return j;
+    };
+    // Table-valued functions aren't tables
+    if words.is(end, "(") && !has_columns {
+        return j;
+    }
+
+    let kind = if ctes.contains(&name.to_lowercase()) {
+        "cte"
+    } else {
+        "table"
+    };
+    tables.push((
+        name,
+        kind,
+        words.token(j).start,
+        words.token(end - 1).end,
+        confident && !ambiguous,
+    ));
+
+    j = end;
+    if words.is(j, "AS") {
+        j += 1;
+    }
+    if words.is_name(j) {
+        j += 1;
+    }
+    j
+}
+
+/// Whether the token at `i` is a keyword that's one of the given ones, or a multi-word keyword
+/// starting with one, e.g. `GROUP BY` for `GROUP`.
+fn starts_any(words: &Words, i: usize, keywords: &[&str]) -> bool {
+    !words.is_name(i)
+        && words.is_word(i)
+        && words
+            .token(i)
+            .text
+            .split_whitespace()
+            .next()
+            .is_some_and(|word| keywords.contains(&word.to_ascii_uppercase().as_str()))
+}
+
+/// Finds the dotted names used as columns, i.e. those that aren't tables, CTEs, aliases, function
+/// names or parameters such as `:name` and `@name`.
+pub fn find_columns(words: &Words) -> Vec<ColumnReference> {
+    let tables: HashSet<usize> = find_tables(words)
+        .into_iter()
+        .map(|(_, _, start, _, _)| start)
+        .collect();
+
+    let mut columns = Vec::new();
+    let mut i = 0;
+    while i < words.len() {
+        let Some((end, name, _)) = words.is_name(i).then(|| words.dotted_name(i)).flatten() else {
+            i += 1;
+            continue;
+        };
+
+        let is_column = !tables.contains(&words.token(i).start)
+            && !words.is(end, "(")
+            && (i == 0 || !(words.is_any(i - 1, &["AS", ":", "@"]) || words.ends_operand(i - 1)));
+        if is_column {
+            columns.push((name, words.token(i).start, words.token(end - 1).end));
+        }
+        i = end;
+    }
+    columns
+}
+
+/// Finds the `*` projections in select lists, which are those following `SELECT`, its `DISTINCT`
+/// or `ALL` modifiers or a comma in the select list, as opposed to multiplications or `COUNT(*)`.
+pub fn find_select_stars(words: &Words) -> Vec<SelectStar> {
+    let mut stars = Vec::new();
+    let mut statement = 0;
+    let mut depth = 0;
+    // The paren depths of the select lists currently being scanned, innermost last
+    let mut select_lists: Vec<usize> = Vec::new();
+
+    for i in 0..words.len() {
+        let in_select_list = select_lists.last() == Some(&depth);
+        let is_modifier =
+            i > 0 && words.is(i - 1, "SELECT") && words.is_any(i, &["DISTINCT", "ALL"]);
+        let starts_item =
+            words.is(i, "SELECT") || in_select_list && (words.is(i, ",") || is_modifier);
+
+        if starts_item {
+            let mut j = i + 1;
+            // Qualified stars, e.g. `t.*` or `db.t.*`
+            while words.is_word(j) && words.is(j + 1, ".") {
+                j += 2;
+            }
+            if words.is(j, "*") {
+                stars.push((statement, words.token(i + 1).start, words.token(j).end));
+            }
+        }
+
+        if words.is(i, ";") {
+            statement += 1;
+            select_lists.clear();
+        } else if words.is(i, "(") {
+            depth += 1;
+        } else if words.is(i, ")") {
+            depth = depth.saturating_sub(1);
+            select_lists.retain(|&d| d <= depth);
+        } else if words.is(i, "SELECT") {
+            select_lists.push(depth);
+        } else if in_select_list && words.is_any(i, &["FROM", "INTO", "WHERE"]) {
+            select_lists.pop();
+        }
+    }
+    stars
+}
+
+/// Finds the session state set by a script, i.e. the assignments of `SET` and `ALTER SESSION SET`
+/// statements, including comma-separated ones, and `PRAGMA` statements.
+pub fn find_settings(words: &Words) -> Vec<SessionSetting> {
+    let mut settings = Vec::new();
+    for statement in statements(words) {
+        let mut i = statement.start;
+        if words.is(i, "ALTER") && words.is(i + 1, "SESSION") {
+            i += 2;
+        }
+
+        if words.is(i, "SET") {
+            let mut j = i + 1;
+            while let Some((setting, end)) = assignment(words, j, statement.end) {
+                settings.push(setting);
+                if !words.is(end, ",") {
+                    break;
+                }
+                j = end + 1;
+            }
+        } else if words.is(i, "PRAGMA") && i + 1 < statement.end {
+            let key = key_end(words, i + 1, statement.end);
+            let value = if words.is(key, "=") && key + 1 < statement.end {
+                Some(words.text(key + 1..statement.end).to_string())
+            } else if words.is(key, "(") && words.is(statement.end - 1, ")") {
+                Some(words.text(key + 1..statement.end - 1).to_string())
+            } else {
+                None
+            };
+            settings.push((
+                words.text(i + 1..key).to_string(),
+                value,
+                words.token(i + 1).start,
+                words.token(statement.end - 1).end,
+            ));
+        }
+    }
+    settings
+}
+
+/// Returns the index past the variable name starting at `i`, e.g. `@@session.sql_mode`.
+fn key_end(words: &Words, i: usize, end: usize) -> usize {
+    let mut j = i;
+    while j < end
+        && !words.is_any(j, &ASSIGNMENT_OPERATORS)
+        && (words.is_word(j) || words.is_any(j, &[".", "@", "@@"]))
+    {
+        j += 1;
+    }
+    j.max(i + 1)
+}
+
+/// Whether the tokens at `i` look like `[scope] name =`, i.e. the start of another assignment
+/// rather than the next item of a list value such as `SET search_path TO a, b`.
+fn starts_assignment(words: &Words, i: usize, end: usize) -> bool {
+    let mut j = i;
+    while words.is_any(j, &SET_SCOPES) && !words.is_any(j + 1, &ASSIGNMENT_OPERATORS) {
+        j += 1;
+    }
+    j < end && words.is_any(key_end(words, j, end), &ASSIGNMENT_OPERATORS)
+}
+
+/// Reads a single assignment of a `SET` statement starting at `i`, returning it along with the
+/// index past its value.
+fn assignment(words: &Words, i: usize, end: usize) -> Option<(SessionSetting, usize)> {
+    let mut key = i;
+    while words.is_any(key, &SET_SCOPES) && !words.is_any(key + 1, &ASSIGNMENT_OPERATORS) {
+        key += 1;
+    }
+    if key >= end || !(words.is_word(key) || words.is_any(key, &["@", "@@"])) {
+        return None;
+    }
+
+    // Settings without an operator, e.g. `SET NOCOUNT ON`, are named by a single word
+    let (name_end, value) = match key_end(words, key, end) {
+        j if words.is_any(j, &ASSIGNMENT_OPERATORS) => (j, j + 1),
+        _ if words.is(key, "TIME") && words.is(key + 1, "ZONE") => (key + 2, key + 2),
+        _ => (key + 1, key + 1),
+    };
+
+    let mut value_end = value;
+    let mut depth = 0;
+    while value_end < end {
+        if words.is(value_end, "(") {
+            depth += 1;
+        } else if words.is(value_end, ")") {
+            depth -= 1;
+        } else if depth == 0
+            && words.is(value_end, ",")
+            && starts_assignment(words, value_end + 1, end)
+        {
+            break;
+        }
+        value_end += 1;
+    }
+
+    let last = if value < value_end {
+        value_end
+    } else {
+        name_end
+    };
+    let setting = (
+        words.text(key..name_end).to_string(),
+        (value < value_end).then(|| words.text(value..value_end).to_string()),
+        words.token(i).start,
+        words.token(last - 1).end,
+    );
+    Some((setting, value_end))
+}
+
+/// Finds the calls to any of the given functions, i.e. the words or quoted identifiers followed by
+/// `(` whose name matches one of `names` regardless of case. Names are expected in uppercase.
+pub fn find_function_calls(words: &Words, names: &HashSet<String>) -> Vec<FunctionCall> {
+    (0..words.len())
+        .filter(|&i| words.is(i + 1, "(") && words.is_word(i))
+        .map(|i| words.token(i))
+        .filter(|token| names.contains(&token.text.to_uppercase()))
+        .map(|token| (token.text.to_string(), token.start, token.end))
+        .collect()
+}
+
+/// Finds the CTEs defined by the `WITH` clause each statement starts with, along with the aliases
+/// of the select list of the statement itself, if it's a query.
+pub fn find_ctes_and_aliases(words: &Words) -> Vec<IndexedName> {
+    let mut names = Vec::new();
+    for statement in statements(words) {
+        let mut i = statement.start;
+        while words.is(i, "(") {
+            i += 1;
+        }
+
+        if words.is(i, "WITH") {
+            i += if words.is(i + 1, "RECURSIVE") { 2 } else { 1 };
+            while let Some((cte, end)) = cte_definition(words, i, statement.end) {
+                names.push(cte);
+                i = end;
+                if !words.is(i, ",") {
+                    break;
+                }
+                i += 1;
+            }
+        }
+
+        if words.is(i, "SELECT") {
+            select_aliases(words, i + 1, statement.end, &mut names);
+        }
+    }
+    names
+}
+
+/// Reads a CTE such as `name (a, b) AS [NOT] MATERIALIZED (query)` starting at `i`, returning it
+/// along with the index past its query.
+fn cte_definition(words: &Words, i: usize, end: usize) -> Option<(IndexedName, usize)> {
+    if !words.is_word(i) {
+        return None;
+    }
+    let mut j = i + 1;
+    if words.is(j, "(") {
+        j = closing_paren(words, j, end)? + 1;
+    }
+    if !words.is(j, "AS") {
+        return None;
+    }
+    j += 1;
+    if words.is(j, "NOT") {
+        j += 1;
+    }
+    if words.is(j, "MATERIALIZED") {
+        j += 1;
+    }
+    if !words.is(j, "(") {
+        return None;
+    }
+
+    let close = closing_paren(words, j, end)?;
+    let name = words.token(i);
+    let cte = (
+        name.text.to_string(),
+        "cte",
+        name.start,
+        name.end,
+        words.token(j).start,
+        words.token(close).end,
+    );
+    Some((cte, close + 1))
+}
+
+/// Records the aliases of the select list starting at `i`, i.e. the names following `AS` or an
+/// operand at the end of its items.
+fn select_aliases(words: &Words, i: usize, end: usize, names: &mut Vec<IndexedName>) {
+    let ends_list = |j: usize| starts_any(words, j, &SELECT_LIST_ENDS);
+
+    let start = if words.is_any(i, &["DISTINCT", "ALL"]) {
+        i + 1
+    } else {
+        i
+    };
+    let mut item_start = start;
+    let mut depth = 0;
+    for j in start..=end {
+        // The query may be wrapped in parentheses, which close its select list too
+        let is_item_end = j == end || depth == 0 && (words.is_any(j, &[",", ")"]) || ends_list(j));
+        if is_item_end {
+            if let Some(alias) = alias(words, item_start..j) {
+                names.push(alias);
+            }
+            if !words.is(j, ",") {
+                return;
+            }
+            item_start = j + 1;
+        } else if words.is(j, "(") {
+            depth += 1;
+        } else if words.is(j, ")") {
+            depth -= 1;
+        }
+    }
+}
+
+fn alias(words: &Words, item: Range<usize>) -> Option<IndexedName> {
+    if item.len() < 2 {
+        return None;
+    }
+    let name = item.end - 1;
+    let expression_end = if words.is(name - 1, "AS") && words.is_word(name) {
+        name - 1
+    } else if words.is_name(name) && words.ends_operand(name - 1) {
+        name
+    } else {
+        return None;
+    };
+    if expression_end == item.start {
+        return None;
+    }
+
+    let token = words.token(name);
+    Some((
+        token.text.to_string(),
+        "alias",
+        token.start,
+        token.end,
+        words.token(item.start).start,
+        words.token(expression_end - 1).end,
+    ))
+}
+
+/// The index of the parenthesis closing the one at `i`, if it's closed before `end`.
+fn closing_paren(words: &Words, i: usize, end: usize) -> Option<usize> {
+    let mut depth = 0;
+    for j in i..end {
+        if words.is(j, "(") {
+            depth += 1;
+        } else if words.is(j, ")") {
+            depth -= 1;
+            if depth == 0 {
+                return Some(j);
+            }
+        }
+    }
+    None
+}
+
+/// Extracts the names of tables and CTEs referenced by `sql` using token heuristics rather than
+/// a full parse. This is much cheaper, but can miss or misreport names in unusual queries.
+#[pyfunction]
+pub fn extract_tables(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<TableReference>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    Ok(find_tables(&Words::new(
+        &source,
+        &tokens,
+        &config.token_types,
+    )))
+}
+
+/// Extracts the qualified names of the tables referenced by `sql`, other than CTEs, using the same
+/// token heuristics as `extract_tables`. It's meant as a fast pre-filter, e.g. for access control
+/// checks, that queries can be parsed after if they pass.
+#[pyfunction]
+pub fn extract_table_names(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<TableName>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    Ok(find_table_names(&Words::new(
+        &source,
+        &tokens,
+        &config.token_types,
+    )))
+}
+
+/// Indexes the CTEs defined by `sql` and the aliases of its top-level select lists, along with
+/// the spans of their definitions, using token heuristics. It's meant for telling which queries
+/// are worth a full parse, e.g. for lineage, rather than for resolving names precisely.
+#[pyfunction]
+pub fn index_ctes(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<IndexedName>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    Ok(find_ctes_and_aliases(&Words::new(
+        &source,
+        &tokens,
+        &config.token_types,
+    )))
+}
+
+/// Extracts the column references in `sql` using token heuristics, as a cheap first pass before
+/// deciding which queries are worth parsing.
+#[pyfunction]
+pub fn extract_columns(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<ColumnReference>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    Ok(find_columns(&Words::new(
+        &source,
+        &tokens,
+        &config.token_types,
+    )))
+}
+
+/// Extracts the session settings assigned by `sql`, e.g. for connection poolers that need to
+/// replay session state, covering `SET`, `ALTER SESSION SET` and `PRAGMA` statements.
+#[pyfunction]
+pub fn extract_settings(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<SessionSetting>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    Ok(find_settings(&Words::new(
+        &source,
+        &tokens,
+        &config.token_types,
+    )))
+}
+
+/// Reports the calls in `sql` to any of the given functions, e.g. `SLEEP` or `XP_CMDSHELL` when
+/// screening inbound queries. Names match case-insensitively, and since matching is done on
+/// tokens, mentions inside strings and comments are never reported.
+#[pyfunction]
+pub fn detect_functions(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    names: Vec<String>,
+) -> PyResult<Vec<FunctionCall>> {
+    let names = names.iter().map(|name| name.to_uppercase()).collect();
+    function_calls(&tokenizer.config(), sql, &dialect_settings, &names, 0)
+}
+
+fn function_calls(
+    config: &TokenizerConfig,
+    sql: &str,
+    dialect_settings: &TokenizerDialectSettings,
+    names: &HashSet<String>,
+    offset: usize,
+) -> PyResult<Vec<FunctionCall>> {
+    let tokens = config.try_tokenize(sql, dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+
+    let mut calls = find_function_calls(&words, names);
+    for call in calls.iter_mut() {
+        call.1 += offset;
+        call.2 += offset;
+    }
+    // Commands such as `EXEC` aren't scanned past their keyword, so their calls would go unseen
+    for i in 0..words.len() {
+        if let Some(start) = words.command_start(i) {
+            let text = &words.token(i).text;
+            let nested = function_calls(config, text, dialect_settings, names, offset + start)?;
+            calls.extend(nested);
+        }
+    }
+    calls.sort_by_key(|call| call.1);
+    Ok(calls)
+}
+
+/// Reports every `SELECT *` style projection in `sql`, including qualified ones like `t.*`,
+/// along with the index of the statement it appears in.
+#[pyfunction]
+pub fn find_select_star(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<SelectStar>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    Ok(find_select_stars(&Words::new(
+        &source,
+        &tokens,
+        &config.token_types,
+    )))
+}
+
+/// Reports every bind parameter placeholder in `sql`, whatever its style, e.g. `?`, `$1`, `:name`,
+/// `@name` or `%(name)s`, in order of appearance, so that callers can check that the parameters
+/// they supply match the ones the query expects.
+#[pyfunction]
+pub fn extract_parameters(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<Parameter>> {
+    parameters(&tokenizer.config(), sql, &dialect_settings, 0)
+}
+
+fn parameters(
+    config: &TokenizerConfig,
+    sql: &str,
+    dialect_settings: &TokenizerDialectSettings,
+    offset: usize,
+) -> PyResult<Vec<Parameter>> {
+    let tokens = config.try_tokenize(sql, dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+
+    let mut found: Vec<Parameter> = find_all_placeholders(&source, &tokens)
+        .into_iter()
+        .map(|(style, placeholder)| {
+            (
+                style.as_str(),
+                placeholder.key,
+                placeholder.start + offset,
+                placeholder.end + offset,
+            )
+        })
+        .collect();
+    // Commands such as `EXEC` aren't scanned past their keyword, e.g. `EXEC p @a`
+    for i in 0..words.len() {
+        if let Some(start) = words.command_start(i) {
+            let text = &words.token(i).text;
+            found.extend(parameters(config, text, dialect_settings, offset + start)?);
+        }
+    }
+    found.sort_by_key(|parameter| parameter.2);
+    Ok(found)
+}
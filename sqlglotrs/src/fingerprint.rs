@@ -0,0 +1,24 @@
+use crate::settings::TokenizerDialectSettings;
+use crate::tokenizer::{Tokenizer, TryTokenize};
+use pyo3::prelude::*;
+use sqlglotrs_core::fingerprint::{fingerprint_parts, hash_parts};
+use sqlglotrs_core::source::Source;
+
+/// Returns a stable hash of the shape of `sql`, e.g. to group the queries in a log that only
+/// differ by their literals, parameters, case or formatting. Comments and trailing semicolons are
+/// ignored, while quoted identifiers are compared as written.
+#[pyfunction]
+pub fn fingerprint(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<String> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    Ok(hash_parts(&fingerprint_parts(
+        &source,
+        &tokens,
+        &config.token_types,
+    )))
+}
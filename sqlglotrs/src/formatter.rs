@@ -0,0 +1,518 @@
+use crate::detokenize::{Detokenizer, KeywordCase, Spacing};
+use crate::settings::TokenizerDialectSettings;
+use crate::token::RawToken;
+use crate::tokenizer::{Tokenizer, TokenizerConfig, TryTokenize};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::statement::statements;
+use sqlglotrs_core::words::Words;
+use std::borrow::Cow;
+use std::ops::Range;
+
+const CLAUSE_KEYWORDS: [&str; 9] = [
+    "FROM",
+    "WHERE",
+    "HAVING",
+    "QUALIFY",
+    "WINDOW",
+    "LIMIT",
+    "OFFSET",
+    "FETCH",
+    "RETURNING",
+];
+const SORT_KEYWORDS: [&str; 5] = ["GROUP", "ORDER", "CLUSTER", "DISTRIBUTE", "SORT"];
+const SET_OPERATIONS: [&str; 4] = ["UNION", "INTERSECT", "EXCEPT", "MINUS"];
+const JOIN_MODIFIERS: [&str; 11] = [
+    "NATURAL",
+    "LEFT",
+    "RIGHT",
+    "FULL",
+    "INNER",
+    "OUTER",
+    "CROSS",
+    "SEMI",
+    "ANTI",
+    "ASOF",
+    "POSITIONAL",
+];
+const JOINS: [&str; 3] = ["JOIN", "STRAIGHT_JOIN", "APPLY"];
+const DML_KEYWORDS: [&str; 5] = ["INSERT", "UPDATE", "DELETE", "MERGE", "SET"];
+
+/// Where `format_sql` puts the commas between the items of a list that spans several lines.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommaStyle {
+    /// At the end of each item but the last
+    Trailing,
+    /// At the start of each item but the first
+    Leading,
+}
+
+impl CommaStyle {
+    pub fn from_name(name: &str) -> Option<CommaStyle> {
+        match name {
+            "trailing" => Some(CommaStyle::Trailing),
+            "leading" => Some(CommaStyle::Leading),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FormatOptions {
+    pub keyword_case: Option<KeywordCase>,
+    pub comma_style: CommaStyle,
+    pub indent: usize,
+    pub max_line_width: usize,
+}
+
+/// How a clause's body is laid out once it doesn't fit on the clause's line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Layout {
+    /// One item per line, e.g. projections
+    List,
+    /// One operand of a top-level `AND` or `OR` per line, e.g. filters
+    Condition,
+    /// Kept on the clause's line, e.g. joins
+    Inline,
+}
+
+/// A clause of a statement, e.g. `GROUP BY a, b`, where the header is the range of the keywords
+/// introducing it, if any, and the body the range of tokens that follow.
+struct Clause {
+    header: Range<usize>,
+    body: Range<usize>,
+    layout: Layout,
+}
+
+/// Lays out a token stream as indented SQL, starting every clause on a line of its own and
+/// splitting clauses that don't fit in the maximum width into one item or condition per line.
+/// Only the whitespace between tokens changes, apart from comments, which are written as block
+/// comments after the token they belong to.
+pub struct Formatter<'a> {
+    words: &'a Words<'a>,
+    detokenizer: Detokenizer<'a>,
+    options: FormatOptions,
+}
+
+impl<'a> Formatter<'a> {
+    pub fn new(
+        words: &'a Words<'a>,
+        config: &'a TokenizerConfig,
+        options: FormatOptions,
+    ) -> Formatter<'a> {
+        Formatter {
+            words,
+            detokenizer: Detokenizer::new(config, Spacing::Compact, options.keyword_case, true),
+            options,
+        }
+    }
+
+    pub fn format(&self) -> String {
+        let mut out = String::new();
+        let mut position = 0;
+        for statement in statements(self.words) {
+            // Semicolons end the statement before them, and empty statements are just that
+            self.push_semicolons(&mut out, position..statement.start);
+            if !out.is_empty() {
+                out.push_str("\n\n");
+            }
+            out.push_str(&self.statement(statement.clone(), 0));
+            position = statement.end;
+        }
+        self.push_semicolons(&mut out, position..self.words.len());
+        out
+    }
+
+    fn push_semicolons(&self, out: &mut String, tokens: Range<usize>) {
+        for i in tokens {
+            let token = self.words.token(i);
+            out.push_str(&self.detokenizer.token_text(token, None));
+            self.detokenizer.push_comments(out, token);
+        }
+    }
+
+    fn statement(&self, statement: Range<usize>, depth: usize) -> String {
+        self.clauses(statement)
+            .iter()
+            .map(|clause| self.clause(clause, depth))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Splits a statement at the keywords of its clauses, leaving nested queries alone.
+    fn clauses(&self, statement: Range<usize>) -> Vec<Clause> {
+        let words = self.words;
+        let start = statement.start;
+        let is_update = words.is(start, "UPDATE");
+
+        let mut clauses = Vec::new();
+        let mut clause = Clause {
+            header: start..start,
+            body: start..start,
+            layout: Layout::Inline,
+        };
+        let mut depth = 0;
+        let mut i = start;
+        while i < statement.end {
+            if words.is_any(i, &["(", "["]) {
+                depth += 1;
+            } else if words.is_any(i, &[")", "]"]) {
+                depth -= 1;
+            }
+
+            let found = if depth == 0 {
+                self.clause_header(i, start, is_update)
+            } else {
+                None
+            };
+            match found {
+                Some((end, layout)) => {
+                    clause.body.end = i;
+                    if !clause.header.is_empty() || !clause.body.is_empty() {
+                        clauses.push(clause);
+                    }
+                    let end = end.min(statement.end);
+                    clause = Clause {
+                        header: i..end,
+                        body: end..end,
+                        layout,
+                    };
+                    i = end;
+                }
+                None => i += 1,
+            }
+        }
+        clause.body.end = statement.end.max(clause.body.start);
+        clauses.push(clause);
+        clauses
+    }
+
+    /// If a clause starts at `i`, returns the index past the keywords introducing it and how its
+    /// body is laid out.
+    fn clause_header(&self, i: usize, start: usize, is_update: bool) -> Option<(usize, Layout)> {
+        let words = self.words;
+        if let Some(end) = self.keywords(i, &["SELECT"]) {
+            let end = match self
+                .keywords(end, &["DISTINCT"])
+                .or(self.keywords(end, &["ALL"]))
+            {
+                Some(modifier) if !words.is(modifier, "ON") => modifier,
+                _ => end,
+            };
+            Some((end, Layout::List))
+        } else if words.is(i, "FROM") && words.is(i.wrapping_sub(1), "DISTINCT") {
+            // IS [NOT] DISTINCT FROM
+            None
+        } else if words.is_any(i, &["FROM", "WINDOW", "RETURNING", "VALUES"]) {
+            Some((i + 1, Layout::List))
+        } else if words.is_any(i, &CLAUSE_KEYWORDS) {
+            let layout = if words.is_any(i, &["WHERE", "HAVING", "QUALIFY"]) {
+                Layout::Condition
+            } else {
+                Layout::Inline
+            };
+            Some((i + 1, layout))
+        } else if let Some(end) = SORT_KEYWORDS
+            .iter()
+            .find_map(|keyword| self.keywords(i, &[keyword, "BY"]))
+        {
+            Some((end, Layout::List))
+        } else if let Some(end) = SET_OPERATIONS
+            .iter()
+            .find_map(|keyword| self.keywords(i, &[keyword]))
+        {
+            // SELECT * EXCEPT (...) isn't a set operation
+            if words.is(i.wrapping_sub(1), "*") {
+                return None;
+            }
+            let end = self
+                .keywords(end, &["ALL"])
+                .or(self.keywords(end, &["DISTINCT"]))
+                .unwrap_or(end);
+            Some((end, Layout::Inline))
+        } else if i == start && words.is(i, "WITH") {
+            let end = self.keywords(i + 1, &["RECURSIVE"]).unwrap_or(i + 1);
+            Some((end, Layout::List))
+        } else if i == start && words.is_any(i, &DML_KEYWORDS) {
+            Some((i + 1, Layout::Inline))
+        } else if is_update && words.is(i, "SET") {
+            Some((i + 1, Layout::List))
+        } else {
+            self.join(i).map(|end| (end, Layout::Inline))
+        }
+    }
+
+    /// If the tokens from `i` on spell out the given keywords, returns the index past them. A run
+    /// of keywords the scanner keeps as a single token, e.g. `GROUP BY`, matches as well.
+    fn keywords(&self, i: usize, keywords: &[&str]) -> Option<usize> {
+        let mut rest = keywords;
+        let mut j = i;
+        while !rest.is_empty() {
+            for word in self.keyword_words(j)? {
+                let (keyword, others) = rest.split_first()?;
+                if !word.eq_ignore_ascii_case(keyword) {
+                    return None;
+                }
+                rest = others;
+            }
+            j += 1;
+        }
+        Some(j)
+    }
+
+    /// If a join starts at `i`, e.g. `LEFT OUTER JOIN`, returns the index past its keywords.
+    fn join(&self, i: usize) -> Option<usize> {
+        let mut j = i;
+        loop {
+            let words: Vec<&str> = self.keyword_words(j)?.collect();
+            let (last, modifiers) = words.split_last()?;
+            let is_modifier = |word: &&str| {
+                JOIN_MODIFIERS
+                    .iter()
+                    .any(|modifier| word.eq_ignore_ascii_case(modifier))
+            };
+            if !modifiers.iter().all(is_modifier) {
+                return None;
+            }
+            j += 1;
+            if JOINS.iter().any(|join| last.eq_ignore_ascii_case(join)) {
+                return Some(j);
+            }
+            if !is_modifier(last) {
+                return None;
+            }
+        }
+    }
+
+    fn keyword_words(&self, i: usize) -> Option<std::str::SplitWhitespace<'a>> {
+        (i < self.words.len() && self.words.is_word(i) && !self.words.is_name(i))
+            .then(|| self.words.token(i).text.split_whitespace())
+    }
+
+    fn clause(&self, clause: &Clause, depth: usize) -> String {
+        let mut header = String::new();
+        let mut prev: Option<(&RawToken, Cow<str>)> = None;
+        for i in clause.header.clone() {
+            let token = self.words.token(i);
+            let text = self
+                .detokenizer
+                .token_text(token, prev.as_ref().map(|(t, _)| *t));
+            if prev.is_some() {
+                header.push(' ');
+            }
+            header.push_str(&text);
+            self.detokenizer.push_comments(&mut header, token);
+            prev = Some((token, text));
+        }
+
+        let mut out = self.indentation(depth);
+        out.push_str(&header);
+        if clause.body.is_empty() {
+            return out;
+        }
+        if !header.is_empty() {
+            out.push(' ');
+        }
+
+        let inline = self.span(clause.body.clone(), depth);
+        let fits = !inline.contains('\n')
+            && out.chars().count() + inline.chars().count() <= self.options.max_line_width;
+        let parts = match clause.layout {
+            Layout::List => self.split_list(clause.body.clone()),
+            Layout::Condition => self.split_condition(clause.body.clone()),
+            Layout::Inline => vec![clause.body.clone()],
+        };
+        if fits || parts.len() == 1 || header.is_empty() {
+            out.push_str(&inline);
+            return out;
+        }
+
+        out.pop();
+        for (n, part) in parts.iter().enumerate() {
+            out.push('\n');
+            out.push_str(&self.indentation(depth + 1));
+            let is_comma = n > 0 && clause.layout == Layout::List;
+            if is_comma && self.options.comma_style == CommaStyle::Leading {
+                self.push_token(&mut out, part.start - 1);
+                out.push(' ');
+            }
+            out.push_str(&self.span(part.clone(), depth + 1));
+            if n + 1 < parts.len()
+                && clause.layout == Layout::List
+                && self.options.comma_style == CommaStyle::Trailing
+            {
+                self.push_token(&mut out, part.end);
+            }
+        }
+        out
+    }
+
+    fn push_token(&self, out: &mut String, i: usize) {
+        let token = self.words.token(i);
+        out.push_str(&self.detokenizer.token_text(token, None));
+        self.detokenizer.push_comments(out, token);
+    }
+
+    /// The items of a list, split at its top-level commas, which are left out of the ranges.
+    fn split_list(&self, body: Range<usize>) -> Vec<Range<usize>> {
+        let mut parts = Vec::new();
+        let mut start = body.start;
+        let mut depth = 0;
+        for i in body.clone() {
+            if self.words.is_any(i, &["(", "["]) {
+                depth += 1;
+            } else if self.words.is_any(i, &[")", "]"]) {
+                depth -= 1;
+            } else if depth == 0 && self.words.is(i, ",") {
+                parts.push(start..i);
+                start = i + 1;
+            }
+        }
+        parts.push(start..body.end);
+        parts
+    }
+
+    /// The operands of a condition's top-level `AND`s and `OR`s, each but the first starting with
+    /// the operator before it. The `AND` of a `BETWEEN` and those of `CASE` expressions are kept.
+    fn split_condition(&self, body: Range<usize>) -> Vec<Range<usize>> {
+        let mut parts = Vec::new();
+        let mut start = body.start;
+        let mut depth = 0;
+        let mut cases = 0;
+        let mut between = false;
+        for i in body.clone() {
+            if self.words.is_any(i, &["(", "["]) {
+                depth += 1;
+            } else if self.words.is_any(i, &[")", "]"]) {
+                depth -= 1;
+            } else if depth > 0 {
+                continue;
+            } else if self.words.is(i, "CASE") {
+                cases += 1;
+            } else if self.words.is(i, "END") && cases > 0 {
+                cases -= 1;
+            } else if self.words.is(i, "BETWEEN") {
+                between = true;
+            } else if self.words.is(i, "AND") && between {
+                between = false;
+            } else if cases == 0 && self.words.is_any(i, &["AND", "OR"]) && i > start {
+                parts.push(start..i);
+                start = i;
+            }
+        }
+        parts.push(start..body.end);
+        parts
+    }
+
+    /// Writes a range of tokens on one line, except for the queries nested in it, which are laid
+    /// out on lines of their own one level deeper.
+    fn span(&self, tokens: Range<usize>, depth: usize) -> String {
+        let words = self.words;
+        let mut out = String::new();
+        let mut prev: Option<(&RawToken, Cow<str>)> = None;
+        let mut i = tokens.start;
+        while i < tokens.end {
+            let token = words.token(i);
+            let text = self
+                .detokenizer
+                .token_text(token, prev.as_ref().map(|(t, _)| *t));
+            if let Some((prev, prev_text)) = &prev {
+                self.detokenizer
+                    .push_separator(&mut out, prev, prev_text, token, &text);
+            }
+            out.push_str(&text);
+            self.detokenizer.push_comments(&mut out, token);
+
+            match self.subquery(i, tokens.end) {
+                Some(close) => {
+                    out.push('\n');
+                    out.push_str(&self.statement(i + 1..close, depth + 1));
+                    out.push('\n');
+                    out.push_str(&self.indentation(depth));
+                    let token = words.token(close);
+                    let text = self.detokenizer.token_text(token, None);
+                    out.push_str(&text);
+                    self.detokenizer.push_comments(&mut out, token);
+                    prev = Some((token, text));
+                    i = close + 1;
+                }
+                None => {
+                    prev = Some((token, text));
+                    i += 1;
+                }
+            }
+        }
+        out
+    }
+
+    /// If the token at `i` opens parentheses around a query, returns the index of the closing
+    /// parenthesis.
+    fn subquery(&self, i: usize, end: usize) -> Option<usize> {
+        let words = self.words;
+        if !words.is(i, "(") || !words.is_any(i + 1, &["SELECT", "WITH", "VALUES"]) {
+            return None;
+        }
+        let mut depth = 0;
+        for j in i..end {
+            if words.is(j, "(") {
+                depth += 1;
+            } else if words.is(j, ")") {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(j);
+                }
+            }
+        }
+        None
+    }
+
+    fn indentation(&self, depth: usize) -> String {
+        " ".repeat(depth * self.options.indent)
+    }
+}
+
+/// Reformats `sql` with every clause on its own line and nested queries indented by `indent`
+/// spaces per level. Clauses longer than `max_line_width` chars are split into one item per line,
+/// with commas placed according to `comma_style`, "trailing" or "leading", or one condition per
+/// line. Keywords are written in `keyword_case`, "upper" or "lower", or as they are if it's None.
+#[pyfunction]
+#[pyo3(signature = (
+    tokenizer,
+    sql,
+    dialect_settings,
+    keyword_case = Some("upper"),
+    comma_style = "trailing",
+    indent = 2,
+    max_line_width = 80
+))]
+pub fn format_sql(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    keyword_case: Option<&str>,
+    comma_style: &str,
+    indent: usize,
+    max_line_width: usize,
+) -> PyResult<String> {
+    let keyword_case = keyword_case
+        .map(|case| {
+            KeywordCase::from_name(case)
+                .ok_or_else(|| PyValueError::new_err(format!("Unknown keyword case '{}'", case)))
+        })
+        .transpose()?;
+    let comma_style = CommaStyle::from_name(comma_style)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown comma style '{}'", comma_style)))?;
+    let options = FormatOptions {
+        keyword_case,
+        comma_style,
+        indent,
+        max_line_width,
+    };
+
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+    Ok(Formatter::new(&words, &config, options).format())
+}
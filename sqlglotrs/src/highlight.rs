@@ -0,0 +1,378 @@
+use crate::settings::TokenizerDialectSettings;
+use crate::tokenizer::{Tokenizer, TokenizerConfig, TryTokenize};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rustc_hash::FxHashMap as HashMap;
+use sqlglotrs_core::placeholder::find_all_placeholders;
+use sqlglotrs_core::source::{adjacent, span, Source};
+use sqlglotrs_core::words::Words;
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::ops::Range;
+
+/// How a span of a query is highlighted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TokenClass {
+    Keyword,
+    Identifier,
+    Function,
+    String,
+    Number,
+    Parameter,
+    Operator,
+    Comment,
+}
+
+impl TokenClass {
+    pub const ALL: [TokenClass; 8] = [
+        TokenClass::Keyword,
+        TokenClass::Identifier,
+        TokenClass::Function,
+        TokenClass::String,
+        TokenClass::Number,
+        TokenClass::Parameter,
+        TokenClass::Operator,
+        TokenClass::Comment,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TokenClass::Keyword => "keyword",
+            TokenClass::Identifier => "identifier",
+            TokenClass::Function => "function",
+            TokenClass::String => "string",
+            TokenClass::Number => "number",
+            TokenClass::Parameter => "parameter",
+            TokenClass::Operator => "operator",
+            TokenClass::Comment => "comment",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<TokenClass> {
+        TokenClass::ALL
+            .into_iter()
+            .find(|class| class.as_str() == name)
+    }
+
+    /// The LSP semantic token type of this class, see `semantic_tokens`.
+    pub fn semantic_token_type(self) -> &'static str {
+        match self {
+            TokenClass::Identifier => "variable",
+            class => class.as_str(),
+        }
+    }
+
+    /// The SGR parameters `highlight_ansi` uses for this class unless its theme says otherwise.
+    fn default_ansi(self) -> &'static str {
+        match self {
+            TokenClass::Keyword => "1;34",
+            TokenClass::Function => "33",
+            TokenClass::String => "32",
+            TokenClass::Number => "36",
+            TokenClass::Parameter => "35",
+            TokenClass::Comment => "90",
+            TokenClass::Identifier | TokenClass::Operator => "",
+        }
+    }
+}
+
+/// Splits `sql` into classified, non-overlapping char ranges in order. Whatever isn't covered,
+/// which is only ever whitespace, is left as is. Comments aren't tokens of their own, so they're
+/// found in the text between tokens, and the rest of commands such as `EXEC`, which the scanner
+/// keeps as a single string, is scanned again so it's highlighted like any other SQL. Parameters
+/// are the placeholders of every style that `find_all_placeholders` finds, e.g. `:name` or `$1`.
+pub(crate) fn highlight_spans(
+    config: &TokenizerConfig,
+    sql: &str,
+    dialect_settings: &TokenizerDialectSettings,
+) -> PyResult<Vec<(TokenClass, Range<usize>)>> {
+    let tokens = config.try_tokenize(sql, dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+    // The last char of each placeholder by its first one
+    let placeholders: HashMap<usize, usize> = find_all_placeholders(&source, &tokens)
+        .into_iter()
+        .map(|(_, placeholder)| (placeholder.start, placeholder.end))
+        .collect();
+
+    let mut spans = Vec::new();
+    let mut position = 0;
+    let mut i = 0;
+    while i < words.len() {
+        let token = words.token(i);
+        let command_start = words.command_start(i);
+        let start = command_start.unwrap_or(token.start);
+        comment_spans(&source, position..start, &mut spans);
+        position = token.end + 1;
+
+        if let Some(start) = command_start {
+            for (class, range) in highlight_spans(config, &token.text, dialect_settings)? {
+                spans.push((class, range.start + start..range.end + start));
+            }
+        } else if let Some(&end) = placeholders.get(&token.start) {
+            // Placeholders such as `@name` and `%(name)s` can be scanned as several tokens
+            while i + 1 < words.len() && words.token(i + 1).end <= end {
+                i += 1;
+            }
+            position = end + 1;
+            spans.push((TokenClass::Parameter, token.start..position));
+        } else {
+            spans.push((token_class(config, &source, i, &words), span(token)));
+        }
+        i += 1;
+    }
+    comment_spans(&source, position..sql.chars().count(), &mut spans);
+    Ok(spans)
+}
+
+/// Adds the comments in a stretch of text between tokens, keeping the whitespace around them out.
+fn comment_spans(source: &Source, gap: Range<usize>, spans: &mut Vec<(TokenClass, Range<usize>)>) {
+    let text = source.text(gap.clone());
+    let leading = text.chars().take_while(|c| c.is_whitespace()).count();
+    let trailing = text.chars().rev().take_while(|c| c.is_whitespace()).count();
+    if leading < gap.len() {
+        spans.push((TokenClass::Comment, gap.start + leading..gap.end - trailing));
+    }
+}
+
+fn token_class(config: &TokenizerConfig, source: &Source, i: usize, words: &Words) -> TokenClass {
+    let token = words.token(i);
+    let token_types = &config.token_types;
+    let is_word = token
+        .text
+        .starts_with(|c: char| c.is_alphabetic() || c == '_');
+    // A name directly followed by a parenthesis is most likely a function being called, though it
+    // may also be e.g. a table followed by its columns
+    let name_class = || {
+        if words.is(i + 1, "(") && adjacent(token, words.token(i + 1)) {
+            TokenClass::Function
+        } else {
+            TokenClass::Identifier
+        }
+    };
+
+    if token.token_type == token_types.hint || token.token_type == token_types.hint_end {
+        TokenClass::Comment
+    } else if token.token_type == token_types.identifier || token.token_type == token_types.var {
+        name_class()
+    } else if !source.is_verbatim(token) {
+        TokenClass::String
+    } else if token.token_type == token_types.number {
+        TokenClass::Number
+    } else if token.token_type == token_types.parameter {
+        TokenClass::Parameter
+    } else if is_word && config.keyword_types.contains(&token.token_type) {
+        TokenClass::Keyword
+    } else if is_word {
+        name_class()
+    } else {
+        TokenClass::Operator
+    }
+}
+
+/// Returns the classified spans of `sql` in order as (class, start, end) triples, where the char
+/// offset `end` is exclusive and the class is one of keyword, identifier, function, string,
+/// number, parameter, operator and comment, e.g. for editors to color a query the way sqlglot
+/// scans it. Whatever no span covers is whitespace. Raises a ValueError if the scan fails.
+#[pyfunction]
+pub fn highlight(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<(&'static str, usize, usize)>> {
+    let spans = highlight_spans(&tokenizer.config(), sql, &dialect_settings)?;
+    Ok(spans
+        .into_iter()
+        .map(|(class, range)| (class.as_str(), range.start, range.end))
+        .collect())
+}
+
+/// Renders `sql` with ANSI color codes for terminals. The theme maps class names, i.e. keyword,
+/// identifier, function, string, number, parameter, operator and comment, to SGR parameters
+/// such as `"1;34"`, overriding the default theme, and an empty string leaves a class uncolored.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, theme = None))]
+pub fn highlight_ansi(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    theme: Option<HashMap<String, String>>,
+) -> PyResult<String> {
+    let mut codes: HashMap<TokenClass, String> = TokenClass::ALL
+        .into_iter()
+        .map(|class| (class, class.default_ansi().to_string()))
+        .collect();
+    for (name, code) in theme.unwrap_or_default() {
+        let class = TokenClass::from_name(&name)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown token class '{}'", name)))?;
+        codes.insert(class, code);
+    }
+
+    let spans = highlight_spans(&tokenizer.config(), sql, &dialect_settings)?;
+    Ok(render(sql, &spans, |out, class, text| {
+        match class.map(|class| codes[&class].as_str()) {
+            Some(code) if !code.is_empty() => {
+                let _ = write!(out, "\x1b[{}m{}\x1b[0m", code, text);
+            }
+            _ => out.push_str(text),
+        }
+    }))
+}
+
+/// Renders `sql` as HTML, wrapping each token and comment in a span whose class is the prefix
+/// followed by its class name, e.g. `sql-keyword`, and escaping everything else.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, css_class_prefix = ""))]
+pub fn highlight_html(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    css_class_prefix: &str,
+) -> PyResult<String> {
+    let spans = highlight_spans(&tokenizer.config(), sql, &dialect_settings)?;
+    let prefix = escape_html(css_class_prefix);
+    Ok(render(sql, &spans, |out, class, text| match class {
+        Some(class) => {
+            let _ = write!(
+                out,
+                "<span class=\"{}{}\">{}</span>",
+                prefix,
+                class.as_str(),
+                escape_html(text)
+            );
+        }
+        None => out.push_str(&escape_html(text)),
+    }))
+}
+
+/// Encodes the classified spans of `sql` as LSP semantic tokens, i.e. five integers per token: the
+/// line relative to the previous token's, the start column relative to the previous token's if
+/// they're on the same line, the length, the token type and its modifiers, which are always 0.
+/// Token types index `SEMANTIC_TOKEN_TYPES`, the legend a language server announces, and
+/// columns and lengths are counted in the code units of `position_encoding`, one of utf-8,
+/// utf-16 and utf-32. Spans that run over several lines, e.g. block comments, are split into a
+/// token per line. Raises a ValueError if the scan fails.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, position_encoding = "utf-16"))]
+pub fn semantic_tokens(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    position_encoding: &str,
+) -> PyResult<Vec<u32>> {
+    let unit_count: fn(char) -> u32 = match position_encoding {
+        "utf-8" => |c| c.len_utf8() as u32,
+        "utf-16" => |c| c.len_utf16() as u32,
+        "utf-32" => |_| 1,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown position encoding '{}'",
+                position_encoding
+            )))
+        }
+    };
+
+    let spans = highlight_spans(&tokenizer.config(), sql, &dialect_settings)?;
+    let mut tokens = SemanticTokens::default();
+    let mut spans = spans.iter().peekable();
+    // The class of the span being read, where it ends and the column its part on this line starts
+    let mut current: Option<(TokenClass, usize, u32)> = None;
+    let (mut line, mut column) = (0, 0);
+    let mut chars = sql.chars().enumerate().peekable();
+    while let Some((i, c)) = chars.next() {
+        if let Some((class, end, start)) = current {
+            // A span may end within a \r\n that was skipped over, hence the inequality
+            if end <= i {
+                tokens.push(line, start, column - start, class);
+                current = None;
+            }
+        }
+        if current.is_none() {
+            if let Some((class, range)) = spans.next_if(|(_, range)| range.start == i) {
+                current = Some((*class, range.end, column));
+            }
+        }
+
+        if c == '\n' || c == '\r' {
+            if let Some((class, _, start)) = &mut current {
+                tokens.push(line, *start, column - *start, *class);
+                *start = 0;
+            }
+            if c == '\r' {
+                chars.next_if(|&(_, c)| c == '\n');
+            }
+            line += 1;
+            column = 0;
+        } else {
+            column += unit_count(c);
+        }
+    }
+    if let Some((class, _, start)) = current {
+        tokens.push(line, start, column - start, class);
+    }
+    Ok(tokens.data)
+}
+
+/// The LSP semantic token data being encoded, along with the position of the last token in it.
+#[derive(Default)]
+struct SemanticTokens {
+    data: Vec<u32>,
+    line: u32,
+    start: u32,
+}
+
+impl SemanticTokens {
+    fn push(&mut self, line: u32, start: u32, length: u32, class: TokenClass) {
+        if length == 0 {
+            return;
+        }
+        let delta_start = if line == self.line {
+            start - self.start
+        } else {
+            start
+        };
+        let token_type = TokenClass::ALL.iter().position(|&c| c == class).unwrap() as u32;
+        self.data
+            .extend([line - self.line, delta_start, length, token_type, 0]);
+        self.line = line;
+        self.start = start;
+    }
+}
+
+/// Renders the classified spans of `sql` along with the text between them, which `push` is
+/// given without a class.
+fn render(
+    sql: &str,
+    spans: &[(TokenClass, Range<usize>)],
+    mut push: impl FnMut(&mut String, Option<TokenClass>, &str),
+) -> String {
+    let source = Source::new(sql);
+    let mut out = String::with_capacity(sql.len() * 2);
+    let mut position = 0;
+    for (class, range) in spans {
+        push(&mut out, None, source.text(position..range.start));
+        push(&mut out, Some(*class), source.text(range.clone()));
+        position = range.end;
+    }
+    push(&mut out, None, source.text(position..sql.chars().count()));
+    out
+}
+
+fn escape_html(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len() + 16);
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
@@ -0,0 +1,222 @@
+use pyo3::exceptions::PyUnicodeDecodeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::io::{self, Read};
+use std::ops::Range;
+
+/// Decodes `data` like `bytes.decode(encoding, errors)` does. UTF-8, Latin-1 and UTF-16, which
+/// dumps are usually written in, are decoded here without the GIL and without ever creating a
+/// Python str, as long as `errors` is "strict", "replace" or "ignore". Anything else is left to
+/// Python's codecs.
+pub fn decode_sql<'a>(
+    py: Python<'_>,
+    data: &'a [u8],
+    encoding: &str,
+    errors: &str,
+) -> PyResult<Cow<'a, str>> {
+    let (Some(encoding), Some(errors)) = (
+        Encoding::from_name(encoding),
+        DecodeErrors::from_name(errors),
+    ) else {
+        let text = PyBytes::new(py, data).call_method1("decode", (encoding, errors))?;
+        return Ok(Cow::Owned(text.extract()?));
+    };
+    py.allow_threads(|| decode(data, encoding, errors))
+        .map_err(|(range, reason)| {
+            match PyUnicodeDecodeError::new(py, encoding.name(), data, range, reason) {
+                Ok(error) => PyErr::from_value(error.into_any()),
+                Err(error) => error,
+            }
+        })
+}
+
+/// The encodings `decode_sql` decodes itself.
+#[derive(Clone, Copy)]
+enum Encoding {
+    Utf8,
+    Latin1,
+    // Little-endian unless the data starts with a byte order mark, which is skipped
+    Utf16,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl Encoding {
+    // Names are matched as Python's codecs normalize them, e.g. "UTF-8" as "utf_8"
+    fn from_name(name: &str) -> Option<Encoding> {
+        match name.to_ascii_lowercase().replace(['-', ' '], "_").as_str() {
+            "utf_8" | "utf8" | "u8" => Some(Encoding::Utf8),
+            "latin_1" | "latin1" | "l1" | "iso_8859_1" | "iso8859_1" => Some(Encoding::Latin1),
+            "utf_16" | "utf16" | "u16" => Some(Encoding::Utf16),
+            "utf_16_le" | "utf_16le" => Some(Encoding::Utf16Le),
+            "utf_16_be" | "utf_16be" => Some(Encoding::Utf16Be),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static CStr {
+        match self {
+            Encoding::Utf8 => c"utf-8",
+            Encoding::Latin1 => c"latin-1",
+            Encoding::Utf16 => c"utf-16",
+            Encoding::Utf16Le => c"utf-16-le",
+            Encoding::Utf16Be => c"utf-16-be",
+        }
+    }
+}
+
+/// How `decode_sql` handles malformed data, named like Python's error handlers.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DecodeErrors {
+    Strict,
+    Replace,
+    Ignore,
+}
+
+impl DecodeErrors {
+    fn from_name(name: &str) -> Option<DecodeErrors> {
+        match name {
+            "strict" => Some(DecodeErrors::Strict),
+            "replace" => Some(DecodeErrors::Replace),
+            "ignore" => Some(DecodeErrors::Ignore),
+            _ => None,
+        }
+    }
+}
+
+// The bytes a strict decode failed at and why, as a UnicodeDecodeError reports them
+type DecodeError = (Range<usize>, &'static CStr);
+
+fn decode(
+    data: &[u8],
+    encoding: Encoding,
+    errors: DecodeErrors,
+) -> Result<Cow<'_, str>, DecodeError> {
+    let little_endian = match encoding {
+        Encoding::Utf8 => return decode_utf8(data, errors),
+        // Latin-1 maps every byte to the char with the same code point
+        Encoding::Latin1 => {
+            return Ok(match std::str::from_utf8(data) {
+                Ok(text) if text.is_ascii() => Cow::Borrowed(text),
+                _ => Cow::Owned(data.iter().map(|&byte| char::from(byte)).collect()),
+            })
+        }
+        Encoding::Utf16 => !data.starts_with(&[0xFE, 0xFF]),
+        Encoding::Utf16Le => true,
+        Encoding::Utf16Be => false,
+    };
+    let bom = match encoding {
+        Encoding::Utf16 if data.starts_with(&[0xFF, 0xFE]) || data.starts_with(&[0xFE, 0xFF]) => 2,
+        _ => 0,
+    };
+    decode_utf16(data, bom, little_endian, errors).map(Cow::Owned)
+}
+
+fn decode_utf8(data: &[u8], errors: DecodeErrors) -> Result<Cow<'_, str>, DecodeError> {
+    match std::str::from_utf8(data) {
+        Ok(text) => Ok(Cow::Borrowed(text)),
+        Err(error) if errors == DecodeErrors::Strict => {
+            let start = error.valid_up_to();
+            Err(match error.error_len() {
+                Some(len) if (0xC2..=0xF4).contains(&data[start]) => {
+                    (start..start + len, c"invalid continuation byte")
+                }
+                Some(len) => (start..start + len, c"invalid start byte"),
+                None => (start..data.len(), c"unexpected end of data"),
+            })
+        }
+        Err(_) if errors == DecodeErrors::Replace => Ok(String::from_utf8_lossy(data)),
+        Err(_) => Ok(Cow::Owned(
+            data.utf8_chunks().map(|chunk| chunk.valid()).collect(),
+        )),
+    }
+}
+
+fn decode_utf16(
+    data: &[u8],
+    start: usize,
+    little_endian: bool,
+    errors: DecodeErrors,
+) -> Result<String, DecodeError> {
+    let units = data[start..].chunks_exact(2).map(|pair| {
+        let pair = [pair[0], pair[1]];
+        if little_endian {
+            u16::from_le_bytes(pair)
+        } else {
+            u16::from_be_bytes(pair)
+        }
+    });
+
+    let mut text = String::with_capacity(data.len() / 2);
+    let mut at = start;
+    for c in char::decode_utf16(units) {
+        match c {
+            Ok(c) => {
+                text.push(c);
+                at += 2 * c.len_utf16();
+            }
+            Err(error) if errors == DecodeErrors::Strict => {
+                let reason = match error.unpaired_surrogate() {
+                    0xDC00.. => c"illegal encoding",
+                    _ if at + 2 >= data.len() => c"unexpected end of data",
+                    _ => c"illegal UTF-16 surrogate",
+                };
+                return Err((at..at + 2, reason));
+            }
+            Err(_) => {
+                if errors == DecodeErrors::Replace {
+                    text.push(char::REPLACEMENT_CHARACTER);
+                }
+                at += 2;
+            }
+        }
+    }
+
+    // An odd byte out ends the data
+    if at < data.len() {
+        match errors {
+            DecodeErrors::Strict => return Err((at..data.len(), c"truncated data")),
+            DecodeErrors::Replace => text.push(char::REPLACEMENT_CHARACTER),
+            DecodeErrors::Ignore => {}
+        }
+    }
+    Ok(text)
+}
+
+/// A Python file-like object read through its `read` method, which may have been opened in
+/// binary or in text mode, in which case its text is read as UTF-8.
+pub struct PyReader {
+    file: Py<PyAny>,
+    // What the last call returned past the buffer it was read into, as text mode reads chars
+    pending: Vec<u8>,
+}
+
+impl PyReader {
+    pub fn new(file: Py<PyAny>) -> PyReader {
+        PyReader {
+            file,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl Read for PyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pending.is_empty() {
+            Python::with_gil(|py| -> PyResult<()> {
+                let data = self.file.bind(py).call_method1("read", (buf.len(),))?;
+                match data.downcast::<PyString>() {
+                    Ok(text) => self.pending.extend_from_slice(text.to_str()?.as_bytes()),
+                    Err(_) => self.pending.extend_from_slice(data.extract::<&[u8]>()?),
+                }
+                Ok(())
+            })?;
+        }
+        let count = buf.len().min(self.pending.len());
+        buf[..count].copy_from_slice(&self.pending[..count]);
+        self.pending.drain(..count);
+        Ok(count)
+    }
+}
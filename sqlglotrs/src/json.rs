@@ -0,0 +1,63 @@
+use crate::token::RawToken;
+use std::fmt::Write;
+
+/// Writes tokens as a JSON array of objects with their type, text, position and comments, without
+/// going through serde, which is only a dependency with the `json` feature. Types are written by
+/// name if `token_type_names`, which is indexed by token type, has one, and by index otherwise.
+pub fn tokens_to_json<S: AsRef<str>>(
+    tokens: &[RawToken],
+    token_type_names: Option<&[S]>,
+) -> String {
+    let mut out = String::with_capacity(tokens.len() * 96);
+    out.push('[');
+    for (i, token) in tokens.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str("{\"type\":");
+        match token_type_names.and_then(|names| names.get(token.token_type as usize)) {
+            Some(name) => push_string(&mut out, name.as_ref()),
+            None => {
+                let _ = write!(out, "{}", token.token_type);
+            }
+        }
+        out.push_str(",\"text\":");
+        push_string(&mut out, &token.text);
+        let _ = write!(
+            out,
+            ",\"line\":{},\"col\":{},\"start\":{},\"end\":{},\"comments\":[",
+            token.line, token.col, token.start, token.end
+        );
+        for (j, comment) in token.comments.iter().enumerate() {
+            if j > 0 {
+                out.push(',');
+            }
+            push_string(&mut out, comment);
+        }
+        out.push_str("]}");
+    }
+    out.push(']');
+    out
+}
+
+// Escapes the way Python's `json.dumps` does with `ensure_ascii=False`, so that both tokenizers
+// write the same JSON
+fn push_string(out: &mut String, text: &str) {
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
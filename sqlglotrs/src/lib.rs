@@ -1,20 +1,104 @@
 use pyo3::prelude::*;
 use pyo3::{pymodule, types::PyModule, Bound, PyResult};
 use settings::{TokenTypeSettings, TokenizerDialectSettings, TokenizerSettings};
-use token::Token;
-use tokenizer::Tokenizer;
+use token::{FrozenToken, Token};
+use tokenizer::{TokenChunks, TokenStream, TokenizeError, Tokenizer};
 
+pub mod arrow;
+pub mod bench;
+pub mod detokenize;
+pub mod directive;
+pub mod edit;
+pub mod extract;
+pub mod fingerprint;
+pub mod formatter;
+pub mod highlight;
+pub mod input;
+pub mod json;
+pub mod markdown;
+pub mod parser;
+pub mod rewrite;
 pub mod settings;
+pub mod similarity;
+pub mod snapshot;
+pub mod statement;
+pub mod stats;
 pub mod token;
 pub mod tokenizer;
-pub mod trie;
+pub mod trace;
+pub mod trivia;
+
+// mimalloc takes precedence if both allocator features end up enabled, e.g. with --all-features
+#[cfg(feature = "mimalloc")]
+#[global_allocator]
+static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
+
+#[cfg(all(feature = "jemalloc", not(feature = "mimalloc")))]
+#[global_allocator]
+static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 #[pymodule]
 fn sqlglotrs(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("CUSTOM_TOKEN_TYPE_START", settings::CUSTOM_TOKEN_TYPE_START)?;
+    m.add(
+        "PRESET_DIALECTS",
+        sqlglotrs_core::presets::dialects().collect::<Vec<_>>(),
+    )?;
+    m.add(
+        "SEMANTIC_TOKEN_TYPES",
+        highlight::TokenClass::ALL.map(highlight::TokenClass::semantic_token_type),
+    )?;
     m.add_class::<Token>()?;
+    m.add_class::<FrozenToken>()?;
     m.add_class::<TokenTypeSettings>()?;
     m.add_class::<TokenizerSettings>()?;
     m.add_class::<TokenizerDialectSettings>()?;
     m.add_class::<Tokenizer>()?;
+    m.add_class::<TokenStream>()?;
+    m.add_class::<TokenChunks>()?;
+    m.add_class::<TokenizeError>()?;
+    m.add_class::<arrow::ArrowTokens>()?;
+    m.add_class::<edit::EditLog>()?;
+    m.add_function(wrap_pyfunction!(bench::time_tokenize, m)?)?;
+    m.add_function(wrap_pyfunction!(detokenize::detokenize, m)?)?;
+    m.add_function(wrap_pyfunction!(directive::parse_directives, m)?)?;
+    m.add_function(wrap_pyfunction!(extract::detect_functions, m)?)?;
+    m.add_function(wrap_pyfunction!(extract::extract_columns, m)?)?;
+    m.add_function(wrap_pyfunction!(extract::extract_parameters, m)?)?;
+    m.add_function(wrap_pyfunction!(extract::extract_settings, m)?)?;
+    m.add_function(wrap_pyfunction!(extract::extract_table_names, m)?)?;
+    m.add_function(wrap_pyfunction!(extract::extract_tables, m)?)?;
+    m.add_function(wrap_pyfunction!(extract::find_select_star, m)?)?;
+    m.add_function(wrap_pyfunction!(extract::index_ctes, m)?)?;
+    m.add_function(wrap_pyfunction!(fingerprint::fingerprint, m)?)?;
+    m.add_function(wrap_pyfunction!(formatter::format_sql, m)?)?;
+    m.add_function(wrap_pyfunction!(highlight::highlight, m)?)?;
+    m.add_function(wrap_pyfunction!(highlight::highlight_ansi, m)?)?;
+    m.add_function(wrap_pyfunction!(highlight::highlight_html, m)?)?;
+    m.add_function(wrap_pyfunction!(highlight::semantic_tokens, m)?)?;
+    m.add_function(wrap_pyfunction!(markdown::extract_sql_blocks, m)?)?;
+    m.add_function(wrap_pyfunction!(parser::parse_select, m)?)?;
+    m.add_function(wrap_pyfunction!(rewrite::convert_identifier_quotes, m)?)?;
+    m.add_function(wrap_pyfunction!(rewrite::convert_placeholders, m)?)?;
+    m.add_function(wrap_pyfunction!(rewrite::normalize_literals, m)?)?;
+    m.add_function(wrap_pyfunction!(rewrite::redact, m)?)?;
+    m.add_function(wrap_pyfunction!(rewrite::truncate, m)?)?;
+    m.add_function(wrap_pyfunction!(similarity::similarity, m)?)?;
+    m.add_function(wrap_pyfunction!(snapshot::dump_debug, m)?)?;
+    m.add_function(wrap_pyfunction!(snapshot::load_debug, m)?)?;
+    m.add_function(wrap_pyfunction!(snapshot::print_tokens, m)?)?;
+    m.add_function(wrap_pyfunction!(statement::classify, m)?)?;
+    m.add_function(wrap_pyfunction!(statement::ensure_limit, m)?)?;
+    m.add_function(wrap_pyfunction!(statement::has_multiple_statements, m)?)?;
+    m.add_function(wrap_pyfunction!(statement::is_read_only, m)?)?;
+    m.add_function(wrap_pyfunction!(statement::resolve_context, m)?)?;
+    m.add_function(wrap_pyfunction!(statement::split_statements, m)?)?;
+    m.add_function(wrap_pyfunction!(statement::statement_kind, m)?)?;
+    m.add_function(wrap_pyfunction!(stats::enable_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(stats::disable_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(stats::get_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(stats::reset_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(trace::enable_tracing, m)?)?;
+    m.add_function(wrap_pyfunction!(trace::disable_tracing, m)?)?;
     Ok(())
 }
@@ -0,0 +1,165 @@
+use crate::settings::TokenizerDialectSettings;
+use crate::token::{PyTexts, Token};
+use crate::tokenizer::Tokenizer;
+use pyo3::prelude::*;
+use sqlglotrs_core::source::Source;
+use std::ops::Range;
+
+/// A snippet found by `extract_sql_blocks`: (sql, start, end, line, tokens, error). The span and
+/// the 1-based line it starts on are relative to the whole document, as are the positions of
+/// its tokens. The error is set if the snippet couldn't be fully tokenized, in which case the
+/// tokens are the ones scanned up to that point.
+pub type SqlBlock = (String, usize, usize, usize, Vec<Token>, Option<String>);
+
+// The keywords a paragraph has to start with, in uppercase, to be taken for SQL
+const SQL_STARTS: [&str; 10] = [
+    "SELECT", "WITH", "INSERT", "UPDATE", "DELETE", "MERGE", "CREATE", "ALTER", "DROP", "EXPLAIN",
+];
+
+struct Line<'a> {
+    text: &'a str,
+    // Char offset of the line in the document
+    start: usize,
+}
+
+/// Finds the SQL snippets in a markdown document, returning the char range of each along with
+/// the index of the line it starts on. These are the fenced code blocks whose info string is
+/// `sql`, and if `heuristic` is set, paragraphs outside of code blocks starting with an
+/// uppercase statement keyword such as `SELECT`.
+pub fn find_sql_blocks(text: &str, heuristic: bool) -> Vec<(Range<usize>, usize)> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for line in text.split_inclusive('\n') {
+        lines.push(Line { text: line, start });
+        start += line.chars().count();
+    }
+    let end_of = |i: usize| lines.get(i).map_or(start, |line: &Line| line.start);
+
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        if let Some((fence, info)) = opening_fence(lines[i].text) {
+            let close = (i + 1..lines.len())
+                .find(|&j| is_closing_fence(lines[j].text, fence))
+                .unwrap_or(lines.len());
+            let is_sql = info
+                .split_whitespace()
+                .next()
+                .is_some_and(|lang| lang.eq_ignore_ascii_case("sql"));
+            if is_sql {
+                push_block(&lines, i + 1..close, end_of(close), &mut blocks);
+            }
+            i = close + 1;
+        } else if heuristic && starts_sql_paragraph(&lines, i) {
+            let end = (i..lines.len())
+                .find(|&j| {
+                    lines[j].text.trim().is_empty() || opening_fence(lines[j].text).is_some()
+                })
+                .unwrap_or(lines.len());
+            push_block(&lines, i..end, end_of(end), &mut blocks);
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    blocks
+}
+
+/// Adds the block made up of `lines`, leaving out the line break that ends it.
+fn push_block(
+    lines: &[Line],
+    range: Range<usize>,
+    end: usize,
+    blocks: &mut Vec<(Range<usize>, usize)>,
+) {
+    // Blocks with nothing but whitespace in them aren't worth reporting
+    if lines[range.clone()]
+        .iter()
+        .all(|line| line.text.trim().is_empty())
+    {
+        return;
+    }
+    let first = &lines[range.start];
+    let last = &lines[range.end - 1].text;
+    let trailing = last.len() - last.trim_end_matches(['\n', '\r']).len();
+    blocks.push((first.start..end - trailing, range.start));
+}
+
+/// Parses an opening code fence, i.e. at least three backticks or tildes indented by up to
+/// three spaces, returning the fence and the info string following it.
+fn opening_fence(line: &str) -> Option<(&str, &str)> {
+    let line = strip_indent(line)?;
+    let c = line.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let size = line.len() - line.trim_start_matches(c).len();
+    let info = line[size..].trim();
+    (size >= 3 && !(c == '`' && info.contains('`'))).then(|| (&line[..size], info))
+}
+
+fn is_closing_fence(line: &str, fence: &str) -> bool {
+    let Some(line) = strip_indent(line) else {
+        return false;
+    };
+    let c = fence.chars().next().unwrap_or('`');
+    let rest = line.trim_start_matches(c);
+    line.len() - rest.len() >= fence.len() && rest.trim().is_empty()
+}
+
+fn strip_indent(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start_matches(' ');
+    (line.len() - trimmed.len() <= 3).then_some(trimmed)
+}
+
+fn starts_sql_paragraph(lines: &[Line], i: usize) -> bool {
+    let starts_paragraph = i == 0 || lines[i - 1].text.trim().is_empty();
+    let first_word = lines[i]
+        .text
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default();
+    starts_paragraph && SQL_STARTS.contains(&first_word)
+}
+
+/// Finds the SQL snippets in a markdown document, i.e. ```` ```sql ```` fenced code blocks and,
+/// if `heuristic` is set, paragraphs that look like SQL, and tokenizes each of them.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, text, dialect_settings, heuristic = false))]
+pub fn extract_sql_blocks(
+    py: Python<'_>,
+    tokenizer: PyRef<'_, Tokenizer>,
+    text: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    heuristic: bool,
+) -> Vec<SqlBlock> {
+    let config = tokenizer.config();
+    let source = Source::new(text);
+    let mut texts = PyTexts::default();
+    find_sql_blocks(text, heuristic)
+        .into_iter()
+        .map(|(chars, line)| {
+            let sql = source.text(chars.clone());
+            let (tokens, error) = config.tokenize_raw(sql, &dialect_settings);
+            let start_byte = source.byte_offset(chars.start);
+            // Blocks start at the beginning of a line, so columns don't need to be shifted
+            let tokens = tokens
+                .into_iter()
+                .map(|mut token| {
+                    token.start += chars.start;
+                    token.end += chars.start;
+                    token.start_byte += start_byte;
+                    token.end_byte += start_byte;
+                    token.line += line;
+                    Token::new(py, token, &mut texts)
+                })
+                .collect();
+            let end = chars.end - 1;
+            (
+                sql.to_string(),
+                chars.start,
+                end,
+                line + 1,
+                tokens,
+                error.map(|e| e.to_string()),
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,592 @@
+use crate::settings::TokenizerDialectSettings;
+use crate::token::RawToken;
+use crate::tokenizer::{Tokenizer, TryTokenize};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use sqlglotrs_core::settings::TokenTypeSettings;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::statement::statements;
+use sqlglotrs_core::words::Words;
+
+/// A node of the tree built by `parse_select`, in the shape `sqlglot.serde.dump` gives
+/// expressions, so that `sqlglot.serde.load` turns it into the `exp` tree sqlglot's parser builds.
+#[derive(Debug)]
+pub struct Node<'a> {
+    class: &'static str,
+    args: Vec<(&'static str, Arg<'a>)>,
+    // The token an identifier or star was parsed from, whose position goes into its meta
+    token: Option<&'a RawToken>,
+}
+
+#[derive(Debug)]
+pub enum Arg<'a> {
+    Node(Node<'a>),
+    Nodes(Vec<Node<'a>>),
+    Text(&'a str),
+    Bool(bool),
+}
+
+impl<'a> From<Node<'a>> for Arg<'a> {
+    fn from(node: Node<'a>) -> Arg<'a> {
+        Arg::Node(node)
+    }
+}
+
+impl<'a> From<Vec<Node<'a>>> for Arg<'a> {
+    fn from(nodes: Vec<Node<'a>>) -> Arg<'a> {
+        Arg::Nodes(nodes)
+    }
+}
+
+impl<'a> From<&'a str> for Arg<'a> {
+    fn from(text: &'a str) -> Arg<'a> {
+        Arg::Text(text)
+    }
+}
+
+impl From<bool> for Arg<'_> {
+    fn from(value: bool) -> Self {
+        Arg::Bool(value)
+    }
+}
+
+impl<'a> Node<'a> {
+    pub fn new(class: &'static str) -> Node<'a> {
+        Node {
+            class,
+            args: Vec::new(),
+            token: None,
+        }
+    }
+
+    pub fn with(mut self, name: &'static str, arg: impl Into<Arg<'a>>) -> Node<'a> {
+        self.args.push((name, arg.into()));
+        self
+    }
+
+    fn binary(class: &'static str, this: Node<'a>, expression: Node<'a>) -> Node<'a> {
+        Node::new(class)
+            .with("this", this)
+            .with("expression", expression)
+    }
+
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let args = PyDict::new(py);
+        for (name, arg) in &self.args {
+            match arg {
+                Arg::Node(node) => args.set_item(name, node.to_dict(py)?)?,
+                Arg::Nodes(nodes) => {
+                    let nodes = nodes
+                        .iter()
+                        .map(|node| node.to_dict(py))
+                        .collect::<PyResult<Vec<_>>>()?;
+                    args.set_item(name, PyList::new(py, nodes)?)?
+                }
+                Arg::Text(text) => args.set_item(name, text)?,
+                Arg::Bool(value) => args.set_item(name, value)?,
+            }
+        }
+
+        let dict = PyDict::new(py);
+        dict.set_item("class", self.class)?;
+        dict.set_item("args", args)?;
+        if let Some(token) = self.token {
+            let meta = PyDict::new(py);
+            meta.set_item("line", token.line)?;
+            meta.set_item("col", token.col)?;
+            meta.set_item("start", token.start)?;
+            meta.set_item("end", token.end)?;
+            dict.set_item("meta", meta)?;
+        }
+        Ok(dict)
+    }
+}
+
+/// Where NULLs sort when an ORDER BY doesn't say, as given by a dialect's `NULL_ORDERING`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NullOrdering {
+    NullsAreSmall,
+    NullsAreLarge,
+    NullsAreLast,
+}
+
+impl NullOrdering {
+    pub fn from_name(name: &str) -> Option<NullOrdering> {
+        match name {
+            "nulls_are_small" => Some(NullOrdering::NullsAreSmall),
+            "nulls_are_large" => Some(NullOrdering::NullsAreLarge),
+            "nulls_are_last" => Some(NullOrdering::NullsAreLast),
+            _ => None,
+        }
+    }
+}
+
+/// The properties of a dialect that change the tree `Parser` builds.
+#[derive(Clone, Copy, Debug)]
+pub struct DialectOptions {
+    pub null_ordering: NullOrdering,
+    pub typed_division: bool,
+    pub safe_division: bool,
+}
+
+// The operators of each binary precedence level, along with the expression class they build
+const EQUALITY: [(&str, &str); 3] = [("=", "EQ"), ("<>", "NEQ"), ("!=", "NEQ")];
+const COMPARISON: [(&str, &str); 4] = [(">", "GT"), (">=", "GTE"), ("<", "LT"), ("<=", "LTE")];
+// `%` binds like `+` and `-` in sqlglot, unlike in most engines
+const TERM: [(&str, &str); 3] = [("+", "Add"), ("-", "Sub"), ("%", "Mod")];
+const FACTOR: [(&str, &str); 2] = [("*", "Mul"), ("/", "Div")];
+
+/// Parses a single SELECT statement made up of the constructs every dialect parses alike, i.e.
+/// columns, literals, arithmetic, comparisons, `AND`/`OR`/`NOT`, `IS`, `IN` lists, `LIKE` and
+/// `BETWEEN`, selected from at most one table and filtered, grouped, ordered and limited.
+/// Anything else, including comments, gives `None` rather than an error, so that the caller can
+/// fall back to sqlglot's parser, which is also the one to report syntax errors.
+pub struct Parser<'a> {
+    words: &'a Words<'a>,
+    token_types: &'a TokenTypeSettings,
+    options: DialectOptions,
+    i: usize,
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(
+        words: &'a Words<'a>,
+        token_types: &'a TokenTypeSettings,
+        options: DialectOptions,
+    ) -> Parser<'a> {
+        Parser {
+            words,
+            token_types,
+            options,
+            i: 0,
+            end: 0,
+        }
+    }
+
+    pub fn parse(&mut self) -> Option<Node<'a>> {
+        let [statement] = statements(self.words).try_into().ok()?;
+        if statement
+            .clone()
+            .any(|i| !self.words.token(i).comments.is_empty())
+        {
+            return None;
+        }
+        self.i = statement.start;
+        self.end = statement.end;
+        let select = self.select()?;
+        (self.i == self.end).then_some(select)
+    }
+
+    fn select(&mut self) -> Option<Node<'a>> {
+        self.expect("SELECT")?;
+        let mut select = Node::new("Select");
+        if self.eat("DISTINCT") {
+            select = select.with("distinct", Node::new("Distinct"));
+        }
+        select = select.with("expressions", self.csv(Self::projection)?);
+
+        // sqlglot's parser creates the Select with its LIMIT, which puts it before the clauses in
+        // between in the order of the args, and so in `repr`
+        let mut clauses = Node::new("Select");
+        if self.eat("FROM") {
+            clauses = clauses.with("from", Node::new("From").with("this", self.table()?));
+        }
+        if self.eat("WHERE") {
+            clauses = clauses.with("where", Node::new("Where").with("this", self.expression()?));
+        }
+        if self.eat_all(&["GROUP", "BY"]) {
+            let expressions = self.csv(Self::expression)?;
+            clauses = clauses.with("group", Node::new("Group").with("expressions", expressions));
+        }
+        if self.eat("HAVING") {
+            clauses = clauses.with(
+                "having",
+                Node::new("Having").with("this", self.expression()?),
+            );
+        }
+        if self.eat_all(&["ORDER", "BY"]) {
+            let expressions = self.csv(Self::ordered)?;
+            clauses = clauses.with("order", Node::new("Order").with("expressions", expressions));
+        }
+        if self.eat("LIMIT") {
+            select = select.with(
+                "limit",
+                Node::new("Limit").with("expression", self.number()?),
+            );
+        }
+        select.args.extend(clauses.args);
+        if self.eat("OFFSET") {
+            select = select.with(
+                "offset",
+                Node::new("Offset").with("expression", self.number()?),
+            );
+        }
+        Some(select)
+    }
+
+    fn projection(&mut self) -> Option<Node<'a>> {
+        let this = self.expression()?;
+        let explicit = self.eat("AS");
+        if explicit || self.is_name() {
+            return Some(
+                Node::new("Alias")
+                    .with("this", this)
+                    .with("alias", self.identifier()?),
+            );
+        }
+        Some(this)
+    }
+
+    fn table(&mut self) -> Option<Node<'a>> {
+        let mut parts = vec![self.identifier()?];
+        while self.eat(".") {
+            parts.push(self.identifier()?);
+        }
+        if parts.len() > 3 || self.at("(") {
+            return None;
+        }
+
+        let mut table = Node::new("Table");
+        for (name, part) in ["this", "db", "catalog"]
+            .into_iter()
+            .zip(parts.into_iter().rev())
+        {
+            table = table.with(name, part);
+        }
+        let explicit = self.eat("AS");
+        if explicit || self.is_name() {
+            let alias = Node::new("TableAlias").with("this", self.identifier()?);
+            table = table.with("alias", alias);
+        }
+        Some(table)
+    }
+
+    fn ordered(&mut self) -> Option<Node<'a>> {
+        let mut ordered = Node::new("Ordered").with("this", self.expression()?);
+        let desc = if self.eat("ASC") {
+            Some(false)
+        } else {
+            self.eat("DESC").then_some(true)
+        };
+        if let Some(desc) = desc {
+            ordered = ordered.with("desc", desc);
+        }
+
+        let desc = desc.unwrap_or(false);
+        let nulls_first = if self.eat_all(&["NULLS", "FIRST"]) {
+            true
+        } else if self.eat_all(&["NULLS", "LAST"]) {
+            false
+        } else {
+            match self.options.null_ordering {
+                NullOrdering::NullsAreSmall => !desc,
+                NullOrdering::NullsAreLarge => desc,
+                NullOrdering::NullsAreLast => false,
+            }
+        };
+        Some(ordered.with("nulls_first", nulls_first))
+    }
+
+    fn expression(&mut self) -> Option<Node<'a>> {
+        let mut this = self.conjunction()?;
+        while self.eat("OR") {
+            this = Node::binary("Or", this, self.conjunction()?);
+        }
+        Some(this)
+    }
+
+    fn conjunction(&mut self) -> Option<Node<'a>> {
+        let mut this = self.equality()?;
+        while self.eat("AND") {
+            this = Node::binary("And", this, self.equality()?);
+        }
+        Some(this)
+    }
+
+    fn equality(&mut self) -> Option<Node<'a>> {
+        self.binary(&EQUALITY, Self::comparison)
+    }
+
+    fn comparison(&mut self) -> Option<Node<'a>> {
+        self.binary(&COMPARISON, Self::range)
+    }
+
+    fn range(&mut self) -> Option<Node<'a>> {
+        let mut this = self.term()?;
+        let negate = self.eat("NOT");
+
+        if self.eat("BETWEEN") {
+            let low = self.term()?;
+            self.expect("AND")?;
+            let high = self.term()?;
+            this = Node::new("Between")
+                .with("this", this)
+                .with("low", low)
+                .with("high", high);
+        } else if self.eat("IN") {
+            self.expect("(")?;
+            let expressions = self.csv(Self::expression)?;
+            self.expect(")")?;
+            this = Node::new("In")
+                .with("this", this)
+                .with("expressions", expressions);
+        } else if self.eat("LIKE") {
+            this = Node::binary("Like", this, self.term()?);
+        } else if self.eat("ILIKE") {
+            this = Node::binary("ILike", this, self.term()?);
+        } else if negate {
+            return None;
+        }
+        // LIKE ... ESCAPE isn't supported
+        if self.at("ESCAPE") {
+            return None;
+        }
+        if negate {
+            this = Node::new("Not").with("this", this);
+        }
+
+        if self.eat("IS") {
+            let negate = self.eat("NOT");
+            let expression = if self.eat("NULL") {
+                Node::new("Null")
+            } else if self.eat("TRUE") {
+                Node::new("Boolean").with("this", true)
+            } else if self.eat("FALSE") {
+                Node::new("Boolean").with("this", false)
+            } else {
+                return None;
+            };
+            this = Node::binary("Is", this, expression);
+            if negate {
+                this = Node::new("Not").with("this", this);
+            }
+            // Chained IS predicates nest differently in the Python parser
+            if self.at("IS") {
+                return None;
+            }
+        }
+        Some(this)
+    }
+
+    fn term(&mut self) -> Option<Node<'a>> {
+        self.binary(&TERM, Self::factor)
+    }
+
+    fn factor(&mut self) -> Option<Node<'a>> {
+        self.binary(&FACTOR, Self::unary)
+    }
+
+    fn unary(&mut self) -> Option<Node<'a>> {
+        if self.eat("NOT") {
+            Some(Node::new("Not").with("this", self.equality()?))
+        } else if self.eat("-") {
+            Some(Node::new("Neg").with("this", self.unary()?))
+        } else {
+            self.primary()
+        }
+    }
+
+    fn primary(&mut self) -> Option<Node<'a>> {
+        let token = self.token()?;
+        if token.token_type == self.token_types.number {
+            self.number()
+        } else if token.token_type == self.token_types.string {
+            self.i += 1;
+            Some(literal(&token.text, true))
+        } else if self.eat("(") {
+            let this = self.expression()?;
+            self.expect(")")?;
+            Some(Node::new("Paren").with("this", this))
+        } else if self.eat("NULL") {
+            Some(Node::new("Null"))
+        } else if self.eat("TRUE") {
+            Some(Node::new("Boolean").with("this", true))
+        } else if self.eat("FALSE") {
+            Some(Node::new("Boolean").with("this", false))
+        } else if self.at("*") {
+            self.star()
+        } else {
+            self.column()
+        }
+    }
+
+    fn column(&mut self) -> Option<Node<'a>> {
+        let mut parts = vec![self.identifier()?];
+        while self.eat(".") {
+            if self.at("*") {
+                parts.push(self.star()?);
+                break;
+            }
+            parts.push(self.identifier()?);
+        }
+        // Function calls aren't supported, and longer paths are dot expressions
+        if parts.len() > 4 || self.at("(") {
+            return None;
+        }
+
+        let mut column = Node::new("Column");
+        let names = ["this", "table", "db", "catalog"];
+        for (name, part) in names.into_iter().zip(parts.into_iter().rev()) {
+            column = column.with(name, part);
+        }
+        Some(column)
+    }
+
+    fn identifier(&mut self) -> Option<Node<'a>> {
+        if !self.is_name() {
+            return None;
+        }
+        let token = self.token()?;
+        self.i += 1;
+        let mut identifier = Node::new("Identifier")
+            .with("this", &*token.text)
+            .with("quoted", token.token_type == self.token_types.identifier);
+        identifier.token = Some(token);
+        Some(identifier)
+    }
+
+    fn star(&mut self) -> Option<Node<'a>> {
+        let token = self.token()?;
+        self.expect("*")?;
+        let mut star = Node::new("Star");
+        star.token = Some(token);
+        Some(star)
+    }
+
+    fn number(&mut self) -> Option<Node<'a>> {
+        let token = self
+            .token()
+            .filter(|token| token.token_type == self.token_types.number)?;
+        self.i += 1;
+        Some(literal(&token.text, false))
+    }
+
+    /// Parses a chain of left-associative binary operators found in `operators`.
+    fn binary(
+        &mut self,
+        operators: &[(&str, &'static str)],
+        operand: fn(&mut Self) -> Option<Node<'a>>,
+    ) -> Option<Node<'a>> {
+        let mut this = operand(self)?;
+        // Some dialects scan characters other dialects use as operators into names, e.g. `%`
+        while let Some(&(_, class)) = operators
+            .iter()
+            .find(|(op, _)| self.at(op) && !self.is_name())
+        {
+            self.i += 1;
+            this = Node::binary(class, this, operand(self)?);
+            // Divisions carry the dialect's semantics for them
+            if class == "Div" {
+                this = this
+                    .with("typed", self.options.typed_division)
+                    .with("safe", self.options.safe_division);
+            }
+        }
+        Some(this)
+    }
+
+    fn csv(&mut self, item: fn(&mut Self) -> Option<Node<'a>>) -> Option<Vec<Node<'a>>> {
+        let mut items = vec![item(self)?];
+        while self.eat(",") {
+            items.push(item(self)?);
+        }
+        Some(items)
+    }
+
+    fn token(&self) -> Option<&'a RawToken> {
+        (self.i < self.end).then(|| self.words.token(self.i))
+    }
+
+    fn at(&self, text: &str) -> bool {
+        self.i < self.end && self.words.is(self.i, text)
+    }
+
+    fn is_name(&self) -> bool {
+        self.i < self.end && self.words.is_name(self.i)
+    }
+
+    fn eat(&mut self, text: &str) -> bool {
+        let matched = self.at(text);
+        self.i += matched as usize;
+        matched
+    }
+
+    /// Eats the keywords in `texts`, whether they're a token each or scanned as one, such as
+    /// `GROUP BY`.
+    fn eat_all(&mut self, texts: &[&str]) -> bool {
+        let mut rest = texts;
+        let mut i = self.i;
+        while !rest.is_empty() {
+            if i >= self.end || !self.words.is_word(i) {
+                return false;
+            }
+            let token = self.words.token(i);
+            if token.token_type == self.token_types.identifier {
+                return false;
+            }
+            for word in token.text.split_whitespace() {
+                match rest.split_first() {
+                    Some((text, others)) if word.eq_ignore_ascii_case(text) => rest = others,
+                    _ => return false,
+                }
+            }
+            i += 1;
+        }
+        self.i = i;
+        true
+    }
+
+    fn expect(&mut self, text: &str) -> Option<()> {
+        self.eat(text).then_some(())
+    }
+}
+
+fn literal(text: &str, is_string: bool) -> Node<'_> {
+    Node::new("Literal")
+        .with("this", text)
+        .with("is_string", is_string)
+}
+
+/// Parses `sql` in Rust if it's a SELECT simple enough for `Parser`, returning its tree in the
+/// format of `sqlglot.serde.dump`, or None if sqlglot's parser has to be used instead. The
+/// remaining arguments are the dialect's `NULL_ORDERING`, `TYPED_DIVISION` and `SAFE_DIVISION`.
+#[pyfunction]
+#[pyo3(signature = (
+    tokenizer,
+    sql,
+    dialect_settings,
+    null_ordering = "nulls_are_small",
+    typed_division = false,
+    safe_division = false
+))]
+pub fn parse_select<'py>(
+    py: Python<'py>,
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    null_ordering: &str,
+    typed_division: bool,
+    safe_division: bool,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let options = DialectOptions {
+        null_ordering: NullOrdering::from_name(null_ordering).ok_or_else(|| {
+            PyValueError::new_err(format!("Unknown null ordering '{}'", null_ordering))
+        })?,
+        typed_division,
+        safe_division,
+    };
+
+    let config = tokenizer.config();
+    let Ok(tokens) = config.try_tokenize(sql, &dialect_settings) else {
+        // Let sqlglot report the error
+        return Ok(None);
+    };
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+    Parser::new(&words, &config.token_types, options)
+        .parse()
+        .map(|tree| tree.to_dict(py))
+        .transpose()
+}
@@ -0,0 +1,455 @@
+use crate::edit::{apply_edits, EditLog};
+use crate::settings::TokenizerDialectSettings;
+use crate::token::RawToken;
+use crate::tokenizer::{value_error, Tokenizer, TryTokenize};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rustc_hash::FxHashMap as HashMap;
+pub use sqlglotrs_core::placeholder::{
+    find_all_placeholders, find_placeholders, Placeholder, PlaceholderStyle,
+};
+use sqlglotrs_core::settings::{TokenTypeSettings, TokenizerSettings};
+use sqlglotrs_core::source::{span, Edit, Source};
+
+/// Returns the edits that rewrite the placeholders of one style into another, along with, for
+/// each parameter the new query expects, the key of the original parameter it takes its value
+/// from. Positional targets are numbered in order of appearance, so `$2, $1` becomes `:1, :2`
+/// with the order `["2", "1"]`; repeated keys share a number or name where the target style
+/// can express that and are repeated in the order otherwise.
+pub fn placeholder_edits(
+    source: &Source,
+    tokens: &[RawToken],
+    from_style: PlaceholderStyle,
+    to_style: PlaceholderStyle,
+) -> (Vec<Edit>, Vec<String>) {
+    let mut order: Vec<String> = Vec::new();
+    let mut targets: HashMap<String, String> = HashMap::default();
+    let mut edits = Vec::new();
+
+    // Styles without keys need one placeholder per occurrence, even for repeated parameters
+    let shared = to_style.is_named() || to_style.is_numbered();
+    for placeholder in find_placeholders(source, tokens, from_style) {
+        let target = match targets.get(&placeholder.key) {
+            Some(target) if shared => target.clone(),
+            _ => {
+                let target = if from_style.is_named() && to_style.is_named() {
+                    placeholder.key.clone()
+                } else if to_style.is_named() {
+                    format!("p{}", order.len() + 1)
+                } else {
+                    (order.len() + 1).to_string()
+                };
+                order.push(placeholder.key.clone());
+                targets.insert(placeholder.key, target.clone());
+                target
+            }
+        };
+        edits.push((
+            placeholder.start..placeholder.end + 1,
+            to_style.render(&target),
+        ));
+    }
+
+    (edits, order)
+}
+
+/// Converts the placeholders in `sql` from one style to another (one of "qmark", "numeric",
+/// "named", "format", "pyformat", "dollar" or "at"), e.g. `?` to `$1`. Returns the rewritten
+/// query and the order in which the original parameters should be bound to it.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, from_style, to_style, edit_log = None))]
+pub fn convert_placeholders(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    from_style: &str,
+    to_style: &str,
+    edit_log: Option<PyRefMut<'_, EditLog>>,
+) -> PyResult<(String, Vec<String>)> {
+    let from_style = placeholder_style(from_style)?;
+    let to_style = placeholder_style(to_style)?;
+    let tokens = tokenizer.config().try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let (edits, order) = placeholder_edits(&source, &tokens, from_style, to_style);
+    Ok((apply_edits(&source, edits, edit_log), order))
+}
+
+/// Returns the edits that rewrite every string literal to use `target_quote`, escaping quotes in
+/// its contents with `escape`, i.e. by doubling them or by prefixing them with a backslash. In
+/// dialects where backslashes start escape sequences, backslashes are escaped too and the chars
+/// that sequences such as `\n` stand for are written as those sequences again, so that the
+/// literals keep their meaning. Identifiers and other kinds of strings, e.g. raw or national
+/// strings, are left untouched.
+pub fn literal_edits(
+    source: &Source,
+    tokens: &[RawToken],
+    settings: &TokenizerSettings,
+    token_types: &TokenTypeSettings,
+    dialect_settings: &TokenizerDialectSettings,
+    target_quote: char,
+    escape: char,
+) -> Vec<Edit> {
+    let quotes = &settings.quotes;
+    let has_backslash_escapes = settings.string_escapes.contains(&'\\');
+    let sequences: HashMap<char, &str> = dialect_settings
+        .unescaped_sequences
+        .iter()
+        .filter(|_| has_backslash_escapes)
+        .filter_map(|(sequence, value)| {
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some((c, sequence.as_str())),
+                _ => None,
+            }
+        })
+        .collect();
+
+    tokens
+        .iter()
+        .filter(|token| token.token_type == token_types.string)
+        .filter(|token| {
+            // Format strings can map onto STRING too, but their prefix may come with escaping
+            // rules of its own, so only literals delimited by plain quotes are rewritten
+            let slice = source.slice(token);
+            quotes.iter().any(|(start, end)| {
+                slice.starts_with(start.as_str()) && slice.ends_with(end.as_str())
+            })
+        })
+        .map(|token| {
+            let mut literal = String::with_capacity(token.text.len() + 2);
+            literal.push(target_quote);
+            for c in token.text.chars() {
+                if c == target_quote {
+                    literal.push(escape);
+                    literal.push(c);
+                } else if let Some(sequence) = sequences.get(&c) {
+                    literal.push_str(sequence);
+                } else if c == '\\' && has_backslash_escapes {
+                    literal.push_str("\\\\");
+                } else {
+                    literal.push(c);
+                }
+            }
+            literal.push(target_quote);
+            (span(token), literal)
+        })
+        .collect()
+}
+
+/// Converts all string literals in `sql` to the `target_quote` style, e.g. MySQL's `"it's"` to
+/// `'it''s'`. Quotes are escaped with `escape`, which defaults to doubling them unless the dialect
+/// only escapes them with a backslash. Raises a ValueError if the dialect doesn't quote strings
+/// with `target_quote` or escape them with `escape`.
+#[pyfunction]
+#[pyo3(signature = (
+    tokenizer, sql, dialect_settings, target_quote = "'", escape = None, edit_log = None
+))]
+pub fn normalize_literals(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    target_quote: &str,
+    escape: Option<&str>,
+    edit_log: Option<PyRefMut<'_, EditLog>>,
+) -> PyResult<String> {
+    let config = tokenizer.config();
+    let settings = &config.settings;
+    if settings.quotes.get(target_quote).map(String::as_str) != Some(target_quote) {
+        return Err(PyValueError::new_err(format!(
+            "Strings aren't quoted with {} in this dialect",
+            target_quote
+        )));
+    }
+    let target_quote = single_char(target_quote)?;
+
+    let escapes = &settings.string_escapes;
+    let escape = match escape {
+        Some(escape) => {
+            let escape = single_char(escape)?;
+            if escape != target_quote && escape != '\\' {
+                return Err(PyValueError::new_err(format!(
+                    "Escape must be the target quote or a backslash, got {}",
+                    escape
+                )));
+            }
+            if !escapes.contains(&escape) {
+                return Err(PyValueError::new_err(format!(
+                    "Strings aren't escaped with {} in this dialect",
+                    escape
+                )));
+            }
+            escape
+        }
+        None if !escapes.contains(&target_quote) && escapes.contains(&'\\') => '\\',
+        None => target_quote,
+    };
+
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let edits = literal_edits(
+        &source,
+        &tokens,
+        settings,
+        &config.token_types,
+        &dialect_settings,
+        target_quote,
+        escape,
+    );
+    Ok(apply_edits(&source, edits, edit_log))
+}
+
+/// Returns the edits that replace every literal with `placeholder`: numbers, strings and any
+/// prefixed strings, e.g. hex, bit, raw, national or heredoc strings. The rest of a command such
+/// as `EXEC` is scanned as a string too, but isn't a literal and is left untouched.
+pub fn redaction_edits(
+    tokens: &[RawToken],
+    settings: &TokenizerSettings,
+    token_types: &TokenTypeSettings,
+    placeholder: &str,
+) -> Vec<Edit> {
+    let is_literal = |token_type| {
+        [
+            token_types.number,
+            token_types.string,
+            token_types.hex_string,
+            token_types.bit_string,
+            token_types.raw_string,
+            token_types.heredoc_string,
+        ]
+        .contains(&token_type)
+            || settings
+                .format_strings
+                .values()
+                .any(|(_, format_type)| *format_type == token_type)
+    };
+
+    tokens
+        .iter()
+        .enumerate()
+        .filter(|&(i, token)| {
+            is_literal(token.token_type)
+                && !(i > 0 && settings.commands.contains(&tokens[i - 1].token_type))
+        })
+        .map(|(_, token)| (span(token), placeholder.to_string()))
+        .collect()
+}
+
+/// Replaces the literals in `sql` with `placeholder`, e.g. to scrub values out of query logs.
+/// Everything else, including comments and whitespace, is kept exactly as written.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, placeholder = "?", edit_log = None))]
+pub fn redact(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    placeholder: &str,
+    edit_log: Option<PyRefMut<'_, EditLog>>,
+) -> PyResult<String> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let edits = redaction_edits(&tokens, &config.settings, &config.token_types, placeholder);
+    Ok(apply_edits(&source, edits, edit_log))
+}
+
+/// Shortens `sql` to at most `max_chars` chars for previews, without cutting inside a string,
+/// identifier or comment. Long strings and quoted identifiers are cut short and closed instead.
+/// Since previews are often of broken queries, `sql` is scanned like `tokenize_lenient` does, so
+/// e.g. an unterminated string is cut between tokens rather than raising a ValueError.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, max_chars, ellipsis = "..."))]
+pub fn truncate(
+    py: Python<'_>,
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    max_chars: usize,
+    ellipsis: &str,
+) -> PyResult<String> {
+    let config = tokenizer.config();
+    let (tokens, error) = config.tokenize_lenient_raw(sql, &dialect_settings);
+    if let Some(error) = error {
+        return Err(value_error(py, error));
+    }
+    Ok(truncate_sql(
+        sql,
+        &tokens,
+        &config.settings,
+        &config.token_types,
+        max_chars,
+        ellipsis,
+    ))
+}
+
+fn placeholder_style(name: &str) -> PyResult<PlaceholderStyle> {
+    PlaceholderStyle::from_name(name)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown placeholder style '{}'", name)))
+}
+
+/// Returns the edits that rewrite the identifiers quoted with `from_quote` to use `to_quote`,
+/// where a `[` quote is closed by `]` and any other quote by itself. Embedded closing quotes are
+/// escaped by doubling them, which is how every dialect with these quotes escapes them.
+pub fn identifier_quote_edits(
+    source: &Source,
+    tokens: &[RawToken],
+    token_types: &TokenTypeSettings,
+    from_quote: char,
+    to_quote: char,
+) -> Vec<Edit> {
+    let to_end = closing_quote(to_quote);
+
+    tokens
+        .iter()
+        .filter(|token| {
+            token.token_type == token_types.identifier
+                && source.slice(token).starts_with(from_quote)
+        })
+        .map(|token| {
+            let mut identifier = String::with_capacity(token.text.len() + 2);
+            identifier.push(to_quote);
+            for c in token.text.chars() {
+                if c == to_end {
+                    identifier.push(c);
+                }
+                identifier.push(c);
+            }
+            identifier.push(to_end);
+            (span(token), identifier)
+        })
+        .collect()
+}
+
+/// Converts identifiers in `sql` from one quote style to another, e.g. `` `a` `` to `"a"` or
+/// `[a]`, translating escaped quotes along the way. Strings are left untouched.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, from_quote, to_quote, edit_log = None))]
+pub fn convert_identifier_quotes(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    from_quote: &str,
+    to_quote: &str,
+    edit_log: Option<PyRefMut<'_, EditLog>>,
+) -> PyResult<String> {
+    let from_quote = single_char(from_quote)?;
+    let to_quote = single_char(to_quote)?;
+
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let edits = identifier_quote_edits(&source, &tokens, &config.token_types, from_quote, to_quote);
+    Ok(apply_edits(&source, edits, edit_log))
+}
+
+/// Shortens `sql` to at most `max_chars` chars for display, ending it with `ellipsis`. The query
+/// is cut between tokens, except that a long string or quoted identifier may be cut short and
+/// closed, so the preview never ends inside a literal, identifier or comment.
+pub fn truncate_sql(
+    sql: &str,
+    tokens: &[RawToken],
+    settings: &TokenizerSettings,
+    token_types: &TokenTypeSettings,
+    max_chars: usize,
+    ellipsis: &str,
+) -> String {
+    let source = Source::new(sql);
+    if sql.chars().count() <= max_chars {
+        return sql.to_string();
+    }
+
+    // The ellipsis is cut short too when there's no room for all of it
+    let ellipsis_chars = ellipsis.chars().count();
+    if max_chars < ellipsis_chars {
+        return ellipsis.chars().take(max_chars).collect();
+    }
+
+    let budget = max_chars - ellipsis_chars;
+    let mut cut = 0;
+    let mut closing = "";
+    for token in tokens {
+        if token.end < budget {
+            cut = token.end + 1;
+            continue;
+        }
+
+        let text = source.slice(token);
+        if let Some((opening, close)) = delimiters(text, token, settings, token_types) {
+            let content = token.start + opening.chars().count();
+            let mut end = budget.saturating_sub(close.chars().count());
+            // Don't leave an escape behind that would swallow the closing quote
+            let is_escape = |c: char| {
+                close.starts_with(c)
+                    || settings.string_escapes.contains(&c)
+                    || settings.identifier_escapes.contains(&c)
+            };
+            while end > content && source.text(content..end).ends_with(is_escape) {
+                end -= 1;
+            }
+            if end > content {
+                cut = end;
+                closing = close;
+            }
+        }
+        break;
+    }
+
+    let mut preview = source.text(0..cut).trim_end().to_string();
+    preview.push_str(closing);
+    preview.push_str(ellipsis);
+    preview
+}
+
+/// Returns the opening and closing delimiters of a quoted token, e.g. `E'` and `'`, or None if
+/// the token isn't quoted.
+fn delimiters<'a>(
+    text: &'a str,
+    token: &RawToken,
+    settings: &'a TokenizerSettings,
+    token_types: &TokenTypeSettings,
+) -> Option<(&'a str, &'a str)> {
+    if text == &*token.text {
+        return None;
+    }
+
+    let quotes = settings
+        .quotes
+        .iter()
+        .map(|(o, c)| (o.as_str(), c.as_str()));
+    let format_strings = settings
+        .format_strings
+        .iter()
+        .map(|(o, (c, _))| (o.as_str(), c.as_str()));
+    let (opening, close) = quotes
+        .chain(format_strings)
+        .filter(|(opening, _)| text.starts_with(opening))
+        .max_by_key(|(opening, _)| opening.len())
+        .or_else(|| {
+            let quote = text.chars().next()?;
+            let end = settings.identifiers.get(&quote)?;
+            let n = quote.len_utf8();
+            Some((&text[..n], &text[text.len() - end.len_utf8()..]))
+        })?;
+
+    // Heredoc strings are closed by their whole tag, e.g. `$tag$`
+    if token.token_type == token_types.heredoc_string {
+        let tag = text[opening.len()..].find(close)?;
+        let tag = &text[..opening.len() + tag + close.len()];
+        return Some((tag, tag));
+    }
+    Some((opening, close))
+}
+
+fn closing_quote(quote: char) -> char {
+    match quote {
+        '[' => ']',
+        quote => quote,
+    }
+}
+
+fn single_char(value: &str) -> PyResult<char> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(PyValueError::new_err(format!("Invalid char: {}", value))),
+    }
+}
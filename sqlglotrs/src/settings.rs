@@ -1,32 +1,25 @@
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 use rustc_hash::FxHashMap as HashMap;
 use rustc_hash::FxHashSet as HashSet;
+use sqlglotrs_core::presets;
+use sqlglotrs_core::settings as core_settings;
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
-pub type TokenType = u16;
-
-#[derive(Clone, Debug)]
-#[pyclass]
-#[cfg_attr(feature = "profiling", derive(serde::Serialize, serde::Deserialize))]
-pub struct TokenTypeSettings {
-    pub bit_string: TokenType,
-    pub break_: TokenType,
-    pub dcolon: TokenType,
-    pub heredoc_string: TokenType,
-    pub raw_string: TokenType,
-    pub hex_string: TokenType,
-    pub identifier: TokenType,
-    pub number: TokenType,
-    pub parameter: TokenType,
-    pub semicolon: TokenType,
-    pub string: TokenType,
-    pub var: TokenType,
-    pub heredoc_string_alternative: TokenType,
-    pub hint: TokenType,
-}
+pub use sqlglotrs_core::settings::{TokenType, CUSTOM_TOKEN_TYPE_START};
+
+/// The Python side of `sqlglotrs_core::settings::TokenTypeSettings`, which it derefs to.
+#[derive(Clone, Debug, PartialEq)]
+#[pyclass(eq)]
+pub struct TokenTypeSettings(pub core_settings::TokenTypeSettings);
 
 #[pymethods]
 impl TokenTypeSettings {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         bit_string: TokenType,
         break_: TokenType,
@@ -42,8 +35,12 @@ impl TokenTypeSettings {
         var: TokenType,
         heredoc_string_alternative: TokenType,
         hint: TokenType,
+        error: TokenType,
+        comment: TokenType,
+        placeholder: TokenType,
+        hint_end: TokenType,
     ) -> Self {
-        let token_type_settings = TokenTypeSettings {
+        let token_type_settings = TokenTypeSettings(core_settings::TokenTypeSettings {
             bit_string,
             break_,
             dcolon,
@@ -58,7 +55,11 @@ impl TokenTypeSettings {
             var,
             heredoc_string_alternative,
             hint,
-        };
+            error,
+            comment,
+            placeholder,
+            hint_end,
+        });
 
         #[cfg(feature = "profiling")]
         {
@@ -67,12 +68,18 @@ impl TokenTypeSettings {
 
         token_type_settings
     }
+
+    /// The token types of a builtin dialect's preset, see `TokenizerSettings.for_dialect`.
+    #[staticmethod]
+    pub fn for_dialect(dialect: &str) -> PyResult<Self> {
+        preset(dialect, presets::token_type_settings(dialect)).map(TokenTypeSettings)
+    }
 }
 
 #[cfg(feature = "profiling")]
 impl TokenTypeSettings {
     pub fn write_json_to_string(&self) {
-        let json = serde_json::to_string(self).unwrap();
+        let json = serde_json::to_string(&self.0).unwrap();
         let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("benches/token_type_settings.json");
         // Write to file
@@ -80,35 +87,15 @@ impl TokenTypeSettings {
     }
 }
 
-#[derive(Clone, Debug)]
-#[pyclass]
-#[cfg_attr(feature = "profiling", derive(serde::Serialize, serde::Deserialize))]
-pub struct TokenizerSettings {
-    pub white_space: HashMap<char, TokenType>,
-    pub single_tokens: HashMap<char, TokenType>,
-    pub keywords: HashMap<String, TokenType>,
-    pub numeric_literals: HashMap<String, String>,
-    pub identifiers: HashMap<char, char>,
-    pub identifier_escapes: HashSet<char>,
-    pub string_escapes: HashSet<char>,
-    pub quotes: HashMap<String, String>,
-    pub format_strings: HashMap<String, (String, TokenType)>,
-    pub has_bit_strings: bool,
-    pub has_hex_strings: bool,
-    pub comments: HashMap<String, Option<String>>,
-    pub var_single_tokens: HashSet<char>,
-    pub commands: HashSet<TokenType>,
-    pub command_prefix_tokens: HashSet<TokenType>,
-    pub tokens_preceding_hint: HashSet<TokenType>,
-    pub heredoc_tag_is_identifier: bool,
-    pub string_escapes_allowed_in_raw_strings: bool,
-    pub nested_comments: bool,
-    pub hint_start: String,
-}
+/// The Python side of `sqlglotrs_core::settings::TokenizerSettings`, which it derefs to.
+#[derive(Clone, Debug, PartialEq)]
+#[pyclass(eq)]
+pub struct TokenizerSettings(pub core_settings::TokenizerSettings);
 
 #[pymethods]
 impl TokenizerSettings {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         white_space: HashMap<String, TokenType>,
         single_tokens: HashMap<String, TokenType>,
@@ -119,10 +106,12 @@ impl TokenizerSettings {
         string_escapes: HashSet<String>,
         quotes: HashMap<String, String>,
         format_strings: HashMap<String, (String, TokenType)>,
+        verbatim_strings: HashSet<String>,
         has_bit_strings: bool,
         has_hex_strings: bool,
         comments: HashMap<String, Option<String>>,
         var_single_tokens: HashSet<String>,
+        placeholder_styles: HashSet<String>,
         commands: HashSet<TokenType>,
         command_prefix_tokens: HashSet<TokenType>,
         tokens_preceding_hint: HashSet<TokenType>,
@@ -162,28 +151,30 @@ impl TokenizerSettings {
         let var_single_tokens_native: HashSet<char> =
             var_single_tokens.iter().map(&to_char).collect();
 
-        let tokenizer_settings = TokenizerSettings {
-            white_space: white_space_native,
-            single_tokens: single_tokens_native,
-            keywords,
-            numeric_literals,
-            identifiers: identifiers_native,
-            identifier_escapes: identifier_escapes_native,
-            string_escapes: string_escapes_native,
-            quotes,
-            format_strings,
+        let tokenizer_settings = TokenizerSettings(core_settings::TokenizerSettings {
+            white_space: Arc::new(white_space_native),
+            single_tokens: Arc::new(single_tokens_native),
+            keywords: Arc::new(keywords),
+            numeric_literals: Arc::new(numeric_literals),
+            identifiers: Arc::new(identifiers_native),
+            identifier_escapes: Arc::new(identifier_escapes_native),
+            string_escapes: Arc::new(string_escapes_native),
+            quotes: Arc::new(quotes),
+            format_strings: Arc::new(format_strings),
+            verbatim_strings: Arc::new(verbatim_strings),
             has_bit_strings,
             has_hex_strings,
-            comments,
-            var_single_tokens: var_single_tokens_native,
-            commands,
-            command_prefix_tokens,
-            tokens_preceding_hint,
+            comments: Arc::new(comments),
+            var_single_tokens: Arc::new(var_single_tokens_native),
+            placeholder_styles: Arc::new(placeholder_styles),
+            commands: Arc::new(commands),
+            command_prefix_tokens: Arc::new(command_prefix_tokens),
+            tokens_preceding_hint: Arc::new(tokens_preceding_hint),
             heredoc_tag_is_identifier,
             string_escapes_allowed_in_raw_strings,
             nested_comments,
             hint_start,
-        };
+        });
 
         #[cfg(feature = "profiling")]
         {
@@ -192,12 +183,182 @@ impl TokenizerSettings {
 
         tokenizer_settings
     }
+
+    /// The settings of a builtin dialect, by its name in sqlglot, where the default dialect is
+    /// "". These are embedded in the extension module unless it was built without the `presets`
+    /// feature, which saves converting them from the Python definition.
+    #[staticmethod]
+    pub fn for_dialect(dialect: &str) -> PyResult<Self> {
+        preset(dialect, presets::tokenizer_settings(dialect)).map(TokenizerSettings)
+    }
+
+    /// Derives new settings from `base`. Tables named in `overrides` are merged with the base
+    /// ones, entries named in `remove` are dropped from them and scalar settings are replaced,
+    /// e.g. `overlay(base, {"keywords": {"FOO": 1}}, remove={"identifiers": ['"']})`. Tables that
+    /// aren't touched are shared with `base` rather than copied.
+    #[staticmethod]
+    #[pyo3(signature = (base, overrides, remove = None))]
+    pub fn overlay(
+        base: &TokenizerSettings,
+        overrides: &Bound<'_, PyDict>,
+        remove: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<TokenizerSettings> {
+        let mut settings = base.clone();
+
+        if let Some(remove) = remove {
+            for (name, keys) in remove.iter() {
+                let name = name.extract::<String>()?;
+                let keys = keys.extract::<Vec<Bound<'_, PyAny>>>()?;
+                settings.remove_entries(&name, &keys)?;
+            }
+        }
+
+        for (name, value) in overrides.iter() {
+            settings.merge_setting(&name.extract::<String>()?, &value)?;
+        }
+
+        Ok(settings)
+    }
+}
+
+impl TokenizerSettings {
+    fn merge_setting(&mut self, name: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let entries = || value.downcast::<PyDict>().map_err(PyErr::from);
+        let items = || value.extract::<Vec<Bound<'_, PyAny>>>();
+
+        match name {
+            "white_space" => merge_map(&mut self.white_space, entries()?, char_key, plain)?,
+            "single_tokens" => merge_map(&mut self.single_tokens, entries()?, char_key, plain)?,
+            "keywords" => merge_map(&mut self.keywords, entries()?, plain, plain)?,
+            "numeric_literals" => merge_map(&mut self.numeric_literals, entries()?, plain, plain)?,
+            "identifiers" => merge_map(&mut self.identifiers, entries()?, char_key, char_key)?,
+            "quotes" => merge_map(&mut self.quotes, entries()?, plain, plain)?,
+            "format_strings" => merge_map(&mut self.format_strings, entries()?, plain, plain)?,
+            "comments" => merge_map(&mut self.comments, entries()?, plain, plain)?,
+            "identifier_escapes" => merge_set(&mut self.identifier_escapes, &items()?, char_key)?,
+            "string_escapes" => merge_set(&mut self.string_escapes, &items()?, char_key)?,
+            "var_single_tokens" => merge_set(&mut self.var_single_tokens, &items()?, char_key)?,
+            "verbatim_strings" => merge_set(&mut self.verbatim_strings, &items()?, plain)?,
+            "placeholder_styles" => merge_set(&mut self.placeholder_styles, &items()?, plain)?,
+            "commands" => merge_set(&mut self.commands, &items()?, plain)?,
+            "command_prefix_tokens" => {
+                merge_set(&mut self.command_prefix_tokens, &items()?, plain)?
+            }
+            "tokens_preceding_hint" => {
+                merge_set(&mut self.tokens_preceding_hint, &items()?, plain)?
+            }
+            "has_bit_strings" => self.has_bit_strings = value.extract()?,
+            "has_hex_strings" => self.has_hex_strings = value.extract()?,
+            "heredoc_tag_is_identifier" => self.heredoc_tag_is_identifier = value.extract()?,
+            "string_escapes_allowed_in_raw_strings" => {
+                self.string_escapes_allowed_in_raw_strings = value.extract()?
+            }
+            "nested_comments" => self.nested_comments = value.extract()?,
+            "hint_start" => self.hint_start = value.extract()?,
+            _ => return Err(unknown_setting(name)),
+        }
+        Ok(())
+    }
+
+    fn remove_entries(&mut self, name: &str, keys: &[Bound<'_, PyAny>]) -> PyResult<()> {
+        match name {
+            "white_space" => remove_keys(&mut self.white_space, keys, char_key),
+            "single_tokens" => remove_keys(&mut self.single_tokens, keys, char_key),
+            "keywords" => remove_keys(&mut self.keywords, keys, plain),
+            "numeric_literals" => remove_keys(&mut self.numeric_literals, keys, plain),
+            "identifiers" => remove_keys(&mut self.identifiers, keys, char_key),
+            "quotes" => remove_keys(&mut self.quotes, keys, plain),
+            "format_strings" => remove_keys(&mut self.format_strings, keys, plain),
+            "comments" => remove_keys(&mut self.comments, keys, plain),
+            "identifier_escapes" => remove_items(&mut self.identifier_escapes, keys, char_key),
+            "string_escapes" => remove_items(&mut self.string_escapes, keys, char_key),
+            "var_single_tokens" => remove_items(&mut self.var_single_tokens, keys, char_key),
+            "verbatim_strings" => remove_items(&mut self.verbatim_strings, keys, plain),
+            "placeholder_styles" => remove_items(&mut self.placeholder_styles, keys, plain),
+            "commands" => remove_items(&mut self.commands, keys, plain),
+            "command_prefix_tokens" => remove_items(&mut self.command_prefix_tokens, keys, plain),
+            "tokens_preceding_hint" => remove_items(&mut self.tokens_preceding_hint, keys, plain),
+            _ => Err(unknown_setting(name)),
+        }
+    }
+}
+
+fn preset<T>(dialect: &str, settings: Option<T>) -> PyResult<T> {
+    settings.ok_or_else(|| PyValueError::new_err(format!("No preset for dialect '{}'", dialect)))
+}
+
+fn unknown_setting(name: &str) -> PyErr {
+    PyValueError::new_err(format!("Unknown tokenizer setting '{}'", name))
+}
+
+fn plain<'py, T: FromPyObject<'py>>(value: &Bound<'py, PyAny>) -> PyResult<T> {
+    value.extract()
+}
+
+fn char_key(value: &Bound<'_, PyAny>) -> PyResult<char> {
+    let value = value.extract::<String>()?;
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(PyValueError::new_err(format!("Invalid char: {}", value))),
+    }
+}
+
+type Extract<'py, T> = fn(&Bound<'py, PyAny>) -> PyResult<T>;
+
+fn merge_map<'py, K: Clone + Eq + Hash, V: Clone>(
+    table: &mut Arc<HashMap<K, V>>,
+    entries: &Bound<'py, PyDict>,
+    key: Extract<'py, K>,
+    value: Extract<'py, V>,
+) -> PyResult<()> {
+    let table = Arc::make_mut(table);
+    for (k, v) in entries.iter() {
+        table.insert(key(&k)?, value(&v)?);
+    }
+    Ok(())
+}
+
+fn merge_set<'py, T: Clone + Eq + Hash>(
+    table: &mut Arc<HashSet<T>>,
+    items: &[Bound<'py, PyAny>],
+    item: Extract<'py, T>,
+) -> PyResult<()> {
+    let table = Arc::make_mut(table);
+    for i in items {
+        table.insert(item(i)?);
+    }
+    Ok(())
+}
+
+fn remove_keys<'py, K: Clone + Eq + Hash, V: Clone>(
+    table: &mut Arc<HashMap<K, V>>,
+    keys: &[Bound<'py, PyAny>],
+    key: Extract<'py, K>,
+) -> PyResult<()> {
+    let table = Arc::make_mut(table);
+    for k in keys {
+        table.remove(&key(k)?);
+    }
+    Ok(())
+}
+
+fn remove_items<'py, T: Clone + Eq + Hash>(
+    table: &mut Arc<HashSet<T>>,
+    items: &[Bound<'py, PyAny>],
+    item: Extract<'py, T>,
+) -> PyResult<()> {
+    let table = Arc::make_mut(table);
+    for i in items {
+        table.remove(&item(i)?);
+    }
+    Ok(())
 }
 
 #[cfg(feature = "profiling")]
 impl TokenizerSettings {
     pub fn write_json_to_string(&self) {
-        let json = serde_json::to_string(self).unwrap();
+        let json = serde_json::to_string(&self.0).unwrap();
         let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("benches/tokenizer_settings.json");
         // Write to file
@@ -205,44 +366,141 @@ impl TokenizerSettings {
     }
 }
 
-#[derive(Clone, Debug)]
-#[pyclass]
-#[cfg_attr(feature = "profiling", derive(serde::Serialize, serde::Deserialize))]
-pub struct TokenizerDialectSettings {
-    pub unescaped_sequences: HashMap<String, String>,
-    pub identifiers_can_start_with_digit: bool,
-    pub numbers_can_be_underscore_separated: bool,
-}
+/// The Python side of `sqlglotrs_core::settings::TokenizerDialectSettings`, which it derefs to.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[pyclass(eq)]
+pub struct TokenizerDialectSettings(pub core_settings::TokenizerDialectSettings);
 
 #[pymethods]
 impl TokenizerDialectSettings {
     #[new]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        unescaped_sequences,
+        identifiers_can_start_with_digit,
+        numbers_can_be_underscore_separated,
+        unicode_identifiers = false,
+        versioned_comments_version = None,
+        control_characters = "keep",
+        max_input_bytes = None,
+        max_tokens = None,
+        comment_tokens = false,
+        hint_tokens = false,
+        nul_bytes = "text",
+        position_encoding = "utf32",
+    ))]
     pub fn new(
         unescaped_sequences: HashMap<String, String>,
         identifiers_can_start_with_digit: bool,
         numbers_can_be_underscore_separated: bool,
-    ) -> Self {
-        let settings = TokenizerDialectSettings {
+        unicode_identifiers: bool,
+        versioned_comments_version: Option<u64>,
+        control_characters: &str,
+        max_input_bytes: Option<usize>,
+        max_tokens: Option<usize>,
+        comment_tokens: bool,
+        hint_tokens: bool,
+        nul_bytes: &str,
+        position_encoding: &str,
+    ) -> PyResult<Self> {
+        let control_characters = match control_characters {
+            "keep" => core_settings::ControlCharacters::Keep,
+            "skip" => core_settings::ControlCharacters::Skip,
+            "error" => core_settings::ControlCharacters::Error,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown control_characters policy '{control_characters}', expected 'keep', \
+                     'skip' or 'error'"
+                )))
+            }
+        };
+        let nul_bytes = match nul_bytes {
+            "text" => core_settings::NulBytes::Text,
+            "strip" => core_settings::NulBytes::Strip,
+            "error" => core_settings::NulBytes::Error,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown nul_bytes policy '{nul_bytes}', expected 'text', 'strip' or 'error'"
+                )))
+            }
+        };
+        let position_encoding = match position_encoding {
+            "utf8" => core_settings::PositionEncoding::Utf8,
+            "utf16" => core_settings::PositionEncoding::Utf16,
+            "utf32" => core_settings::PositionEncoding::Utf32,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown position_encoding '{position_encoding}', expected 'utf8', 'utf16' or \
+                     'utf32'"
+                )))
+            }
+        };
+        let settings = TokenizerDialectSettings(core_settings::TokenizerDialectSettings {
             unescaped_sequences,
             identifiers_can_start_with_digit,
             numbers_can_be_underscore_separated,
-        };
+            unicode_identifiers,
+            versioned_comments_version,
+            control_characters,
+            nul_bytes,
+            max_input_bytes,
+            max_tokens,
+            comment_tokens,
+            hint_tokens,
+            position_encoding,
+        });
 
         #[cfg(feature = "profiling")]
         {
             settings.write_json_to_string();
         }
 
-        settings
+        Ok(settings)
+    }
+
+    /// The dialect settings of a builtin dialect's preset, see `TokenizerSettings.for_dialect`.
+    #[staticmethod]
+    pub fn for_dialect(dialect: &str) -> PyResult<Self> {
+        preset(dialect, presets::dialect_settings(dialect)).map(TokenizerDialectSettings)
+    }
+
+    /// Adds unescaped sequences to these settings in place, replacing those with the same
+    /// sequence, e.g. for a tokenizer that unescapes more than its dialect does.
+    pub fn register_unescaped_sequences(&mut self, unescaped_sequences: HashMap<String, String>) {
+        self.0.unescaped_sequences.extend(unescaped_sequences);
     }
 }
 
 #[cfg(feature = "profiling")]
 impl TokenizerDialectSettings {
     pub fn write_json_to_string(&self) {
-        let json = serde_json::to_string(self).unwrap();
+        let json = serde_json::to_string(&self.0).unwrap();
         let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
             .join("benches/tokenizer_dialect_settings.json");
         std::fs::write(path, &json).unwrap();
     }
 }
+
+macro_rules! deref_to_core {
+    ($($name:ident),*) => {$(
+        impl Deref for $name {
+            type Target = core_settings::$name;
+
+            fn deref(&self) -> &core_settings::$name {
+                &self.0
+            }
+        }
+
+        impl DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut core_settings::$name {
+                &mut self.0
+            }
+        }
+    )*};
+}
+
+deref_to_core!(
+    TokenTypeSettings,
+    TokenizerSettings,
+    TokenizerDialectSettings
+);
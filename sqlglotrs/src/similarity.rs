@@ -0,0 +1,100 @@
+use crate::settings::TokenizerDialectSettings;
+use crate::tokenizer::{Tokenizer, TokenizerConfig, TryTokenize};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rustc_hash::FxHashSet as HashSet;
+use sqlglotrs_core::fingerprint::query_shape;
+use sqlglotrs_core::source::Source;
+use std::borrow::Cow;
+use std::hash::Hash;
+
+/// How `similarity` compares the shingle sets of two queries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimilarityMethod {
+    /// The shared shingles over all shingles of both queries
+    Jaccard,
+    /// The share of the first query's shingles found in the second
+    Containment,
+}
+
+impl SimilarityMethod {
+    pub fn from_name(name: &str) -> Option<SimilarityMethod> {
+        match name {
+            "jaccard" => Some(SimilarityMethod::Jaccard),
+            "containment" => Some(SimilarityMethod::Containment),
+            _ => None,
+        }
+    }
+}
+
+/// Scores how alike two query shapes are by comparing their sets of `size` consecutive tokens,
+/// from 0 for nothing in common to 1 for the same set. Shapes shorter than `size` make up a
+/// single shingle, and two empty shapes are considered identical.
+pub fn shingle_similarity<T: Hash + Eq>(
+    a: &[T],
+    b: &[T],
+    size: usize,
+    method: SimilarityMethod,
+) -> f64 {
+    let a = shingles(a, size);
+    let b = shingles(b, size);
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+
+    let shared = a.intersection(&b).count();
+    match method {
+        SimilarityMethod::Jaccard => shared as f64 / (a.len() + b.len() - shared) as f64,
+        SimilarityMethod::Containment if a.is_empty() => 0.0,
+        SimilarityMethod::Containment => shared as f64 / a.len() as f64,
+    }
+}
+
+fn shingles<T: Hash + Eq>(shape: &[T], size: usize) -> HashSet<&[T]> {
+    if shape.len() <= size {
+        shape.chunks(size).collect()
+    } else {
+        shape.windows(size).collect()
+    }
+}
+
+fn shape_of(
+    config: &TokenizerConfig,
+    sql: &str,
+    dialect_settings: &TokenizerDialectSettings,
+) -> PyResult<Vec<String>> {
+    let tokens = config.try_tokenize(sql, dialect_settings)?;
+    let source = Source::new(sql);
+    Ok(query_shape(&source, &tokens, &config.token_types)
+        .into_iter()
+        .map(Cow::into_owned)
+        .collect())
+}
+
+/// Scores how alike two queries are, from 0 to 1, regardless of their literals, case and
+/// formatting, e.g. to cluster near-duplicate queries in logs. The queries are compared by their
+/// runs of `shingle_size` tokens, using either the `jaccard` index or the `containment` of
+/// `sql_a` in `sql_b`.
+#[pyfunction]
+#[pyo3(signature = (
+    tokenizer, sql_a, sql_b, dialect_settings, shingle_size = 3, method = "jaccard"
+))]
+pub fn similarity(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql_a: &str,
+    sql_b: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    shingle_size: usize,
+    method: &str,
+) -> PyResult<f64> {
+    let method = SimilarityMethod::from_name(method)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown similarity method '{}'", method)))?;
+    if shingle_size == 0 {
+        return Err(PyValueError::new_err("Shingle size must be at least 1"));
+    }
+
+    let config = tokenizer.config();
+    let a = shape_of(&config, sql_a, &dialect_settings)?;
+    let b = shape_of(&config, sql_b, &dialect_settings)?;
+    Ok(shingle_similarity(&a, &b, shingle_size, method))
+}
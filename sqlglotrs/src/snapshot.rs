@@ -0,0 +1,253 @@
+use crate::settings::TokenizerDialectSettings;
+use crate::token::Token;
+use crate::tokenizer::{Tokenizer, TryTokenize};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::fmt::Write;
+
+const HEADER: [&str; 5] = ["# type", "line:col", "start..end", "text", "comments"];
+const PRINT_HEADER: [&str; 5] = ["#", "type", "line:col", "text", "comments"];
+
+/// A token as loaded back from a debug dump: (type, line, col, start, end, text, comments).
+pub type DebugRow = (String, usize, usize, usize, usize, String, Vec<String>);
+
+/// Renders tokens as a stable, aligned text table meant for golden files and bug reports.
+#[pyfunction]
+pub fn dump_debug(py: Python<'_>, tokens: Vec<PyRef<'_, Token>>) -> PyResult<String> {
+    let mut rows = vec![HEADER.map(String::from)];
+    for token in &tokens {
+        let token_type = token.token_type_py.bind(py);
+        // Tokens that never went through sqlglot's Python wrapper only know their type index
+        let type_name = if token_type.is_none() {
+            token.token_type.to_string()
+        } else {
+            token_type.getattr("name")?.extract::<String>()?
+        };
+
+        let comments = token
+            .comments
+            .bind(py)
+            .iter()
+            .map(|c| c.extract::<String>().map(|c| quote(&c)))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        rows.push([
+            type_name,
+            format!("{}:{}", token.line, token.col),
+            format!("{}..{}", token.start, token.end),
+            quote(token.text.bind(py).to_str()?),
+            format!("[{}]", comments.join(", ")),
+        ]);
+    }
+
+    Ok(render_table(&rows))
+}
+
+/// Tokenizes `sql` and renders the tokens as an aligned table of their index, type, position,
+/// text and comments, for interactive debugging. Types are shown by the names in
+/// `token_type_names`, which is indexed by token type, defaulting to those of sqlglot's
+/// `TokenType`. They're shown by index if sqlglot can't be imported.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, token_type_names = None))]
+pub fn print_tokens(
+    py: Python<'_>,
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    token_type_names: Option<Vec<String>>,
+) -> PyResult<String> {
+    let tokens = tokenizer.config().try_tokenize(sql, &dialect_settings)?;
+    let token_type_names = match token_type_names {
+        Some(names) => Some(names),
+        None => sqlglot_token_type_names(py),
+    };
+
+    let mut rows = vec![PRINT_HEADER.map(String::from)];
+    for (index, token) in tokens.iter().enumerate() {
+        let type_name = token_type_names
+            .as_ref()
+            .and_then(|names| names.get(token.token_type as usize))
+            .cloned()
+            .unwrap_or_else(|| token.token_type.to_string());
+        let comments: Vec<String> = token.comments.iter().map(|c| quote(c)).collect();
+
+        rows.push([
+            index.to_string(),
+            type_name,
+            format!("{}:{}", token.line, token.col),
+            quote(&token.text),
+            format!("[{}]", comments.join(", ")),
+        ]);
+    }
+    Ok(render_table(&rows))
+}
+
+/// The names of sqlglot's token types, whose declaration order is the order of their ids.
+fn sqlglot_token_type_names(py: Python<'_>) -> Option<Vec<String>> {
+    let token_type = py.import("sqlglot.tokens").ok()?.getattr("TokenType").ok()?;
+    token_type
+        .try_iter()
+        .ok()?
+        .map(|member| member?.getattr("name")?.extract())
+        .collect::<PyResult<_>>()
+        .ok()
+}
+
+/// Lays out rows as columns padded to their widest cell, except for the last one.
+fn render_table<const N: usize>(rows: &[[String; N]]) -> String {
+    let mut widths = [0; N];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut out = String::new();
+    for row in rows {
+        for (cell, width) in row[..N - 1].iter().zip(widths) {
+            let _ = write!(out, "{:<width$} ", cell, width = width);
+        }
+        out.push_str(&row[N - 1]);
+        out.push('\n');
+    }
+    out
+}
+
+/// Parses the output of `dump_debug` back into rows so snapshots can be compared structurally.
+#[pyfunction]
+pub fn load_debug(dump: &str) -> PyResult<Vec<DebugRow>> {
+    dump.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            parse_row(line).ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "Invalid token dump on line {}: {}",
+                    index + 1,
+                    line
+                ))
+            })
+        })
+        .collect()
+}
+
+fn parse_row(line: &str) -> Option<DebugRow> {
+    let mut cursor = Cursor {
+        rest: line.trim_end(),
+    };
+
+    let token_type = cursor.word()?.to_string();
+    let (line, col) = cursor.word()?.split_once(':')?;
+    let (start, end) = cursor.word()?.split_once("..")?;
+    let text = cursor.quoted()?;
+
+    cursor.skip_spaces();
+    cursor.expect('[')?;
+    let mut comments = Vec::new();
+    loop {
+        cursor.skip_spaces();
+        if cursor.expect(']').is_some() {
+            break;
+        }
+        if !comments.is_empty() {
+            cursor.expect(',')?;
+            cursor.skip_spaces();
+        }
+        comments.push(cursor.quoted()?);
+    }
+
+    if !cursor.rest.is_empty() {
+        return None;
+    }
+
+    Some((
+        token_type,
+        line.parse().ok()?,
+        col.parse().ok()?,
+        start.parse().ok()?,
+        end.parse().ok()?,
+        text,
+        comments,
+    ))
+}
+
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{{{:x}}}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+struct Cursor<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn skip_spaces(&mut self) {
+        self.rest = self.rest.trim_start_matches(' ');
+    }
+
+    fn expect(&mut self, c: char) -> Option<()> {
+        self.rest = self.rest.strip_prefix(c)?;
+        Some(())
+    }
+
+    fn word(&mut self) -> Option<&'a str> {
+        self.skip_spaces();
+        let end = self.rest.find(' ').unwrap_or(self.rest.len());
+        let (word, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        (!word.is_empty()).then_some(word)
+    }
+
+    fn quoted(&mut self) -> Option<String> {
+        self.skip_spaces();
+        self.expect('"')?;
+
+        let mut text = String::new();
+        let mut chars = self.rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &self.rest[i + 1..];
+                    return Some(text);
+                }
+                '\\' => match chars.next()?.1 {
+                    'n' => text.push('\n'),
+                    'r' => text.push('\r'),
+                    't' => text.push('\t'),
+                    'u' => {
+                        let (open, '{') = chars.next()? else {
+                            return None;
+                        };
+                        let digits = &self.rest[open + 1..];
+                        let close = digits.find('}')?;
+                        text.push(char::from_u32(
+                            u32::from_str_radix(&digits[..close], 16).ok()?,
+                        )?);
+                        // Skip the hex digits and the closing brace
+                        for _ in 0..=close {
+                            chars.next();
+                        }
+                    }
+                    c => text.push(c),
+                },
+                c => text.push(c),
+            }
+        }
+        None
+    }
+}
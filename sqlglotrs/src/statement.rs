@@ -0,0 +1,448 @@
+use crate::edit::{apply_edits, EditLog};
+use crate::settings::TokenizerDialectSettings;
+use crate::tokenizer::{value_error, Tokenizer, TokenizerConfig, TryTokenize};
+use pyo3::prelude::*;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::statement::statements;
+use sqlglotrs_core::tokenizer::BatchScan;
+use sqlglotrs_core::words::Words;
+use std::ops::Range;
+
+/// The coarse kind of a statement, as told by its leading keyword.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatementKind {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Merge,
+    Ddl,
+    Utility,
+    Tcl,
+    Unknown,
+}
+
+impl StatementKind {
+    /// Maps a statement's leading keyword onto its kind. Multi-word keywords such as
+    /// `BEGIN TRANSACTION` are classified by their first word.
+    pub fn from_keyword(keyword: &str) -> StatementKind {
+        let word = keyword.split_whitespace().next().unwrap_or_default();
+        match word.to_ascii_uppercase().as_str() {
+            "SELECT" | "VALUES" | "TABLE" | "FROM" => StatementKind::Select,
+            "INSERT" | "REPLACE" | "UPSERT" => StatementKind::Insert,
+            "UPDATE" => StatementKind::Update,
+            "DELETE" => StatementKind::Delete,
+            "MERGE" => StatementKind::Merge,
+            "CREATE" | "ALTER" | "DROP" | "TRUNCATE" | "RENAME" | "COMMENT" | "UNDROP" => {
+                StatementKind::Ddl
+            }
+            "BEGIN" | "START" | "COMMIT" | "ROLLBACK" | "SAVEPOINT" | "RELEASE" | "END"
+            | "ABORT" => StatementKind::Tcl,
+            "SET" | "SHOW" | "DESCRIBE" | "DESC" | "EXPLAIN" | "USE" | "ANALYZE" | "VACUUM"
+            | "COPY" | "CALL" | "EXEC" | "EXECUTE" | "GRANT" | "REVOKE" | "LOCK" | "UNLOCK"
+            | "REFRESH" | "PRAGMA" | "ATTACH" | "DETACH" | "INSTALL" | "LOAD" | "PUT" | "GET"
+            | "LIST" | "REMOVE" | "UNLOAD" | "DECLARE" | "PREPARE" | "DEALLOCATE" | "OPTIMIZE"
+            | "CACHE" | "UNCACHE" | "MSCK" | "KILL" => StatementKind::Utility,
+            _ => StatementKind::Unknown,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StatementKind::Select => "SELECT",
+            StatementKind::Insert => "INSERT",
+            StatementKind::Update => "UPDATE",
+            StatementKind::Delete => "DELETE",
+            StatementKind::Merge => "MERGE",
+            StatementKind::Ddl => "DDL",
+            StatementKind::Utility => "UTILITY",
+            StatementKind::Tcl => "TCL",
+            StatementKind::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+// The statements that can follow a WITH clause
+const CTE_STATEMENTS: [&str; 5] = ["SELECT", "INSERT", "UPDATE", "DELETE", "MERGE"];
+
+/// Returns the index of the keyword that determines the kind of the statement spanning
+/// `statement`, skipping opening parentheses and any CTEs.
+pub fn head(words: &Words, statement: Range<usize>) -> Option<usize> {
+    let mut i = statement.start;
+    while i < statement.end && words.is(i, "(") {
+        i += 1;
+    }
+
+    if !words.is(i, "WITH") {
+        return (i < statement.end).then_some(i);
+    }
+
+    // The statement proper is the first verb outside of the CTEs' parentheses
+    let mut depth = 0;
+    for j in i + 1..statement.end {
+        if words.is(j, "(") {
+            depth += 1;
+        } else if words.is(j, ")") {
+            depth -= 1;
+        } else if depth == 0 && words.is_any(j, &CTE_STATEMENTS) {
+            return Some(j);
+        }
+    }
+    None
+}
+
+// Keywords that make a statement write, wherever they appear outside of function calls. INTO
+// covers `SELECT ... INTO new_table` and UPDATE covers `SELECT ... FOR UPDATE`, which takes locks.
+const WRITE_KEYWORDS: [&str; 20] = [
+    "INSERT", "UPDATE", "DELETE", "MERGE", "UPSERT", "REPLACE", "CREATE", "ALTER", "DROP",
+    "TRUNCATE", "RENAME", "COMMENT", "UNDROP", "GRANT", "REVOKE", "COPY", "CALL", "EXEC",
+    "EXECUTE", "INTO",
+];
+// The statements EXPLAIN options are skipped up to
+const EXPLAINABLE_STATEMENTS: [&str; 10] = [
+    "SELECT", "VALUES", "TABLE", "WITH", "INSERT", "UPDATE", "DELETE", "MERGE", "CREATE", "EXECUTE",
+];
+const READ_ONLY_HEADS: [&str; 7] = [
+    "SELECT", "VALUES", "TABLE", "FROM", "SHOW", "DESCRIBE", "DESC",
+];
+
+/// Whether every statement in `sql` only reads data. This errs on the side of caution: any
+/// statement that isn't a query, `SHOW`, `DESCRIBE` or `EXPLAIN` of one, or that contains a write
+/// keyword anywhere, e.g. in a data-modifying CTE or `FOR UPDATE`, makes it false. When
+/// `explained` is set, the statements may be preceded by EXPLAIN options such as ANALYZE.
+fn read_only(
+    config: &TokenizerConfig,
+    sql: &str,
+    dialect_settings: &TokenizerDialectSettings,
+    explained: bool,
+) -> PyResult<bool> {
+    let tokens = config.try_tokenize(sql, dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+    let explains = |i| words.is_any(i, &EXPLAINABLE_STATEMENTS);
+
+    for statement in statements(&words) {
+        let mut start = statement.start;
+        if explained {
+            while start < statement.end && !explains(start) {
+                start += 1;
+            }
+        }
+        let Some(i) = head(&words, start..statement.end) else {
+            return Ok(false);
+        };
+
+        if words.is(i, "EXPLAIN") {
+            // Dialects that treat EXPLAIN as a command scan the rest of the statement as a string
+            let rest = words.token(statement.end - 1);
+            if statement.end == i + 2 && rest.token_type == config.token_types.string {
+                if !read_only(config, &rest.text, dialect_settings, true)? {
+                    return Ok(false);
+                }
+                continue;
+            }
+            start = i + 1;
+            while start < statement.end && !explains(start) {
+                start += 1;
+            }
+        }
+
+        let writes = (start..statement.end).any(|j| {
+            let is_name = words.is(j + 1, "(") || j > 0 && words.is(j - 1, ".");
+            (words.is_any(j, &WRITE_KEYWORDS) && !is_name)
+                || (words.is(j, "FOR") && words.is(j + 1, "SHARE"))
+        });
+        let reads =
+            head(&words, start..statement.end).is_some_and(|i| words.is_any(i, &READ_ONLY_HEADS));
+        if writes || !reads {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Finds the SELECT statements of a token stream that aren't limited by a top-level `LIMIT`,
+/// `FETCH` or `TOP` clause and returns the char offset at which a `LIMIT` can be inserted into
+/// each, along with whether that's right before the statement's locking clause, e.g. `FOR UPDATE`,
+/// rather than at its end.
+pub fn missing_limits(words: &Words) -> Vec<(usize, bool)> {
+    let mut offsets = Vec::new();
+    for statement in statements(words) {
+        let is_select = head(words, statement.clone()).is_some_and(|i| {
+            StatementKind::from_keyword(&words.token(i).text) == StatementKind::Select
+        });
+        if !is_select {
+            continue;
+        }
+
+        let mut depth = 0;
+        let mut limited = false;
+        let mut locking_clause = None;
+        for i in statement.clone() {
+            if words.is(i, "(") {
+                depth += 1;
+            } else if words.is(i, ")") {
+                depth -= 1;
+            } else if depth == 0 {
+                let is_top = i > 0 && words.is_any(i - 1, &["SELECT", "DISTINCT"]);
+                limited |= words.is(i, "LIMIT")
+                    || words.is(i, "FETCH") && words.is_any(i + 1, &["FIRST", "NEXT"])
+                    || words.is(i, "TOP") && is_top;
+                if locking_clause.is_none()
+                    && words.is(i, "FOR")
+                    && words.is_any(i + 1, &["UPDATE", "SHARE", "NO", "KEY"])
+                {
+                    locking_clause = Some(i);
+                }
+            }
+        }
+
+        if !limited {
+            offsets.push(match locking_clause {
+                Some(i) => (words.token(i).start, true),
+                None => (words.token(statement.end - 1).end + 1, false),
+            });
+        }
+    }
+    offsets
+}
+
+/// The context a statement runs in, as found by `resolve_context`: (catalog, schema, start, end),
+/// where the span covers the statement.
+pub type StatementContext = (Option<String>, Option<String>, usize, usize);
+
+// What `USE` can switch to other than a catalog or schema, e.g. `USE WAREHOUSE w`
+const USE_OTHER_KINDS: [&str; 3] = ["ROLE", "WAREHOUSE", "SECONDARY"];
+
+/// Tracks the catalog and schema changes made by `USE` statements, returning the context that
+/// each statement runs in. `USE CATALOG c` and `USE DATABASE c` switch the catalog, and since
+/// the new catalog's default schema isn't known, reset the schema. Anything else, e.g. `USE s`,
+/// `USE SCHEMA s` or `USE c.s`, switches the schema and, if qualified, the catalog.
+pub fn resolve_contexts(
+    words: &Words,
+    mut catalog: Option<String>,
+    mut schema: Option<String>,
+) -> Vec<StatementContext> {
+    let mut contexts = Vec::new();
+    for statement in statements(words) {
+        let start = words.token(statement.start).start;
+        let end = words.token(statement.end - 1).end;
+        contexts.push((catalog.clone(), schema.clone(), start, end));
+
+        let i = statement.start;
+        if !words.is(i, "USE") {
+            continue;
+        }
+        // The kind is optional, so e.g. `USE role` switches to a schema called role
+        let kind = words.is_word(i + 2).then_some(i + 1);
+        if kind.is_some_and(|k| words.is_any(k, &USE_OTHER_KINDS)) {
+            continue;
+        }
+        let name = kind.map_or(i + 1, |k| k + 1);
+        let Some((_, parts, _)) = words.name_parts(name) else {
+            continue;
+        };
+
+        let parts: Vec<String> = parts.into_iter().map(String::from).collect();
+        if kind.is_some_and(|k| words.is_any(k, &["CATALOG", "DATABASE"])) {
+            catalog = parts.last().cloned();
+            schema = None;
+        } else if let [.., qualifier, name] = parts.as_slice() {
+            catalog = Some(qualifier.clone());
+            schema = Some(name.clone());
+        } else {
+            schema = parts.last().cloned();
+        }
+    }
+    contexts
+}
+
+/// The kind of the statement spanning `statement`, or None if it has no head.
+fn head_kind(words: &Words, statement: Range<usize>) -> Option<StatementKind> {
+    head(words, statement).map(|i| StatementKind::from_keyword(&words.token(i).text))
+}
+
+/// Classifies every statement of a token stream, returning (kind, start, end) for each, where
+/// the span covers the statement without its terminating semicolon.
+pub fn classify_statements(words: &Words) -> Vec<(StatementKind, usize, usize)> {
+    statements(words)
+        .into_iter()
+        .map(|statement| {
+            let kind = head_kind(words, statement.clone()).unwrap_or(StatementKind::Unknown);
+            let start = words.token(statement.start).start;
+            let end = words.token(statement.end - 1).end;
+            (kind, start, end)
+        })
+        .collect()
+}
+
+/// Classifies the first statement of a token stream that may only be a prefix of the query's,
+/// returning None if more tokens are needed to tell its kind.
+fn first_statement_kind(words: &Words, is_complete: bool) -> Option<StatementKind> {
+    let Some(statement) = statements(words).into_iter().next() else {
+        return is_complete.then_some(StatementKind::Unknown);
+    };
+    // Past a semicolon that ends it, the statement's head can't come any later
+    let is_complete = is_complete || statement.end < words.len();
+    head_kind(words, statement).or(is_complete.then_some(StatementKind::Unknown))
+}
+
+/// Whether `sql` only reads data, e.g. for enforcing read-only endpoints. Statements are checked
+/// conservatively, so anything that might write, lock rows or change the catalog is rejected.
+#[pyfunction]
+pub fn is_read_only(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<bool> {
+    read_only(&tokenizer.config(), sql, &dialect_settings, false)
+}
+
+/// Whether `sql` holds more than one non-empty statement. Returns the answer along with the span
+/// of the second statement, if any.
+#[pyfunction]
+pub fn has_multiple_statements(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<(bool, Option<(usize, usize)>)> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+    let second = statements(&words).get(1).map(|statement| {
+        (
+            words.token(statement.start).start,
+            words.token(statement.end - 1).end,
+        )
+    });
+    Ok((second.is_some(), second))
+}
+
+/// Splits `sql` into its non-empty statements, returning (text, start, end) for each, where the
+/// half-open range is in bytes of `sql`. Semicolons inside strings, comments, parentheses and
+/// `BEGIN ... END` blocks don't end a statement. The text runs from the statement's first token
+/// to its last, so it leaves out the comments around it and the terminating semicolon.
+#[pyfunction]
+pub fn split_statements(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<(String, usize, usize)>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+    Ok(statements(&words)
+        .into_iter()
+        .map(|statement| {
+            let start = source.byte_offset(words.token(statement.start).start);
+            let end = source.byte_offset(words.token(statement.end - 1).end + 1);
+            (sql[start..end].to_string(), start, end)
+        })
+        .collect())
+}
+
+/// Appends `LIMIT n` to every SELECT statement in `sql` that isn't already limited. Returns the
+/// rewritten query and the char offsets in the original query where a limit was inserted.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, n, edit_log = None))]
+pub fn ensure_limit(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    n: u64,
+    edit_log: Option<PyRefMut<'_, EditLog>>,
+) -> PyResult<(String, Vec<usize>)> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let insertions = missing_limits(&Words::new(&source, &tokens, &config.token_types));
+
+    let edits = insertions
+        .iter()
+        .map(|&(offset, before_clause)| {
+            let limit = if before_clause {
+                format!("LIMIT {} ", n)
+            } else {
+                format!(" LIMIT {}", n)
+            };
+            (offset..offset, limit)
+        })
+        .collect();
+    let offsets = insertions.into_iter().map(|(offset, _)| offset).collect();
+    Ok((apply_edits(&source, edits, edit_log), offsets))
+}
+
+/// Resolves the catalog and schema that each statement in `sql` runs in, starting from the given
+/// ones and following the `USE` statements in the script.
+#[pyfunction]
+#[pyo3(signature = (tokenizer, sql, dialect_settings, initial_catalog = None, initial_schema = None))]
+pub fn resolve_context(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+    initial_catalog: Option<String>,
+    initial_schema: Option<String>,
+) -> PyResult<Vec<StatementContext>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+    Ok(resolve_contexts(&words, initial_catalog, initial_schema))
+}
+
+// The number of tokens `statement_kind` scans before it first looks for the leading keyword,
+// which doubles with every batch so that long CTEs aren't looked through too many times
+const KIND_BATCH_SIZE: usize = 16;
+
+/// Classifies the first statement in `sql` as `classify` does, but only scans as far as its
+/// leading keyword, e.g. for routing queries by their kind without tokenizing all of them.
+#[pyfunction]
+pub fn statement_kind(
+    py: Python<'_>,
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<&'static str> {
+    let config = tokenizer.config();
+    let source = Source::new(sql);
+    let mut scan = BatchScan::new(&config, sql, &dialect_settings);
+    let mut tokens = Vec::new();
+    let mut size = KIND_BATCH_SIZE;
+
+    loop {
+        let (batch, error) = scan.next_batch(&config, &dialect_settings, size);
+        let is_complete = batch.is_empty() && error.is_none();
+        tokens.extend(batch);
+
+        // What comes past the leading keyword isn't scanned, so errors there don't matter
+        let words = Words::new(&source, &tokens, &config.token_types);
+        if let Some(kind) = first_statement_kind(&words, is_complete) {
+            return Ok(kind.as_str());
+        }
+        if let Some(error) = error {
+            return Err(value_error(py, error));
+        }
+        size *= 2;
+    }
+}
+
+/// Classifies each statement in `sql` by its leading keyword, after comments, parentheses and
+/// CTEs, as one of SELECT, INSERT, UPDATE, DELETE, MERGE, DDL, UTILITY, TCL or UNKNOWN. Returns
+/// the kind and span of every statement.
+#[pyfunction]
+pub fn classify(
+    tokenizer: PyRef<'_, Tokenizer>,
+    sql: &str,
+    dialect_settings: PyRef<'_, TokenizerDialectSettings>,
+) -> PyResult<Vec<(&'static str, usize, usize)>> {
+    let config = tokenizer.config();
+    let tokens = config.try_tokenize(sql, &dialect_settings)?;
+    let source = Source::new(sql);
+    let words = Words::new(&source, &tokens, &config.token_types);
+    Ok(classify_statements(&words)
+        .into_iter()
+        .map(|(kind, start, end)| (kind.as_str(), start, end))
+        .collect())
+}
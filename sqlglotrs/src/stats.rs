@@ -0,0 +1,63 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use sqlglotrs_core::stats::{self, ScanStats};
+
+/// Starts counting the queries tokenized anywhere in the process, e.g. to export them as metrics.
+#[pyfunction]
+pub fn enable_stats() {
+    stats::enable();
+}
+
+/// Stops counting tokenized queries. The counts so far are kept until `reset_stats` is called.
+#[pyfunction]
+pub fn disable_stats() {
+    stats::disable();
+}
+
+/// Clears all counts.
+#[pyfunction]
+pub fn reset_stats() {
+    stats::reset();
+}
+
+/// Returns the counts recorded since stats were enabled or last reset: the number of `queries`
+/// tokenized, the `bytes` scanned, the `errors` by kind, and the total, mean and estimated p95
+/// durations of scans in seconds.
+#[pyfunction]
+pub fn get_stats(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let snapshot = stats::snapshot();
+
+    let errors = PyDict::new(py);
+    for (kind, count) in &snapshot.errors {
+        errors.set_item(kind, count)?;
+    }
+
+    let stats = PyDict::new(py);
+    stats.set_item("queries", snapshot.queries)?;
+    stats.set_item("bytes", snapshot.bytes)?;
+    stats.set_item("errors", errors)?;
+    stats.set_item("total_seconds", snapshot.total.as_secs_f64())?;
+    stats.set_item("mean_seconds", snapshot.mean().as_secs_f64())?;
+    stats.set_item("p95_seconds", snapshot.p95.as_secs_f64())?;
+    Ok(stats)
+}
+
+/// The counts of a single scan as returned by `Tokenizer.tokenize_with_stats`: its
+/// `elapsed_ns`, the `bytes` scanned, the `tokens` produced, the `keyword_hits` and
+/// `keyword_misses` of the keyword automaton, and the texts `allocations` made.
+pub(crate) fn scan_stats_dict<'py>(
+    py: Python<'py>,
+    scan_stats: &ScanStats,
+) -> PyResult<Bound<'py, PyDict>> {
+    let stats = PyDict::new(py);
+    stats.set_item(
+        "elapsed_ns",
+        u64::try_from(scan_stats.elapsed.as_nanos()).unwrap_or(u64::MAX),
+    )?;
+    stats.set_item("bytes", scan_stats.bytes)?;
+    stats.set_item("tokens", scan_stats.tokens)?;
+    stats.set_item("keyword_hits", scan_stats.keyword_hits)?;
+    stats.set_item("keyword_misses", scan_stats.keyword_misses)?;
+    stats.set_item("allocations", scan_stats.allocations)?;
+    Ok(stats)
+}
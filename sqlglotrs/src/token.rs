@@ -1,7 +1,14 @@
 use crate::settings::TokenType;
 use pyo3::prelude::*;
-use pyo3::types::{PyList, PyString};
+use pyo3::types::{PyDict, PyList, PyMappingProxy, PyString, PyTuple};
 use pyo3::{pyclass, pymethods, Py, PyObject, Python};
+use rustc_hash::{FxHashMap as HashMap, FxHasher};
+use std::hash::{Hash, Hasher};
+
+use sqlglotrs_core::token::{TokenText, MAX_INTERNED_LEN};
+use sqlglotrs_core::tokenizer::TokenShift;
+
+pub use sqlglotrs_core::token::RawToken;
 
 #[derive(Debug)]
 #[pyclass]
@@ -12,54 +19,227 @@ pub struct Token {
     pub token_type_py: PyObject,
     #[pyo3(get)]
     pub text: Py<PyString>,
+    /// The line and column of the token's last char, also available as `end_line` and
+    /// `end_column`
     #[pyo3(get)]
     pub line: usize,
     #[pyo3(get)]
     pub col: usize,
+    /// Those of its first char, which differ for tokens spanning several lines, e.g. strings
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get, name = "start_column")]
+    pub start_col: usize,
     #[pyo3(get)]
     pub start: usize,
     #[pyo3(get)]
     pub end: usize,
+    /// The token's span in bytes of the query's UTF-8, with an exclusive end unlike `end`
+    #[pyo3(get)]
+    pub start_byte: usize,
+    #[pyo3(get)]
+    pub end_byte: usize,
     #[pyo3(get)]
     pub comments: Py<PyList>,
+    // See the `prefix` getter
+    pub prefix: Option<Py<PyString>>,
+    /// The whitespace and comments between the previous token and this one, and the token itself,
+    /// as written. Only set by `Tokenizer.tokenize_trivia`.
+    #[pyo3(get)]
+    pub leading_trivia: Option<Py<PyString>>,
+    #[pyo3(get)]
+    pub source_text: Option<Py<PyString>>,
+    // Arbitrary annotations attached by downstream passes, created lazily on first access
+    pub extras: Option<Py<PyDict>>,
+}
+
+/// The Python strings made for the texts of tokens converted together, so that tokens with the
+/// same short text, e.g. keywords and common identifiers, share one string object.
+#[derive(Default)]
+pub struct PyTexts(HashMap<TokenText, Py<PyString>>);
+
+impl PyTexts {
+    pub fn get(&mut self, py: Python, text: &TokenText) -> Py<PyString> {
+        if text.len() > MAX_INTERNED_LEN {
+            return PyString::new(py, text).unbind();
+        }
+        self.0
+            .entry(text.clone())
+            .or_insert_with(|| PyString::new(py, text).unbind())
+            .clone_ref(py)
+    }
+
+    /// Forgets the strings made so far once there are more than `capacity` of them.
+    pub fn trim(&mut self, capacity: usize) {
+        if self.0.len() > capacity {
+            self.0.clear();
+        }
+    }
 }
 
 impl Token {
-    pub fn new(
-        token_type: TokenType,
-        text: String,
-        line: usize,
-        col: usize,
-        start: usize,
-        end: usize,
-        comments: Vec<String>,
-    ) -> Token {
-        Python::with_gil(|py| Token {
-            token_type,
+    pub fn new(py: Python, token: RawToken, texts: &mut PyTexts) -> Token {
+        Token {
+            token_type: token.token_type,
             token_type_py: py.None(),
-            text: PyString::new(py, &text).unbind(),
-            line,
-            col,
-            start,
-            end,
-            comments: PyList::new(py, &comments).unwrap().unbind(),
+            text: texts.get(py, &token.text),
+            line: token.line,
+            col: token.col,
+            start_line: token.start_line,
+            start_col: token.start_col,
+            start: token.start,
+            end: token.end,
+            start_byte: token.start_byte,
+            end_byte: token.end_byte,
+            comments: PyList::new(py, token.comments.iter().map(|c| &**c))
+                .unwrap()
+                .unbind(),
+            prefix: token.prefix.as_ref().map(|prefix| texts.get(py, prefix)),
+            leading_trivia: None,
+            source_text: None,
+            extras: None,
+        }
+    }
+
+    /// A copy of this token moved by `shift`, sharing its text, with a copy of its `extras`. Its
+    /// trivia isn't carried over, as the text around it may have changed.
+    pub fn shifted(&self, py: Python, shift: &TokenShift) -> PyResult<Token> {
+        let mut position = self.position();
+        shift.apply(&mut position);
+        Ok(Token {
+            token_type: self.token_type,
+            token_type_py: self.token_type_py.clone_ref(py),
+            text: self.text.clone_ref(py),
+            line: position.line,
+            col: position.col,
+            start_line: position.start_line,
+            start_col: position.start_col,
+            start: position.start,
+            end: position.end,
+            start_byte: position.start_byte,
+            end_byte: position.end_byte,
+            comments: PyList::new(py, self.comments.bind(py))?.unbind(),
+            prefix: self.prefix.as_ref().map(|prefix| prefix.clone_ref(py)),
+            leading_trivia: None,
+            source_text: None,
+            extras: match &self.extras {
+                Some(extras) => Some(extras.bind(py).copy()?.unbind()),
+                None => None,
+            },
         })
     }
 
-    pub fn append_comments(&self, comments: &mut Vec<String>) {
-        Python::with_gil(|py| {
-            let pylist = self.comments.bind(py);
-            for comment in comments.drain(..) {
-                if let Err(_) = pylist.append(comment) {
-                    panic!("Failed to append comments to the Python list");
-                }
-            }
-        });
+    /// This token's type and position, without its text or comments.
+    pub fn position(&self) -> RawToken {
+        RawToken {
+            token_type: self.token_type,
+            text: "".into(),
+            line: self.line,
+            col: self.col,
+            start_line: self.start_line,
+            start_col: self.start_col,
+            start: self.start,
+            end: self.end,
+            start_byte: self.start_byte,
+            end_byte: self.end_byte,
+            comments: Vec::new(),
+            prefix: None,
+        }
+    }
+
+    /// Copies this token back into the scanner's representation, for helpers that take tokens
+    /// from Python rather than scanning a query themselves.
+    pub fn to_raw(&self, py: Python) -> PyResult<RawToken> {
+        let comments = self.comments.bind(py).extract::<Vec<String>>()?;
+        Ok(RawToken {
+            token_type: self.token_type,
+            text: self.text.bind(py).to_str()?.into(),
+            line: self.line,
+            col: self.col,
+            start_line: self.start_line,
+            start_col: self.start_col,
+            start: self.start,
+            end: self.end,
+            start_byte: self.start_byte,
+            end_byte: self.end_byte,
+            comments: comments.into_iter().map(Into::into).collect(),
+            prefix: match &self.prefix {
+                Some(prefix) => Some(prefix.bind(py).to_str()?.into()),
+                None => None,
+            },
+        })
     }
 }
 
 #[pymethods]
 impl Token {
+    #[getter]
+    fn extras(&mut self, py: Python) -> Py<PyDict> {
+        self.extras
+            .get_or_insert_with(|| PyDict::new(py).unbind())
+            .clone_ref(py)
+    }
+
+    #[setter]
+    fn set_extras(&mut self, extras: Option<Bound<'_, PyDict>>) {
+        self.extras = extras.map(Bound::unbind);
+    }
+
+    /// The chars written before a string's opening quote, e.g. `N` for `N'abc'`, as they were
+    /// cased, so that generators can write the string as it was. Empty for other tokens.
+    #[getter]
+    fn prefix(&self, py: Python) -> Py<PyString> {
+        match &self.prefix {
+            Some(prefix) => prefix.clone_ref(py),
+            None => PyString::intern(py, "").unbind(),
+        }
+    }
+
+    /// The line of the token's last char, i.e. `line`, spelled out to pair with `start_line`.
+    #[getter]
+    fn end_line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the token's last char, i.e. `col`.
+    #[getter]
+    fn end_column(&self) -> usize {
+        self.col
+    }
+
+    /// Returns an immutable, hashable copy of this token, with a read-only copy of its `extras`,
+    /// which isn't hashed or compared. Trivia is not carried over.
+    fn freeze(&self, py: Python) -> PyResult<FrozenToken> {
+        let text = self.text.bind(py).to_str()?;
+        let comments = self.comments.bind(py).extract::<Vec<String>>()?;
+
+        let mut hasher = FxHasher::default();
+        (self.token_type, text, &comments).hash(&mut hasher);
+        (self.line, self.col, self.start, self.end).hash(&mut hasher);
+
+        Ok(FrozenToken {
+            token_type: self.token_type,
+            token_type_py: self.token_type_py.clone_ref(py),
+            text: self.text.clone_ref(py),
+            line: self.line,
+            col: self.col,
+            start_line: self.start_line,
+            start_col: self.start_col,
+            start: self.start,
+            end: self.end,
+            start_byte: self.start_byte,
+            end_byte: self.end_byte,
+            comments: PyTuple::new(py, comments)?.unbind(),
+            extras: match &self.extras {
+                Some(extras) => {
+                    Some(PyMappingProxy::new(py, extras.bind(py).copy()?.as_mapping()).unbind())
+                }
+                None => None,
+            },
+            hash: hasher.finish(),
+        })
+    }
+
     fn __repr__(&self, py: Python) -> PyResult<String> {
         let text = self.text.bind(py).to_str()?;
         let comments = self.comments.bind(py);
@@ -79,3 +259,97 @@ impl Token {
         ))
     }
 }
+
+/// A read-only counterpart of `Token` whose hash is computed once at construction, so frozen
+/// tokens can be shared across threads and used as cache keys without defensive copies.
+#[derive(Debug)]
+#[pyclass(frozen)]
+pub struct FrozenToken {
+    #[pyo3(get, name = "token_type_index")]
+    pub token_type: TokenType,
+    #[pyo3(get, name = "token_type")]
+    pub token_type_py: PyObject,
+    #[pyo3(get)]
+    pub text: Py<PyString>,
+    /// The line and column of the token's last char, also available as `end_line` and
+    /// `end_column`
+    #[pyo3(get)]
+    pub line: usize,
+    #[pyo3(get)]
+    pub col: usize,
+    /// Those of its first char, which differ for tokens spanning several lines, e.g. strings
+    #[pyo3(get)]
+    pub start_line: usize,
+    #[pyo3(get, name = "start_column")]
+    pub start_col: usize,
+    #[pyo3(get)]
+    pub start: usize,
+    #[pyo3(get)]
+    pub end: usize,
+    #[pyo3(get)]
+    pub start_byte: usize,
+    #[pyo3(get)]
+    pub end_byte: usize,
+    #[pyo3(get)]
+    pub comments: Py<PyTuple>,
+    // See the `extras` getter
+    pub extras: Option<Py<PyMappingProxy>>,
+    hash: u64,
+}
+
+#[pymethods]
+impl FrozenToken {
+    /// A read-only view of the `extras` of the token this was frozen from, as they were then.
+    #[getter]
+    fn extras(&self, py: Python) -> Py<PyMappingProxy> {
+        match &self.extras {
+            Some(extras) => extras.clone_ref(py),
+            None => PyMappingProxy::new(py, PyDict::new(py).as_mapping()).unbind(),
+        }
+    }
+
+    #[getter]
+    fn end_line(&self) -> usize {
+        self.line
+    }
+
+    #[getter]
+    fn end_column(&self) -> usize {
+        self.col
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.hash
+    }
+
+    fn __eq__(&self, py: Python, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let Ok(other) = other.downcast::<FrozenToken>() else {
+            return Ok(false);
+        };
+        let other = other.get();
+
+        Ok(self.hash == other.hash
+            && self.token_type == other.token_type
+            && (self.line, self.col, self.start, self.end)
+                == (other.line, other.col, other.start, other.end)
+            && self.text.bind(py).as_any().eq(other.text.bind(py))?
+            && self
+                .comments
+                .bind(py)
+                .as_any()
+                .eq(other.comments.bind(py))?)
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!(
+            "<FrozenToken token_type: {}, text: {}, line: {}, col: {}, start: {}, end: {}, comments: {}>",
+            self.token_type_py.bind(py).str()?,
+            self.text.bind(py).to_str()?,
+            self.line,
+            self.col,
+            self.start,
+            self.end,
+            self.comments.bind(py).repr()?
+        ))
+    }
+}
@@ -1,761 +1,771 @@
-use crate::settings::TokenType;
-use crate::trie::{Trie, TrieResult};
+use crate::arrow::ArrowTokens;
+use crate::input::{decode_sql, PyReader};
+use crate::json::tokens_to_json;
+use crate::stats::scan_stats_dict;
+use crate::token::{PyTexts, RawToken};
+use crate::trivia::split_trivia;
 use crate::{Token, TokenTypeSettings, TokenizerDialectSettings, TokenizerSettings};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use std::cmp::{max, min};
-
-#[derive(Debug)]
-pub struct TokenizerError {
-    message: String,
-    context: String,
+use pyo3::pybacked::PyBackedStr;
+use pyo3::types::{PyBytes, PyDict, PyString};
+use sqlglotrs_core::input::SqlFile;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::tokenizer::{BatchScan, PreviousTokens, ReaderScan};
+use std::collections::VecDeque;
+use std::io::Read;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+pub use sqlglotrs_core::tokenizer::{TokenizerConfig, TokenizerError, TokenizerErrorKind};
+
+/// A tokenizer error as returned to Python, carrying where the scan failed so that editors and
+/// logs can point at it. Its string form is the message `TokenError` is raised with.
+#[derive(Clone, Debug)]
+#[pyclass(frozen)]
+pub struct TokenizeError {
+    #[pyo3(get)]
+    pub kind: String,
+    #[pyo3(get)]
+    pub message: String,
+    #[pyo3(get)]
+    pub line: usize,
+    #[pyo3(get)]
+    pub column: usize,
+    #[pyo3(get)]
+    pub start: usize,
+    #[pyo3(get)]
+    pub char: Option<char>,
+    /// Up to 50 chars on either side of where the scan failed.
+    #[pyo3(get)]
+    pub context: String,
+    display: String,
 }
 
-#[derive(Debug)]
-#[pyclass]
-pub struct Tokenizer {
-    settings: TokenizerSettings,
-    token_types: TokenTypeSettings,
-    keyword_trie: Trie,
+impl From<TokenizerError> for TokenizeError {
+    fn from(error: TokenizerError) -> TokenizeError {
+        TokenizeError {
+            kind: error.kind.as_str().to_string(),
+            display: error.to_string(),
+            line: error.line,
+            column: error.column,
+            start: error.start,
+            char: error.character,
+            message: error.message().to_string(),
+            context: error.context().to_string(),
+        }
+    }
 }
 
-#[pymethods]
-impl Tokenizer {
-    #[new]
-    pub fn new(settings: TokenizerSettings, token_types: TokenTypeSettings) -> Tokenizer {
-        let mut keyword_trie = Trie::default();
-
-        let trie_filter = |key: &&String| {
-            key.contains(" ") || settings.single_tokens.keys().any(|&t| key.contains(t))
-        };
+impl std::fmt::Display for TokenizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.display)
+    }
+}
 
-        keyword_trie.add(settings.keywords.keys().filter(trie_filter));
-        keyword_trie.add(settings.comments.keys().filter(trie_filter));
-        keyword_trie.add(settings.quotes.keys().filter(trie_filter));
-        keyword_trie.add(settings.format_strings.keys().filter(trie_filter));
+/// A `ValueError` for a tokenizer error, which the helpers that can't return the error raise
+/// instead. Its `error` attribute holds the `TokenizeError`, so that where the scan failed isn't
+/// lost.
+pub(crate) fn value_error(py: Python<'_>, error: TokenizerError) -> PyErr {
+    let err = PyValueError::new_err(error.to_string());
+    if let Ok(error) = Py::new(py, TokenizeError::from(error)) {
+        // Only fails if the exception object is out of memory, when the message still stands
+        let _ = err.value(py).setattr("error", error);
+    }
+    err
+}
 
-        Tokenizer {
-            settings,
-            token_types,
-            keyword_trie,
-        }
+#[pymethods]
+impl TokenizeError {
+    fn __str__(&self) -> &str {
+        &self.display
     }
 
-    pub fn tokenize(
-        &self,
-        sql: &str,
-        dialect_settings: &TokenizerDialectSettings,
-    ) -> (Vec<Token>, Option<String>) {
-        let mut state = TokenizerState::new(
-            sql,
-            &self.settings,
-            &self.token_types,
-            dialect_settings,
-            &self.keyword_trie,
-        );
-        let tokenize_result = state.tokenize();
-        match tokenize_result {
-            Ok(tokens) => (tokens, None),
-            Err(e) => {
-                let msg = format!("Error tokenizing '{}': {}", e.context, e.message);
-                (state.tokens, Some(msg))
-            }
-        }
+    fn __repr__(&self) -> String {
+        format!(
+            "<TokenizeError kind: {}, line: {}, column: {}, start: {}, message: {}>",
+            self.kind, self.line, self.column, self.start, self.message
+        )
     }
 }
 
-#[derive(Debug)]
-struct TokenizerState<'a> {
-    sql: Vec<char>,
-    size: usize,
-    tokens: Vec<Token>,
-    start: usize,
-    current: usize,
-    line: usize,
-    column: usize,
-    comments: Vec<String>,
-    is_end: bool,
-    current_char: char,
-    peek_char: char,
-    previous_token_line: Option<usize>,
-    keyword_trie: &'a Trie,
-    settings: &'a TokenizerSettings,
-    dialect_settings: &'a TokenizerDialectSettings,
-    token_types: &'a TokenTypeSettings,
+/// Scans into a `Vec` of tokens, turning a tokenizer error into a Python `ValueError`, which is
+/// what the helpers built on top of the token stream raise.
+pub(crate) trait TryTokenize {
+    fn try_tokenize(
+        &self,
+        sql: &str,
+        dialect_settings: &sqlglotrs_core::settings::TokenizerDialectSettings,
+    ) -> PyResult<Vec<RawToken>>;
 }
 
-impl<'a> TokenizerState<'a> {
-    fn new(
+impl TryTokenize for TokenizerConfig {
+    fn try_tokenize(
+        &self,
         sql: &str,
-        settings: &'a TokenizerSettings,
-        token_types: &'a TokenTypeSettings,
-        dialect_settings: &'a TokenizerDialectSettings,
-        keyword_trie: &'a Trie,
-    ) -> TokenizerState<'a> {
-        let sql_vec = sql.chars().collect::<Vec<char>>();
-        let sql_vec_len = sql_vec.len();
-        TokenizerState {
-            sql: sql_vec,
-            size: sql_vec_len,
-            tokens: Vec::new(),
-            start: 0,
-            current: 0,
-            line: 1,
-            column: 0,
-            comments: Vec::new(),
-            is_end: false,
-            current_char: '\0',
-            peek_char: '\0',
-            previous_token_line: None,
-            keyword_trie,
-            settings,
-            dialect_settings,
-            token_types,
+        dialect_settings: &sqlglotrs_core::settings::TokenizerDialectSettings,
+    ) -> PyResult<Vec<RawToken>> {
+        match self.tokenize_raw(sql, dialect_settings) {
+            (tokens, None) => Ok(tokens),
+            (_, Some(error)) => Err(Python::with_gil(|py| value_error(py, error))),
         }
     }
+}
 
-    fn tokenize(&mut self) -> Result<Vec<Token>, TokenizerError> {
-        self.scan(None)?;
-        Ok(std::mem::take(&mut self.tokens))
-    }
-
-    fn scan(&mut self, until_peek_char: Option<char>) -> Result<(), TokenizerError> {
-        while self.size > 0 && !self.is_end {
-            let mut current = self.current;
+// A step of a scan's trace as handed to Python, i.e. its kind, position and detail
+type PyTraceStep = (&'static str, usize, String);
 
-            // Skip spaces here rather than iteratively calling advance() for performance reasons
-            while current < self.size {
-                let ch = self.char_at(current)?;
+// The number of tokens a stream scans at a time
+const STREAM_BATCH_SIZE: usize = 1024;
 
-                if ch == ' ' || ch == '\t' {
-                    current += 1;
-                } else {
-                    break;
-                }
-            }
+// The most texts a stream keeps sharing between its tokens, past which it starts over, so that
+// streams of distinct texts, e.g. of generated names, don't keep all of them alive
+const STREAM_INTERNED_TEXTS: usize = 16 * 1024;
 
-            let offset = if current > self.current {
-                current - self.current
-            } else {
-                1
-            };
+// The number of bytes `Tokenizer.stream_reader` reads at a time by default
+const READER_CHUNK_SIZE: usize = 64 * 1024;
 
-            self.start = current;
-            self.advance(offset as isize)?;
+/// Where a `TokenStream` scans its tokens from.
+enum StreamScan {
+    Text(Box<BatchScan>),
+    // Python readers and decompressed files, which are read a chunk at a time. Decoders aren't
+    // necessarily Sync, which pyclasses have to be, so it's behind a lock that is never contended
+    // as the stream is only scanned through `&mut self`
+    Reader(Mutex<ReaderScan<Box<dyn Read + Send>>>),
+}
 
-            if self.current_char == '\0' {
-                break;
-            }
+/// The iterator returned by `Tokenizer.stream`, `stream_file` and `stream_reader`.
+#[pyclass]
+pub struct TokenStream {
+    config: Arc<TokenizerConfig>,
+    dialect_settings: TokenizerDialectSettings,
+    scan: StreamScan,
+    ready: VecDeque<RawToken>,
+    error: Option<TokenizerError>,
+    texts: PyTexts,
+}
 
-            if !self.current_char.is_whitespace() {
-                if self.current_char.is_ascii_digit() {
-                    self.scan_number()?;
-                } else if let Some(identifier_end) =
-                    self.settings.identifiers.get(&self.current_char)
-                {
-                    self.scan_identifier(&identifier_end.to_string())?;
-                } else {
-                    self.scan_keyword()?;
-                }
-            }
+#[pymethods]
+impl TokenStream {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
 
-            if let Some(c) = until_peek_char {
-                if self.peek_char == c {
-                    break;
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Token>> {
+        if self.ready.is_empty() && self.error.is_none() {
+            let (tokens, error) = match &mut self.scan {
+                StreamScan::Text(scan) => {
+                    scan.next_batch(&self.config, &self.dialect_settings, STREAM_BATCH_SIZE)
                 }
-            }
-        }
-        if !self.tokens.is_empty() && !self.comments.is_empty() {
-            self.tokens
-                .last_mut()
-                .unwrap()
-                .append_comments(&mut self.comments);
+                StreamScan::Reader(scan) => scan
+                    .get_mut()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .next_batch(&self.config, &self.dialect_settings)?,
+            };
+            self.ready.extend(tokens);
+            self.error = error;
+            self.texts.trim(STREAM_INTERNED_TEXTS);
         }
-        Ok(())
-    }
-
-    fn advance(&mut self, i: isize) -> Result<(), TokenizerError> {
-        if Some(&self.token_types.break_) == self.settings.white_space.get(&self.current_char) {
-            // Ensures we don't count an extra line if we get a \r\n line break sequence.
-            if !(self.current_char == '\r' && self.peek_char == '\n') {
-                self.column = i as usize;
-                self.line += 1;
-            }
-        } else {
-            self.column = self.column.wrapping_add_signed(i);
+        if let Some(token) = self.ready.pop_front() {
+            return Ok(Some(Token::new(py, token, &mut self.texts)));
         }
-
-        self.current = self.current.wrapping_add_signed(i);
-        self.is_end = self.current >= self.size;
-        self.current_char = self.char_at(self.current - 1)?;
-        self.peek_char = if self.is_end {
-            '\0'
-        } else {
-            self.char_at(self.current)?
-        };
-        Ok(())
-    }
-
-    fn chars(&self, size: usize) -> String {
-        let start = self.current - 1;
-        let end = start + size;
-        if end <= self.size {
-            self.sql[start..end].iter().collect()
-        } else {
-            String::new()
+        match self.error.take() {
+            Some(error) => Err(value_error(py, error)),
+            None => Ok(None),
         }
     }
+}
 
-    fn char_at(&self, index: usize) -> Result<char, TokenizerError> {
-        self.sql.get(index).copied().ok_or_else(|| {
-            self.error(format!(
-                "Index {} is out of bound (size {})",
-                index, self.size
-            ))
-        })
-    }
+/// The iterator returned by `Tokenizer.iter_tokens`.
+#[pyclass]
+pub struct TokenChunks {
+    config: Arc<TokenizerConfig>,
+    dialect_settings: TokenizerDialectSettings,
+    scan: BatchScan,
+    chunk_size: usize,
+    ready: VecDeque<RawToken>,
+    error: Option<TokenizerError>,
+    done: bool,
+    texts: PyTexts,
+}
 
-    fn text(&self) -> String {
-        self.sql[self.start..self.current].iter().collect()
+#[pymethods]
+impl TokenChunks {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
     }
 
-    fn add(&mut self, token_type: TokenType, text: Option<String>) -> Result<(), TokenizerError> {
-        self.previous_token_line = Some(self.line);
-
-        if !self.comments.is_empty()
-            && !self.tokens.is_empty()
-            && token_type == self.token_types.semicolon
-        {
-            self.tokens
-                .last_mut()
-                .unwrap()
-                .append_comments(&mut self.comments);
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Vec<Token>>> {
+        while self.ready.len() < self.chunk_size && self.error.is_none() && !self.done {
+            let size = self.chunk_size - self.ready.len();
+            let (scan, config, dialect_settings) =
+                (&mut self.scan, &self.config, &self.dialect_settings);
+            let (tokens, error) =
+                py.allow_threads(|| scan.next_batch(config, dialect_settings, size));
+            self.done = tokens.is_empty() && error.is_none();
+            self.ready.extend(tokens);
+            self.error = error;
+            self.texts.trim(STREAM_INTERNED_TEXTS);
         }
-
-        self.tokens.push(Token::new(
-            token_type,
-            text.unwrap_or(self.text()),
-            self.line,
-            self.column,
-            self.start,
-            self.current - 1,
-            std::mem::take(&mut self.comments),
-        ));
-
-        // If we have either a semicolon or a begin token before the command's token, we'll parse
-        // whatever follows the command's token as a string.
-        if self.settings.commands.contains(&token_type)
-            && self.peek_char != ';'
-            && (self.tokens.len() == 1
-                || self
-                    .settings
-                    .command_prefix_tokens
-                    .contains(&self.tokens[self.tokens.len() - 2].token_type))
-        {
-            let start = self.current;
-            let tokens_len = self.tokens.len();
-            self.scan(Some(';'))?;
-            self.tokens.truncate(tokens_len);
-            let text = self.sql[start..self.current]
-                .iter()
-                .collect::<String>()
-                .trim()
-                .to_string();
-            if !text.is_empty() {
-                self.add(self.token_types.string, Some(text))?;
-            }
+        if !self.ready.is_empty() {
+            let size = self.chunk_size.min(self.ready.len());
+            let chunk = self
+                .ready
+                .drain(..size)
+                .map(|token| Token::new(py, token, &mut self.texts))
+                .collect();
+            return Ok(Some(chunk));
         }
-        Ok(())
-    }
-
-    fn scan_keyword(&mut self) -> Result<(), TokenizerError> {
-        let mut size: usize = 0;
-        let mut word: Option<String> = None;
-        let mut chars = self.text();
-        let mut current_char = '\0';
-        let mut prev_space = false;
-        let mut skip;
-        let mut is_single_token = chars.len() == 1
-            && self
-                .settings
-                .single_tokens
-                .contains_key(&chars.chars().next().unwrap());
-
-        let (mut trie_result, mut trie_node) =
-            self.keyword_trie.root.contains(&chars.to_uppercase());
-
-        while !chars.is_empty() {
-            if let TrieResult::Failed = trie_result {
-                break;
-            } else if let TrieResult::Exists = trie_result {
-                word = Some(chars.clone());
-            }
-
-            let end = self.current + size;
-            size += 1;
-
-            if end < self.size {
-                current_char = self.char_at(end)?;
-                is_single_token =
-                    is_single_token || self.settings.single_tokens.contains_key(&current_char);
-                let is_space = current_char.is_whitespace();
-
-                if !is_space || !prev_space {
-                    if is_space {
-                        current_char = ' ';
-                    }
-                    chars.push(current_char);
-                    prev_space = is_space;
-                    skip = false;
-                } else {
-                    skip = true;
-                }
-            } else {
-                current_char = '\0';
-                break;
-            }
-
-            if skip {
-                trie_result = TrieResult::Prefix;
-            } else {
-                (trie_result, trie_node) =
-                    trie_node.contains(&current_char.to_uppercase().collect::<String>());
-            }
+        match self.error.take() {
+            Some(error) => Err(value_error(py, error)),
+            None => Ok(None),
         }
+    }
+}
 
-        if let Some(unwrapped_word) = word {
-            if self.scan_string(&unwrapped_word)? {
-                return Ok(());
-            }
-            if self.scan_comment(&unwrapped_word)? {
-                return Ok(());
-            }
-            if prev_space || is_single_token || current_char == '\0' {
-                self.advance((size - 1) as isize)?;
-                let normalized_word = unwrapped_word.to_uppercase();
-                let keyword_token =
-                    *self
-                        .settings
-                        .keywords
-                        .get(&normalized_word)
-                        .ok_or_else(|| {
-                            self.error(format!("Unexpected keyword '{}'", &normalized_word))
-                        })?;
-                self.add(keyword_token, Some(unwrapped_word))?;
-                return Ok(());
-            }
-        }
+/// The JSON definition accepted by `Tokenizer.reload_json`. The token types can be left out, in
+/// which case the current ones are kept.
+#[cfg(feature = "json")]
+#[derive(serde::Deserialize)]
+struct TokenizerDefinition {
+    settings: sqlglotrs_core::settings::TokenizerSettings,
+    token_types: Option<sqlglotrs_core::settings::TokenTypeSettings>,
+}
 
-        match self.settings.single_tokens.get(&self.current_char) {
-            Some(token_type) => self.add(*token_type, Some(self.current_char.to_string())),
-            None => self.scan_var(),
+#[derive(Debug)]
+#[pyclass]
+pub struct Tokenizer {
+    // Each scan holds on to the config it started with, so a reload only has to swap the pointer
+    // and in-flight calls finish against the old definition
+    config: RwLock<Arc<TokenizerConfig>>,
+}
+
+#[pymethods]
+impl Tokenizer {
+    #[new]
+    pub fn new(settings: TokenizerSettings, token_types: TokenTypeSettings) -> Tokenizer {
+        Tokenizer {
+            config: RwLock::new(Arc::new(TokenizerConfig::new(settings.0, token_types.0))),
         }
     }
 
-    fn scan_comment(&mut self, comment_start: &str) -> Result<bool, TokenizerError> {
-        if !self.settings.comments.contains_key(comment_start) {
-            return Ok(false);
+    /// Like the constructor, but shares the definition, i.e. the settings and the keyword
+    /// automaton built from them, with every other tokenizer created by `cached` or
+    /// `for_dialect` from equal settings. Reloading one of them only changes that one.
+    #[staticmethod]
+    pub fn cached(settings: TokenizerSettings, token_types: TokenTypeSettings) -> Tokenizer {
+        Tokenizer {
+            config: RwLock::new(TokenizerConfig::cached(settings.0, token_types.0)),
         }
+    }
 
-        let comment_start_line = self.line;
-        let comment_start_size = comment_start.len();
-
-        if let Some(comment_end) = self.settings.comments.get(comment_start).unwrap() {
-            // Skip the comment's start delimiter.
-            self.advance(comment_start_size as isize)?;
-
-            let mut comment_count = 1;
-            let comment_end_size = comment_end.len();
+    /// Creates a tokenizer from the preset of a builtin dialect, see
+    /// `TokenizerSettings.for_dialect`, which shares its definition like `cached` does.
+    #[staticmethod]
+    pub fn for_dialect(dialect: &str) -> PyResult<Tokenizer> {
+        Ok(Tokenizer::cached(
+            TokenizerSettings::for_dialect(dialect)?,
+            TokenTypeSettings::for_dialect(dialect)?,
+        ))
+    }
 
-            while !self.is_end {
-                if self.chars(comment_end_size) == *comment_end {
-                    comment_count -= 1;
-                    if comment_count == 0 {
-                        break;
-                    }
-                }
+    /// Serializes the definition, i.e. the settings along with the keyword automaton built from
+    /// them, to bytes that `from_snapshot` loads without rebuilding the automaton, e.g. to skip
+    /// that work in short-lived workers by shipping a snapshot taken at build time.
+    pub fn to_snapshot<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.config().to_snapshot())
+    }
 
-                self.advance(1)?;
+    /// Creates a tokenizer from bytes returned by `to_snapshot`, raising a ValueError if they
+    /// aren't a snapshot this build of sqlglotrs can read.
+    #[staticmethod]
+    pub fn from_snapshot(snapshot: &[u8]) -> PyResult<Tokenizer> {
+        let config = TokenizerConfig::from_snapshot(snapshot)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(Tokenizer {
+            config: RwLock::new(Arc::new(config)),
+        })
+    }
 
-                // Nested comments are allowed by some dialects, e.g. databricks, duckdb, postgres
-                if self.settings.nested_comments
-                    && !self.is_end
-                    && self.chars(comment_start_size) == *comment_start
-                {
-                    self.advance(comment_start_size as isize)?;
-                    comment_count += 1
-                }
-            }
+    /// Atomically replaces the settings and rebuilds the keyword automaton. Calls that are already
+    /// tokenizing keep using the previous definition.
+    pub fn reload(&self, settings: TokenizerSettings, token_types: TokenTypeSettings) {
+        self.swap(TokenizerConfig::new(settings.0, token_types.0));
+    }
 
-            let text = self.text();
-            self.comments
-                .push(text[comment_start_size..text.len() - comment_end_size + 1].to_string());
-            self.advance((comment_end_size - 1) as isize)?;
-        } else {
-            while !self.is_end
-                && self.settings.white_space.get(&self.peek_char) != Some(&self.token_types.break_)
-            {
-                self.advance(1)?;
-            }
-            self.comments
-                .push(self.text()[comment_start_size..].to_string());
+    /// Like `reload`, but reads the new definition from a JSON document of the form
+    /// `{"settings": {...}, "token_types": {...}}`, where `token_types` is optional.
+    pub fn reload_json(&self, json: &str) -> PyResult<()> {
+        #[cfg(feature = "json")]
+        {
+            let definition = serde_json::from_str::<TokenizerDefinition>(json).map_err(|e| {
+                PyValueError::new_err(format!("Invalid tokenizer definition: {}", e))
+            })?;
+            let token_types = match definition.token_types {
+                Some(token_types) => token_types,
+                None => self.config().token_types.clone(),
+            };
+            self.swap(TokenizerConfig::new(definition.settings, token_types));
+            Ok(())
         }
 
-        if comment_start == self.settings.hint_start
-            && self.tokens.last().is_some()
-            && self
-                .settings
-                .tokens_preceding_hint
-                .contains(&self.tokens.last().unwrap().token_type)
+        #[cfg(not(feature = "json"))]
         {
-            self.add(self.token_types.hint, None)?;
+            let _ = json;
+            Err(PyValueError::new_err(
+                "sqlglotrs was built without the `json` feature",
+            ))
         }
+    }
 
-        // Leading comment is attached to the succeeding token, whilst trailing comment to the preceding.
-        // Multiple consecutive comments are preserved by appending them to the current comments list.
-        if Some(comment_start_line) == self.previous_token_line {
-            self.tokens
-                .last_mut()
-                .unwrap()
-                .append_comments(&mut self.comments);
-            self.previous_token_line = Some(self.line);
-        }
+    /// Scans `sql` without holding the GIL, which is only taken back to create the tokens, so
+    /// that other Python threads can run in the meantime. The same goes for the other methods
+    /// that tokenize.
+    pub fn tokenize(
+        &self,
+        py: Python<'_>,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<Token>, Option<TokenizeError>) {
+        let (tokens, error) = py.allow_threads(|| self.tokenize_raw(sql, dialect_settings));
+        (to_py_tokens(py, tokens), error.map(TokenizeError::from))
+    }
 
-        Ok(true)
-    }
-
-    fn scan_string(&mut self, start: &String) -> Result<bool, TokenizerError> {
-        let (base, token_type, end) = if let Some(end) = self.settings.quotes.get(start) {
-            (None, self.token_types.string, end.clone())
-        } else if self.settings.format_strings.contains_key(start) {
-            let (ref end, token_type) = self.settings.format_strings.get(start).unwrap();
-
-            if *token_type == self.token_types.hex_string {
-                (Some(16), *token_type, end.clone())
-            } else if *token_type == self.token_types.bit_string {
-                (Some(2), *token_type, end.clone())
-            } else if *token_type == self.token_types.heredoc_string {
-                self.advance(1)?;
-
-                let tag = if self.current_char.to_string() == *end {
-                    String::new()
-                } else {
-                    self.extract_string(end, false, true, !self.settings.heredoc_tag_is_identifier)?
-                };
-
-                if !tag.is_empty()
-                    && self.settings.heredoc_tag_is_identifier
-                    && (self.is_end || !self.is_identifier(&tag))
-                {
-                    if !self.is_end {
-                        self.advance(-1)?;
-                    }
-
-                    self.advance(-(tag.len() as isize))?;
-                    self.add(self.token_types.heredoc_string_alternative, None)?;
-                    return Ok(true);
-                }
+    /// Decodes `data` like `bytes.decode(encoding, errors)`, see `decode_sql`, and tokenizes it
+    /// like `tokenize`, e.g. for Latin-1 or UTF-16 dumps that would otherwise have to be
+    /// transcoded into a Python str first. Token positions are those of the decoded text.
+    #[pyo3(signature = (data, dialect_settings, encoding = "utf-8", errors = "replace"))]
+    pub fn tokenize_bytes(
+        &self,
+        py: Python<'_>,
+        data: &[u8],
+        dialect_settings: &TokenizerDialectSettings,
+        encoding: &str,
+        errors: &str,
+    ) -> PyResult<(Vec<Token>, Option<TokenizeError>)> {
+        let sql = decode_sql(py, data, encoding, errors)?;
+        let (tokens, error) = py.allow_threads(|| self.tokenize_raw(&sql, dialect_settings));
+        Ok((to_py_tokens(py, tokens), error.map(TokenizeError::from)))
+    }
 
-                (None, *token_type, format!("{}{}{}", start, tag, end))
-            } else {
-                (None, *token_type, end.clone())
-            }
-        } else {
-            return Ok(false);
-        };
+    /// Like `tokenize`, but also returns the counts of the scan as a dict, see `scan_stats_dict`.
+    pub fn tokenize_with_stats<'py>(
+        &self,
+        py: Python<'py>,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> PyResult<(Vec<Token>, Option<TokenizeError>, Bound<'py, PyDict>)> {
+        let (tokens, error, scan_stats) =
+            py.allow_threads(|| self.config().tokenize_with_stats(sql, dialect_settings));
+        Ok((
+            to_py_tokens(py, tokens),
+            error.map(TokenizeError::from),
+            scan_stats_dict(py, &scan_stats)?,
+        ))
+    }
 
-        self.advance(start.len() as isize)?;
-        let text =
-            self.extract_string(&end, false, token_type == self.token_types.raw_string, true)?;
+    /// Like `tokenize`, but also returns the scanner's decisions as (kind, position, detail)
+    /// tuples, see `TraceKind` for the kinds.
+    pub fn tokenize_with_trace(
+        &self,
+        py: Python<'_>,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<Token>, Option<TokenizeError>, Vec<PyTraceStep>) {
+        let (tokens, error, trace) =
+            py.allow_threads(|| self.config().tokenize_with_trace(sql, dialect_settings));
+        let trace = trace
+            .into_iter()
+            .map(|step| (step.kind.as_str(), step.position, step.detail))
+            .collect();
+        (
+            to_py_tokens(py, tokens),
+            error.map(TokenizeError::from),
+            trace,
+        )
+    }
 
-        if let Some(b) = base {
-            if u128::from_str_radix(&text, b).is_err() {
-                return self.error_result(format!(
-                    "Numeric string contains invalid characters from {}:{}",
-                    self.line, self.start
-                ));
-            }
+    /// Scans `sql` lazily, returning an iterator over its tokens that scans a batch at a time, so
+    /// that huge scripts never have all of their tokens in memory at once. A tokenizer error is
+    /// raised as a ValueError once the tokens before it have been yielded.
+    pub fn stream(&self, sql: &str, dialect_settings: &TokenizerDialectSettings) -> TokenStream {
+        let config = self.config();
+        TokenStream {
+            scan: StreamScan::Text(Box::new(BatchScan::new(&config, sql, dialect_settings))),
+            config,
+            dialect_settings: dialect_settings.clone(),
+            ready: VecDeque::new(),
+            error: None,
+            texts: PyTexts::default(),
         }
+    }
 
-        self.add(token_type, Some(text))?;
-        Ok(true)
+    /// Scans the file at `path` lazily like `stream`. Uncompressed files are memory-mapped and
+    /// `.gz` and `.zst` files decompressed, and either is scanned like `stream_reader` scans its
+    /// reader, a chunk at a time, so that SQL dumps never have to be read into a Python str, or
+    /// into memory at all, first. Only the text since the last complete statement is kept.
+    pub fn stream_file(
+        &self,
+        py: Python<'_>,
+        path: PathBuf,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> PyResult<TokenStream> {
+        let config = self.config();
+        let file = py.allow_threads(|| SqlFile::open(&path))?;
+        Ok(TokenStream {
+            scan: StreamScan::Reader(Mutex::new(ReaderScan::new(
+                Box::new(file),
+                READER_CHUNK_SIZE,
+            ))),
+            config,
+            dialect_settings: dialect_settings.clone(),
+            ready: VecDeque::new(),
+            error: None,
+            texts: PyTexts::default(),
+        })
     }
 
-    fn scan_number(&mut self) -> Result<(), TokenizerError> {
-        if self.current_char == '0' {
-            let peek_char = self.peek_char.to_ascii_uppercase();
-            if peek_char == 'B' {
-                if self.settings.has_bit_strings {
-                    self.scan_bits()?;
-                } else {
-                    self.add(self.token_types.number, None)?;
-                }
-                return Ok(());
-            } else if peek_char == 'X' {
-                if self.settings.has_hex_strings {
-                    self.scan_hex()?;
-                } else {
-                    self.add(self.token_types.number, None)?;
-                }
-                return Ok(());
-            }
+    /// Scans the SQL read from `reader`, a file-like object opened in binary or text mode, lazily
+    /// like `stream`, e.g. to tokenize a pipe or a network stream. It's read `chunk_size` bytes
+    /// at a time and only the text since the last complete statement is kept in memory, see
+    /// `ReaderScan`. The reader's errors are raised as they are.
+    #[pyo3(signature = (reader, dialect_settings, chunk_size = READER_CHUNK_SIZE))]
+    pub fn stream_reader(
+        &self,
+        reader: Py<PyAny>,
+        dialect_settings: &TokenizerDialectSettings,
+        chunk_size: usize,
+    ) -> PyResult<TokenStream> {
+        if chunk_size == 0 {
+            return Err(PyValueError::new_err("chunk_size must be positive"));
         }
+        Ok(TokenStream {
+            scan: StreamScan::Reader(Mutex::new(ReaderScan::new(
+                Box::new(PyReader::new(reader)),
+                chunk_size,
+            ))),
+            config: self.config(),
+            dialect_settings: dialect_settings.clone(),
+            ready: VecDeque::new(),
+            error: None,
+            texts: PyTexts::default(),
+        })
+    }
 
-        let mut decimal = false;
-        let mut scientific = 0;
-
-        loop {
-            if self.peek_char.is_ascii_digit() {
-                self.advance(1)?;
-            } else if self.peek_char == '.' && !decimal {
-                if self.tokens.last().map(|t| t.token_type) == Some(self.token_types.parameter) {
-                    return self.add(self.token_types.number, None);
-                }
-                decimal = true;
-                self.advance(1)?;
-            } else if (self.peek_char == '-' || self.peek_char == '+') && scientific == 1 {
-                scientific += 1;
-                self.advance(1)?;
-            } else if self.peek_char.to_ascii_uppercase() == 'E' && scientific == 0 {
-                scientific += 1;
-                self.advance(1)?;
-            } else if self.is_alphabetic_or_underscore(self.peek_char) {
-                let number_text = self.text();
-                let mut literal = String::new();
-
-                while !self.peek_char.is_whitespace()
-                    && !self.is_end
-                    && !self.settings.single_tokens.contains_key(&self.peek_char)
-                {
-                    literal.push(self.peek_char);
-                    self.advance(1)?;
-                }
-
-                let token_type = self
-                    .settings
-                    .keywords
-                    .get(
-                        self.settings
-                            .numeric_literals
-                            .get(&literal.to_uppercase())
-                            .unwrap_or(&String::new()),
-                    )
-                    .copied();
-
-                let replaced = literal.replace("_", "");
-
-                if let Some(unwrapped_token_type) = token_type {
-                    self.add(self.token_types.number, Some(number_text))?;
-                    self.add(self.token_types.dcolon, Some("::".to_string()))?;
-                    self.add(unwrapped_token_type, Some(literal))?;
-                } else if self.dialect_settings.numbers_can_be_underscore_separated
-                    && self.is_numeric(&replaced)
-                {
-                    self.add(self.token_types.number, Some(number_text + &replaced))?;
-                } else if self.dialect_settings.identifiers_can_start_with_digit {
-                    self.add(self.token_types.var, None)?;
-                } else {
-                    self.advance(-(literal.chars().count() as isize))?;
-                    self.add(self.token_types.number, Some(number_text))?;
-                }
-                return Ok(());
-            } else {
-                return self.add(self.token_types.number, None);
-            }
+    /// Scans `sql` lazily like `stream`, but yields lists of `chunk_size` tokens, the last one
+    /// of which may be shorter. Each chunk is scanned without holding the GIL, which is only
+    /// taken to create its tokens, so that consumers can start on the first chunks while other
+    /// Python threads keep running. A tokenizer error is raised as a ValueError once the chunks
+    /// before it have been yielded.
+    #[pyo3(signature = (sql, dialect_settings, chunk_size = STREAM_BATCH_SIZE))]
+    pub fn iter_tokens(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+        chunk_size: usize,
+    ) -> PyResult<TokenChunks> {
+        if chunk_size == 0 {
+            return Err(PyValueError::new_err("chunk_size must be positive"));
         }
+        let config = self.config();
+        Ok(TokenChunks {
+            scan: BatchScan::new(&config, sql, dialect_settings),
+            config,
+            dialect_settings: dialect_settings.clone(),
+            chunk_size,
+            ready: VecDeque::new(),
+            error: None,
+            done: false,
+            texts: PyTexts::default(),
+        })
     }
 
-    fn scan_bits(&mut self) -> Result<(), TokenizerError> {
-        self.scan_radix_string(2, self.token_types.bit_string)
+    /// Scans `sql` like `tokenize`, but never fails, e.g. for editors tokenizing incomplete SQL as
+    /// it's typed. Anything that can't be scanned, such as an unterminated string, becomes an
+    /// `ERROR` token running up to the next whitespace or delimiter, and scanning resumes there.
+    /// Only going past one of the dialect settings' limits raises a ValueError.
+    pub fn tokenize_lenient(
+        &self,
+        py: Python<'_>,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> PyResult<Vec<Token>> {
+        let (tokens, error) =
+            py.allow_threads(|| self.config().tokenize_lenient_raw(sql, dialect_settings));
+        match error {
+            Some(error) => Err(value_error(py, error)),
+            None => Ok(to_py_tokens(py, tokens)),
+        }
     }
 
-    fn scan_hex(&mut self) -> Result<(), TokenizerError> {
-        self.scan_radix_string(16, self.token_types.hex_string)
+    /// Scans `sql` like `tokenize`, but only returns word keywords such as `SELECT` or `QUALIFY`,
+    /// along with semicolons so that they can be attributed to statements. The text of all other
+    /// tokens is never materialized, which makes this considerably cheaper for indexing.
+    pub fn tokenize_keywords(
+        &self,
+        py: Python<'_>,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<Token>, Option<TokenizeError>) {
+        let (tokens, error) =
+            py.allow_threads(|| self.config().tokenize_keywords_raw(sql, dialect_settings));
+        (to_py_tokens(py, tokens), error.map(TokenizeError::from))
     }
 
-    fn scan_radix_string(
-        &mut self,
-        radix: u32,
-        radix_token_type: TokenType,
-    ) -> Result<(), TokenizerError> {
-        self.advance(1)?;
-        let value = self.extract_value()?[2..].to_string();
+    /// Scans `sql` like `tokenize`, but also keeps what the tokens leave out, so that the query
+    /// can be written back exactly, e.g. by lossless rewriting tools. Each token's
+    /// `leading_trivia` holds the whitespace and comments before it and its `source_text` the
+    /// token as written, and the text after the last token is returned alongside the tokens, so
+    /// that joining all of them in order gives back `sql`.
+    pub fn tokenize_trivia(
+        &self,
+        py: Python<'_>,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<Token>, String, Option<TokenizeError>) {
+        let config = self.config();
+        let source = Source::new(sql);
+        let (tokens, trivia, trailing, error) = py.allow_threads(|| {
+            let (tokens, error) = config.tokenize_raw(sql, dialect_settings);
+            let (trivia, trailing) = split_trivia(&source, &tokens, &config.token_types);
+            (tokens, trivia, trailing, error)
+        });
+
+        let mut texts = PyTexts::default();
+        let tokens = tokens
+            .into_iter()
+            .zip(trivia)
+            .map(|(token, trivia)| {
+                let mut token = Token::new(py, token, &mut texts);
+                token.leading_trivia =
+                    Some(PyString::new(py, source.text(trivia.leading)).unbind());
+                token.source_text = Some(PyString::new(py, source.text(trivia.source)).unbind());
+                token
+            })
+            .collect();
+        (
+            tokens,
+            source.text(trailing).to_string(),
+            error.map(TokenizeError::from),
+        )
+    }
 
-        // Validate if the string consists only of valid hex digits
-        if value.chars().all(|c| c.is_digit(radix)) {
-            self.add(radix_token_type, Some(value))
-        } else {
-            self.add(self.token_types.identifier, None)
+    /// Rescans `sql` after replacing its chars `start..end` with `new_text`, e.g. on every
+    /// keystroke in an editor, given the `tokens` that tokenizing `sql` returned. Only the part
+    /// of the query around the edit is scanned again: the tokens before it are returned as they
+    /// are, and those after it as copies moved to their new position. Returns the tokens of the
+    /// edited query along with the error its scan stopped at, if any.
+    #[allow(clippy::too_many_arguments)]
+    pub fn retokenize(
+        &self,
+        py: Python<'_>,
+        sql: &str,
+        tokens: Vec<Bound<'_, Token>>,
+        start: usize,
+        end: usize,
+        new_text: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> PyResult<(Vec<Py<Token>>, Option<TokenizeError>)> {
+        let byte_offset = |char: usize| {
+            sql.char_indices()
+                .map(|(i, _)| i)
+                .chain([sql.len()])
+                .nth(char)
+        };
+        let (Some(start_byte), Some(end_byte)) = (byte_offset(start), byte_offset(end)) else {
+            return Err(PyValueError::new_err(format!(
+                "Edit {}..{} is out of bounds",
+                start, end
+            )));
+        };
+        if start > end {
+            return Err(PyValueError::new_err(format!(
+                "Edit {}..{} ends before it starts",
+                start, end
+            )));
         }
-    }
+        let edited = [&sql[..start_byte], new_text, &sql[end_byte..]].concat();
 
-    fn scan_var(&mut self) -> Result<(), TokenizerError> {
-        loop {
-            let peek_char = if !self.peek_char.is_whitespace() {
-                self.peek_char
-            } else {
-                '\0'
-            };
-            if peek_char != '\0'
-                && (self.settings.var_single_tokens.contains(&peek_char)
-                    || !self.settings.single_tokens.contains_key(&peek_char))
-            {
-                self.advance(1)?;
-            } else {
-                break;
+        let retokenized = self.config().retokenize_raw(
+            &edited,
+            &PreviousPyTokens(&tokens),
+            start..end,
+            new_text.chars().count(),
+            dialect_settings,
+        );
+
+        let mut result: Vec<Py<Token>> = tokens[..retokenized.kept]
+            .iter()
+            .map(|token| token.clone().unbind())
+            .collect();
+        let mut texts = PyTexts::default();
+        for token in retokenized.tokens {
+            result.push(Py::new(py, Token::new(py, token, &mut texts))?);
+        }
+        if let Some((resumed, shift)) = retokenized.resumed {
+            for token in &tokens[resumed..] {
+                result.push(Py::new(py, token.borrow().shifted(py, &shift)?)?);
             }
         }
+        Ok((result, retokenized.error.map(TokenizeError::from)))
+    }
 
-        let token_type =
-            if self.tokens.last().map(|t| t.token_type) == Some(self.token_types.parameter) {
-                self.token_types.var
-            } else {
-                self.settings
-                    .keywords
-                    .get(&self.text().to_uppercase())
-                    .copied()
-                    .unwrap_or(self.token_types.var)
-            };
-        self.add(token_type, None)
-    }
-
-    fn scan_identifier(&mut self, identifier_end: &str) -> Result<(), TokenizerError> {
-        self.advance(1)?;
-        let text = self.extract_string(identifier_end, true, false, true)?;
-        self.add(self.token_types.identifier, Some(text))
-    }
-
-    fn extract_string(
-        &mut self,
-        delimiter: &str,
-        use_identifier_escapes: bool,
-        raw_string: bool,
-        raise_unmatched: bool,
-    ) -> Result<String, TokenizerError> {
-        let mut text = String::new();
-        let mut combined_identifier_escapes = None;
-        if use_identifier_escapes {
-            let mut tmp = self.settings.identifier_escapes.clone();
-            tmp.extend(delimiter.chars());
-            combined_identifier_escapes = Some(tmp);
-        }
-        let escapes = match combined_identifier_escapes {
-            Some(ref v) => v,
-            None => &self.settings.string_escapes,
-        };
+    /// Scans `sql` and returns its tokens as a JSON array of objects with their `type`, `text`,
+    /// `line`, `col`, `start`, `end` and `comments`, e.g. to hand them to other services without
+    /// creating any Python objects. Types are written by name if `token_type_names`, which is
+    /// indexed by token type, is given, and by index otherwise. Raises a ValueError if the scan
+    /// fails.
+    #[pyo3(signature = (sql, dialect_settings, token_type_names = None))]
+    pub fn tokenize_to_json(
+        &self,
+        py: Python<'_>,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+        token_type_names: Option<Vec<PyBackedStr>>,
+    ) -> PyResult<String> {
+        py.allow_threads(|| {
+            let tokens = self.config().try_tokenize(sql, dialect_settings)?;
+            Ok(tokens_to_json(&tokens, token_type_names.as_deref()))
+        })
+    }
+
+    /// Scans `sql` and returns its tokens as Arrow columns, which `pyarrow.record_batch` and
+    /// other Arrow libraries import without copying, e.g. for analytics over many queries. Types
+    /// are named as in `tokenize_to_json`. Raises a ValueError if the scan fails.
+    #[pyo3(signature = (sql, dialect_settings, token_type_names = None))]
+    pub fn tokenize_to_arrow(
+        &self,
+        py: Python<'_>,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+        token_type_names: Option<Vec<PyBackedStr>>,
+    ) -> PyResult<ArrowTokens> {
+        py.allow_threads(|| {
+            let tokens = self.config().try_tokenize(sql, dialect_settings)?;
+            ArrowTokens::new(&tokens, token_type_names.as_deref())
+        })
+    }
 
-        loop {
-            if !raw_string
-                && !self.dialect_settings.unescaped_sequences.is_empty()
-                && !self.peek_char.is_whitespace()
-                && self.settings.string_escapes.contains(&self.current_char)
+    /// Tokenizes each of `sqls` like `tokenize`, returning a (tokens, error) pair for each. The
+    /// queries are scanned in parallel unless the extension was built without the `parallel`
+    /// feature, e.g. to normalize a warehouse's worth of queries at once.
+    pub fn tokenize_many(
+        &self,
+        py: Python<'_>,
+        sqls: Vec<PyBackedStr>,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> Vec<(Vec<Token>, Option<TokenizeError>)> {
+        let config = self.config();
+        let scan = |sql: &PyBackedStr| config.tokenize_raw(sql, dialect_settings);
+        let results: Vec<_> = py.allow_threads(|| {
+            #[cfg(feature = "parallel")]
             {
-                let sequence_key = format!("{}{}", self.current_char, self.peek_char);
-                if let Some(unescaped_sequence) =
-                    self.dialect_settings.unescaped_sequences.get(&sequence_key)
-                {
-                    self.advance(2)?;
-                    text.push_str(unescaped_sequence);
-                    continue;
-                }
+                use rayon::prelude::*;
+                sqls.par_iter().map(scan).collect()
             }
 
-            if (self.settings.string_escapes_allowed_in_raw_strings || !raw_string)
-                && escapes.contains(&self.current_char)
-                && (self.current_char == self.peek_char
-                    || !self
-                        .settings
-                        .quotes
-                        .contains_key(&self.current_char.to_string()))
+            #[cfg(not(feature = "parallel"))]
             {
-                let peek_char_str = self.peek_char.to_string();
-                let equal_delimiter = delimiter == peek_char_str;
-                if equal_delimiter || escapes.contains(&self.peek_char) {
-                    if equal_delimiter {
-                        text.push(self.peek_char);
-                    } else {
-                        text.push(self.current_char);
-                        text.push(self.peek_char);
-                    }
-                    if self.current + 1 < self.size {
-                        self.advance(2)?;
-                    } else {
-                        return self.error_result(format!(
-                            "Missing {} from {}:{}",
-                            delimiter, self.line, self.current
-                        ));
-                    }
-                    continue;
-                }
-            }
-            if self.chars(delimiter.len()) == delimiter {
-                if delimiter.len() > 1 {
-                    self.advance((delimiter.len() - 1) as isize)?;
-                }
-                break;
+                sqls.iter().map(scan).collect()
             }
-            if self.is_end {
-                if !raise_unmatched {
-                    text.push(self.current_char);
-                    return Ok(text);
-                }
+        });
+        results
+            .into_iter()
+            .map(|(tokens, error)| (to_py_tokens(py, tokens), error.map(TokenizeError::from)))
+            .collect()
+    }
 
-                return self.error_result(format!(
-                    "Missing {} from {}:{}",
-                    delimiter, self.line, self.current
-                ));
-            }
+    /// Returns the keywords within `max_distance` edits of `text`, i.e. insertions, deletions or
+    /// substitutions of a char, closest first, e.g. to suggest `GROUP BY` for `GROUP BT`.
+    pub fn suggest_keyword(&self, text: &str, max_distance: usize) -> Vec<String> {
+        self.config().suggest_keyword(text, max_distance)
+    }
 
-            let current = self.current - 1;
-            self.advance(1)?;
-            text.push_str(
-                &self.sql[current..self.current - 1]
-                    .iter()
-                    .collect::<String>(),
-            );
+    /// Runs the scanner once over every keyword, quote, format string and comment delimiter, so
+    /// that latency-sensitive callers pay the first-scan costs at startup. The keyword automaton
+    /// and settings tables themselves are already built eagerly by the constructor.
+    #[pyo3(signature = (dialect_settings = None))]
+    pub fn warm_up(&self, dialect_settings: Option<&TokenizerDialectSettings>) {
+        let default_settings = TokenizerDialectSettings::default();
+        let dialect_settings = dialect_settings.unwrap_or(&default_settings);
+        let config = self.config();
+        let settings = &config.settings;
+
+        let mut samples = vec!["SELECT a, 1.5e3 FROM t WHERE b = c;".to_string()];
+        samples.extend(settings.keywords.keys().cloned());
+        for (start, end) in settings.quotes.iter() {
+            samples.push(format!("{}0{}", start, end));
+        }
+        for (start, (end, _)) in settings.format_strings.iter() {
+            samples.push(format!("{}0{}", start, end));
+        }
+        for (start, end) in settings.comments.iter() {
+            samples.push(format!("{}x{}", start, end.as_deref().unwrap_or("\n")));
         }
-        Ok(text)
-    }
 
-    fn is_alphabetic_or_underscore(&self, name: char) -> bool {
-        name.is_alphabetic() || name == '_'
+        // Errors are irrelevant here, e.g. heredoc delimiters don't form a complete string
+        for sample in &samples {
+            let _ = self.tokenize_raw(sample, dialect_settings);
+        }
     }
+}
 
-    fn is_identifier(&self, s: &str) -> bool {
-        s.chars().enumerate().all(|(i, c)| {
-            if i == 0 {
-                self.is_alphabetic_or_underscore(c)
-            } else {
-                self.is_alphabetic_or_underscore(c) || c.is_ascii_digit()
-            }
-        })
+/// Python tokens as `retokenize_raw` reads them, which only converts the few around the edit.
+struct PreviousPyTokens<'a, 'py>(&'a [Bound<'py, Token>]);
+
+impl PreviousTokens for PreviousPyTokens<'_, '_> {
+    fn len(&self) -> usize {
+        self.0.len()
     }
 
-    fn is_numeric(&self, s: &str) -> bool {
-        s.chars().all(|c| c.is_ascii_digit())
+    fn span(&self, i: usize) -> (usize, usize) {
+        let token = self.0[i].borrow();
+        (token.start, token.end)
     }
 
-    fn extract_value(&mut self) -> Result<String, TokenizerError> {
-        loop {
-            if !self.peek_char.is_whitespace()
-                && !self.is_end
-                && !self.settings.single_tokens.contains_key(&self.peek_char)
-            {
-                self.advance(1)?;
-            } else {
-                break;
-            }
+    fn get(&self, i: usize) -> RawToken {
+        let token = self.0[i].borrow();
+        RawToken {
+            text: token.text.bind(self.0[i].py()).to_string_lossy().into(),
+            ..token.position()
         }
-        Ok(self.text())
     }
+}
 
-    fn error(&self, message: String) -> TokenizerError {
-        let start = max((self.current as isize) - 50, 0);
-        let end = min(self.current + 50, self.size - 1);
-        let context = self.sql[start as usize..end].iter().collect::<String>();
-        TokenizerError { message, context }
+fn to_py_tokens(py: Python<'_>, tokens: Vec<RawToken>) -> Vec<Token> {
+    let mut texts = PyTexts::default();
+    tokens
+        .into_iter()
+        .map(|token| Token::new(py, token, &mut texts))
+        .collect()
+}
+
+impl Tokenizer {
+    /// Tokenizes `sql` without creating any Python objects. On failure, the tokens scanned up to
+    /// the error are returned alongside it.
+    pub fn tokenize_raw(
+        &self,
+        sql: &str,
+        dialect_settings: &TokenizerDialectSettings,
+    ) -> (Vec<RawToken>, Option<TokenizerError>) {
+        self.config().tokenize_raw(sql, dialect_settings)
     }
 
-    fn error_result<T>(&self, message: String) -> Result<T, TokenizerError> {
-        Err(self.error(message))
+    pub(crate) fn config(&self) -> Arc<TokenizerConfig> {
+        // The lock only guards a pointer swap, so a poisoned lock still holds a valid config
+        self.config
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn swap(&self, config: TokenizerConfig) {
+        let config = Arc::new(config);
+        let previous = std::mem::replace(
+            &mut *self.config.write().unwrap_or_else(|e| e.into_inner()),
+            config,
+        );
+        // Release the lock before the old config, which may be freed here, is dropped
+        drop(previous);
     }
 }
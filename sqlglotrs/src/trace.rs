@@ -0,0 +1,141 @@
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+
+/// Routes the tokenizer's tracing events to the `sqlglotrs` Python logger at the given level
+/// (one of "TRACE", "DEBUG", "INFO", "WARN", "ERROR" or "OFF").
+#[pyfunction]
+#[pyo3(signature = (level = "DEBUG"))]
+pub fn enable_tracing(py: Python<'_>, level: &str) -> PyResult<()> {
+    #[cfg(feature = "tracing")]
+    {
+        python_logging::enable(py, level)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    {
+        let _ = (py, level);
+        Err(PyRuntimeError::new_err(
+            "sqlglotrs was built without the `tracing` feature",
+        ))
+    }
+}
+
+/// Stops forwarding tracing events to Python logging.
+#[pyfunction]
+pub fn disable_tracing(py: Python<'_>) -> PyResult<()> {
+    enable_tracing(py, "OFF")
+}
+
+#[cfg(feature = "tracing")]
+mod python_logging {
+    use super::PyRuntimeError;
+    use pyo3::prelude::*;
+    use std::fmt::{self, Write};
+    use std::str::FromStr;
+    use std::sync::OnceLock;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing::{Event, Level, Subscriber};
+    use tracing_subscriber::filter::LevelFilter;
+    use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+    use tracing_subscriber::registry::{LookupSpan, Registry};
+    use tracing_subscriber::reload;
+
+    static FILTER: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+
+    pub fn enable(py: Python<'_>, level: &str) -> PyResult<()> {
+        let filter = LevelFilter::from_str(level)
+            .map_err(|_| PyRuntimeError::new_err(format!("Invalid tracing level '{}'", level)))?;
+
+        if let Some(handle) = FILTER.get() {
+            return handle
+                .reload(filter)
+                .map_err(|e| PyRuntimeError::new_err(e.to_string()));
+        }
+
+        let logger = py
+            .import("logging")?
+            .call_method1("getLogger", ("sqlglotrs",))?
+            .unbind();
+        let (filter, handle) = reload::Layer::new(filter);
+        let subscriber = Registry::default()
+            .with(filter)
+            .with(PythonLoggingLayer { logger });
+
+        tracing::subscriber::set_global_default(subscriber)
+            .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+        let _ = FILTER.set(handle);
+        Ok(())
+    }
+
+    struct PythonLoggingLayer {
+        logger: PyObject,
+    }
+
+    impl<S> Layer<S> for PythonLoggingLayer
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let mut fields = Fields::default();
+            attrs.record(&mut fields);
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(fields);
+            }
+        }
+
+        fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+            let mut message = String::new();
+
+            if let Some(scope) = ctx.event_scope(event) {
+                for span in scope.from_root() {
+                    message.push_str(span.name());
+                    if let Some(fields) = span.extensions().get::<Fields>() {
+                        if !fields.text.is_empty() {
+                            let _ = write!(message, "{{{}}}", fields.text);
+                        }
+                    }
+                    message.push_str(": ");
+                }
+            }
+
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            message.push_str(&fields.message);
+            if !fields.text.is_empty() {
+                let _ = write!(message, " {}", fields.text);
+            }
+
+            let level = match *event.metadata().level() {
+                Level::TRACE => 5,
+                Level::DEBUG => 10,
+                Level::INFO => 20,
+                Level::WARN => 30,
+                Level::ERROR => 40,
+            };
+
+            Python::with_gil(|py| {
+                let _ = self.logger.call_method1(py, "log", (level, message));
+            });
+        }
+    }
+
+    #[derive(Default)]
+    struct Fields {
+        message: String,
+        text: String,
+    }
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "message" {
+                let _ = write!(self.message, "{:?}", value);
+            } else {
+                if !self.text.is_empty() {
+                    self.text.push(' ');
+                }
+                let _ = write!(self.text, "{}={:?}", field.name(), value);
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::token::RawToken;
+use sqlglotrs_core::settings::TokenTypeSettings;
+use sqlglotrs_core::source::Source;
+use sqlglotrs_core::words::Words;
+use std::ops::Range;
+
+/// Where a token and the trivia before it, i.e. the whitespace and comments since the previous
+/// token, were written in the query, as half-open ranges of chars.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TokenTrivia {
+    pub leading: Range<usize>,
+    pub source: Range<usize>,
+}
+
+/// Splits the query behind a token stream into the trivia before each token, the token as written
+/// and whatever follows the last token, which is the trailing trivia unless the scan failed. The
+/// pieces add up to the whole query.
+pub fn split_trivia(
+    source: &Source,
+    tokens: &[RawToken],
+    token_types: &TokenTypeSettings,
+) -> (Vec<TokenTrivia>, Range<usize>) {
+    let words = Words::new(source, tokens, token_types);
+    let mut position = 0;
+    let mut trivia = Vec::with_capacity(words.len());
+    for (i, token) in tokens.iter().enumerate() {
+        // The rest of a command has an empty span after its text, so it's found from the command
+        let span = match words.command_start(i) {
+            Some(start) => start..start + token.text.chars().count(),
+            None => token.start..token.end + 1,
+        };
+        trivia.push(TokenTrivia {
+            leading: position..span.start,
+            source: span.clone(),
+        });
+        position = span.end;
+    }
+    (trivia, position..source.char_count())
+}
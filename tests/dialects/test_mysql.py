@@ -1,7 +1,7 @@
 import unittest
 import sys
 
-from sqlglot import UnsupportedError, expressions as exp
+from sqlglot import UnsupportedError, expressions as exp, parse_one
 from sqlglot.dialects.mysql import MySQL
 from tests.dialects.test_dialect import Validator
 
@@ -1443,3 +1443,22 @@ COMMENT='客户账户表'"""
         self.validate_identity("ANALYZE tbl UPDATE HISTOGRAM ON col1 WITH 5 BUCKETS AUTO UPDATE")
         self.validate_identity("ANALYZE tbl UPDATE HISTOGRAM ON col1 WITH 5 BUCKETS MANUAL UPDATE")
         self.validate_identity("ANALYZE tbl DROP HISTOGRAM ON col1")
+
+    def test_versioned_comments(self):
+        sql = "SELECT a /*!50700 , b */ /*!80000 , c */ FROM t"
+
+        self.assertEqual(
+            self.parse_one(sql).sql(dialect="mysql"),
+            "SELECT a /* !50700 , b */ /* !80000 , c */ FROM t",
+        )
+
+        for settings, expected in (
+            ("execute_versioned_comments", "SELECT a, b, c FROM t"),
+            ("execute_versioned_comments, version = 5.7", "SELECT a, b /* !80000 , c */ FROM t"),
+        ):
+            with self.subTest(settings=settings):
+                expression = parse_one(sql, read=f"mysql, {settings}")
+                self.assertEqual(expression.sql(dialect="mysql"), expected)
+
+        tokens = MySQL(execute_versioned_comments=True).tokenize("/*! SET NAMES utf8 */;")
+        self.assertEqual([token.text for token in tokens], ["SET", "NAMES", "utf8", ";"])
@@ -0,0 +1,92 @@
+import unittest
+from unittest import mock
+
+from sqlglot import serde, tokens
+from sqlglot.errors import ParseError
+from sqlglot.dialects import dialect as dialect_module
+from sqlglot.dialects.dialect import Dialect
+
+# Queries sqlglotrs' parser handles, which have to parse to the same trees as with sqlglot's
+SUPPORTED = [
+    "SELECT 1",
+    "SELECT x FROM t LIMIT 1 OFFSET 2",
+    "SELECT DISTINCT a AS b, c d FROM db.t AS u",
+    "SELECT t.*, *, c.d.e.f FROM cat.db.t",
+    "SELECT a FROM t WHERE a = 1 AND NOT b OR c IS NOT NULL AND d IS TRUE",
+    "SELECT a / b, -c, 'x', (1 + 2) * 3, a % 2, a - b - c FROM t",
+    "SELECT a FROM t WHERE a BETWEEN 1 AND 2 AND b NOT IN (1, 2) AND c LIKE 'x%'",
+    "SELECT a FROM t WHERE d NOT ILIKE 'y' AND e <> 1 AND f != 2 AND g >= 3 AND h < 4",
+    "SELECT a, COUNT FROM t GROUP BY a, b HAVING a > 1",
+    "SELECT a FROM t ORDER BY a DESC NULLS FIRST, b, c ASC NULLS LAST LIMIT 10",
+    "select a from t group by a order by a limit 5",
+    'SELECT "A" FROM "T" AS x',
+]
+
+# Queries it leaves to sqlglot's parser
+UNSUPPORTED = [
+    "SELECT 1; SELECT 2",
+    "SELECT a FROM t JOIN u ON t.id = u.id",
+    "SELECT COUNT(*) FROM t",
+    "SELECT a FROM t -- comment",
+    "SELECT a FROM t WHERE a LIKE 'x' ESCAPE '!'",
+    "SELECT a FROM (SELECT 1) AS t",
+    "INSERT INTO t VALUES (1)",
+]
+
+# Dialects that take the Rust parser for all of SUPPORTED, while others may leave some of it to
+# sqlglot's, e.g. those where "A" is a string
+RS_DIALECTS = ["", "postgres", "snowflake", "duckdb", "trino"]
+
+
+@unittest.skipUnless(
+    hasattr(dialect_module, "rs_parse_select") and tokens.USE_RS_TOKENIZER,
+    "sqlglotrs is not installed",
+)
+class TestRsParser(unittest.TestCase):
+    """Tests that parsing with `SQLGLOTRS_PARSER=1` gives the trees it gives with `=0`."""
+
+    def parse(self, dialect, sql, use_rs_parser):
+        with mock.patch.object(dialect_module, "USE_RS_PARSER", use_rs_parser):
+            return dialect.parse(sql)
+
+    def assert_same_trees(self, dialect, sql):
+        try:
+            expected = self.parse(dialect, sql, False)
+        except ParseError as e:
+            # Queries a dialect can't parse, e.g. Drill's `SELECT "A"`, fail the same way
+            with self.assertRaises(ParseError) as raised:
+                self.parse(dialect, sql, True)
+            self.assertEqual(str(raised.exception), str(e))
+            return
+        actual = self.parse(dialect, sql, True)
+        self.assertEqual(actual, expected)
+        self.assertEqual(repr(actual), repr(expected))
+        self.assertEqual(serde.dump(actual), serde.dump(expected))
+
+    def dialects(self):
+        # Every dialect that opts into the Rust parser has to parse the same with it
+        for name, dialect_class in Dialect.classes.items():
+            if dialect_class.SUPPORTS_RS_PARSER:
+                yield name, dialect_class()
+
+    def test_supported(self):
+        for name, dialect in self.dialects():
+            for sql in SUPPORTED:
+                with self.subTest(dialect=name, sql=sql):
+                    if name in RS_DIALECTS:
+                        self.assertIsNotNone(dialect._parse_rs(sql))
+                    self.assert_same_trees(dialect, sql)
+
+    def test_unsupported(self):
+        for name, dialect in self.dialects():
+            for sql in UNSUPPORTED:
+                with self.subTest(dialect=name, sql=sql):
+                    self.assertIsNone(dialect._parse_rs(sql))
+                    self.assert_same_trees(dialect, sql)
+
+    def test_dialect_semantics(self):
+        # NULL ordering and division carry the dialect's settings
+        for name in ["", "mysql", "bigquery", "duckdb", "snowflake"]:
+            dialect = Dialect.get_or_raise(name)
+            with self.subTest(dialect=name):
+                self.assert_same_trees(dialect, "SELECT a / b FROM t ORDER BY a, b DESC")
@@ -0,0 +1,1178 @@
+import unittest
+from concurrent.futures import ThreadPoolExecutor
+
+from sqlglot import tokens
+from sqlglot.dialects.dialect import Dialect
+from sqlglot.tokens import Tokenizer, TokenType
+
+try:
+    import sqlglotrs  # type: ignore
+except ImportError:
+    sqlglotrs = None
+
+
+@unittest.skipUnless(tokens.USE_RS_TOKENIZER, "sqlglotrs is not installed")
+class TestSqlglotrs(unittest.TestCase):
+    """Tests of the helpers that sqlglotrs builds on its tokenizer, which have no Python
+    counterpart, so they're called with the Rust tokenizer of a dialect."""
+
+    def rs_tokenizer(self, dialect=""):
+        """The Rust tokenizer of a dialect and its dialect settings."""
+        tokenizer = Dialect.get_or_raise(dialect).tokenizer()
+        return tokenizer._RS_TOKENIZER, tokenizer._rs_dialect_settings
+
+    def call(self, function, sql, *args, dialect="", **kwargs):
+        tokenizer, settings = self.rs_tokenizer(dialect)
+        return function(tokenizer, sql, settings, *args, **kwargs)
+
+    def tokenize(self, sql, dialect=""):
+        tokenizer, settings = self.rs_tokenizer(dialect)
+        result, error = tokenizer.tokenize(sql, settings)
+        self.assertIsNone(error)
+        return result
+
+    def test_convert_placeholders(self):
+        convert = sqlglotrs.convert_placeholders
+
+        # Strings and comments are left alone
+        self.assertEqual(
+            self.call(convert, "SELECT * FROM t WHERE a = ? AND b = '?' -- ?", "qmark", "dollar"),
+            ("SELECT * FROM t WHERE a = $1 AND b = '?' -- ?", ["1"]),
+        )
+        # Repeated parameters share a number or name where the target style can express that
+        self.assertEqual(
+            self.call(convert, "SELECT $2, $1, $2", "dollar", "numeric", dialect="postgres"),
+            ("SELECT :1, :2, :1", ["2", "1"]),
+        )
+        self.assertEqual(
+            self.call(convert, "SELECT $2, $1, $2", "dollar", "qmark", dialect="postgres"),
+            ("SELECT ?, ?, ?", ["2", "1", "2"]),
+        )
+        self.assertEqual(
+            self.call(convert, "SELECT :a, :b, :a", "named", "pyformat"),
+            ("SELECT %(a)s, %(b)s, %(a)s", ["a", "b"]),
+        )
+        self.assertEqual(
+            self.call(convert, "SELECT %s, %s", "format", "named"),
+            ("SELECT :p1, :p2", ["1", "2"]),
+        )
+        # System variables aren't parameters
+        self.assertEqual(
+            self.call(convert, "SELECT @a, @@version", "at", "named", dialect="tsql"),
+            ("SELECT :a, @@version", ["a"]),
+        )
+
+        with self.assertRaisesRegex(ValueError, "Unknown placeholder style 'colon'"):
+            self.call(convert, "SELECT ?", "qmark", "colon")
+
+    def test_normalize_literals(self):
+        normalize = sqlglotrs.normalize_literals
+
+        def texts(sql, dialect):
+            return [token.text for token in self.tokenize(sql, dialect)]
+
+        for dialect, sql, expected in (
+            ("", "SELECT 'it''s', \"a\"", "SELECT 'it''s', \"a\""),
+            ("postgres", "SELECT 'a\\b', E'c'", "SELECT 'a\\b', E'c'"),
+            (
+                "mysql",
+                "SELECT \"it's\", 'a\\\\b', 'x\\ny\\tz', 'q\\'s'",
+                "SELECT 'it''s', 'a\\\\b', 'x\\ny\\tz', 'q''s'",
+            ),
+            # BigQuery only escapes quotes with backslashes
+            (
+                "bigquery",
+                "SELECT \"it's\", '''a\nb''', 'c\\\\'",
+                "SELECT 'it\\'s', 'a\\nb', 'c\\\\'",
+            ),
+        ):
+            with self.subTest(dialect=dialect, sql=sql):
+                normalized = self.call(normalize, sql, dialect=dialect)
+                self.assertEqual(normalized, expected)
+                # The literals still mean the same
+                self.assertEqual(texts(normalized, dialect), texts(sql, dialect))
+
+        self.assertEqual(
+            self.call(normalize, "SELECT 'it''s'", '"', "\\", dialect="mysql"),
+            'SELECT "it\'s"',
+        )
+        self.assertEqual(
+            self.call(normalize, "SELECT \"it's\"", escape="\\", dialect="mysql"),
+            "SELECT 'it\\'s'",
+        )
+
+        # Double quotes delimit identifiers in Postgres
+        with self.assertRaisesRegex(ValueError, "Strings aren't quoted with \""):
+            self.call(normalize, "SELECT 'x'", '"', dialect="postgres")
+        with self.assertRaisesRegex(ValueError, "Strings aren't escaped with \\\\"):
+            self.call(normalize, "SELECT 'x'", escape="\\", dialect="postgres")
+        with self.assertRaisesRegex(ValueError, "Strings aren't escaped with '"):
+            self.call(normalize, "SELECT 'x'", escape="'", dialect="bigquery")
+        with self.assertRaisesRegex(ValueError, "Escape must be the target quote or a backslash"):
+            self.call(normalize, "SELECT 'x'", escape='"', dialect="mysql")
+
+    def test_convert_identifier_quotes(self):
+        convert = sqlglotrs.convert_identifier_quotes
+
+        # Strings and comments are left alone, and embedded quotes are escaped for the new quote
+        self.assertEqual(
+            self.call(
+                convert, "SELECT `a``b`, `c\"d`, '`x`' FROM `t` -- `c`", "`", '"', dialect="mysql"
+            ),
+            "SELECT \"a`b\", \"c\"\"d\", '`x`' FROM \"t\" -- `c`",
+        )
+        self.assertEqual(
+            self.call(convert, 'SELECT [a]]b], "c" FROM [t]', "[", "`", dialect="tsql"),
+            'SELECT `a]b`, "c" FROM `t`',
+        )
+        self.assertEqual(
+            self.call(convert, 'SELECT "a""b", x', '"', "[", dialect="postgres"),
+            'SELECT [a"b], x',
+        )
+
+        with self.assertRaisesRegex(ValueError, "Invalid char"):
+            self.call(convert, "SELECT `a`", "`", "``", dialect="mysql")
+
+    def test_tokenize_keywords(self):
+        tokenizer, settings = self.rs_tokenizer()
+
+        keywords, error = tokenizer.tokenize_keywords(
+            "SELECT a, 'FROM' AS \"WHERE\", group_id FROM t /* WHERE */ GROUP  BY 1; select 2",
+            settings,
+        )
+        self.assertIsNone(error)
+        self.assertEqual(
+            [
+                (TokenType.from_int(token.token_type_index), token.text, token.start, token.end)
+                for token in keywords
+            ],
+            [
+                (TokenType.SELECT, "SELECT", 0, 5),
+                (TokenType.ALIAS, "AS", 17, 18),
+                (TokenType.FROM, "FROM", 38, 41),
+                (TokenType.GROUP_BY, "GROUP BY", 57, 65),
+                (TokenType.SEMICOLON, ";", 68, 68),
+                (TokenType.SELECT, "select", 70, 75),
+            ],
+        )
+
+        keywords, error = tokenizer.tokenize_keywords("SELECT 'abc", settings)
+        self.assertEqual([token.text for token in keywords], ["SELECT"])
+        self.assertIn("Missing '", str(error))
+
+    def test_extract_tables(self):
+        extract = sqlglotrs.extract_tables
+
+        self.assertEqual(
+            self.call(extract, "WITH c AS (SELECT 1) SELECT * FROM c, db.t AS x JOIN u USING (id)"),
+            [
+                ("c", "cte", 5, 5, True),
+                ("c", "cte", 35, 35, True),
+                ("db.t", "table", 38, 41, True),
+                ("u", "table", 53, 53, True),
+            ],
+        )
+        # Keywords in the arguments of function calls don't introduce tables, unlike those of
+        # their subqueries
+        self.assertEqual(
+            self.call(
+                extract,
+                "SELECT EXTRACT(YEAR FROM d), SUBSTRING(a FROM 2), TRIM(BOTH 'x' FROM y), "
+                "ARRAY(SELECT x FROM u), COALESCE((SELECT y FROM v), 0) FROM t",
+            ),
+            [
+                ("u", "table", 93, 93, True),
+                ("v", "table", 121, 121, True),
+                ("t", "table", 133, 133, True),
+            ],
+        )
+        self.assertEqual(
+            self.call(extract, "SELECT CONVERT(x USING utf8mb4) FROM t", dialect="mysql"),
+            [("t", "table", 37, 37, True)],
+        )
+        # Commas separate tables throughout a FROM clause, after joins and subqueries too
+        self.assertEqual(
+            [
+                name
+                for name, *_ in self.call(
+                    extract,
+                    "SELECT * FROM a JOIN (SELECT x FROM b) e, f, g JOIN h ON a.x = h.x, i "
+                    "WHERE j IN (1, 2) ORDER BY k, l",
+                )
+            ],
+            ["a", "b", "f", "g", "h", "i"],
+        )
+
+    def test_extract_table_names(self):
+        extract = sqlglotrs.extract_table_names
+
+        # CTEs are left out, and quoted parts are unquoted but kept whole
+        self.assertEqual(
+            self.call(
+                extract,
+                'WITH c AS (SELECT 1) SELECT * FROM c, cat."my.db".t JOIN u x ON 1',
+                dialect="postgres",
+            ),
+            [(["cat", "my.db", "t"], 38, 50), (["u"], 57, 57)],
+        )
+        self.assertEqual(
+            self.call(extract, "INSERT INTO a.b SELECT * FROM `d`.`t`", dialect="mysql"),
+            [(["a", "b"], 12, 14), (["d", "t"], 30, 36)],
+        )
+
+    def test_index_ctes(self):
+        self.assertEqual(
+            self.call(
+                sqlglotrs.index_ctes,
+                "WITH c AS (SELECT 1), d (x) AS (SELECT 2) "
+                "SELECT a + 1 AS b, c d, (SELECT 1) AS e FROM c; SELECT 1 AS f",
+            ),
+            [
+                ("c", "cte", 5, 5, 10, 19),
+                ("d", "cte", 22, 22, 31, 40),
+                ("b", "alias", 58, 58, 49, 53),
+                ("d", "alias", 63, 63, 61, 61),
+                ("e", "alias", 80, 80, 66, 75),
+                ("f", "alias", 102, 102, 97, 97),
+            ],
+        )
+        # Only WITH clauses that start a statement are indexed
+        self.assertEqual(
+            self.call(sqlglotrs.index_ctes, "INSERT INTO t WITH c AS (SELECT 1) SELECT x AS y FROM c"),
+            [],
+        )
+
+    def test_extract_columns(self):
+        # Tables, aliases, function names, parameters and literals aren't columns
+        self.assertEqual(
+            self.call(
+                sqlglotrs.extract_columns,
+                'SELECT t.a, b AS c, f(d), :p, @q, 1 FROM t AS x WHERE "e" > 2 ORDER BY x.g',
+            ),
+            [("t.a", 7, 9), ("b", 12, 12), ("d", 22, 22), ("e", 54, 56), ("x.g", 71, 73)],
+        )
+
+        with self.assertRaisesRegex(ValueError, "Missing '"):
+            self.call(sqlglotrs.extract_columns, "SELECT a FROM 'x")
+
+    def test_find_select_star(self):
+        # Qualified stars span their qualifier, while COUNT(*) and products aren't projections
+        self.assertEqual(
+            self.call(
+                sqlglotrs.find_select_star,
+                "SELECT * FROM t; SELECT a, t.*, COUNT(*), 2 * 3 FROM (SELECT DISTINCT * FROM u) t",
+            ),
+            [(0, 7, 7), (1, 27, 29), (1, 70, 70)],
+        )
+        self.assertEqual(self.call(sqlglotrs.find_select_star, "SELECT a * b FROM t"), [])
+
+    def test_extract_parameters(self):
+        extract = sqlglotrs.extract_parameters
+
+        # Placeholders in strings and comments, and modulo operators, aren't parameters
+        self.assertEqual(
+            self.call(
+                extract,
+                "SELECT $1, :name, %s, %(n)s, :1, ?, 'x = ?' -- ?\nFROM t WHERE a % b",
+                dialect="postgres",
+            ),
+            [
+                ("dollar", "1", 7, 8),
+                ("named", "name", 11, 15),
+                ("format", "1", 18, 19),
+                ("pyformat", "n", 22, 26),
+                ("numeric", "1", 29, 30),
+                ("qmark", "1", 33, 33),
+            ],
+        )
+        # Commands are scanned past their keyword, and positions are in the whole query
+        self.assertEqual(
+            self.call(extract, "EXEC p @a, @b; SELECT @c", dialect="tsql"),
+            [("at", "a", 7, 8), ("at", "b", 11, 12), ("at", "c", 22, 23)],
+        )
+        self.assertEqual(self.call(extract, "SELECT @x, @@y", dialect="mysql"), [("at", "x", 7, 8)])
+
+    def test_classify(self):
+        self.assertEqual(
+            self.call(
+                sqlglotrs.classify,
+                "SELECT 1; (SELECT 2); WITH c AS (SELECT 1) INSERT INTO t SELECT * FROM c; "
+                "CREATE TABLE t (a INT); BEGIN; SET x = 1; frobnicate",
+            ),
+            [
+                ("SELECT", 0, 7),
+                ("SELECT", 10, 19),
+                ("INSERT", 22, 71),
+                ("DDL", 74, 95),
+                ("TCL", 98, 102),
+                ("UTILITY", 105, 113),
+                ("UNKNOWN", 116, 125),
+            ],
+        )
+        self.assertEqual(self.call(sqlglotrs.classify, "-- nothing\n;"), [])
+
+    def test_statement_kind(self):
+        ctes = ", ".join(f"c{i} AS (SELECT {i})" for i in range(20))
+        for sql, expected in (
+            ("-- c\n(VALUES 1)", "SELECT"),
+            (f"WITH {ctes} DELETE FROM t", "DELETE"),
+            ("BEGIN TRANSACTION", "TCL"),
+            ("frobnicate", "UNKNOWN"),
+            ("", "UNKNOWN"),
+            (";", "UNKNOWN"),
+            # Errors past the leading keyword aren't scanned
+            ("SELECT 'x", "SELECT"),
+            (f"WITH {ctes} DELETE FROM t WHERE 'x", "DELETE"),
+        ):
+            with self.subTest(sql=sql):
+                self.assertEqual(self.call(sqlglotrs.statement_kind, sql), expected)
+
+        with self.assertRaisesRegex(ValueError, "Missing ' from 1:20"):
+            self.call(sqlglotrs.statement_kind, "WITH c AS (SELECT 'x")
+
+    def test_is_read_only(self):
+        for sql, expected in (
+            ("SELECT 1", True),
+            ("WITH c AS (SELECT 1) SELECT * FROM c", True),
+            ("SHOW TABLES", True),
+            ("EXPLAIN SELECT 1", True),
+            ("SELECT replace(a, 'x', 'y') FROM t", True),
+            ("SELECT 1; DELETE FROM t", False),
+            ("SELECT * FROM t FOR UPDATE", False),
+            ("SELECT a INTO b FROM t", False),
+            ("WITH d AS (DELETE FROM t RETURNING *) SELECT * FROM d", False),
+            ("EXPLAIN ANALYZE DELETE FROM t", False),
+            ("VACUUM", False),
+        ):
+            with self.subTest(sql=sql):
+                self.assertIs(self.call(sqlglotrs.is_read_only, sql, dialect="postgres"), expected)
+
+    def test_has_multiple_statements(self):
+        check = sqlglotrs.has_multiple_statements
+
+        self.assertEqual(self.call(check, "SELECT 1;"), (False, None))
+        # Empty statements don't count
+        self.assertEqual(self.call(check, ";;SELECT 1;;"), (False, None))
+        self.assertEqual(self.call(check, "SELECT 1; -- x\n DROP TABLE t"), (True, (16, 27)))
+        self.assertEqual(self.call(check, "SELECT ';'"), (False, None))
+
+    def test_ensure_limit(self):
+        # Limits go before locking clauses, and limited subqueries don't limit their statement
+        self.assertEqual(
+            self.call(
+                sqlglotrs.ensure_limit,
+                "SELECT 1; SELECT * FROM t LIMIT 5; SELECT * FROM t FOR UPDATE; "
+                "INSERT INTO t VALUES (1); SELECT * FROM (SELECT * FROM t LIMIT 1) x",
+                10,
+            ),
+            (
+                "SELECT 1 LIMIT 10; SELECT * FROM t LIMIT 5; SELECT * FROM t LIMIT 10 FOR UPDATE; "
+                "INSERT INTO t VALUES (1); SELECT * FROM (SELECT * FROM t LIMIT 1) x LIMIT 10",
+                [8, 51, 130],
+            ),
+        )
+        self.assertEqual(
+            self.call(sqlglotrs.ensure_limit, "SELECT TOP 3 a FROM t", 10, dialect="tsql"),
+            ("SELECT TOP 3 a FROM t", []),
+        )
+
+    def test_parse_directives(self):
+        parse = sqlglotrs.parse_directives
+
+        # A comment before the next statement belongs to it, and quoted values can hold commas
+        self.assertEqual(
+            self.call(
+                parse,
+                "/* sqlglot: dialect=duckdb, no_optimize */ SELECT 1; "
+                "-- SQLGLOT: note='a, ''b''' \nSELECT 2 /* not one */",
+            ),
+            [(0, "dialect", "duckdb"), (0, "no_optimize", None), (1, "note", "a, 'b'")],
+        )
+
+        for sql, message in (
+            ("/* sqlglot: =x */ SELECT 1", "Invalid key ''"),
+            ("/* sqlglot: a='x */ SELECT 1", "Missing closing quote for 'a'"),
+            ("/* sqlglot: a= */ SELECT 1", "Missing value for 'a'"),
+            ("/* sqlglot: a='x' y */ SELECT 1", "Unexpected text after the value of 'a'"),
+        ):
+            with self.subTest(sql=sql):
+                with self.assertRaisesRegex(ValueError, f"Invalid sqlglot directive: {message}"):
+                    self.call(parse, sql)
+
+    def test_extract_settings(self):
+        self.assertEqual(
+            self.call(
+                sqlglotrs.extract_settings,
+                "SET search_path = public, x; SET SESSION sql_mode = 'ANSI'; "
+                "SET @@session.time_zone := 'UTC'; ALTER SESSION SET QUERY_TAG = 'x'; "
+                "PRAGMA foreign_keys = ON; PRAGMA journal_mode; SELECT 1",
+            ),
+            [
+                ("search_path", "public, x", 4, 26),
+                ("sql_mode", "'ANSI'", 33, 57),
+                ("@@session.time_zone", "'UTC'", 64, 91),
+                ("QUERY_TAG", "'x'", 112, 126),
+                ("foreign_keys", "ON", 136, 152),
+                ("journal_mode", None, 162, 173),
+            ],
+        )
+        self.assertEqual(
+            self.call(sqlglotrs.extract_settings, "SET TIME ZONE 'UTC'", dialect="postgres"),
+            [("TIME ZONE", "'UTC'", 4, 18)],
+        )
+
+    def test_resolve_context(self):
+        # USE CATALOG resets the schema, a qualified name sets both and USE ROLE sets neither
+        self.assertEqual(
+            self.call(
+                sqlglotrs.resolve_context,
+                "SELECT 1; USE db; SELECT 2; USE CATALOG c; SELECT 3; USE c2.s2; USE ROLE r",
+                "cat",
+            ),
+            [
+                ("cat", None, 0, 7),
+                ("cat", None, 10, 15),
+                ("cat", "db", 18, 25),
+                ("cat", "db", 28, 40),
+                ("c", None, 43, 50),
+                ("c", None, 53, 61),
+                ("c2", "s2", 64, 73),
+            ],
+        )
+        self.assertEqual(
+            self.call(sqlglotrs.resolve_context, "SELECT 1", initial_schema="s"),
+            [(None, "s", 0, 7)],
+        )
+
+    def test_detect_functions(self):
+        # Names match case-insensitively, but not in strings, comments or without a call
+        self.assertEqual(
+            self.call(
+                sqlglotrs.detect_functions,
+                "SELECT sleep(1), 'sleep(2)', db.SLEEP (3), pg_sleep -- sleep(4)\n, "
+                "Benchmark(1, 2) FROM t",
+                ["SLEEP", "benchmark"],
+            ),
+            [("sleep", 7, 11), ("SLEEP", 32, 36), ("Benchmark", 66, 74)],
+        )
+
+    def test_truncate(self):
+        truncate = sqlglotrs.truncate
+
+        # Queries are cut between tokens, except for long quoted ones, which are cut and closed
+        for sql, max_chars, expected in (
+            ("SELECT a, b, c FROM some_table", 16, "SELECT a, b,..."),
+            ("SELECT a, b", 11, "SELECT a, b"),
+            ("SELECT 'a long string literal' FROM t", 20, "SELECT 'a long s'..."),
+            ('SELECT "a long identifier" FROM t', 20, 'SELECT "a long i"...'),
+            ("SELECT 'it''s long'", 14, "SELECT 'it'..."),
+            ("SELECT 1 /* a comment */", 18, "SELECT 1..."),
+            # There's no room for more than the ellipsis, or even all of it
+            ("SELECT a, b", 3, "..."),
+            ("SELECT a, b", 2, ".."),
+            ("SELECT a, b", 0, ""),
+            # Previews of broken queries don't fail
+            ("SELECT 'unterminated", 12, "SELECT..."),
+            ("SELECT 'unterminated", 100, "SELECT 'unterminated"),
+        ):
+            with self.subTest(sql=sql, max_chars=max_chars):
+                self.assertEqual(self.call(truncate, sql, max_chars), expected)
+
+        self.assertEqual(self.call(truncate, "SELECT a, b, c", 10, ellipsis="…"), "SELECT a,…")
+
+    def test_print_tokens(self):
+        self.assertEqual(
+            self.call(sqlglotrs.print_tokens, "SELECT a, 'x\ny' -- c\nFROM t"),
+            "# type   line:col text     comments\n"
+            '0 SELECT 1:6      "SELECT" []\n'
+            '1 VAR    1:8      "a"      []\n'
+            '2 COMMA  1:9      ","      []\n'
+            '3 STRING 2:2      "x\\ny"   [" c"]\n'
+            '4 FROM   3:4      "FROM"   []\n'
+            '5 VAR    3:6      "t"      []\n',
+        )
+        names = [token_type.name.lower() for token_type in TokenType]
+        self.assertEqual(
+            self.call(sqlglotrs.print_tokens, "SELECT 1", names),
+            '# type   line:col text     comments\n0 select 1:6      "SELECT" []\n'
+            '1 number 1:8      "1"      []\n',
+        )
+
+    def test_highlight(self):
+        def highlight(sql, dialect=""):
+            spans = self.call(sqlglotrs.highlight, sql, dialect=dialect)
+            return [(token_class, sql[start:end]) for token_class, start, end in spans]
+
+        self.assertEqual(
+            highlight("SELECT f(a) -- c\nFROM t"),
+            [
+                ("keyword", "SELECT"),
+                ("function", "f"),
+                ("operator", "("),
+                ("identifier", "a"),
+                ("operator", ")"),
+                ("comment", "-- c"),
+                ("keyword", "FROM"),
+                ("identifier", "t"),
+            ],
+        )
+
+        # Placeholders of every style are single parameters, even when scanned as several tokens
+        spans = highlight("SELECT $1, :name, %s, %(n)s, :1, ?, a % b", "postgres")
+        self.assertEqual(
+            [text for token_class, text in spans if token_class == "parameter"],
+            ["$1", ":name", "%s", "%(n)s", ":1", "?"],
+        )
+        self.assertIn(("operator", "%"), spans)
+        self.assertEqual(
+            highlight("SELECT @p, @@x", "mysql")[1:],
+            [("parameter", "@p"), ("operator", ","), ("operator", "@@"), ("identifier", "x")],
+        )
+
+        # The rest of a command is highlighted like any other SQL
+        self.assertEqual(
+            highlight("EXEC p @a", "tsql"),
+            [("keyword", "EXEC"), ("identifier", "p"), ("parameter", "@a")],
+        )
+
+    def test_semantic_tokens(self):
+        semantic_tokens = sqlglotrs.semantic_tokens
+        types = sqlglotrs.SEMANTIC_TOKEN_TYPES
+        keyword, variable, string, operator, comment = (
+            types.index(name) for name in ("keyword", "variable", "string", "operator", "comment")
+        )
+
+        # Block comments are split into a token per line, and columns count UTF-16 code units
+        self.assertEqual(
+            self.call(semantic_tokens, "SELECT a,\n  'é😀' /* x\r\ny */ FROM t"),
+            [
+                *(0, 0, 6, keyword, 0),
+                *(0, 7, 1, variable, 0),
+                *(0, 1, 1, operator, 0),
+                *(1, 2, 5, string, 0),
+                *(0, 6, 4, comment, 0),
+                *(1, 0, 4, comment, 0),
+                *(0, 5, 4, keyword, 0),
+                *(0, 5, 1, variable, 0),
+            ],
+        )
+        self.assertEqual(
+            self.call(semantic_tokens, "SELECT '😀', 1", position_encoding="utf-8")[5:10],
+            [0, 7, 6, string, 0],
+        )
+        self.assertEqual(
+            self.call(semantic_tokens, "SELECT '😀', 1", position_encoding="utf-32")[5:10],
+            [0, 7, 3, string, 0],
+        )
+
+        with self.assertRaisesRegex(ValueError, "Unknown position encoding 'utf-7'"):
+            self.call(semantic_tokens, "SELECT 1", position_encoding="utf-7")
+        with self.assertRaisesRegex(ValueError, "Missing ' from 1:9"):
+            self.call(semantic_tokens, "SELECT 'x")
+
+    def test_highlight_ansi(self):
+        highlight = sqlglotrs.highlight_ansi
+
+        self.assertEqual(
+            self.call(highlight, "SELECT f(a), 'x', 1 -- c\nFROM t"),
+            "\x1b[1;34mSELECT\x1b[0m \x1b[33mf\x1b[0m(a), \x1b[32m'x'\x1b[0m, \x1b[36m1\x1b[0m "
+            "\x1b[90m-- c\x1b[0m\n\x1b[1;34mFROM\x1b[0m t",
+        )
+        # Themes override the default one, and an empty code leaves a class uncolored
+        self.assertEqual(
+            self.call(highlight, "SELECT a", theme={"keyword": "", "identifier": "4"}),
+            "SELECT \x1b[4ma\x1b[0m",
+        )
+
+        with self.assertRaisesRegex(ValueError, "Unknown token class 'kw'"):
+            self.call(highlight, "SELECT a", theme={"kw": "1"})
+
+    def test_highlight_html(self):
+        self.assertEqual(
+            self.call(
+                sqlglotrs.highlight_html,
+                "SELECT '<b>' & x /* \"c\" */ FROM t",
+                css_class_prefix="sql-",
+            ),
+            '<span class="sql-keyword">SELECT</span> '
+            '<span class="sql-string">&#39;&lt;b&gt;&#39;</span> '
+            '<span class="sql-operator">&amp;</span> <span class="sql-identifier">x</span> '
+            '<span class="sql-comment">/* &quot;c&quot; */</span> '
+            '<span class="sql-keyword">FROM</span> <span class="sql-identifier">t</span>',
+        )
+        self.assertEqual(
+            self.call(sqlglotrs.highlight_html, "a\n<", css_class_prefix='"'),
+            '<span class="&quot;identifier">a</span>\n<span class="&quot;operator">&lt;</span>',
+        )
+
+    def test_extract_sql_blocks(self):
+        extract = sqlglotrs.extract_sql_blocks
+        document = (
+            "# Café\n\n```sql\nSELECT é FROM t\n```\n\n"
+            "SELECT 1\nFROM u\n\nselect no\n\n```SQL\nSELECT 'x\n```\n"
+        )
+
+        def summary(blocks):
+            return [
+                (
+                    sql,
+                    start,
+                    end,
+                    line,
+                    [(t.text, t.line, t.start, t.start_byte) for t in block_tokens],
+                    error,
+                )
+                for sql, start, end, line, block_tokens, error in blocks
+            ]
+
+        # Positions are relative to the document, and unterminated blocks report their error
+        fenced = (
+            "SELECT é FROM t",
+            15,
+            29,
+            4,
+            [("SELECT", 4, 15, 16), ("é", 4, 22, 23), ("FROM", 4, 24, 26), ("t", 4, 29, 31)],
+            None,
+        )
+        unterminated = ("SELECT 'x", 71, 79, 13, [("SELECT", 13, 71, 73)], "Missing '")
+        blocks = summary(self.call(extract, document))
+        self.assertEqual(blocks[0], fenced)
+        self.assertEqual(blocks[1][:5], unterminated[:5])
+        self.assertIn(unterminated[5], blocks[1][5])
+        self.assertEqual(len(blocks), 2)
+
+        # Heuristically, uppercase paragraphs starting with a statement keyword are SQL too
+        blocks = summary(self.call(extract, document, heuristic=True))
+        self.assertEqual(
+            blocks[1],
+            (
+                "SELECT 1\nFROM u",
+                36,
+                50,
+                7,
+                [("SELECT", 7, 36, 38), ("1", 7, 43, 45), ("FROM", 8, 45, 47), ("u", 8, 50, 52)],
+                None,
+            ),
+        )
+        self.assertEqual(len(blocks), 3)
+
+    def test_edit_log(self):
+        log = sqlglotrs.EditLog()
+        sql = "SELECT a FROM t WHERE b = ? AND c = ?"
+
+        limited, _ = self.call(sqlglotrs.ensure_limit, sql, 10, edit_log=log)
+        converted, _ = self.call(
+            sqlglotrs.convert_placeholders, limited, "qmark", "named", edit_log=log
+        )
+        self.assertEqual(converted, "SELECT a FROM t WHERE b = :p1 AND c = :p2 LIMIT 10")
+        self.assertEqual(len(log), 2)
+
+        # Offsets inside a replacement map to its start, and insertions at an offset move it along
+        self.assertEqual(
+            [log.to_new(offset) for offset in (0, 26, 27, 36, 37)], [0, 26, 29, 38, 50]
+        )
+        self.assertEqual(
+            [log.to_original(offset) for offset in (0, 26, 28, 29, 39, 50)], [0, 26, 26, 27, 36, 37]
+        )
+
+        edited = log.apply(converted, [(0, 6, "select"), (7, 7, "DISTINCT ")])
+        self.assertEqual(edited, "select DISTINCT a FROM t WHERE b = :p1 AND c = :p2 LIMIT 10")
+        self.assertEqual((len(log), log.to_new(7), log.to_original(16)), (3, 16, 7))
+
+        log.clear()
+        self.assertEqual((len(log), log.to_new(5), log.to_original(5)), (0, 5, 5))
+
+    def test_stats(self):
+        tokenizer, settings = self.rs_tokenizer()
+
+        sqlglotrs.reset_stats()
+        self.addCleanup(sqlglotrs.reset_stats)
+        self.addCleanup(sqlglotrs.disable_stats)
+
+        # Nothing is counted until stats are enabled
+        tokenizer.tokenize("SELECT 1", settings)
+        self.assertEqual(sqlglotrs.get_stats()["queries"], 0)
+
+        sqlglotrs.enable_stats()
+        tokenizer.tokenize("SELECT é", settings)
+        tokenizer.tokenize("SELECT 'x", settings)
+        stats = sqlglotrs.get_stats()
+        self.assertEqual(
+            (stats["queries"], stats["bytes"], stats["errors"]), (2, 18, {"unterminated": 1})
+        )
+        self.assertGreater(stats["total_seconds"], 0)
+        self.assertAlmostEqual(stats["mean_seconds"], stats["total_seconds"] / 2)
+        self.assertGreater(stats["p95_seconds"], 0)
+
+        # Disabling keeps the counts so far, which resetting clears
+        sqlglotrs.disable_stats()
+        tokenizer.tokenize("SELECT 1", settings)
+        self.assertEqual(sqlglotrs.get_stats()["queries"], 2)
+        sqlglotrs.reset_stats()
+        self.assertEqual(
+            sqlglotrs.get_stats(),
+            {
+                "queries": 0,
+                "bytes": 0,
+                "errors": {},
+                "total_seconds": 0.0,
+                "mean_seconds": 0.0,
+                "p95_seconds": 0.0,
+            },
+        )
+
+        _, _, scan_stats = tokenizer.tokenize_with_stats("SELECT a FROM t", settings)
+        self.assertEqual(
+            {key: value for key, value in scan_stats.items() if key != "elapsed_ns"},
+            {"bytes": 15, "tokens": 4, "keyword_hits": 0, "keyword_misses": 4, "allocations": 0},
+        )
+
+    def test_similarity(self):
+        tokenizer, settings = self.rs_tokenizer()
+
+        def similarity(sql_a, sql_b, *args, **kwargs):
+            return sqlglotrs.similarity(tokenizer, sql_a, sql_b, settings, *args, **kwargs)
+
+        # Literals, case and formatting don't matter
+        self.assertEqual(
+            similarity("SELECT a FROM t WHERE b = 1", "select  a from T where b = 'x'"), 1.0
+        )
+        self.assertAlmostEqual(similarity("SELECT a FROM t", "SELECT a FROM t WHERE b = 1"), 1 / 3)
+        self.assertEqual(
+            similarity("SELECT a FROM t", "SELECT a FROM t WHERE b = 1", method="containment"), 1.0
+        )
+        self.assertAlmostEqual(
+            similarity("SELECT a FROM t WHERE b = 1", "SELECT a FROM t", method="containment"),
+            1 / 3,
+        )
+        self.assertAlmostEqual(similarity("SELECT a", "SELECT b", 1), 1 / 3)
+        self.assertEqual(similarity("SELECT a", "DELETE FROM u"), 0.0)
+        self.assertEqual(similarity("", ""), 1.0)
+        self.assertEqual(similarity("", "SELECT 1"), 0.0)
+
+        with self.assertRaisesRegex(ValueError, "Shingle size must be at least 1"):
+            similarity("a", "b", 0)
+        with self.assertRaisesRegex(ValueError, "Unknown similarity method 'cosine'"):
+            similarity("a", "b", method="cosine")
+
+    def test_tokenize_errors(self):
+        # Malformed input ends the scan with an error rather than a panic, keeping the tokens
+        # scanned before it
+        for dialect, sql, texts, kind in (
+            ("", "SELECT 'abc", ["SELECT"], "unterminated"),
+            ("", 'SELECT "abc', ["SELECT"], "unterminated"),
+            ("postgres", "SELECT 1, $a$ x", ["SELECT", "1", ","], "unterminated"),
+            ("mysql", "SELECT x'ZZ'", ["SELECT"], "invalid_number"),
+        ):
+            with self.subTest(dialect=dialect, sql=sql):
+                tokenizer, settings = self.rs_tokenizer(dialect)
+                result, error = tokenizer.tokenize(sql, settings)
+                self.assertEqual([token.text for token in result], texts)
+                self.assertEqual(error.kind, kind)
+
+    def test_tokenize_error(self):
+        tokenizer, settings = self.rs_tokenizer()
+        _, error = tokenizer.tokenize("SELECT\n  'é", settings)
+        self.assertIsInstance(error, sqlglotrs.TokenizeError)
+        self.assertEqual(
+            (error.kind, error.line, error.column, error.start, error.char, error.context),
+            ("unterminated", 2, 4, 9, "é", "SELECT\n  '"),
+        )
+        self.assertEqual(error.message, "Missing ' from 2:11")
+        self.assertEqual(str(error), "Error tokenizing 'SELECT\n  '': Missing ' from 2:11")
+        self.assertEqual(
+            repr(error),
+            "<TokenizeError kind: unterminated, line: 2, column: 4, start: 9, "
+            "message: Missing ' from 2:11>",
+        )
+
+        # Helpers that raise a ValueError instead keep the error on it
+        with self.assertRaises(ValueError) as raised:
+            self.call(sqlglotrs.extract_tables, "SELECT 'x")
+        error = raised.exception.error
+        self.assertEqual((error.kind, error.start), ("unterminated", 7))
+
+    def test_tokenize_lenient(self):
+        tokenizer, settings = self.rs_tokenizer("mysql")
+
+        def scan(sql):
+            return [
+                (TokenType.from_int(token.token_type_index), token.text, token.start, token.end)
+                for token in tokenizer.tokenize_lenient(sql, settings)
+            ]
+
+        # What can't be scanned becomes an error token, after which the scan resumes
+        self.assertEqual(
+            scan("SELECT 'abc FROM t"),
+            [
+                (TokenType.SELECT, "SELECT", 0, 5),
+                (TokenType.ERROR, "'abc", 7, 10),
+                (TokenType.FROM, "FROM", 12, 15),
+                (TokenType.VAR, "t", 17, 17),
+            ],
+        )
+        self.assertEqual(
+            scan("SELECT x'ZZ' + 1"),
+            [
+                (TokenType.SELECT, "SELECT", 0, 5),
+                (TokenType.ERROR, "x'ZZ'", 7, 11),
+                (TokenType.PLUS, "+", 13, 13),
+                (TokenType.NUMBER, "1", 15, 15),
+            ],
+        )
+        self.assertEqual(scan("SELECT 1 /* x")[-1], (TokenType.ERROR, "/* x", 9, 12))
+
+        # Limits still raise
+        limited = tokens.RsTokenizerDialectSettings({}, False, False, max_tokens=2)
+        with self.assertRaisesRegex(ValueError, "more tokens than max_tokens of 2"):
+            tokenizer.tokenize_lenient("SELECT 1, 2", limited)
+
+    def test_stream(self):
+        tokenizer, settings = self.rs_tokenizer()
+
+        def summary(result):
+            return [(token.text, token.line, token.col, token.start, token.end) for token in result]
+
+        # Long queries are scanned over several batches
+        sql = "SELECT " + ",\n".join(f"c{i}" for i in range(3000)) + " FROM t"
+        streamed = list(tokenizer.stream(sql, settings))
+        self.assertEqual(len(streamed), 6002)
+        self.assertEqual(summary(streamed), summary(self.tokenize(sql)))
+
+        # Errors are raised once the tokens before them have been yielded
+        texts = []
+        with self.assertRaisesRegex(ValueError, "Missing '") as raised:
+            for token in tokenizer.stream("SELECT 1, 'x", settings):
+                texts.append(token.text)
+        self.assertEqual(texts, ["SELECT", "1", ","])
+        self.assertEqual(raised.exception.error.kind, "unterminated")
+
+    def test_multi_byte_positions(self):
+        if not tokens.USE_RS_TOKENIZER:
+            self.skipTest("sqlglotrs is not installed or disabled")
+
+        # The scanner reads UTF-8, but positions are counted in chars like the Python tokenizer's
+        sql = "SELECT 'é😀x', \"名前\" /* ü */, 😀\nFROM t -- ß\nWHERE a = 'ÿ'"
+
+        def summary(use_rs_tokenizer):
+            return [
+                (token.text, token.line, token.col, token.start, token.end, token.comments)
+                for token in Tokenizer(use_rs_tokenizer=use_rs_tokenizer).tokenize(sql)
+            ]
+
+        self.assertEqual(summary(True), summary(False))
+        self.assertEqual(
+            summary(True)[:6],
+            [
+                ("SELECT", 1, 6, 0, 5, []),
+                ("é😀x", 1, 12, 7, 11, []),
+                (",", 1, 13, 12, 12, []),
+                ("名前", 1, 18, 14, 17, [" ü "]),
+                (",", 1, 27, 26, 26, []),
+                ("😀", 1, 29, 28, 28, []),
+            ],
+        )
+
+    def test_tokenize_in_threads(self):
+        # Scans release the GIL, so threads sharing a tokenizer run them concurrently
+        tokenizer, settings = self.rs_tokenizer()
+        sqls = [f"SELECT {i}, '{'x' * i}' FROM t{i}" for i in range(64)]
+
+        def scan(sql):
+            result, error = tokenizer.tokenize(sql, settings)
+            return [(token.text, token.start, token.end) for token in result], error
+
+        with ThreadPoolExecutor(8) as executor:
+            results = list(executor.map(scan, sqls))
+        self.assertEqual(results, [scan(sql) for sql in sqls])
+        self.assertEqual(
+            results[3],
+            (
+                [
+                    ("SELECT", 0, 5),
+                    ("3", 7, 7),
+                    (",", 8, 8),
+                    ("xxx", 10, 14),
+                    ("FROM", 16, 19),
+                    ("t3", 21, 22),
+                ],
+                None,
+            ),
+        )
+
+    def test_tokenize_many(self):
+        tokenizer, settings = self.rs_tokenizer()
+        sqls = [f"SELECT {i} FROM t" for i in range(100)] + ["SELECT 'x", ""]
+
+        results = tokenizer.tokenize_many(sqls, settings)
+        self.assertEqual(len(results), 102)
+        # Results are in the order of the queries, each with its own error
+        for sql, (result, error) in zip(sqls, results):
+            expected, expected_error = tokenizer.tokenize(sql, settings)
+            self.assertEqual(
+                [(token.text, token.start) for token in result],
+                [(token.text, token.start) for token in expected],
+            )
+            self.assertEqual(str(error), str(expected_error))
+        self.assertEqual(results[-2][1].kind, "unterminated")
+        self.assertEqual(results[-1], ([], None))
+        self.assertEqual(tokenizer.tokenize_many([], settings), [])
+
+    def test_split_statements(self):
+        # Semicolons in strings, comments, dollar quotes and BEGIN ... END blocks don't split, and
+        # the offsets are in bytes
+        self.assertEqual(
+            self.call(
+                sqlglotrs.split_statements,
+                "SELECT 'a;b'; -- c;\nCREATE FUNCTION f() AS $$ SELECT 1; $$; "
+                "BEGIN SELECT 1; END;;SELECT 'é'",
+                dialect="postgres",
+            ),
+            [
+                ("SELECT 'a;b'", 0, 12),
+                ("CREATE FUNCTION f() AS $$ SELECT 1; $$", 20, 58),
+                ("BEGIN SELECT 1; END", 60, 79),
+                ("SELECT 'é'", 81, 92),
+            ],
+        )
+        self.assertEqual(self.call(sqlglotrs.split_statements, " ;; "), [])
+
+    def test_detokenize(self):
+        detokenize = sqlglotrs.detokenize
+        tokenizer = self.rs_tokenizer()[0]
+        sql = "select a.b ,  f( x )\n  FROM t -- note\nWHERE s = 'it''s' AND n = 1.5"
+        sql_tokens = self.tokenize(sql)
+
+        self.assertEqual(
+            detokenize(tokenizer, sql_tokens),
+            "select a.b, f(x) FROM t /* note*/ WHERE s = 'it''s' AND n = 1.5",
+        )
+        self.assertEqual(
+            detokenize(tokenizer, sql_tokens, "preserve"),
+            "select a.b , f( x )\n  FROM t /* note*/\nWHERE s = 'it''s' AND n = 1.5",
+        )
+        self.assertEqual(
+            detokenize(tokenizer, sql_tokens, keyword_case="upper", comments=False),
+            "SELECT a.b, f(x) FROM t WHERE s = 'it''s' AND n = 1.5",
+        )
+        # Dropping tokens
+        self.assertEqual(
+            detokenize(tokenizer, sql_tokens[:4] + sql_tokens[-3:]), "select a.b n = 1.5"
+        )
+
+        # The output scans back into the same tokens
+        detokenized = detokenize(tokenizer, sql_tokens)
+        self.assertEqual(
+            [token.text for token in self.tokenize(detokenized)],
+            [token.text for token in sql_tokens],
+        )
+
+        # Quotes are escaped the dialect's way
+        self.assertEqual(
+            detokenize(
+                self.rs_tokenizer("mysql")[0],
+                self.tokenize("SELECT `a``b`, 'x\\'y' FROM t", dialect="mysql"),
+            ),
+            "SELECT `a``b`, 'x''y' FROM t",
+        )
+
+        with self.assertRaisesRegex(ValueError, "Unknown spacing 'nope'"):
+            detokenize(tokenizer, sql_tokens, "nope")
+        with self.assertRaisesRegex(ValueError, "Unknown keyword case 'title'"):
+            detokenize(tokenizer, sql_tokens, keyword_case="title")
+
+    def test_format_sql(self):
+        format_sql = sqlglotrs.format_sql
+
+        sql = (
+            "with c as (select a, b from t where x = 1) select c.a, count(*) as n from c "
+            "left join u on c.a = u.a and u.b > 2 where c.b in (select b from v) "
+            "group by c.a order by n desc limit 10; select 1"
+        )
+        self.assertEqual(
+            self.call(format_sql, sql),
+            """WITH c AS (
+  SELECT a, b
+  FROM t
+  WHERE x = 1
+)
+SELECT c.a, count(*) AS n
+FROM c
+LEFT JOIN u ON c.a = u.a AND u.b > 2
+WHERE c.b IN (
+  SELECT b
+  FROM v
+)
+GROUP BY c.a
+ORDER BY n DESC
+LIMIT 10;
+
+SELECT 1""",
+        )
+
+        # Clauses too long for a line get an item or condition per line
+        self.assertEqual(
+            self.call(
+                format_sql,
+                "select aaaaaaaaaa, bbbbbbbbbbb, ccccccccccc from t "
+                "where aaaaaaaaaa = 1 and bbbbbbbbbbb = 2 or cccccccccc = 3",
+                keyword_case="lower",
+                comma_style="leading",
+                indent=4,
+                max_line_width=30,
+            ),
+            """select
+    aaaaaaaaaa
+    , bbbbbbbbbbb
+    , ccccccccccc
+from t
+where
+    aaaaaaaaaa = 1
+    and bbbbbbbbbbb = 2
+    or cccccccccc = 3""",
+        )
+
+        # Comments are kept
+        self.assertEqual(
+            self.call(format_sql, "SELECT a -- x\nFROM t", keyword_case=None),
+            "SELECT a /* x*/\nFROM t",
+        )
+
+        with self.assertRaisesRegex(ValueError, "Unknown keyword case 'x'"):
+            self.call(format_sql, "SELECT 1", keyword_case="x")
+        with self.assertRaisesRegex(ValueError, "Unknown comma style 'x'"):
+            self.call(format_sql, "SELECT 1", comma_style="x")
+        with self.assertRaisesRegex(ValueError, "Missing ' from 1:9"):
+            self.call(format_sql, "select 'x")
+
+    def test_fingerprint(self):
+        fingerprint = sqlglotrs.fingerprint
+
+        for dialect in ("", "postgres", "mysql", "tsql", "snowflake", "oracle"):
+            expected = self.call(
+                fingerprint, "SELECT * FROM t WHERE a = ? AND b = 1", dialect=dialect
+            )
+            self.assertEqual(len(expected), 16)
+
+            # Literals and bind parameters of every style have the same shape
+            for parameter in ("?", "'x'", ":name", ":1", "%s", "%(name)s", "$1", "@name"):
+                with self.subTest(dialect=dialect, parameter=parameter):
+                    self.assertEqual(
+                        self.call(
+                            fingerprint,
+                            f"select *  from t\nwhere a = {parameter} and b = 2;",
+                            dialect=dialect,
+                        ),
+                        expected,
+                    )
+
+        # Names, operators and the case of quoted identifiers still count, while comments don't
+        self.assertNotEqual(
+            self.call(fingerprint, "SELECT a % s FROM t"),
+            self.call(fingerprint, "SELECT a % b FROM t"),
+        )
+        self.assertNotEqual(
+            self.call(fingerprint, "SELECT a FROM t WHERE b = :x"),
+            self.call(fingerprint, "SELECT a FROM t WHERE b = x"),
+        )
+        self.assertNotEqual(
+            self.call(fingerprint, 'SELECT "a" FROM t'), self.call(fingerprint, 'SELECT "A" FROM t')
+        )
+        self.assertEqual(
+            self.call(fingerprint, "SELECT a /* x */ FROM t"),
+            self.call(fingerprint, "SELECT a FROM t"),
+        )
+
+    def test_redact(self):
+        redact = sqlglotrs.redact
+
+        # Comments, identifiers and operators are kept, and `$$x$$` is a heredoc string
+        self.assertEqual(
+            self.call(
+                redact,
+                "SELECT 'é', 1.5, x'AF', \"id\" FROM t -- 'keep' 42\nWHERE a = -3 AND b = $$x$$",
+                dialect="postgres",
+            ),
+            "SELECT ?, ?, ?, \"id\" FROM t -- 'keep' 42\nWHERE a = -? AND b = ?",
+        )
+        self.assertEqual(
+            self.call(redact, "SELECT b'01', r'x', N'y', 'a' FROM t", "<v>", dialect="bigquery"),
+            "SELECT <v>, <v>, <v>, <v> FROM t",
+        )
+        # The rest of a command isn't a literal
+        self.assertEqual(self.call(redact, "EXEC p 'x', 1", dialect="tsql"), "EXEC p 'x', 1")
+
+        log = sqlglotrs.EditLog()
+        self.assertEqual(self.call(redact, "SELECT 'abc', 2", edit_log=log), "SELECT ?, ?")
+        self.assertEqual((len(log), log.to_original(10), log.to_new(14)), (1, 14, 10))
+
+    def test_tokenize_trivia(self):
+        tokenizer, settings = self.rs_tokenizer()
+
+        def pieces(result):
+            return [(token.leading_trivia, token.source_text) for token in result]
+
+        sql = "  SELECT a ,/* c */ 'it''s' -- x\nFROM t  "
+        result, trailing, error = tokenizer.tokenize_trivia(sql, settings)
+        self.assertIsNone(error)
+        self.assertEqual(
+            pieces(result),
+            [
+                ("  ", "SELECT"),
+                (" ", "a"),
+                (" ", ","),
+                ("/* c */ ", "'it''s'"),
+                (" -- x\n", "FROM"),
+                (" ", "t"),
+            ],
+        )
+        self.assertEqual(result[3].text, "it's")
+        self.assertEqual(trailing, "  ")
+        self.assertEqual("".join(map("".join, pieces(result))) + trailing, sql)
+
+        # What follows a failed scan's last token is returned as it is
+        result, trailing, error = tokenizer.tokenize_trivia("SELECT 1, 'x", settings)
+        self.assertEqual(pieces(result), [("", "SELECT"), (" ", "1"), ("", ",")])
+        self.assertEqual(trailing, " 'x")
+        self.assertEqual(error.kind, "unterminated")
+
+        # The rest of a command is written as it was
+        tsql_tokenizer, tsql_settings = self.rs_tokenizer("tsql")
+        result, trailing, error = tsql_tokenizer.tokenize_trivia(
+            "EXEC  p 'x' ; SELECT 1", tsql_settings
+        )
+        self.assertEqual(pieces(result)[:3], [("", "EXEC"), ("  ", "p 'x'"), (" ", ";")])
+
+    def test_retokenize(self):
+        tokenizer, settings = self.rs_tokenizer()
+
+        def summary(result):
+            return [
+                (token.text, token.line, token.col, token.start, token.end)
+                + (token.start_line, token.start_column, token.start_byte, token.end_byte)
+                for token in result
+            ]
+
+        sql = "SELECT a,\n  b FROM t\nWHERE c = 1 AND d = 'é'"
+        old = self.tokenize(sql)
+
+        # Each edit gives the tokens a full scan of the edited query would
+        for start, end, new_text in [(16, 16, "\n"), (27, 28, "cc"), (7, 8, ""), (0, 44, "SELECT")]:
+            with self.subTest(start=start, end=end, new_text=new_text):
+                edited = sql[:start] + new_text + sql[end:]
+                result, error = tokenizer.retokenize(sql, old, start, end, new_text, settings)
+                self.assertIsNone(error)
+                self.assertEqual(summary(result), summary(self.tokenize(edited)))
+
+        # The tokens before the edit are reused
+        result, _ = tokenizer.retokenize(sql, old, 27, 28, "cc", settings)
+        self.assertTrue(all(new is token for new, token in zip(result[:5], old)))
+
+        result, error = tokenizer.retokenize(sql, old, 41, 44, "'x", settings)
+        self.assertEqual(summary(result)[-1], ("=", 3, 19, 39, 39, 3, 19, 39, 40))
+        self.assertEqual(error.kind, "unterminated")
+
+        with self.assertRaisesRegex(ValueError, "out of bounds"):
+            tokenizer.retokenize(sql, old, 0, 99, "", settings)
+        with self.assertRaisesRegex(ValueError, "ends before it starts"):
+            tokenizer.retokenize(sql, old, 3, 1, "", settings)
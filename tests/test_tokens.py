@@ -1,8 +1,15 @@
+import gzip
+import importlib.util
+import io
+import json
+import os
+import tempfile
 import unittest
+from unittest import mock
 
-from sqlglot.dialects import BigQuery
-from sqlglot.errors import TokenError
-from sqlglot.tokens import Tokenizer, TokenType
+from sqlglot.dialects import BigQuery, Postgres, Spark
+from sqlglot.errors import TokenError, TokenizerConformanceError, TokenLimitError
+from sqlglot.tokens import CUSTOM_TOKEN_TYPE_START, Token, Tokenizer, TokenType
 
 
 class TestTokens(unittest.TestCase):
@@ -36,6 +43,13 @@ class TestTokens(unittest.TestCase):
         for sql, comment in sql_comment:
             self.assertEqual(tokenizer.tokenize(sql)[0].comments, comment)
 
+        self.assertEqual(tokenizer.tokenize("/*é*/ foo")[0].comments, ["é"])
+        self.assertEqual(tokenizer.tokenize("foo --")[0].comments, [""])
+        self.assertEqual(BigQuery.Tokenizer().tokenize("foo #")[0].comments, [""])
+
+        with self.assertRaises(TokenError):
+            tokenizer.tokenize("foo /*日本")
+
     def test_token_line_col(self):
         tokens = Tokenizer().tokenize(
             """SELECT /*
@@ -81,6 +95,63 @@ x"""
         self.assertEqual(tokens[1].line, 2)
         self.assertEqual(tokens[1].col, 7)
 
+    def test_token_start_end(self):
+        sql = "SELECT 'a\n  b',\n  /* c\n d */ x"
+        tokens = Tokenizer(comment_tokens=True).tokenize(sql)
+
+        # `line` and `col` are those of the last char, which multi-line tokens start lines before
+        self.assertEqual(
+            [
+                (token.text, token.start_line, token.start_column, token.end_line, token.end_column)
+                for token in tokens
+            ],
+            [
+                ("SELECT", 1, 1, 1, 6),
+                ("a\n  b", 1, 8, 2, 4),
+                (",", 2, 5, 2, 5),
+                (" c\n d ", 3, 3, 4, 5),
+                ("x", 4, 7, 4, 7),
+            ],
+        )
+        self.assertEqual(
+            (tokens[1].end_line, tokens[1].end_column), (tokens[1].line, tokens[1].col)
+        )
+
+        frozen = tokens[1].freeze()
+        self.assertEqual(
+            (frozen.start_line, frozen.start_column, frozen.end_line, frozen.end_column),
+            (1, 8, 2, 4),
+        )
+
+        # A token starts at the first unit of its first char
+        tokens = Tokenizer(position_encoding="utf16").tokenize("SELECT '😀\n😀', 😀x")
+        self.assertEqual(
+            [(token.start_column, token.end_column) for token in tokens],
+            [(1, 6), (8, 3), (4, 4), (6, 8)],
+        )
+
+    def test_token_bytes(self):
+        sql = "SELECT 'é😀', \"名\" /* ü */ FROM t"
+        tokens = Tokenizer(comment_tokens=True).tokenize(sql)
+
+        # Byte offsets are those of the query's UTF-8 and half-open, unlike `start` and `end`
+        self.assertEqual(
+            [(token.text, token.start_byte, token.end_byte) for token in tokens],
+            [("SELECT", 0, 6), ("é😀", 7, 15), (",", 15, 16), ("名", 17, 22), (" ü ", 23, 31)]
+            + [("FROM", 32, 36), ("t", 37, 38)],
+        )
+        encoded = sql.encode()
+        self.assertEqual(encoded[tokens[3].start_byte : tokens[3].end_byte].decode(), '"名"')
+        self.assertEqual(tokens[1].freeze().end_byte, 15)
+
+        # They're char offsets for ASCII queries, and count a byte order mark's 3 bytes
+        for sql, offsets in [
+            ("SELECT x", [(0, 6), (7, 8)]),
+            ("\ufeffSELECT 'é'", [(3, 9), (10, 14)]),
+        ]:
+            tokens = Tokenizer().tokenize(sql)
+            self.assertEqual([(token.start_byte, token.end_byte) for token in tokens], offsets)
+
     def test_crlf(self):
         tokens = Tokenizer().tokenize("SELECT a\r\nFROM b")
         tokens = [(token.token_type, token.text) for token in tokens]
@@ -122,6 +193,658 @@ x"""
         self.assertEqual(tokens[2].token_type, TokenType.SHOW)
         self.assertEqual(tokens[3].token_type, TokenType.SEMICOLON)
 
+    def test_tokenize_to_json(self):
+        sql = "SELECT ü, 'a\"b\\\\c\n\t\x01\x08\x0c' /* c */ FROM t -- d"
+        tokenizer = Tokenizer()
+        result = tokenizer.tokenize_to_json(sql)
+
+        self.assertEqual(
+            json.loads(result),
+            [
+                {
+                    "type": token.token_type.name,
+                    "text": token.text,
+                    "line": token.line,
+                    "col": token.col,
+                    "start": token.start,
+                    "end": token.end,
+                    "comments": token.comments,
+                }
+                for token in tokenizer.tokenize(sql)
+            ],
+        )
+        # Both tokenizers write the same JSON, escapes included
+        self.assertIn(r'"text":"a\"b\\\\c\n\t\u0001\b\f"', result)
+        self.assertEqual(result, Tokenizer(use_rs_tokenizer=False).tokenize_to_json(sql))
+        self.assertEqual(tokenizer.tokenize_to_json(""), "[]")
+
+        with self.assertRaises(TokenError):
+            tokenizer.tokenize_to_json("SELECT 'x")
+
+    def test_dollar_quoted_strings(self):
+        tokenizer = Postgres().tokenizer()
+        tokens = tokenizer.tokenize("SELECT $fn$ RETURN $$x$$ $fn$, $é$ a\nb $é$ + $1")
+        self.assertEqual(
+            [(token.token_type, token.text, token.line, token.col) for token in tokens],
+            [
+                (TokenType.SELECT, "SELECT", 1, 6),
+                (TokenType.HEREDOC_STRING, " RETURN $$x$$ ", 1, 29),
+                (TokenType.COMMA, ",", 1, 30),
+                (TokenType.HEREDOC_STRING, " a\nb ", 2, 5),
+                (TokenType.PLUS, "+", 2, 7),
+                (TokenType.PARAMETER, "$", 2, 9),
+                (TokenType.NUMBER, "1", 2, 10),
+            ],
+        )
+
+        with self.assertRaises(TokenError):
+            tokenizer.tokenize("SELECT $a$ x $b$")
+
+        # A $ without an identifier tag is a parameter, which is scanned without moving past it
+        tokens = tokenizer.tokenize("SELECT $1\n+ $a b\n$")
+        self.assertEqual(
+            [(token.token_type, token.text, token.line, token.col) for token in tokens],
+            [
+                (TokenType.SELECT, "SELECT", 1, 6),
+                (TokenType.PARAMETER, "$", 1, 8),
+                (TokenType.NUMBER, "1", 1, 9),
+                (TokenType.PLUS, "+", 2, 1),
+                (TokenType.PARAMETER, "$", 2, 3),
+                (TokenType.VAR, "a", 2, 4),
+                (TokenType.VAR, "b", 2, 6),
+                (TokenType.PARAMETER, "$", 3, 1),
+            ],
+        )
+
+    def test_unicode_identifiers(self):
+        from sqlglot.dialects.dialect import Dialect
+
+        class UnicodeIdentifiers(Dialect):
+            UNICODE_IDENTIFIERS = True
+
+        sql = "SELECT 列名，café, cafe\u0301 AS naïve€ FROM テーブル WHERE Ωmega_1 = 1位"
+
+        self.assertEqual(
+            [(token.token_type, token.text) for token in Tokenizer().tokenize(sql)],
+            [
+                (TokenType.SELECT, "SELECT"),
+                (TokenType.VAR, "列名，café"),
+                (TokenType.COMMA, ","),
+                (TokenType.VAR, "cafe\u0301"),
+                (TokenType.ALIAS, "AS"),
+                (TokenType.VAR, "naïve€"),
+                (TokenType.FROM, "FROM"),
+                (TokenType.VAR, "テーブル"),
+                (TokenType.WHERE, "WHERE"),
+                (TokenType.VAR, "Ωmega_1"),
+                (TokenType.EQ, "="),
+                (TokenType.NUMBER, "1"),
+                (TokenType.VAR, "位"),
+            ],
+        )
+        self.assertEqual(
+            [
+                (token.token_type, token.text, token.col)
+                for token in Tokenizer(dialect=UnicodeIdentifiers).tokenize(sql)
+            ],
+            [
+                (TokenType.SELECT, "SELECT", 6),
+                (TokenType.VAR, "列名", 9),
+                (TokenType.VAR, "，", 10),
+                (TokenType.VAR, "café", 14),
+                (TokenType.COMMA, ",", 15),
+                (TokenType.VAR, "cafe\u0301", 21),
+                (TokenType.ALIAS, "AS", 24),
+                (TokenType.VAR, "naïve", 30),
+                (TokenType.VAR, "€", 31),
+                (TokenType.FROM, "FROM", 36),
+                (TokenType.VAR, "テーブル", 41),
+                (TokenType.WHERE, "WHERE", 47),
+                (TokenType.VAR, "Ωmega_1", 55),
+                (TokenType.EQ, "=", 57),
+                (TokenType.NUMBER, "1", 59),
+                (TokenType.VAR, "位", 60),
+            ],
+        )
+
+    def test_byte_order_mark(self):
+        tokens = Tokenizer().tokenize("\ufeffSELECT 1")
+        self.assertEqual(
+            [(token.token_type, token.text, token.col, token.start) for token in tokens],
+            [(TokenType.SELECT, "SELECT", 6, 1), (TokenType.NUMBER, "1", 8, 8)],
+        )
+        self.assertEqual(Tokenizer().tokenize("\ufeff"), [])
+
+    def test_control_characters(self):
+        sql = "SELECT a\u200bb, '\u200b' FROM\u2060t"
+
+        def tokenize(policy):
+            tokenizer = Tokenizer(dialect=f"duckdb, control_characters={policy}")
+            return [(token.token_type, token.text) for token in tokenizer.tokenize(sql)]
+
+        self.assertEqual(
+            tokenize("keep"),
+            [
+                (TokenType.SELECT, "SELECT"),
+                (TokenType.VAR, "a\u200bb"),
+                (TokenType.COMMA, ","),
+                (TokenType.STRING, "\u200b"),
+                (TokenType.VAR, "FROM\u2060t"),
+            ],
+        )
+        self.assertEqual(
+            tokenize("skip"),
+            [
+                (TokenType.SELECT, "SELECT"),
+                (TokenType.VAR, "a"),
+                (TokenType.VAR, "b"),
+                (TokenType.COMMA, ","),
+                (TokenType.STRING, "\u200b"),
+                (TokenType.FROM, "FROM"),
+                (TokenType.VAR, "t"),
+            ],
+        )
+
+        with self.assertRaises(TokenError):
+            tokenize("error")
+
+        with self.assertRaisesRegex(ValueError, "Unknown control_characters policy 'drop'"):
+            tokenize("drop")
+
+    def test_nul_bytes(self):
+        sql = "SELECT a\x00b, 'c\x00' /* d\x00 */ FROM\x00t"
+
+        def tokenize(policy):
+            tokenizer = Tokenizer(dialect=f"duckdb, nul_bytes={policy}")
+            return [
+                (token.token_type, token.text, token.start, token.comments)
+                for token in tokenizer.tokenize(sql)
+            ]
+
+        self.assertEqual(
+            tokenize("text"),
+            [
+                (TokenType.SELECT, "SELECT", 0, []),
+                (TokenType.VAR, "a\x00b", 7, []),
+                (TokenType.COMMA, ",", 10, []),
+                (TokenType.STRING, "c\x00", 12, [" d\x00 "]),
+                (TokenType.VAR, "FROM\x00t", 26, []),
+            ],
+        )
+        self.assertEqual(
+            tokenize("strip"),
+            [
+                (TokenType.SELECT, "SELECT", 0, []),
+                (TokenType.VAR, "a", 7, []),
+                (TokenType.VAR, "b", 9, []),
+                (TokenType.COMMA, ",", 10, []),
+                (TokenType.STRING, "c", 12, [" d "]),
+                (TokenType.FROM, "FROM", 26, []),
+                (TokenType.VAR, "t", 31, []),
+            ],
+        )
+
+        with self.assertRaisesRegex(TokenError, "Error tokenizing"):
+            tokenize("error")
+
+        with self.assertRaisesRegex(ValueError, "Unknown nul_bytes policy 'drop'"):
+            tokenize("drop")
+
+    def test_position_encoding(self):
+        sql = "SELECT '😀', é\n  , 'a😀\n😀' AS \"😀b\""
+
+        def positions(position_encoding):
+            tokenizer = Tokenizer(position_encoding=position_encoding)
+            return [(token.line, token.col, token.start) for token in tokenizer.tokenize(sql)]
+
+        # Only the columns depend on the encoding
+        for position_encoding, cols in (
+            ("utf32", [6, 10, 11, 13, 3, 2, 5, 10]),
+            ("utf16", [6, 11, 12, 14, 3, 3, 6, 12]),
+            ("utf8", [6, 13, 14, 17, 3, 5, 8, 16]),
+        ):
+            with self.subTest(position_encoding=position_encoding):
+                self.assertEqual(
+                    positions(position_encoding),
+                    [
+                        (line, col, start)
+                        for (line, start), col in zip(
+                            [(1, 0), (1, 7), (1, 10), (1, 12), (2, 16), (3, 18), (3, 25), (3, 28)],
+                            cols,
+                        )
+                    ],
+                )
+
+        with self.assertRaisesRegex(ValueError, "Unknown position_encoding 'utf-16'"):
+            Tokenizer(position_encoding="utf-16")
+
+    def test_limits(self):
+        sql = "SELECT a, b FROM t"
+
+        self.assertEqual(len(Tokenizer(max_input_bytes=18, max_tokens=6).tokenize(sql)), 6)
+
+        with self.assertRaises(TokenLimitError) as cm:
+            Tokenizer(max_tokens=4).tokenize(sql)
+
+        error = cm.exception
+        self.assertEqual(
+            (error.limit, error.value, error.line, error.col, error.start),
+            ("max_tokens", 4, 1, 16, 12),
+        )
+
+        with self.assertRaises(TokenLimitError) as cm:
+            list(Tokenizer(max_input_bytes=10).stream("SELECT 'é'"))
+
+        error = cm.exception
+        self.assertEqual(
+            (error.limit, error.value, error.line, error.col, error.start),
+            ("max_input_bytes", 10, 1, 0, 0),
+        )
+        self.assertIn("The query has 11 bytes, more than max_input_bytes of 10", str(error))
+
+    def test_comment_tokens(self):
+        sql = "/* lead */ SELECT /*+ HINT */ a, -- one\nb /* two */ FROM t; SHOW x /* in */ -- end"
+        tokens = Tokenizer(dialect="spark", comment_tokens=True).tokenize(sql)
+
+        self.assertEqual(
+            [(token.token_type, token.text, token.start, token.end) for token in tokens],
+            [
+                (TokenType.STANDALONE_COMMENT, " lead ", 0, 9),
+                (TokenType.SELECT, "SELECT", 11, 16),
+                (TokenType.HINT, "/*+ HINT */", 18, 28),
+                (TokenType.VAR, "a", 30, 30),
+                (TokenType.COMMA, ",", 31, 31),
+                (TokenType.STANDALONE_COMMENT, " one", 33, 38),
+                (TokenType.VAR, "b", 40, 40),
+                (TokenType.STANDALONE_COMMENT, " two ", 42, 50),
+                (TokenType.FROM, "FROM", 52, 55),
+                (TokenType.VAR, "t", 57, 57),
+                (TokenType.SEMICOLON, ";", 58, 58),
+                (TokenType.SHOW, "SHOW", 60, 63),
+                (TokenType.STRING, "x /* in */ -- end", 76, 81),
+            ],
+        )
+        self.assertEqual(tokens[2].comments, [" HINT "])
+        self.assertTrue(all(not token.comments for token in tokens if token is not tokens[2]))
+        self.assertEqual((tokens[5].line, tokens[5].col), (1, 39))
+
+        # The other tokens are the same as when comments are attached to them
+        self.assertEqual(
+            [
+                (token.token_type, token.start)
+                for token in tokens
+                if token.token_type != TokenType.STANDALONE_COMMENT
+            ],
+            [(token.token_type, token.start) for token in Tokenizer("spark").tokenize(sql)],
+        )
+        self.assertEqual(
+            [token.text for token in Tokenizer(comment_tokens=True).stream(sql)],
+            [token.text for token in Tokenizer(comment_tokens=True).tokenize(sql)],
+        )
+
+    def test_hint_tokens(self):
+        sql = "SELECT /*+ BROADCAST(b), x */ a /*+ c */ FROM b"
+        tokens = Tokenizer(dialect="spark", hint_tokens=True).tokenize(sql)
+
+        self.assertEqual(
+            [(token.token_type, token.text, token.start, token.end) for token in tokens],
+            [
+                (TokenType.SELECT, "SELECT", 0, 5),
+                (TokenType.HINT, "/*+", 7, 9),
+                (TokenType.VAR, "BROADCAST", 11, 19),
+                (TokenType.L_PAREN, "(", 20, 20),
+                (TokenType.VAR, "b", 21, 21),
+                (TokenType.R_PAREN, ")", 22, 22),
+                (TokenType.COMMA, ",", 23, 23),
+                (TokenType.VAR, "x", 25, 25),
+                (TokenType.HINT_END, "*/", 27, 28),
+                (TokenType.VAR, "a", 30, 30),
+                (TokenType.FROM, "FROM", 41, 44),
+                (TokenType.VAR, "b", 46, 46),
+            ],
+        )
+        # Only hints have their bodies tokenized, other comments are still attached to tokens
+        self.assertEqual(tokens[9].comments, [" c "])
+
+        # The parser takes hints scanned as tokens as it takes those kept as comments
+        self.assertEqual(
+            Spark().parser().parse(tokens, sql)[0].sql("spark"),
+            "SELECT /*+ BROADCAST(b), X */ a /* c */ FROM b",
+        )
+
+    def test_iter_tokens(self):
+        sql = "; ".join(f"SELECT a{i} FROM t" for i in range(500))
+        tokenizer = Tokenizer()
+        chunks = list(tokenizer.iter_tokens(sql, chunk_size=300))
+
+        self.assertEqual([len(chunk) for chunk in chunks], [300] * 8 + [99])
+        self.assertEqual(
+            [(token.token_type, token.text, token.start) for chunk in chunks for token in chunk],
+            [(token.token_type, token.text, token.start) for token in tokenizer.tokenize(sql)],
+        )
+        self.assertEqual(list(tokenizer.iter_tokens("")), [])
+
+        with self.assertRaises(TokenError):
+            list(tokenizer.iter_tokens("SELECT a, b, 'c", chunk_size=2))
+
+        with self.assertRaises(ValueError):
+            list(tokenizer.iter_tokens(sql, chunk_size=0))
+
+    def test_tokenize_file(self):
+        sql = "; ".join(f"SELECT 'ü{i}' FROM t" for i in range(500))
+        tokenizer = Tokenizer()
+
+        with tempfile.TemporaryDirectory() as directory:
+            path = os.path.join(directory, "dump.sql")
+            with open(path, "w", encoding="utf-8") as file:
+                file.write(sql)
+
+            tokens = tokenizer.tokenize_file(path)
+            self.assertEqual(
+                [(token.token_type, token.text, token.start) for token in tokens],
+                [(token.token_type, token.text, token.start) for token in tokenizer.tokenize(sql)],
+            )
+
+            with open(path, "w", encoding="utf-8") as file:
+                file.write("")
+            self.assertEqual(list(tokenizer.tokenize_file(path)), [])
+
+            with open(path, "w", encoding="utf-8") as file:
+                file.write("SELECT a, b, 'c")
+            with self.assertRaises(TokenError):
+                list(tokenizer.tokenize_file(path))
+
+            # Compressed files are decompressed as they're scanned, a statement at a time, and
+            # their extensions are matched in any case
+            for name in ("dump.sql.gz", "DUMP.SQL.GZ"):
+                gzipped = os.path.join(directory, name)
+                with gzip.open(gzipped, "wt", encoding="utf-8") as file:
+                    file.write(sql)
+                try:
+                    tokens = list(tokenizer.tokenize_file(gzipped))
+                except OSError as e:
+                    self.skipTest(str(e))
+                self.assertEqual(
+                    [(token.token_type, token.text, token.start) for token in tokens],
+                    [
+                        (token.token_type, token.text, token.start)
+                        for token in tokenizer.tokenize(sql)
+                    ],
+                )
+
+    def test_tokenize_reader(self):
+        sql = "\n".join(
+            f"SELECT 'ü{i};\n' AS a, /* c; {i} */ b FROM t; -- {i}\nSHOW TABLES;  SELECT \"{i}\";"
+            for i in range(20)
+        )
+        tokenizer = Tokenizer()
+
+        def positions(tokens):
+            return [
+                (token.token_type, token.text, token.line, token.col, token.start, token.comments)
+                for token in tokens
+            ]
+
+        # Strings, comments and chars are cut short by chunks this small
+        for chunk_size in (1, 3, 64, 65536):
+            for reader in (io.BytesIO(sql.encode()), io.StringIO(sql)):
+                with self.subTest(chunk_size=chunk_size, reader=type(reader).__name__):
+                    self.assertEqual(
+                        positions(tokenizer.tokenize_reader(reader, chunk_size=chunk_size)),
+                        positions(tokenizer.tokenize(sql)),
+                    )
+
+        self.assertEqual(list(tokenizer.tokenize_reader(io.BytesIO(b""))), [])
+
+        sql = "SELECT 1;\n" * 20 + "SELECT 'c"
+        with self.assertRaises(TokenError) as cm:
+            list(tokenizer.tokenize_reader(io.BytesIO(sql.encode()), chunk_size=4))
+
+        # Messages point at where the scan stopped in the whole stream as well, while their
+        # context only reaches back to the last complete statement
+        with self.assertRaises(TokenError) as expected:
+            tokenizer.tokenize(sql)
+        self.assertEqual(
+            str(cm.exception).rsplit("': ", 1)[-1], str(expected.exception).rsplit("': ", 1)[-1]
+        )
+
+        with self.assertRaises(TokenLimitError) as cm:
+            list(Tokenizer(max_tokens=6).tokenize_reader(io.BytesIO(b"SELECT 1;\nSELECT a, b"), 2))
+
+        # The limit and the error's position apply to the whole stream rather than a statement
+        error = cm.exception
+        self.assertEqual((error.line, error.col, error.start), (2, 11, 20))
+
+        reader = io.BytesIO(b"SELECT 1")
+        reader.close()
+        with self.assertRaisesRegex(ValueError, "closed file"):
+            list(tokenizer.tokenize_reader(reader))
+
+    def test_tokenize_bytes(self):
+        sql = "SELECT 'café', \"naïve\" FROM t -- é\n"
+        tokenizer = Tokenizer()
+        expected = [
+            (token.token_type, token.text, token.start, token.comments)
+            for token in tokenizer.tokenize(sql)
+        ]
+
+        for encoding in ("utf-8", "latin-1", "utf-16", "UTF-16-LE", "utf_16_be", "cp1252"):
+            with self.subTest(encoding=encoding):
+                self.assertEqual(
+                    [
+                        (token.token_type, token.text, token.start, token.comments)
+                        for token in tokenizer.tokenize_bytes(sql.encode(encoding), encoding)
+                    ],
+                    expected,
+                )
+
+        # Malformed data is handled like bytes.decode handles it
+        data = "SELECT 'a'".encode("utf-16-le") + b"\x00\xdc"
+        self.assertEqual(
+            [token.text for token in tokenizer.tokenize_bytes(data, "utf-16-le")],
+            ["SELECT", "a", "�"],
+        )
+        self.assertEqual(
+            [token.text for token in tokenizer.tokenize_bytes(b"SELECT 'a\xff'", errors="ignore")],
+            ["SELECT", "a"],
+        )
+        with self.assertRaises(UnicodeDecodeError) as cm:
+            tokenizer.tokenize_bytes(b"SELECT 'a\xff'", errors="strict")
+        self.assertEqual((cm.exception.start, cm.exception.reason), (9, "invalid start byte"))
+
+    @unittest.skipUnless(importlib.util.find_spec("pyarrow"), "pyarrow isn't installed")
+    def test_tokenize_to_arrow(self):
+        sql = "SELECT ü, 'x' /* c */ FROM t"
+        tokenizer = Tokenizer()
+        batch = tokenizer.tokenize_to_arrow(sql)
+
+        self.assertEqual(batch.schema.names, ["token_type", "text", "line", "col", "start", "end"])
+        self.assertEqual(
+            list(zip(*(column.to_pylist() for column in batch.columns))),
+            [
+                (token.token_type.name, token.text, token.line, token.col, token.start, token.end)
+                for token in tokenizer.tokenize(sql)
+            ],
+        )
+        self.assertEqual(tokenizer.tokenize_to_arrow("").num_rows, 0)
+
+        with self.assertRaises(TokenError):
+            tokenizer.tokenize_to_arrow("SELECT 'x")
+
+    def test_tokenize_with_stats(self):
+        sql = "SELECT a, 'b' FROM t /* c */ ORDER BY t.a"
+        tokenizer = Tokenizer()
+        tokens, stats = tokenizer.tokenize_with_stats(sql)
+
+        self.assertEqual(
+            [token.text for token in tokens], [token.text for token in tokenizer.tokenize(sql)]
+        )
+        self.assertEqual(stats["bytes"], len(sql))
+        self.assertEqual(stats["tokens"], len(tokens))
+        self.assertGreaterEqual(stats["elapsed_ns"], 0)
+        if tokenizer.use_rs_tokenizer:
+            # The string, the comment and ORDER BY match, while single words are scanned as vars
+            self.assertEqual((stats["keyword_hits"], stats["keyword_misses"]), (3, 8))
+            self.assertGreater(stats["allocations"], 0)
+
+        with self.assertRaises(TokenError):
+            tokenizer.tokenize_with_stats("SELECT 'a")
+
+    def test_tokenize_with_trace(self):
+        from sqlglot import tokens
+
+        if not tokens.USE_RS_TOKENIZER:
+            self.skipTest("sqlglotrs is not installed or disabled")
+
+        tokenizer = Tokenizer()
+        scanned, trace = tokenizer.tokenize_with_trace("SELECT 1, 'a' ORDER BY x")
+        self.assertEqual(len(scanned), 6)
+        self.assertEqual(
+            [(step["kind"], step["position"], step["detail"]) for step in trace],
+            [
+                ("scan_keyword", 0, ""),
+                ("keyword_missed", 0, ""),
+                ("scan_var", 0, "SELECT"),
+                ("scan_number", 7, ""),
+                ("scan_keyword", 8, ""),
+                ("keyword_missed", 8, ""),
+                ("scan_keyword", 10, ""),
+                ("keyword_matched", 10, "'"),
+                ("scan_string", 10, "'"),
+                ("scan_keyword", 14, ""),
+                ("keyword_matched", 14, "ORDER BY"),
+                ("scan_keyword", 23, ""),
+                ("keyword_missed", 23, ""),
+                ("scan_var", 23, "x"),
+            ],
+        )
+
+        # A keyword the word goes on past is given up on
+        _, trace = tokenizer.tokenize_with_trace("ORDER BYx")
+        self.assertIn({"kind": "backtrack", "position": 0, "detail": "ORDER BY"}, trace)
+
+        with self.assertRaises(TokenError) as e:
+            tokenizer.tokenize_with_trace("SELECT 'a")
+        self.assertEqual(
+            e.exception.trace[-1], {"kind": "scan_string", "position": 7, "detail": "'"}
+        )
+
+    def test_register_escape_sequences(self):
+        sql = r"SELECT '\u00e9\u00e\101\n'"
+        tokenizer = BigQuery().tokenizer()
+        self.assertEqual(tokenizer.tokenize(sql)[1].text, "\\u00e9\\u00e\\101\n")
+
+        tokenizer.register_escape_sequences({"\\u00e9": "é", "\\u00e": "?", "\\101": "A"})
+        self.assertEqual(tokenizer.tokenize(sql)[1].text, "é?A\n")
+
+        # Other tokenizers of the dialect are unaffected
+        self.assertEqual(BigQuery().tokenizer().tokenize(sql)[1].text, "\\u00e9\\u00e\\101\n")
+
+        for sequence in ("u00e9", "\\"):
+            with self.subTest(sequence=sequence), self.assertRaises(ValueError):
+                tokenizer.register_escape_sequences({sequence: "é"})
+
+    def test_string_prefixes(self):
+        for tokenizer, sql, prefixes in (
+            (Tokenizer(), "SELECT N'a', n'b', 'c'", ["", "N", "", "n", "", ""]),
+            (BigQuery().tokenizer(), "r'a', R'''b''', b'c'", ["r", "", "R", "", "b"]),
+            (Postgres().tokenizer(), "e'a' X'0f' B'01' $t$x$t$", ["e", "X", "B", ""]),
+        ):
+            with self.subTest(sql=sql):
+                self.assertEqual([token.prefix for token in tokenizer.tokenize(sql)], prefixes)
+
+    def test_verbatim_strings(self):
+        class VerbatimTokenizer(BigQuery.Tokenizer):
+            VERBATIM_STRINGS = {"r'", "R'"}
+
+        sql = r"""r'a\\b\' 'c\'d' r"e\"f" """
+        self.assertEqual(
+            [token.text for token in VerbatimTokenizer().tokenize(sql)],
+            ["a\\\\b\\", "c'd", 'e"f'],
+        )
+        # Raw strings still escape their closing quote unless they're verbatim
+        tokens = BigQuery().tokenizer().tokenize(r"r'a\'b'")
+        self.assertEqual([token.text for token in tokens], ["a'b"])
+
+    def test_placeholder_styles(self):
+        class PlaceholderTokenizer(Tokenizer):
+            PLACEHOLDER_STYLES = {"numeric", "named", "format", "pyformat", "dollar", "at", "brace"}
+
+        sql = "SELECT :1, :name, %s, %(name)s, $2, @name, {name}, x::INT, @@y, {{z}}, a % s"
+        self.assertEqual(
+            [
+                token.text
+                for token in PlaceholderTokenizer().tokenize(sql)
+                if token.token_type == TokenType.PLACEHOLDER
+            ],
+            [":1", ":name", "%s", "%(name)s", "$2", "@name", "{name}"],
+        )
+
+        class NamedTokenizer(Tokenizer):
+            PLACEHOLDER_STYLES = {"named"}
+
+        tokens = NamedTokenizer().tokenize("SELECT :name, :1, :1a")
+        self.assertEqual(
+            [(token.token_type, token.text) for token in tokens],
+            [
+                (TokenType.SELECT, "SELECT"),
+                (TokenType.PLACEHOLDER, ":name"),
+                (TokenType.COMMA, ","),
+                (TokenType.COLON, ":"),
+                (TokenType.NUMBER, "1"),
+                (TokenType.COMMA, ","),
+                (TokenType.COLON, ":"),
+                (TokenType.NUMBER, "1"),
+                (TokenType.VAR, "a"),
+            ],
+        )
+
+    def test_suggest_keyword(self):
+        for use_rs_tokenizer in (False, True):
+            if use_rs_tokenizer and not Tokenizer._RS_TOKENIZER:
+                continue
+
+            tokenizer = Tokenizer(use_rs_tokenizer=use_rs_tokenizer)
+            with self.subTest(use_rs_tokenizer=use_rs_tokenizer):
+                self.assertEqual(tokenizer.suggest_keyword("group bt", 1), ["GROUP BY"])
+                self.assertEqual(tokenizer.suggest_keyword("SELCT", 1), ["SELECT"])
+                self.assertEqual(
+                    tokenizer.suggest_keyword("form"), ["FOR", "FORMAT", "FROM", "OR", "XOR"]
+                )
+                self.assertEqual(tokenizer.suggest_keyword("::", 1), [])
+                self.assertEqual(tokenizer.suggest_keyword("XYZZY", 0), [])
+
+    def test_verify_roundtrip(self):
+        tokenizer = Tokenizer()
+        for sql in (
+            "",
+            "SELECT a /* b /* c */ */ FROM t -- d\n",
+            "\ufeffSELECT 1::INT, 'x' ORDER BY 1",
+        ):
+            with self.subTest(sql=sql):
+                self.assertIsNone(tokenizer.verify_roundtrip(sql, tokenizer.tokenize(sql)))
+
+        sql = "SELECT a, b"
+        select, a, comma, b = tokenizer.tokenize(sql)
+        self.assertEqual(
+            tokenizer.verify_roundtrip(sql, [select, comma, b]),
+            {
+                "kind": "untokenized",
+                "index": 1,
+                "start": 7,
+                "end": 8,
+                "message": "'a' at 7-8 is neither trivia nor part of a token",
+            },
+        )
+        self.assertEqual(tokenizer.verify_roundtrip(sql, [select, a, comma])["index"], 3)
+        for query, scanned, kind in (
+            (sql, [select, a, comma, b, comma], "out_of_order"),
+            (sql, [select, Token(TokenType.VAR, "LECT", start=2, end=5)], "overlap"),
+            ("SELECT", [select, a], "out_of_bounds"),
+        ):
+            with self.subTest(kind=kind):
+                self.assertEqual(tokenizer.verify_roundtrip(query, scanned)["kind"], kind)
+
     def test_error_msg(self):
         with self.assertRaisesRegex(TokenError, "Error tokenizing 'select /'"):
             Tokenizer().tokenize("select /*")
@@ -208,3 +931,254 @@ x"""
             repr(Tokenizer().tokenize("foo")),
             "[<Token token_type: TokenType.VAR, text: foo, line: 1, col: 3, start: 0, end: 2, comments: []>]",
         )
+
+    def test_token_extras(self):
+        tokens = Tokenizer().tokenize("SELECT a")
+        self.assertEqual(tokens[0].extras, {})
+
+        tokens[0].extras["lint"] = ["select-star"]
+        self.assertEqual(tokens[0].extras, {"lint": ["select-star"]})
+        self.assertEqual(tokens[1].extras, {})
+
+        tokens[1].extras = {"k": 1}
+        self.assertEqual(tokens[1].extras, {"k": 1})
+        tokens[1].extras = None
+        self.assertEqual(tokens[1].extras, {})
+
+        self.assertEqual(Token.var("x").extras, {})
+        self.assertNotIn("extras", repr(tokens))
+
+    def test_token_freeze(self):
+        tokens = Tokenizer().tokenize("SELECT a /*c*/, a")
+        tokens[1].extras["k"] = 1
+        frozen = [token.freeze() for token in tokens]
+
+        self.assertEqual(
+            repr(frozen[1]),
+            "<FrozenToken token_type: TokenType.VAR, text: a, line: 1, col: 8, start: 7, end: 7, "
+            "comments: ('c',)>",
+        )
+        self.assertEqual(frozen[1].comments, ("c",))
+        self.assertEqual(dict(frozen[1].extras), {"k": 1})
+
+        # Tokens are compared by their type, text, position and comments, but not their extras
+        self.assertEqual(frozen[1], tokens[1].freeze())
+        self.assertEqual(hash(frozen[1]), hash(tokens[1].freeze()))
+        self.assertNotEqual(frozen[1], frozen[3])
+        self.assertNotEqual(frozen[1], tokens[1])
+        tokens[3].extras["k"] = 2
+        self.assertEqual(frozen[3], tokens[3].freeze())
+        self.assertEqual(len({*frozen, *(token.freeze() for token in tokens)}), 4)
+
+        with self.assertRaises(AttributeError):
+            frozen[1].text = "b"  # type: ignore
+        with self.assertRaises(AttributeError):
+            frozen[1].comments = ()  # type: ignore
+        with self.assertRaises(TypeError):
+            frozen[1].extras["k"] = 2  # type: ignore
+
+        # Frozen tokens don't change along with the token they were frozen from
+        tokens[1].comments.append("d")
+        tokens[1].extras["k"] = 2
+        self.assertEqual(frozen[1].comments, ("c",))
+        self.assertEqual(frozen[1].extras["k"], 1)
+        self.assertNotEqual(frozen[1], tokens[1].freeze())
+
+    def test_rs_token_extras(self):
+        tokenizer = Tokenizer()
+        if not tokenizer.use_rs_tokenizer:
+            self.skipTest("sqlglotrs is not installed")
+
+        sql = "SELECT a FROM t"
+        tokens = tokenizer.tokenize(sql)
+        self.assertEqual(tokens[3].extras, {})
+        tokens[3].extras["k"] = [1]
+        self.assertEqual(tokens[3].extras, {"k": [1]})
+        tokens[3].extras = None
+        self.assertEqual(tokens[3].extras, {})
+        tokens[3].extras["k"] = [1]
+
+        # Tokens moved by an edit keep a copy of their extras
+        retokenized, error = tokenizer._RS_TOKENIZER.retokenize(
+            sql, tokens, 7, 8, "bb", tokenizer._rs_dialect_settings
+        )
+        self.assertIsNone(error)
+        self.assertEqual(retokenized[3].start, 15)
+        self.assertEqual(retokenized[3].extras, {"k": [1]})
+        retokenized[3].extras["k"] = [2]
+        self.assertEqual(tokens[3].extras, {"k": [1]})
+
+        frozen = tokens[3].freeze()
+        self.assertEqual(dict(frozen.extras), {"k": [1]})
+        with self.assertRaises(TypeError):
+            frozen.extras["k"] = [2]
+        tokens[3].extras["l"] = 2
+        self.assertNotIn("l", frozen.extras)
+        self.assertEqual(dict(tokens[0].freeze().extras), {})
+
+    def test_token_type_ids(self):
+        for token_type in (TokenType.L_PAREN, TokenType.SELECT, TokenType.HIVE_TOKEN_STREAM):
+            self.assertEqual(TokenType.from_int(token_type.to_int()), token_type)
+
+        self.assertEqual(TokenType.L_PAREN.to_int(), 0)
+
+        with self.assertRaisesRegex(ValueError, "reserved for custom token types"):
+            TokenType.from_int(CUSTOM_TOKEN_TYPE_START)
+
+        with self.assertRaisesRegex(ValueError, "Unknown token type id"):
+            TokenType.from_int(-1)
+
+    def test_rs_interned_texts(self):
+        tokenizer = Tokenizer()
+        if not tokenizer.use_rs_tokenizer:
+            self.skipTest("sqlglotrs is not installed")
+
+        tokens = tokenizer.tokenize("SELECT a, a FROM t WHERE a = 'a'")
+        self.assertIs(tokens[1].text, tokens[3].text)
+        self.assertIs(tokens[1].text, tokens[7].text)
+        self.assertIs(tokens[1].text, tokens[9].text)
+
+        long = "x" * 100
+        tokens = tokenizer.tokenize(f"SELECT '{long}', '{long}'")
+        self.assertEqual(tokens[1].text, tokens[3].text)
+
+    def test_rs_presets(self):
+        from sqlglot import tokens
+        from sqlglot.dialects.dialect import Dialect
+
+        if not hasattr(tokens, "RsTokenizer"):
+            self.skipTest("sqlglotrs is not installed")
+
+        from sqlglotrs import PRESET_DIALECTS
+
+        # The presets have to be regenerated with sqlglotrs/gen_presets.py when a dialect changes
+        for name in PRESET_DIALECTS:
+            with self.subTest(dialect=name):
+                dialect = Dialect.get_or_raise(name)
+                tokenizer = dialect.tokenizer_class
+                self.assertEqual(
+                    tokens.RsTokenizerSettings.for_dialect(name),
+                    tokens.RsTokenizerSettings(**tokens._rs_tokenizer_settings(tokenizer)),
+                )
+                self.assertEqual(
+                    tokens.RsTokenTypeSettings.for_dialect(name),
+                    tokens.RsTokenTypeSettings(**tokens._rs_token_type_settings(tokenizer)),
+                )
+                self.assertEqual(
+                    tokens.RsTokenizerDialectSettings.for_dialect(name),
+                    tokens.RsTokenizerDialectSettings(**tokens._rs_dialect_settings(dialect)),
+                )
+
+    def test_rs_cached_tokenizers(self):
+        from sqlglot import tokens
+        from sqlglot.dialects.dialect import Dialect
+
+        if not hasattr(tokens, "RsTokenizer"):
+            self.skipTest("sqlglotrs is not installed")
+
+        def definition(tokenizer_class):
+            return (
+                tokens.RsTokenizerSettings(**tokens._rs_tokenizer_settings(tokenizer_class)),
+                tokens.RsTokenTypeSettings(**tokens._rs_token_type_settings(tokenizer_class)),
+            )
+
+        def token_types(tokenizer):
+            scanned, _ = tokenizer.tokenize("SELECT $$x$$", dialect_settings)
+            return [token.token_type_index for token in scanned]
+
+        dialect_settings = tokens.RsTokenizerDialectSettings(
+            **tokens._rs_dialect_settings(Dialect())
+        )
+        first = tokens.RsTokenizer.cached(*definition(Tokenizer))
+        second = tokens.RsTokenizer.cached(*definition(Tokenizer))
+        self.assertEqual(token_types(first), token_types(second))
+
+        # Cached tokenizers share their definition, but reloading one leaves the others be
+        default_token_types = token_types(second)
+        first.reload(*definition(Postgres.Tokenizer))
+        self.assertNotEqual(token_types(first), default_token_types)
+        self.assertEqual(token_types(second), default_token_types)
+        self.assertEqual(
+            token_types(tokens.RsTokenizer.cached(*definition(Tokenizer))), default_token_types
+        )
+
+    def test_rs_conformance(self):
+        from sqlglot import tokens
+
+        if not tokens.USE_RS_TOKENIZER:
+            self.skipTest("sqlglotrs is not installed or disabled")
+
+        def texts(tokens):
+            return [(token.token_type, token.text, token.comments) for token in tokens]
+
+        sql = "SELECT a, b FROM t WHERE c = 'x' -- d"
+        reference = texts(Tokenizer(use_rs_tokenizer=False).tokenize(sql))
+        self.assertEqual(
+            texts(Tokenizer(use_rs_tokenizer=True, conformance="raise").tokenize(sql)), reference
+        )
+
+        # Both tokenizers failing is an agreement, which raises their error as usual
+        with self.assertRaises(TokenError) as e:
+            Tokenizer(use_rs_tokenizer=True, conformance="raise").tokenize("SELECT 'x")
+        self.assertNotIsInstance(e.exception, TokenizerConformanceError)
+
+        tokenize_rs = Tokenizer.tokenize_rs
+
+        def diverging_tokenize_rs(self, sql):
+            return [
+                tokens.Token(
+                    token.token_type,
+                    "B" if token.text == "b" else token.text,
+                    token.line,
+                    token.col,
+                    token.start,
+                    token.end,
+                    token.comments,
+                    start_line=token.start_line,
+                    start_column=token.start_column,
+                    start_byte=token.start_byte,
+                    end_byte=token.end_byte,
+                )
+                for token in tokenize_rs(self, sql)
+            ]
+
+        with mock.patch.object(Tokenizer, "tokenize_rs", diverging_tokenize_rs):
+            with self.assertRaises(TokenizerConformanceError) as e:
+                Tokenizer(use_rs_tokenizer=True, conformance="raise").tokenize(sql)
+            self.assertEqual(e.exception.sql, "b")
+
+            with self.assertLogs("sqlglot", level="WARNING") as logs:
+                tokenized = Tokenizer(use_rs_tokenizer=True, conformance="log").tokenize(sql)
+            self.assertIn("disagree on 'b'", logs.output[0])
+            self.assertEqual(texts(tokenized), reference)
+
+        with self.assertRaises(ValueError):
+            Tokenizer(conformance="warn")
+
+    def test_rs_tokenizer_snapshots(self):
+        from sqlglot import tokens
+        from sqlglot.dialects.dialect import Dialect
+
+        if not hasattr(tokens, "RsTokenizer"):
+            self.skipTest("sqlglotrs is not installed")
+
+        def scan(tokenizer, sql):
+            scanned, error = tokenizer.tokenize(sql, dialect_settings)
+            self.assertIsNone(error)
+            return [(token.token_type_index, token.text, token.start) for token in scanned]
+
+        sql = "SELECT $tag$x$tag$, 'a''b', x::INT /* c */ FROM t LEFT OUTER JOIN u"
+        for dialect in ("", "postgres", "bigquery", "mysql"):
+            with self.subTest(dialect=dialect):
+                dialect_settings = tokens.RsTokenizerDialectSettings(
+                    **tokens._rs_dialect_settings(Dialect.get_or_raise(dialect or None))
+                )
+                tokenizer = tokens.RsTokenizer.for_dialect(dialect)
+                snapshot = tokenizer.to_snapshot()
+                loaded = tokens.RsTokenizer.from_snapshot(snapshot)
+                self.assertEqual(scan(loaded, sql), scan(tokenizer, sql))
+                self.assertEqual(loaded.to_snapshot(), snapshot)
+
+        for invalid in (b"", b"not a snapshot", snapshot[:-1], snapshot + b"\x00"):
+            with self.assertRaises(ValueError):
+                tokens.RsTokenizer.from_snapshot(invalid)